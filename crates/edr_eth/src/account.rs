@@ -9,7 +9,7 @@
 use alloy_rlp::{RlpDecodable, RlpEncodable};
 pub use revm_primitives::KECCAK_EMPTY;
 
-use crate::{trie::KECCAK_NULL_RLP, AccountInfo, B256, U256};
+use crate::{trie::KECCAK_NULL_RLP, AccountInfo, Address, Bytes, B256, U256};
 
 /// Basic account type.
 #[derive(Debug, Clone, PartialEq, Eq, RlpDecodable, RlpEncodable)]
@@ -56,3 +56,38 @@ impl From<(&AccountInfo, B256)> for BasicAccount {
         }
     }
 }
+
+/// Merkle proof of an account and a selection of its storage slots, as
+/// specified by EIP-1186.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct AccountProof {
+    /// Address of the account.
+    pub address: Address,
+    /// RLP-encoded trie nodes from the state root to the account.
+    pub account_proof: Vec<Bytes>,
+    /// Balance of the account.
+    pub balance: U256,
+    /// Code hash of the account.
+    pub code_hash: B256,
+    /// Nonce of the account.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde::u64"))]
+    pub nonce: u64,
+    /// Storage root of the account.
+    pub storage_hash: B256,
+    /// Proofs of the requested storage slots.
+    pub storage_proof: Vec<StorageProof>,
+}
+
+/// Merkle proof of a storage slot, as specified by EIP-1186.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct StorageProof {
+    /// Index of the storage slot.
+    pub key: U256,
+    /// Value of the storage slot.
+    pub value: U256,
+    /// RLP-encoded trie nodes from the storage root to the storage slot.
+    pub proof: Vec<Bytes>,
+}
@@ -10,7 +10,7 @@ mod reorg;
 mod reward;
 
 use alloy_rlp::{BufMut, Decodable, RlpDecodable, RlpEncodable};
-use revm_primitives::{calc_excess_blob_gas, keccak256};
+use revm_primitives::{calc_blob_gasprice, calc_excess_blob_gas, keccak256};
 
 use self::difficulty::calculate_ethash_canonical_difficulty;
 pub use self::{
@@ -253,15 +253,7 @@ impl PartialHeader {
             }),
             blob_gas: options.blob_gas.or_else(|| {
                 if spec_id >= SpecId::CANCUN {
-                    let excess_gas = parent.and_then(|parent| parent.blob_gas.as_ref()).map_or(
-                        // For the first (post-fork) block, both parent.blob_gas_used and
-                        // parent.excess_blob_gas are evaluated as 0.
-                        0,
-                        |BlobGas {
-                             gas_used,
-                             excess_gas,
-                         }| calc_excess_blob_gas(*excess_gas, *gas_used),
-                    );
+                    let excess_gas = parent.map_or(0, calculate_next_excess_blob_gas);
 
                     Some(BlobGas {
                         gas_used: 0,
@@ -369,6 +361,26 @@ pub fn calculate_next_base_fee(parent: &Header) -> U256 {
     }
 }
 
+/// Calculates the excess blob gas for a post-Cancun block, given the parent's
+/// header.
+pub fn calculate_next_excess_blob_gas(parent: &Header) -> u64 {
+    parent.blob_gas.as_ref().map_or(
+        // For the first (post-fork) block, both parent.blob_gas_used and
+        // parent.excess_blob_gas are evaluated as 0.
+        0,
+        |BlobGas {
+             gas_used,
+             excess_gas,
+         }| calc_excess_blob_gas(*excess_gas, *gas_used),
+    )
+}
+
+/// Calculates the blob base fee for a post-Cancun block, given the parent's
+/// header.
+pub fn calculate_next_blob_base_fee(parent: &Header) -> U256 {
+    U256::from(calc_blob_gasprice(calculate_next_excess_blob_gas(parent)))
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -603,4 +615,22 @@ mod tests {
         assert_eq!(encoded, expected_encoding);
         assert_eq!(header.hash(), expected_hash);
     }
+
+    #[test]
+    fn next_blob_base_fee() {
+        let mut parent = Header::default();
+
+        // Pre-Cancun parents are treated as having no blob gas
+        assert_eq!(calculate_next_excess_blob_gas(&parent), 0);
+        assert_eq!(calculate_next_blob_base_fee(&parent), U256::from(1));
+
+        parent.blob_gas = Some(BlobGas {
+            gas_used: 0x080000u64,
+            excess_gas: 0x220000u64,
+        });
+
+        // The parent used 0x20000 blob gas more than the target of 0x60000
+        assert_eq!(calculate_next_excess_blob_gas(&parent), 0x240000u64);
+        assert_eq!(calculate_next_blob_base_fee(&parent), U256::from(2));
+    }
 }
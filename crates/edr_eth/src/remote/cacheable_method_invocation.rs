@@ -50,6 +50,8 @@ enum CacheableRequestMethod<'a> {
         /// include transaction data
         include_tx_data: bool,
     },
+    /// eth_getBlockReceipts
+    GetBlockReceipts { block_spec: CacheableBlockSpec<'a> },
     /// eth_getCode
     GetCode {
         address: &'a Address,
@@ -106,6 +108,9 @@ impl<'a> CacheableRequestMethod<'a> {
                     block_hash: _,
                     include_tx_data: _,
                 } => Some(WriteCacheKey::finalize(hasher)),
+                CacheableRequestMethod::GetBlockReceipts { block_spec } => {
+                    WriteCacheKey::needs_safety_check(hasher, block_spec)
+                }
                 CacheableRequestMethod::GetCode {
                     address: _,
                     block_spec,
@@ -177,6 +182,11 @@ impl<'a> TryFrom<&'a RequestMethod> for CacheableRequestMethod<'a> {
                     include_tx_data: *include_tx_data,
                 })
             }
+            RequestMethod::GetBlockReceipts(block_spec) => {
+                Ok(CacheableRequestMethod::GetBlockReceipts {
+                    block_spec: block_spec.try_into()?,
+                })
+            }
             RequestMethod::GetCode(address, block_spec) => Ok(CacheableRequestMethod::GetCode {
                 address,
                 block_spec: block_spec.try_into()?,
@@ -207,8 +217,11 @@ impl<'a> TryFrom<&'a RequestMethod> for CacheableRequestMethod<'a> {
 
             // Explicit to make sure if a new method is added, it is not forgotten here.
             // Chain id is not cacheable since a remote might change its chain id e.g. if it's a
-            // forked node running on localhost.
-            RequestMethod::BlockNumber(_) | RequestMethod::ChainId(_) => {
+            // forked node running on localhost. Proofs are only requested on demand and are
+            // not worth caching.
+            RequestMethod::BlockNumber(_)
+            | RequestMethod::ChainId(_)
+            | RequestMethod::GetProof(_, _, _) => {
                 Err(MethodNotCacheableError::RequestMethod(value.clone()))
             }
         }
@@ -720,6 +733,9 @@ impl Hasher {
                 block_hash,
                 include_tx_data,
             } => this.hash_b256(block_hash).hash_bool(include_tx_data),
+            CacheableRequestMethod::GetBlockReceipts { block_spec } => {
+                this.hash_block_spec(block_spec)?
+            }
             CacheableRequestMethod::GetCode {
                 address,
                 block_spec,
@@ -810,6 +826,7 @@ impl<'a> CacheKeyVariant for &'a CacheableRequestMethod<'a> {
             CacheableRequestMethod::GetTransactionReceipt { .. } => 13,
             CacheableRequestMethod::NetVersion => 14,
             CacheableRequestMethod::FeeHistory { .. } => 15,
+            CacheableRequestMethod::GetBlockReceipts { .. } => 16,
         }
     }
 }
@@ -970,4 +987,22 @@ mod test {
 
         assert_eq!(key_one, key_two);
     }
+
+    #[test]
+    fn test_get_block_receipts_needs_safety_check() {
+        let method = RequestMethod::GetBlockReceipts(BlockSpec::Number(1));
+        assert!(matches!(
+            try_write_cache_key(&method),
+            Some(WriteCacheKey::NeedsSafetyCheck(
+                CacheKeyForUncheckedBlockNumber {
+                    block_number: 1,
+                    ..
+                }
+            ))
+        ));
+
+        let method = RequestMethod::GetBlockReceipts(BlockSpec::latest());
+        assert!(try_read_cache_key(&method).is_none());
+        assert!(try_write_cache_key(&method).is_none());
+    }
 }
@@ -5,7 +5,10 @@ use std::{
     fmt::Debug,
     io,
     path::{Path, PathBuf},
-    sync::atomic::{AtomicU64, Ordering},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
     thread::available_parallelism,
     time::{Duration, Instant},
 };
@@ -33,6 +36,7 @@ use super::{
 };
 pub use crate::remote::client::reqwest_error::{MiddlewareError, ReqwestError};
 use crate::{
+    account::AccountProof,
     block::{block_time, is_safe_block_number, IsSafeBlockNumberArgs},
     log::FilterLog,
     receipt::BlockReceipt,
@@ -168,7 +172,7 @@ pub struct Request<RequestMethod> {
 /// it with local nodes.
 #[derive(Debug)]
 pub struct RpcClient {
-    url: url::Url,
+    url: Mutex<url::Url>,
     chain_id: OnceCell<u64>,
     cached_block_number: RwLock<Option<CachedBlockNumber>>,
     client: ClientWithMiddleware,
@@ -224,7 +228,7 @@ impl RpcClient {
         let tmp_dir = rpc_cache_dir.join(TMP_DIR);
 
         Ok(RpcClient {
-            url: url.parse()?,
+            url: Mutex::new(url.parse()?),
             chain_id: OnceCell::new(),
             cached_block_number: RwLock::new(None),
             client,
@@ -234,6 +238,20 @@ impl RpcClient {
         })
     }
 
+    /// Replaces the URL of the remote node. Subsequent requests are sent to the
+    /// new URL, which is expected to serve the same chain.
+    pub fn set_url(&self, url: &str) -> Result<(), RpcClientError> {
+        let url: url::Url = url.parse()?;
+        *self.url.lock().expect("Mutex is not poisoned") = url;
+
+        Ok(())
+    }
+
+    /// Retrieves the URL of the remote node.
+    pub fn url(&self) -> url::Url {
+        self.url.lock().expect("Mutex is not poisoned").clone()
+    }
+
     fn parse_response_str<T: DeserializeOwned>(response: &str) -> Result<T, RpcClientError> {
         serde_json::from_str(response).map_err(|error| RpcClientError::InvalidResponse {
             response: response.to_string(),
@@ -260,8 +278,9 @@ impl RpcClient {
     async fn make_cache_path(&self, cache_key: &str) -> Result<PathBuf, RpcClientError> {
         let chain_id = self.chain_id().await?;
 
-        let host = self.url.host_str().unwrap_or("unknown-host");
-        let remote = if let Some(port) = self.url.port() {
+        let url = self.url();
+        let host = url.host_str().unwrap_or("unknown-host");
+        let remote = if let Some(port) = url.port() {
             // Include the port if it's not the default port for the protocol.
             format!("{host}_{port}")
         } else {
@@ -486,7 +505,7 @@ impl RpcClient {
         request_body: &SerializedRequest,
     ) -> Result<String, RpcClientError> {
         self.client
-            .post(self.url.clone())
+            .post(self.url())
             .body(request_body.to_json_string())
             .send()
             .await
@@ -727,7 +746,7 @@ impl RpcClient {
         let chain_id = *self
             .chain_id
             .get_or_try_init(|| async {
-                if let Some(chain_id) = chain_id_from_url(&self.url) {
+                if let Some(chain_id) = chain_id_from_url(&self.url()) {
                     Ok(chain_id)
                 } else {
                     self.call_without_cache::<U64>(RequestMethod::ChainId(()))
@@ -768,7 +787,7 @@ impl RpcClient {
         // Only request the chain id if we don't have it yet.
         let mut maybe_chain_id_from_url = None;
         if !self.chain_id.initialized() {
-            maybe_chain_id_from_url = chain_id_from_url(&self.url);
+            maybe_chain_id_from_url = chain_id_from_url(&self.url());
             if maybe_chain_id_from_url.is_none() {
                 inputs.push(RequestMethod::ChainId(()));
             }
@@ -919,6 +938,15 @@ impl RpcClient {
         .await
     }
 
+    /// Calls `eth_getBlockReceipts`.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip(self)))]
+    pub async fn get_block_receipts(
+        &self,
+        block_spec: BlockSpec,
+    ) -> Result<Option<Vec<BlockReceipt>>, RpcClientError> {
+        self.call(RequestMethod::GetBlockReceipts(block_spec)).await
+    }
+
     /// Calls `eth_getLogs` using a starting and ending block (inclusive).
     #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip(self)))]
     pub async fn get_logs_by_range(
@@ -992,6 +1020,18 @@ impl RpcClient {
             .collect()
     }
 
+    /// Calls `eth_getProof`.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip(self)))]
+    pub async fn get_proof(
+        &self,
+        address: &Address,
+        storage_keys: Vec<U256>,
+        block: Option<BlockSpec>,
+    ) -> Result<AccountProof, RpcClientError> {
+        self.call(RequestMethod::GetProof(*address, storage_keys, block))
+            .await
+    }
+
     /// Calls `eth_getStorageAt`.
     #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip(self)))]
     pub async fn get_storage_at(
@@ -1790,6 +1830,27 @@ mod tests {
             }
         }
 
+        #[tokio::test]
+        async fn get_block_receipts_some() {
+            let alchemy_url = get_alchemy_url();
+
+            let transaction_hash = B256::from_str(
+                "0xc008e9f9bb92057dd0035496fbf4fb54f66b4b18b370928e46d6603933054d5a",
+            )
+            .expect("failed to parse hash from string");
+
+            let receipts = TestRpcClient::new(&alchemy_url)
+                .get_block_receipts(BlockSpec::Number(0xa74fde))
+                .await
+                .expect("failed to get block receipts")
+                .expect("block should exist");
+
+            assert!(receipts
+                .iter()
+                .all(|receipt| receipt.block_number == 0xa74fde));
+            assert_eq!(receipts[136].transaction_hash, transaction_hash);
+        }
+
         #[tokio::test]
         async fn get_transaction_receipt_some() {
             let alchemy_url = get_alchemy_url();
@@ -99,6 +99,8 @@ pub enum FilteredEvents {
     NewHeads(Vec<B256>),
     /// new pending transactions
     NewPendingTransactions(Vec<B256>),
+    /// transactions dropped from the mempool
+    DroppedTransactions(Vec<B256>),
 }
 
 impl FilteredEvents {
@@ -108,6 +110,7 @@ impl FilteredEvents {
             Self::Logs(v) => Self::Logs(take(v)),
             Self::NewHeads(v) => Self::NewHeads(take(v)),
             Self::NewPendingTransactions(v) => Self::NewPendingTransactions(take(v)),
+            Self::DroppedTransactions(v) => Self::DroppedTransactions(take(v)),
         }
     }
 
@@ -117,6 +120,7 @@ impl FilteredEvents {
             Self::Logs(_) => SubscriptionType::Logs,
             Self::NewHeads(_) => SubscriptionType::NewHeads,
             Self::NewPendingTransactions(_) => SubscriptionType::NewPendingTransactions,
+            Self::DroppedTransactions(_) => SubscriptionType::DroppedTransactions,
         }
     }
 }
@@ -132,6 +136,9 @@ pub enum SubscriptionType {
     /// Induces the emission of transaction hashes that are sent to the network
     /// and marked as "pending".
     NewPendingTransactions,
+    /// Induces the emission of transaction hashes that are dropped from the
+    /// mempool, e.g. due to eviction or expiry.
+    DroppedTransactions,
 }
 
 impl serde::Serialize for SubscriptionType {
@@ -143,6 +150,7 @@ impl serde::Serialize for SubscriptionType {
             SubscriptionType::Logs => "logs",
             SubscriptionType::NewHeads => "newHeads",
             SubscriptionType::NewPendingTransactions => "newPendingTransactions",
+            SubscriptionType::DroppedTransactions => "droppedTransactions",
         })
     }
 }
@@ -168,6 +176,7 @@ impl<'a> serde::Deserialize<'a> for SubscriptionType {
                     "logs" => Ok(SubscriptionType::Logs),
                     "newHeads" => Ok(SubscriptionType::NewHeads),
                     "newPendingTransactions" => Ok(SubscriptionType::NewPendingTransactions),
+                    "droppedTransactions" => Ok(SubscriptionType::DroppedTransactions),
                     _ => Err(serde::de::Error::custom("Invalid subscription type")),
                 }
             }
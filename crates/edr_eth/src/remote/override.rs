@@ -28,3 +28,40 @@ pub struct AccountOverrideOptions {
 
 /// Type representing a full set of overrides for account information.
 pub type StateOverrideOptions = HashMap<Address, AccountOverrideOptions>;
+
+/// Options for overriding the block environment of a call.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockOverrideOptions {
+    /// Block number override.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::serde::optional_u64"
+    )]
+    pub number: Option<u64>,
+    /// Block timestamp override.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::serde::optional_u64",
+        alias = "time"
+    )]
+    pub timestamp: Option<u64>,
+    /// Base fee per gas override.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_fee: Option<U256>,
+    /// Beneficiary override.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coinbase: Option<Address>,
+    /// Previous RANDAO override.
+    #[serde(default, skip_serializing_if = "Option::is_none", alias = "random")]
+    pub prev_randao: Option<B256>,
+    /// Block gas limit override.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::serde::optional_u64"
+    )]
+    pub gas_limit: Option<u64>,
+}
@@ -53,6 +53,9 @@ pub enum RequestMethod {
         /// include transaction data
         bool,
     ),
+    /// eth_getBlockReceipts
+    #[serde(rename = "eth_getBlockReceipts", with = "crate::serde::sequence")]
+    GetBlockReceipts(BlockSpec),
     /// eth_getCode
     #[serde(rename = "eth_getCode")]
     GetCode(
@@ -66,6 +69,18 @@ pub enum RequestMethod {
     /// eth_getLogs
     #[serde(rename = "eth_getLogs", with = "crate::serde::sequence")]
     GetLogs(LogFilterOptions),
+    /// eth_getProof
+    #[serde(rename = "eth_getProof")]
+    GetProof(
+        Address,
+        /// storage keys
+        Vec<U256>,
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            default = "optional_block_spec::latest"
+        )]
+        Option<BlockSpec>,
+    ),
     /// eth_getStorageAt
     #[serde(rename = "eth_getStorageAt")]
     GetStorageAt(
@@ -108,8 +123,10 @@ impl RequestMethod {
             Self::GetBalance(_, _) => "eth_getBalance",
             Self::GetBlockByNumber(_, _) => "eth_getBlockByNumber",
             Self::GetBlockByHash(_, _) => "eth_getBlockByHash",
+            Self::GetBlockReceipts(_) => "eth_getBlockReceipts",
             Self::GetCode(_, _) => "eth_getCode",
             Self::GetLogs(_) => "eth_getLogs",
+            Self::GetProof(_, _, _) => "eth_getProof",
             Self::GetStorageAt(_, _, _) => "eth_getStorageAt",
             Self::GetTransactionByHash(_) => "eth_getTransactionByHash",
             Self::GetTransactionCount(_, _) => "eth_getTransactionCount",
@@ -33,7 +33,7 @@ impl Eip4844TransactionRequest {
     pub fn hash(&self) -> B256 {
         let encoded = alloy_rlp::encode(self);
 
-        keccak256(envelop_bytes(2, &encoded))
+        keccak256(envelop_bytes(3, &encoded))
     }
 
     pub fn sign(self, private_key: &SecretKey) -> Result<Eip4844SignedTransaction, SignatureError> {
@@ -130,6 +130,16 @@ pub(crate) mod tests {
         }
     }
 
+    #[test]
+    fn test_eip4844_transaction_request_hash() {
+        let expected =
+            B256::from_str("0x683122a30d816f961d2b9a3af1c5b59445288228c371531184752a93c18e8ac9")
+                .unwrap();
+
+        let request = dummy_request();
+        assert_eq!(expected, request.hash());
+    }
+
     test_fake_sign_properties!();
 
     // Hardhat doesn't support EIP-4844 yet, hence no fake signature test
@@ -7,8 +7,10 @@ mod legacy;
 use alloy_rlp::{Buf, BufMut, Decodable};
 
 pub use self::{
-    eip155::Eip155SignedTransaction, eip1559::Eip1559SignedTransaction,
-    eip2930::Eip2930SignedTransaction, eip4844::Eip4844SignedTransaction,
+    eip155::Eip155SignedTransaction,
+    eip1559::Eip1559SignedTransaction,
+    eip2930::Eip2930SignedTransaction,
+    eip4844::{BlobSidecar, Eip4844SignedTransaction, Eip4844TransactionWithSidecar},
     legacy::LegacySignedTransaction,
 };
 use super::kind::TransactionKind;
@@ -106,9 +108,17 @@ impl SignedTransaction {
         }
     }
 
-    /// Max cost of the transaction
+    /// Max cost of the transaction, including the max blob fee for EIP-4844
+    /// transactions
     pub fn max_cost(&self) -> U256 {
-        U256::from(self.gas_limit()).saturating_mul(self.gas_price())
+        let max_gas_cost = U256::from(self.gas_limit()).saturating_mul(self.gas_price());
+
+        match self {
+            SignedTransaction::Eip4844(tx) => max_gas_cost.saturating_add(
+                U256::from(tx.total_blob_gas()).saturating_mul(tx.max_fee_per_blob_gas),
+            ),
+            _ => max_gas_cost,
+        }
     }
 
     /// Retrieves the max fee per gas of the transaction, if any.
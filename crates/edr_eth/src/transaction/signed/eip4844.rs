@@ -1,7 +1,7 @@
 use std::sync::OnceLock;
 
-use alloy_rlp::{RlpDecodable, RlpEncodable};
-use revm_primitives::{keccak256, GAS_PER_BLOB};
+use alloy_rlp::{Decodable, Encodable, RlpDecodable, RlpEncodable};
+use revm_primitives::{keccak256, FixedBytes, GAS_PER_BLOB};
 
 use crate::{
     access_list::AccessList,
@@ -98,6 +98,91 @@ impl PartialEq for Eip4844SignedTransaction {
     }
 }
 
+/// The blobs, KZG commitments, and KZG proofs that accompany an EIP-4844
+/// transaction when it's submitted to the network.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BlobSidecar {
+    /// The blobs, each `BYTES_PER_BLOB` bytes long
+    pub blobs: Vec<Bytes>,
+    /// The KZG commitments of the blobs
+    pub commitments: Vec<FixedBytes<48>>,
+    /// The KZG proofs of the blobs
+    pub proofs: Vec<FixedBytes<48>>,
+}
+
+/// An EIP-4844 transaction in its network representation, which wraps the
+/// signed transaction together with its blob sidecar:
+///
+/// `rlp([tx_payload_body, blobs, commitments, proofs])`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Eip4844TransactionWithSidecar {
+    /// The signed transaction
+    pub transaction: Eip4844SignedTransaction,
+    /// The transaction's blob sidecar
+    pub sidecar: BlobSidecar,
+}
+
+impl Eip4844TransactionWithSidecar {
+    fn payload_length(&self) -> usize {
+        self.transaction.length()
+            + self.sidecar.blobs.length()
+            + self.sidecar.commitments.length()
+            + self.sidecar.proofs.length()
+    }
+}
+
+impl Decodable for Eip4844TransactionWithSidecar {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        let header = alloy_rlp::Header::decode(buf)?;
+        if !header.list {
+            return Err(alloy_rlp::Error::UnexpectedString);
+        }
+
+        let started_len = buf.len();
+
+        let transaction = Eip4844SignedTransaction::decode(buf)?;
+        let sidecar = BlobSidecar {
+            blobs: Decodable::decode(buf)?,
+            commitments: Decodable::decode(buf)?,
+            proofs: Decodable::decode(buf)?,
+        };
+
+        let consumed = started_len - buf.len();
+        if consumed != header.payload_length {
+            return Err(alloy_rlp::Error::ListLengthMismatch {
+                expected: header.payload_length,
+                got: consumed,
+            });
+        }
+
+        Ok(Self {
+            transaction,
+            sidecar,
+        })
+    }
+}
+
+impl Encodable for Eip4844TransactionWithSidecar {
+    fn encode(&self, out: &mut dyn alloy_rlp::BufMut) {
+        alloy_rlp::Header {
+            list: true,
+            payload_length: self.payload_length(),
+        }
+        .encode(out);
+
+        self.transaction.encode(out);
+        self.sidecar.blobs.encode(out);
+        self.sidecar.commitments.encode(out);
+        self.sidecar.proofs.encode(out);
+    }
+
+    fn length(&self) -> usize {
+        let payload_length = self.payload_length();
+        payload_length + alloy_rlp::length_of_length(payload_length)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
@@ -153,4 +238,26 @@ mod tests {
         let signed = dummy_transaction();
         assert_eq!(expected, *signed.hash());
     }
+
+    #[test]
+    fn eip4844_transaction_with_sidecar_rlp() {
+        let transaction_with_sidecar = Eip4844TransactionWithSidecar {
+            transaction: dummy_transaction(),
+            sidecar: BlobSidecar {
+                blobs: vec![Bytes::from(vec![1u8; 64])],
+                commitments: vec![FixedBytes::repeat_byte(2)],
+                proofs: vec![FixedBytes::repeat_byte(3)],
+            },
+        };
+
+        let encoded = alloy_rlp::encode(&transaction_with_sidecar);
+        assert_eq!(encoded.len(), transaction_with_sidecar.length());
+
+        let decoded = Eip4844TransactionWithSidecar::decode(&mut encoded.as_slice()).unwrap();
+        assert_eq!(decoded, transaction_with_sidecar);
+        assert_eq!(
+            decoded.transaction.hash(),
+            transaction_with_sidecar.transaction.hash()
+        );
+    }
 }
@@ -0,0 +1,242 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use edr_eth::{
+    access_list::{AccessList, AccessListItem},
+    Address, B256, U256,
+};
+use revm::{
+    handler::register::EvmHandler,
+    interpreter::{
+        opcode::{self, BoxedInstruction, InstructionTables},
+        Interpreter,
+    },
+    Database, Evm,
+};
+
+use crate::debug::GetContextData;
+
+/// Registers the handles of the [`AccessListTracer`], which record the
+/// addresses and storage slots accessed by instructions.
+pub fn register_access_list_tracer_handles<
+    DatabaseT: Database,
+    ContextT: GetContextData<AccessListTracer>,
+>(
+    handler: &mut EvmHandler<'_, ContextT, DatabaseT>,
+) {
+    // Only the instructions that access accounts or storage slots need to be
+    // wrapped.
+    let table = handler
+        .instruction_table
+        .take()
+        .expect("Handler must have instruction table");
+
+    let table = match table {
+        InstructionTables::Plain(table) => table
+            .into_iter()
+            .enumerate()
+            .map(|(opcode, instruction)| {
+                if let Some(opcode) = access_opcode(opcode) {
+                    access_instruction_handler(opcode, instruction)
+                } else {
+                    Box::new(instruction) as BoxedInstruction<'_, _>
+                }
+            })
+            .collect::<Vec<_>>(),
+        InstructionTables::Boxed(table) => table
+            .into_iter()
+            .enumerate()
+            .map(|(opcode, instruction)| {
+                if let Some(opcode) = access_opcode(opcode) {
+                    access_instruction_handler(opcode, instruction)
+                } else {
+                    instruction
+                }
+            })
+            .collect::<Vec<_>>(),
+    };
+
+    // cast vector to array.
+    handler.instruction_table = Some(InstructionTables::Boxed(
+        table.try_into().unwrap_or_else(|_| unreachable!()),
+    ));
+}
+
+/// Returns the opcode, if it accesses an account or storage slot.
+fn access_opcode(opcode: usize) -> Option<u8> {
+    u8::try_from(opcode).ok().filter(|opcode| {
+        matches!(
+            *opcode,
+            opcode::SLOAD
+                | opcode::SSTORE
+                | opcode::BALANCE
+                | opcode::EXTCODECOPY
+                | opcode::EXTCODEHASH
+                | opcode::EXTCODESIZE
+                | opcode::SELFDESTRUCT
+                | opcode::CALL
+                | opcode::CALLCODE
+                | opcode::DELEGATECALL
+                | opcode::STATICCALL
+        )
+    })
+}
+
+fn access_instruction_handler<
+    'a,
+    ContextT: GetContextData<AccessListTracer>,
+    DatabaseT: Database,
+    Instruction: Fn(&mut Interpreter, &mut Evm<'a, ContextT, DatabaseT>) + 'a,
+>(
+    opcode: u8,
+    instruction: Instruction,
+) -> BoxedInstruction<'a, Evm<'a, ContextT, DatabaseT>> {
+    Box::new(
+        move |interpreter: &mut Interpreter, host: &mut Evm<'a, ContextT, DatabaseT>| {
+            // The accessed address or storage slot needs to be read from the stack before
+            // the instruction consumes it.
+            host.context
+                .external
+                .get_context_data()
+                .step(opcode, interpreter);
+
+            // execute instruction.
+            instruction(interpreter, host);
+        },
+    )
+}
+
+/// A tracer that records the addresses and storage slots accessed by a
+/// transaction, to construct its EIP-2930 access list.
+#[derive(Debug)]
+pub struct AccessListTracer {
+    excluded: BTreeSet<Address>,
+    access_list: BTreeMap<Address, BTreeSet<B256>>,
+}
+
+impl AccessListTracer {
+    /// Creates a new tracer, starting from the provided access list. The
+    /// excluded addresses are never added to the access list. Typically,
+    /// these are the sender, the recipient, and the precompiles, which are
+    /// always warm.
+    pub fn new(
+        access_list: &[AccessListItem],
+        excluded: impl IntoIterator<Item = Address>,
+    ) -> Self {
+        let mut tracer = Self {
+            excluded: excluded.into_iter().collect(),
+            access_list: BTreeMap::new(),
+        };
+
+        for item in access_list {
+            tracer.add_address(item.address);
+
+            for storage_key in &item.storage_keys {
+                tracer.add_storage_slot(item.address, U256::from_be_bytes(storage_key.0));
+            }
+        }
+
+        tracer
+    }
+
+    /// Returns the recorded access list.
+    pub fn access_list(&self) -> AccessList {
+        AccessList(
+            self.access_list
+                .iter()
+                .map(|(address, storage_keys)| AccessListItem {
+                    address: *address,
+                    storage_keys: storage_keys.iter().copied().collect(),
+                })
+                .collect(),
+        )
+    }
+
+    fn add_address(&mut self, address: Address) {
+        if !self.excluded.contains(&address) {
+            self.access_list.entry(address).or_default();
+        }
+    }
+
+    fn add_storage_slot(&mut self, address: Address, index: U256) {
+        // Storage slots of excluded addresses are still included, as they
+        // aren't warm by default.
+        self.access_list
+            .entry(address)
+            .or_default()
+            .insert(B256::from(index.to_be_bytes()));
+    }
+
+    fn step(&mut self, opcode: u8, interpreter: &Interpreter) {
+        let stack = &interpreter.stack;
+
+        match opcode {
+            opcode::SLOAD | opcode::SSTORE => {
+                if let Ok(index) = stack.peek(0) {
+                    self.add_storage_slot(interpreter.contract.address, index);
+                }
+            }
+            opcode::BALANCE
+            | opcode::EXTCODECOPY
+            | opcode::EXTCODEHASH
+            | opcode::EXTCODESIZE
+            | opcode::SELFDESTRUCT => {
+                if let Ok(address) = stack.peek(0) {
+                    self.add_address(word_to_address(address));
+                }
+            }
+            opcode::CALL | opcode::CALLCODE | opcode::DELEGATECALL | opcode::STATICCALL => {
+                if let Ok(address) = stack.peek(1) {
+                    self.add_address(word_to_address(address));
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+impl GetContextData<AccessListTracer> for AccessListTracer {
+    fn get_context_data(&mut self) -> &mut AccessListTracer {
+        self
+    }
+}
+
+fn word_to_address(word: U256) -> Address {
+    Address::from_word(B256::from(word.to_be_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn excluded_addresses() {
+        let excluded = Address::random();
+        let included = Address::random();
+
+        let mut tracer = AccessListTracer::new(
+            &[AccessListItem {
+                address: excluded,
+                storage_keys: Vec::new(),
+            }],
+            [excluded],
+        );
+
+        tracer.add_address(excluded);
+        tracer.add_address(included);
+        tracer.add_storage_slot(excluded, U256::from(1));
+
+        let mut expected = vec![
+            AccessListItem {
+                address: excluded,
+                storage_keys: vec![B256::from(U256::from(1).to_be_bytes())],
+            },
+            AccessListItem {
+                address: included,
+                storage_keys: Vec::new(),
+            },
+        ];
+        expected.sort_by_key(|item| item.address);
+
+        assert_eq!(tracer.access_list(), AccessList(expected));
+    }
+}
@@ -0,0 +1,226 @@
+use c_kzg::{Blob, Bytes48, KzgProof};
+use edr_eth::{
+    transaction::{BlobSidecar, Eip4844SignedTransaction},
+    B256,
+};
+use revm::{
+    precompile::kzg_point_evaluation::kzg_to_versioned_hash,
+    primitives::{kzg::EnvKzgSettings, FixedBytes, MAX_BLOB_NUMBER_PER_BLOCK},
+};
+
+/// An error that occurs when validating the blob sidecar of an EIP-4844
+/// transaction.
+#[derive(Debug, thiserror::Error)]
+pub enum BlobSidecarError {
+    /// The number of blobs, commitments, and proofs don't match the number of
+    /// versioned hashes of the transaction.
+    #[error("The transaction has {hashes} blob hashes, but its sidecar contains {blobs} blobs, {commitments} commitments, and {proofs} proofs")]
+    CountMismatch {
+        /// The number of versioned hashes
+        hashes: usize,
+        /// The number of blobs
+        blobs: usize,
+        /// The number of commitments
+        commitments: usize,
+        /// The number of proofs
+        proofs: usize,
+    },
+    /// The transaction doesn't contain any blobs.
+    #[error("A blob transaction must contain at least one blob")]
+    EmptyBlobs,
+    /// The KZG proofs of the blobs are invalid.
+    #[error("The KZG proofs of the blobs are invalid")]
+    InvalidProofs,
+    /// An error occurred in the KZG library, e.g. due to a malformed blob.
+    #[error("KZG error: {0:?}")]
+    Kzg(c_kzg::Error),
+    /// The transaction contains more blobs than fit in a block.
+    #[error("A blob transaction can contain at most {max} blobs, but it contains {actual}")]
+    TooManyBlobs {
+        /// The maximum number of blobs
+        max: usize,
+        /// The number of blobs
+        actual: usize,
+    },
+    /// The versioned hash of a commitment doesn't match the transaction's
+    /// versioned hash.
+    #[error("The versioned hash of commitment {index} is {actual}, but the transaction specifies {expected}")]
+    VersionedHashMismatch {
+        /// The index of the blob
+        index: usize,
+        /// The versioned hash specified by the transaction
+        expected: B256,
+        /// The versioned hash of the commitment
+        actual: B256,
+    },
+}
+
+impl From<c_kzg::Error> for BlobSidecarError {
+    fn from(error: c_kzg::Error) -> Self {
+        Self::Kzg(error)
+    }
+}
+
+/// Computes the versioned hash of a KZG commitment, as specified by
+/// EIP-4844.
+pub fn blob_versioned_hash(commitment: &FixedBytes<48>) -> B256 {
+    B256::from(kzg_to_versioned_hash(commitment.as_slice()))
+}
+
+/// Validates that the blob sidecar belongs to the transaction, by checking
+/// that the commitments match the transaction's versioned hashes and that the
+/// KZG proofs are valid for the blobs.
+pub fn validate_blob_sidecar(
+    transaction: &Eip4844SignedTransaction,
+    sidecar: &BlobSidecar,
+) -> Result<(), BlobSidecarError> {
+    let BlobSidecar {
+        blobs,
+        commitments,
+        proofs,
+    } = sidecar;
+
+    let num_hashes = transaction.blob_hashes.len();
+    if num_hashes == 0 {
+        return Err(BlobSidecarError::EmptyBlobs);
+    }
+
+    let max_blobs = usize::try_from(MAX_BLOB_NUMBER_PER_BLOCK).expect("u64 fits into usize");
+    if num_hashes > max_blobs {
+        return Err(BlobSidecarError::TooManyBlobs {
+            max: max_blobs,
+            actual: num_hashes,
+        });
+    }
+
+    if blobs.len() != num_hashes || commitments.len() != num_hashes || proofs.len() != num_hashes {
+        return Err(BlobSidecarError::CountMismatch {
+            hashes: num_hashes,
+            blobs: blobs.len(),
+            commitments: commitments.len(),
+            proofs: proofs.len(),
+        });
+    }
+
+    for (index, (expected, commitment)) in transaction
+        .blob_hashes
+        .iter()
+        .zip(commitments.iter())
+        .enumerate()
+    {
+        let actual = blob_versioned_hash(commitment);
+        if actual != *expected {
+            return Err(BlobSidecarError::VersionedHashMismatch {
+                index,
+                expected: *expected,
+                actual,
+            });
+        }
+    }
+
+    let blobs = blobs
+        .iter()
+        .map(|blob| Blob::from_bytes(blob))
+        .collect::<Result<Vec<_>, _>>()?;
+    let commitments = commitments
+        .iter()
+        .map(|commitment| Bytes48::from_bytes(commitment.as_slice()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let proofs = proofs
+        .iter()
+        .map(|proof| Bytes48::from_bytes(proof.as_slice()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let is_valid = KzgProof::verify_blob_kzg_proof_batch(
+        &blobs,
+        &commitments,
+        &proofs,
+        EnvKzgSettings::Default.get(),
+    )?;
+
+    if is_valid {
+        Ok(())
+    } else {
+        Err(BlobSidecarError::InvalidProofs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::OnceLock;
+
+    use c_kzg::BYTES_PER_BLOB;
+    use edr_eth::{Address, Bytes, U256};
+
+    use super::*;
+    use crate::test_utils::zero_blob_sidecar;
+
+    fn blob_transaction(blob_hashes: Vec<B256>) -> Eip4844SignedTransaction {
+        Eip4844SignedTransaction {
+            chain_id: 1,
+            nonce: 0,
+            max_priority_fee_per_gas: U256::from(1),
+            max_fee_per_gas: U256::from(1),
+            gas_limit: 21_000,
+            to: Address::ZERO,
+            value: U256::ZERO,
+            input: Bytes::new(),
+            access_list: Vec::new().into(),
+            max_fee_per_blob_gas: U256::from(1),
+            blob_hashes,
+            odd_y_parity: false,
+            r: U256::from(1),
+            s: U256::from(1),
+            hash: OnceLock::new(),
+            is_fake: false,
+        }
+    }
+
+    #[test]
+    fn valid_sidecar() -> anyhow::Result<()> {
+        let sidecar = zero_blob_sidecar(1);
+        let versioned_hash = blob_versioned_hash(&sidecar.commitments[0]);
+
+        validate_blob_sidecar(&blob_transaction(vec![versioned_hash]), &sidecar)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn versioned_hash_mismatch() {
+        let sidecar = zero_blob_sidecar(1);
+        let result = validate_blob_sidecar(&blob_transaction(vec![B256::ZERO]), &sidecar);
+
+        assert!(matches!(
+            result,
+            Err(BlobSidecarError::VersionedHashMismatch { index: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn count_mismatch() {
+        let mut sidecar = zero_blob_sidecar(1);
+        let versioned_hash = blob_versioned_hash(&sidecar.commitments[0]);
+        sidecar.proofs.clear();
+
+        let result = validate_blob_sidecar(&blob_transaction(vec![versioned_hash]), &sidecar);
+        assert!(matches!(
+            result,
+            Err(BlobSidecarError::CountMismatch { proofs: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn invalid_proof() {
+        let mut sidecar = zero_blob_sidecar(1);
+        let versioned_hash = blob_versioned_hash(&sidecar.commitments[0]);
+
+        // A non-zero blob doesn't match the commitment of the zero blob
+        let mut blob = vec![0u8; BYTES_PER_BLOB];
+        blob[31] = 1;
+        sidecar.blobs[0] = Bytes::from(blob);
+
+        let result = validate_blob_sidecar(&blob_transaction(vec![versioned_hash]), &sidecar);
+        assert!(matches!(result, Err(BlobSidecarError::InvalidProofs)));
+    }
+}
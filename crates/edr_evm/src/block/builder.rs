@@ -32,6 +32,8 @@ use crate::{
 
 const DAO_EXTRA_DATA: &[u8] = b"dao-hard-fork";
 
+const WEI_PER_GWEI: u64 = 1_000_000_000;
+
 /// An error caused during construction of a block builder.
 #[derive(Debug, thiserror::Error)]
 pub enum BlockBuilderCreationError {
@@ -41,6 +43,14 @@ pub enum BlockBuilderCreationError {
     /// Unsupported hardfork. Hardforks older than Byzantium are not supported
     #[error("Unsupported hardfork: {0:?}. Hardforks older than Byzantium are not supported.")]
     UnsupportedHardfork(SpecId),
+    /// The amount of a withdrawal doesn't fit in 64 bits (EIP-4895).
+    #[error("Withdrawal {index} has an amount of {amount} Gwei, which exceeds the maximum of 2^64 - 1 Gwei.")]
+    WithdrawalAmountTooLarge {
+        /// The index of the withdrawal
+        index: u64,
+        /// The amount of the withdrawal, in Gwei
+        amount: U256,
+    },
 }
 
 /// An error caused during execution of a transaction while building a block.
@@ -126,6 +136,7 @@ pub struct BuildBlockResult {
 }
 
 /// A builder for constructing Ethereum blocks.
+#[derive(Clone)]
 pub struct BlockBuilder {
     cfg: CfgEnvWithHandlerCfg,
     header: PartialHeader,
@@ -158,6 +169,20 @@ impl BlockBuilder {
             None
         };
 
+        // Withdrawal amounts are 64-bit Gwei values (EIP-4895), which guarantees that
+        // they can be converted to Wei without overflowing.
+        if let Some(withdrawal) = options
+            .withdrawals
+            .iter()
+            .flatten()
+            .find(|withdrawal| withdrawal.amount > U256::from(u64::MAX))
+        {
+            return Err(BlockBuilderCreationError::WithdrawalAmountTooLarge {
+                index: withdrawal.index,
+                amount: withdrawal.amount,
+            });
+        }
+
         let withdrawals = std::mem::take(&mut options.withdrawals).or_else(|| {
             if cfg.handler_cfg.spec_id >= SpecId::SHANGHAI {
                 Some(Vec::new())
@@ -438,7 +463,8 @@ impl BlockBuilder {
     }
 
     /// Finalizes the block, returning the block and the callers of the
-    /// transactions.
+    /// transactions. The rewards and withdrawals are credited to their
+    /// recipients.
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub fn finalize<StateT, StateErrorT>(
         mut self,
@@ -462,6 +488,24 @@ impl BlockBuilder {
             }
         }
 
+        if let Some(withdrawals) = self.withdrawals.as_ref() {
+            for withdrawal in withdrawals {
+                if withdrawal.amount > U256::ZERO {
+                    // Withdrawal amounts are denominated in Gwei (EIP-4895)
+                    let amount = withdrawal.amount * U256::from(WEI_PER_GWEI);
+                    let account_info = state.modify_account(
+                        withdrawal.address,
+                        AccountModifierFn::new(Box::new(move |balance, _nonce, _code| {
+                            *balance = balance.saturating_add(amount);
+                        })),
+                    )?;
+
+                    self.state_diff
+                        .apply_account_change(withdrawal.address, account_info);
+                }
+            }
+        }
+
         if let Some(gas_limit) = self.parent_gas_limit {
             self.header.gas_limit = gas_limit;
         }
@@ -584,4 +628,36 @@ mod tests {
             Err(BlockBuilderCreationError::DaoHardforkInvalidData)
         ));
     }
+
+    #[test]
+    fn withdrawal_amount_too_large() {
+        use edr_eth::block::BlockOptions;
+
+        use super::*;
+
+        let spec_id = SpecId::SHANGHAI;
+        let parent = LocalBlock::empty(spec_id, PartialHeader::default());
+
+        let cfg = CfgEnvWithHandlerCfg::new_with_spec_id(CfgEnv::default(), spec_id);
+
+        let amount = U256::from(u64::MAX) + U256::from(1);
+        let block_options = BlockOptions {
+            withdrawals: Some(vec![Withdrawal {
+                index: 7,
+                validator_index: 0,
+                address: Address::ZERO,
+                amount,
+            }]),
+            ..BlockOptions::default()
+        };
+
+        let block_builder = BlockBuilder::new(cfg, &parent, block_options, None);
+        assert!(matches!(
+            block_builder,
+            Err(BlockBuilderCreationError::WithdrawalAmountTooLarge {
+                index: 7,
+                amount: error_amount,
+            }) if error_amount == amount
+        ));
+    }
 }
@@ -18,12 +18,14 @@ use crate::{
 };
 
 /// A locally mined block, which contains complete information.
-#[derive(Clone, Debug, PartialEq, Eq, RlpEncodable)]
+#[derive(Clone, Debug, PartialEq, Eq, RlpEncodable, serde::Deserialize, serde::Serialize)]
 #[rlp(trailing)]
+#[serde(rename_all = "camelCase")]
 pub struct LocalBlock {
     header: block::Header,
     transactions: Vec<ExecutableTransaction>,
     #[rlp(skip)]
+    #[serde(with = "shared_receipts")]
     transaction_receipts: Vec<Arc<BlockReceipt>>,
     ommers: Vec<block::Header>,
     #[rlp(skip)]
@@ -204,3 +206,28 @@ impl From<LocalBlock> for Arc<dyn SyncBlock<Error = BlockchainError>> {
         Arc::new(value)
     }
 }
+
+/// (De)serialization of shared receipts, as `serde` does not support `Arc`
+/// without the `rc` feature.
+mod shared_receipts {
+    use std::sync::Arc;
+
+    use edr_eth::receipt::BlockReceipt;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S>(receipts: &[Arc<BlockReceipt>], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let receipts: Vec<&BlockReceipt> = receipts.iter().map(AsRef::as_ref).collect();
+        receipts.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<Arc<BlockReceipt>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let receipts = Vec::<BlockReceipt>::deserialize(deserializer)?;
+        Ok(receipts.into_iter().map(Arc::new).collect())
+    }
+}
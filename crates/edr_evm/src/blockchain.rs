@@ -46,6 +46,10 @@ pub enum BlockchainError {
         /// Expected block number
         expected: u64,
     },
+    /// An error that occurs when reading from or writing to the blockchain's
+    /// journal.
+    #[error(transparent)]
+    Journal(#[from] storage::JournalError),
     /// Invalid parent hash
     #[error("Invalid parent hash: {actual}. Expected: {expected}.")]
     InvalidParentHash {
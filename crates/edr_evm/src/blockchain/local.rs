@@ -2,6 +2,7 @@ use std::{
     collections::BTreeMap,
     fmt::Debug,
     num::NonZeroU64,
+    path::Path,
     str::FromStr,
     sync::Arc,
     time::{SystemTime, UNIX_EPOCH},
@@ -20,8 +21,9 @@ use revm::{
 };
 
 use super::{
-    compute_state_at_block, storage::ReservableSparseBlockchainStorage, validate_next_block,
-    Blockchain, BlockchainError, BlockchainMut,
+    compute_state_at_block,
+    storage::{BlockchainJournal, JournalEntry, JournalError, ReservableSparseBlockchainStorage},
+    validate_next_block, Blockchain, BlockchainError, BlockchainMut,
 };
 use crate::{
     state::{StateDebug, StateDiff, StateError, StateOverride, SyncState, TrieState},
@@ -31,6 +33,22 @@ use crate::{
 /// An error that occurs upon creation of a [`LocalBlockchain`].
 #[derive(Debug, thiserror::Error)]
 pub enum CreationError {
+    /// The persisted blockchain is incompatible with the provided
+    /// configuration
+    #[error("The persisted blockchain has chain ID {chain_id} and hardfork {spec_id:?}, which differ from the configured values.")]
+    IncompatibleJournal {
+        /// The persisted chain ID
+        chain_id: u64,
+        /// The persisted hardfork
+        spec_id: SpecId,
+    },
+    /// An error that occurs when reading from or writing to the blockchain's
+    /// journal.
+    #[error(transparent)]
+    Journal(#[from] JournalError),
+    /// Replaying the persisted blockchain failed
+    #[error("Failed to restore the persisted blockchain: {0}")]
+    JournalReplay(BlockchainError),
     /// Missing prevrandao for post-merge blockchain
     #[error("Missing prevrandao for post-merge blockchain")]
     MissingPrevrandao,
@@ -79,6 +97,7 @@ pub struct LocalBlockchain {
     storage: ReservableSparseBlockchainStorage<Arc<dyn SyncBlock<Error = BlockchainError>>>,
     chain_id: u64,
     spec_id: SpecId,
+    journal: Option<BlockchainJournal>,
 }
 
 impl LocalBlockchain {
@@ -92,61 +111,89 @@ impl LocalBlockchain {
         spec_id: SpecId,
         options: GenesisBlockOptions,
     ) -> Result<Self, CreationError> {
-        const EXTRA_DATA: &[u8] = b"\x12\x34";
-
-        if spec_id >= SpecId::CANCUN {
-            let beacon_roots_address =
-                Address::from_str(BEACON_ROOTS_ADDRESS).expect("Is valid address");
-            let beacon_roots_contract = Bytecode::new_raw(
-                Bytes::from_str(BEACON_ROOTS_BYTECODE).expect("Is valid bytecode"),
-            );
+        let genesis_block = create_genesis_block(&mut genesis_diff, spec_id, options)?;
 
-            genesis_diff.apply_account_change(
-                beacon_roots_address,
-                AccountInfo {
-                    code_hash: beacon_roots_contract.hash_slow(),
-                    code: Some(beacon_roots_contract),
-                    ..AccountInfo::default()
-                },
-            );
-        }
-
-        let mut genesis_state = TrieState::default();
-        genesis_state.commit(genesis_diff.clone().into());
-
-        if spec_id >= SpecId::MERGE && options.mix_hash.is_none() {
-            return Err(CreationError::MissingPrevrandao);
-        }
+        Ok(unsafe {
+            Self::with_genesis_block_unchecked(genesis_block, genesis_diff, chain_id, spec_id)
+        })
+    }
 
-        let mut options = BlockOptions::from(options);
-        options.state_root = Some(
-            genesis_state
-                .state_root()
-                .expect("TrieState is guaranteed to successfully compute the state root"),
-        );
+    /// Constructs a new instance that persists its blocks to the journal at
+    /// the provided path.
+    ///
+    /// If the journal already contains a blockchain, it is restored - in
+    /// which case the genesis arguments are ignored. Otherwise, a new genesis
+    /// block is built using the provided arguments.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub fn persistent(
+        path: &Path,
+        mut genesis_diff: StateDiff,
+        chain_id: u64,
+        spec_id: SpecId,
+        options: GenesisBlockOptions,
+    ) -> Result<Self, CreationError> {
+        let (mut journal, entries) = BlockchainJournal::open(path)?;
+
+        let mut entries = entries.into_iter();
+        let mut blockchain = match entries.next() {
+            Some(JournalEntry::Genesis {
+                block,
+                state_diff,
+                chain_id: persisted_chain_id,
+                spec_id: persisted_spec_id,
+            }) => {
+                if persisted_chain_id != chain_id || persisted_spec_id != spec_id {
+                    return Err(CreationError::IncompatibleJournal {
+                        chain_id: persisted_chain_id,
+                        spec_id: persisted_spec_id,
+                    });
+                }
+
+                unsafe { Self::with_genesis_block_unchecked(block, state_diff, chain_id, spec_id) }
+            }
+            Some(_) => return Err(JournalError::MissingGenesis.into()),
+            None => {
+                let genesis_block = create_genesis_block(&mut genesis_diff, spec_id, options)?;
+
+                journal.append(&JournalEntry::Genesis {
+                    block: genesis_block.clone(),
+                    state_diff: genesis_diff.clone(),
+                    chain_id,
+                    spec_id,
+                })?;
+
+                unsafe {
+                    Self::with_genesis_block_unchecked(
+                        genesis_block,
+                        genesis_diff,
+                        chain_id,
+                        spec_id,
+                    )
+                }
+            }
+        };
 
-        if options.timestamp.is_none() {
-            options.timestamp = Some(
-                SystemTime::now()
-                    .duration_since(UNIX_EPOCH)
-                    .expect("Current time must be after unix epoch")
-                    .as_secs(),
-            );
+        for entry in entries {
+            match entry {
+                JournalEntry::Genesis { .. } => return Err(JournalError::MissingGenesis.into()),
+                JournalEntry::InsertBlock { block, state_diff } => {
+                    blockchain.insert_block(block, state_diff).map(|_| ())
+                }
+                JournalEntry::ReserveBlocks {
+                    additional,
+                    interval,
+                } => blockchain.reserve_blocks(additional, interval),
+                JournalEntry::RevertToBlock { block_number } => {
+                    blockchain.revert_to_block(block_number)
+                }
+            }
+            .map_err(CreationError::JournalReplay)?;
         }
 
-        options.extra_data = Some(Bytes::from(EXTRA_DATA));
+        blockchain.journal = Some(journal);
 
-        let partial_header = PartialHeader::new(spec_id, options, None);
-        Ok(unsafe {
-            Self::with_genesis_block_unchecked(
-                LocalBlock::empty(spec_id, partial_header),
-                genesis_diff,
-                chain_id,
-                spec_id,
-            )
-        })
+        Ok(blockchain)
     }
-
     /// Constructs a new instance with the provided genesis block, validating a
     /// zero block number.
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
@@ -200,10 +247,65 @@ impl LocalBlockchain {
             storage,
             chain_id,
             spec_id,
+            journal: None,
         }
     }
 }
 
+/// Creates a genesis block using the provided arguments, adding the beacon
+/// roots contract to the genesis state for post-Cancun blockchains.
+fn create_genesis_block(
+    genesis_diff: &mut StateDiff,
+    spec_id: SpecId,
+    options: GenesisBlockOptions,
+) -> Result<LocalBlock, CreationError> {
+    const EXTRA_DATA: &[u8] = b"\x12\x34";
+
+    if spec_id >= SpecId::CANCUN {
+        let beacon_roots_address =
+            Address::from_str(BEACON_ROOTS_ADDRESS).expect("Is valid address");
+        let beacon_roots_contract =
+            Bytecode::new_raw(Bytes::from_str(BEACON_ROOTS_BYTECODE).expect("Is valid bytecode"));
+
+        genesis_diff.apply_account_change(
+            beacon_roots_address,
+            AccountInfo {
+                code_hash: beacon_roots_contract.hash_slow(),
+                code: Some(beacon_roots_contract),
+                ..AccountInfo::default()
+            },
+        );
+    }
+
+    let mut genesis_state = TrieState::default();
+    genesis_state.commit(genesis_diff.clone().into());
+
+    if spec_id >= SpecId::MERGE && options.mix_hash.is_none() {
+        return Err(CreationError::MissingPrevrandao);
+    }
+
+    let mut options = BlockOptions::from(options);
+    options.state_root = Some(
+        genesis_state
+            .state_root()
+            .expect("TrieState is guaranteed to successfully compute the state root"),
+    );
+
+    if options.timestamp.is_none() {
+        options.timestamp = Some(
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("Current time must be after unix epoch")
+                .as_secs(),
+        );
+    }
+
+    options.extra_data = Some(Bytes::from(EXTRA_DATA));
+
+    let partial_header = PartialHeader::new(spec_id, options, None);
+    Ok(LocalBlock::empty(spec_id, partial_header))
+}
+
 impl Blockchain for LocalBlockchain {
     type BlockchainError = BlockchainError;
 
@@ -335,9 +437,29 @@ impl BlockchainMut for LocalBlockchain {
 
         let total_difficulty = previous_total_difficulty + block.header().difficulty;
 
-        let block = self
+        // Journal the block first, so the in-memory blockchain is never ahead of
+        // the persisted one
+        if let Some(journal) = &mut self.journal {
+            journal.append(&JournalEntry::InsertBlock {
+                block: block.clone(),
+                state_diff: state_diff.clone(),
+            })?;
+        }
+
+        let block = match self
             .storage
-            .insert_block(block, state_diff, total_difficulty)?;
+            .insert_block(block, state_diff, total_difficulty)
+        {
+            Ok(block) => block,
+            Err(error) => {
+                // Truncate the journal entry of the rejected block
+                if let Some(journal) = &mut self.journal {
+                    journal.revert_to_block(last_block.header().number)?;
+                }
+
+                return Err(error.into());
+            }
+        };
 
         Ok(BlockAndTotalDifficulty {
             block: block.clone(),
@@ -369,12 +491,23 @@ impl BlockchainMut for LocalBlockchain {
             self.spec_id,
         );
 
+        if let Some(journal) = &mut self.journal {
+            journal.append(&JournalEntry::ReserveBlocks {
+                additional: additional.get(),
+                interval,
+            })?;
+        }
+
         Ok(())
     }
 
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     fn revert_to_block(&mut self, block_number: u64) -> Result<(), Self::Error> {
         if self.storage.revert_to_block(block_number) {
+            if let Some(journal) = &mut self.journal {
+                journal.revert_to_block(block_number)?;
+            }
+
             Ok(())
         } else {
             Err(BlockchainError::UnknownBlockNumber)
@@ -460,4 +593,97 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn persistent_restores_after_reopen() -> anyhow::Result<()> {
+        const SPEC_ID: SpecId = SpecId::SHANGHAI;
+
+        fn insert_empty_block(blockchain: &mut LocalBlockchain) -> anyhow::Result<B256> {
+            let last_block = blockchain.last_block()?;
+            let block = LocalBlock::empty(
+                SPEC_ID,
+                PartialHeader {
+                    number: last_block.header().number + 1,
+                    parent_hash: *last_block.hash(),
+                    ..PartialHeader::default()
+                },
+            );
+
+            let block = blockchain.insert_block(block, StateDiff::default())?;
+            Ok(*block.block.hash())
+        }
+
+        let directory = tempfile::tempdir()?;
+        let path = directory.path().join("blockchain.jsonl");
+
+        let open = || {
+            LocalBlockchain::persistent(
+                &path,
+                StateDiff::default(),
+                123,
+                SPEC_ID,
+                GenesisBlockOptions {
+                    gas_limit: Some(6_000_000),
+                    mix_hash: Some(B256::random()),
+                    ..GenesisBlockOptions::default()
+                },
+            )
+        };
+
+        let (genesis_hash, block_hash) = {
+            let mut blockchain = open()?;
+            let genesis_hash = *blockchain.last_block()?.hash();
+
+            insert_empty_block(&mut blockchain)?;
+            blockchain.reserve_blocks(5, 1)?;
+            blockchain.revert_to_block(3)?;
+
+            let block_hash = insert_empty_block(&mut blockchain)?;
+            (genesis_hash, block_hash)
+        };
+
+        let mut blockchain = open()?;
+        assert_eq!(blockchain.last_block_number(), 4);
+        assert_eq!(*blockchain.last_block()?.hash(), block_hash);
+        assert_eq!(blockchain.block_hash(U256::ZERO)?, genesis_hash);
+
+        // Reverting truncates the journal, so reverted blocks are not restored
+        blockchain.revert_to_block(1)?;
+        drop(blockchain);
+
+        let blockchain = open()?;
+        assert_eq!(blockchain.last_block_number(), 1);
+        assert!(blockchain.block_by_hash(&block_hash)?.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn persistent_rejects_incompatible_journal() -> anyhow::Result<()> {
+        let directory = tempfile::tempdir()?;
+        let path = directory.path().join("blockchain.jsonl");
+
+        let open = |chain_id| {
+            LocalBlockchain::persistent(
+                &path,
+                StateDiff::default(),
+                chain_id,
+                SpecId::SHANGHAI,
+                GenesisBlockOptions {
+                    mix_hash: Some(B256::random()),
+                    ..GenesisBlockOptions::default()
+                },
+            )
+        };
+
+        open(123)?;
+
+        let result = open(1);
+        assert!(matches!(
+            result,
+            Err(CreationError::IncompatibleJournal { chain_id: 123, .. })
+        ));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,220 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, BufRead, BufReader, Seek, SeekFrom, Write},
+    path::Path,
+};
+
+use revm::primitives::SpecId;
+
+use crate::{state::StateDiff, Block, LocalBlock};
+
+/// An error that occurs when reading from or writing to a
+/// [`BlockchainJournal`].
+#[derive(Debug, thiserror::Error)]
+pub enum JournalError {
+    /// The journal does not start with a genesis entry
+    #[error("The blockchain journal does not start with a genesis block.")]
+    MissingGenesis,
+    /// An entry of the journal could not be deserialized
+    #[error("Invalid entry on line {line} of the blockchain journal: {error}")]
+    InvalidEntry {
+        /// The line number of the entry
+        line: usize,
+        /// The deserialization error
+        error: serde_json::Error,
+    },
+    /// An I/O error
+    #[error(transparent)]
+    Io(#[from] io::Error),
+    /// An entry could not be serialized
+    #[error("Failed to serialize blockchain journal entry: {0}")]
+    Serialization(serde_json::Error),
+}
+
+/// A modification of a blockchain, as recorded in a [`BlockchainJournal`].
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum JournalEntry {
+    /// The blockchain was created with the provided genesis block
+    #[serde(rename_all = "camelCase")]
+    Genesis {
+        /// The genesis block
+        block: LocalBlock,
+        /// The genesis state
+        state_diff: StateDiff,
+        /// The blockchain's chain ID
+        chain_id: u64,
+        /// The blockchain's hardfork
+        spec_id: SpecId,
+    },
+    /// A block was inserted
+    #[serde(rename_all = "camelCase")]
+    InsertBlock {
+        /// The inserted block
+        block: LocalBlock,
+        /// The state diff that was applied by the block
+        state_diff: StateDiff,
+    },
+    /// Blocks were reserved
+    #[serde(rename_all = "camelCase")]
+    ReserveBlocks {
+        /// The number of reserved blocks
+        additional: u64,
+        /// The timestamp interval between reserved blocks
+        interval: u64,
+    },
+    /// The blockchain was reverted to the specified block number
+    #[serde(rename_all = "camelCase")]
+    RevertToBlock {
+        /// The block number that was reverted to
+        block_number: u64,
+    },
+}
+
+/// Bookkeeping information of an entry in the journal file.
+#[derive(Clone, Copy, Debug)]
+struct EntryMetadata {
+    /// The entry's byte offset in the file
+    offset: u64,
+    /// The lowest block number that is affected by the entry
+    first_affected_block: u64,
+    /// The last block number after applying the entry
+    last_block_number: u64,
+}
+
+/// An append-only, on-disk journal of the modifications of a blockchain,
+/// stored as one JSON entry per line. Replaying the journal's entries
+/// restores the blockchain.
+///
+/// When reverting, entries that only affect reverted blocks are truncated
+/// from the journal, to prevent it from growing with stale blocks.
+#[derive(Debug)]
+pub struct BlockchainJournal {
+    file: File,
+    entries: Vec<EntryMetadata>,
+    len: u64,
+}
+
+impl BlockchainJournal {
+    /// Opens or creates the journal at the provided path, returning the
+    /// journal and its existing entries.
+    ///
+    /// An incompletely written trailing entry - e.g. due to a crash - is
+    /// discarded.
+    pub fn open(path: &Path) -> Result<(Self, Vec<JournalEntry>), JournalError> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+
+        let mut journal = Self {
+            file: file.try_clone()?,
+            entries: Vec::new(),
+            len: 0,
+        };
+
+        let mut entries = Vec::new();
+        let mut reader = BufReader::new(&mut file);
+        let mut line = String::new();
+        loop {
+            line.clear();
+
+            let num_bytes = reader.read_line(&mut line)?;
+            if num_bytes == 0 || !line.ends_with('\n') {
+                break;
+            }
+
+            let entry: JournalEntry = serde_json::from_str(line.trim_end()).map_err(|error| {
+                JournalError::InvalidEntry {
+                    line: entries.len() + 1,
+                    error,
+                }
+            })?;
+
+            if entries.is_empty() != matches!(entry, JournalEntry::Genesis { .. }) {
+                return Err(JournalError::MissingGenesis);
+            }
+
+            journal.track(&entry, num_bytes as u64);
+            entries.push(entry);
+        }
+
+        journal.file.set_len(journal.len)?;
+        journal.file.seek(SeekFrom::Start(journal.len))?;
+
+        Ok((journal, entries))
+    }
+
+    /// Appends the provided entry to the journal.
+    pub fn append(&mut self, entry: &JournalEntry) -> Result<(), JournalError> {
+        let mut line = serde_json::to_vec(entry).map_err(JournalError::Serialization)?;
+        line.push(b'\n');
+
+        self.file.write_all(&line)?;
+        self.file.sync_data()?;
+
+        self.track(entry, line.len() as u64);
+
+        Ok(())
+    }
+
+    /// Records the reversion of the blockchain to the provided block number.
+    /// Trailing entries that only affect blocks after the block number are
+    /// truncated.
+    pub fn revert_to_block(&mut self, block_number: u64) -> Result<(), JournalError> {
+        let num_retained = self
+            .entries
+            .iter()
+            .rposition(|entry| entry.first_affected_block <= block_number)
+            .map_or(0, |index| index + 1);
+
+        if let Some(first_truncated) = self.entries.get(num_retained) {
+            self.len = first_truncated.offset;
+            self.entries.truncate(num_retained);
+
+            self.file.set_len(self.len)?;
+            self.file.seek(SeekFrom::Start(self.len))?;
+            self.file.sync_data()?;
+        }
+
+        if self.last_block_number() != Some(block_number) {
+            self.append(&JournalEntry::RevertToBlock { block_number })?;
+        }
+
+        Ok(())
+    }
+
+    fn last_block_number(&self) -> Option<u64> {
+        self.entries.last().map(|entry| entry.last_block_number)
+    }
+
+    fn track(&mut self, entry: &JournalEntry, num_bytes: u64) {
+        let previous_block_number = self.last_block_number().unwrap_or(0);
+
+        let (first_affected_block, last_block_number) = match entry {
+            JournalEntry::Genesis { .. } => (0, 0),
+            JournalEntry::InsertBlock { block, .. } => {
+                let block_number = block.header().number;
+                (block_number, block_number)
+            }
+            JournalEntry::ReserveBlocks { additional, .. } => (
+                previous_block_number + 1,
+                previous_block_number + additional,
+            ),
+            JournalEntry::RevertToBlock { block_number } => (block_number + 1, *block_number),
+        };
+
+        self.entries.push(EntryMetadata {
+            offset: self.len,
+            first_affected_block,
+            last_block_number,
+        });
+        self.len += num_bytes;
+    }
+}
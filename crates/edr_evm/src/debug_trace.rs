@@ -1,3 +1,6 @@
+mod call;
+mod prestate;
+
 use std::{collections::HashMap, fmt::Debug, sync::Arc};
 
 use edr_eth::{signature::SignatureError, utils::u256_to_padded_hex, B256};
@@ -10,11 +13,17 @@ use revm::{
     },
     primitives::{
         hex, Address, BlockEnv, Bytes, CfgEnvWithHandlerCfg, ExecutionResult, ResultAndState,
-        SpecId, U256,
+        SpecId, State, TxEnv, U256,
     },
     Database, Evm, EvmContext, JournalEntry,
 };
 
+pub use self::call::{
+    register_call_tracer_handles, CallFrame, CallFrameType, CallLogFrame, CallTracer,
+    CallTracerConfig,
+};
+pub(crate) use self::prestate::{account_code, u256_to_b256};
+pub use self::prestate::{prestate_trace, PrestateAccount, PrestateTrace, PrestateTracerConfig};
 use crate::{
     blockchain::SyncBlockchain, debug::GetContextData, state::SyncState, ExecutableTransaction,
     TransactionError,
@@ -31,11 +40,96 @@ pub fn debug_trace_transaction<BlockchainErrorT, StateErrorT>(
     block_env: BlockEnv,
     transactions: Vec<ExecutableTransaction>,
     transaction_hash: &B256,
-) -> Result<DebugTraceResult, DebugTraceError<BlockchainErrorT, StateErrorT>>
+) -> Result<DebugTraceOutput, DebugTraceError<BlockchainErrorT, StateErrorT>>
 where
     BlockchainErrorT: Debug + Send,
     StateErrorT: Debug + Send,
 {
+    validate_trace_env(&evm_config, &block_env)?;
+
+    for transaction in transactions {
+        if transaction.hash() == transaction_hash {
+            let (trace, _changes) = trace_transaction(
+                blockchain,
+                state.as_ref(),
+                evm_config,
+                &trace_config,
+                block_env,
+                transaction.into(),
+            )?;
+
+            return Ok(trace);
+        } else {
+            let ResultAndState { state: changes, .. } = {
+                let mut evm = Evm::builder()
+                    .with_ref_db(DatabaseComponents {
+                        state: state.as_ref(),
+                        block_hash: blockchain,
+                    })
+                    .with_cfg_env_with_handler_cfg(evm_config.clone())
+                    .with_block_env(block_env.clone())
+                    .with_tx_env(transaction.into())
+                    .build();
+
+                evm.transact().map_err(TransactionError::from)?
+            };
+
+            state.commit(changes);
+        }
+    }
+
+    Err(DebugTraceError::InvalidTransactionHash {
+        transaction_hash: *transaction_hash,
+        block_number: block_env.number,
+    })
+}
+
+/// Get trace output for all transactions in a block, for
+/// `debug_traceBlockByNumber` and `debug_traceBlockByHash`.
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn debug_trace_block<BlockchainErrorT, StateErrorT>(
+    blockchain: &dyn SyncBlockchain<BlockchainErrorT, StateErrorT>,
+    // Take ownership of the state so that we can apply throw-away modifications on it
+    mut state: Box<dyn SyncState<StateErrorT>>,
+    evm_config: CfgEnvWithHandlerCfg,
+    trace_config: DebugTraceConfig,
+    block_env: BlockEnv,
+    transactions: Vec<ExecutableTransaction>,
+) -> Result<Vec<TransactionTrace>, DebugTraceError<BlockchainErrorT, StateErrorT>>
+where
+    BlockchainErrorT: Debug + Send,
+    StateErrorT: Debug + Send,
+{
+    validate_trace_env(&evm_config, &block_env)?;
+
+    transactions
+        .into_iter()
+        .map(|transaction| {
+            let transaction_hash = *transaction.hash();
+
+            let (result, changes) = trace_transaction(
+                blockchain,
+                state.as_ref(),
+                evm_config.clone(),
+                &trace_config,
+                block_env.clone(),
+                transaction.into(),
+            )?;
+
+            state.commit(changes);
+
+            Ok(TransactionTrace {
+                transaction_hash,
+                result,
+            })
+        })
+        .collect()
+}
+
+fn validate_trace_env<BlockchainErrorT, StateErrorT>(
+    evm_config: &CfgEnvWithHandlerCfg,
+    block_env: &BlockEnv,
+) -> Result<(), DebugTraceError<BlockchainErrorT, StateErrorT>> {
     if evm_config.handler_cfg.spec_id < SpecId::SPURIOUS_DRAGON {
         // Matching Hardhat Network behaviour: https://github.com/NomicFoundation/hardhat/blob/af7e4ce6a18601ec9cd6d4aa335fa7e24450e638/packages/hardhat-core/src/internal/hardhat-network/provider/vm/ethereumjs.ts#L427
         return Err(DebugTraceError::InvalidSpecId {
@@ -47,50 +141,100 @@ where
         return Err(TransactionError::MissingPrevrandao.into());
     }
 
-    for transaction in transactions {
-        if transaction.hash() == transaction_hash {
-            let mut tracer = TracerEip3155::new(trace_config);
+    Ok(())
+}
 
-            let ResultAndState { result, .. } = {
+/// Traces a single transaction with the configured tracer. Returns the trace
+/// and the transaction's state changes, without committing them.
+fn trace_transaction<BlockchainErrorT, StateErrorT>(
+    blockchain: &dyn SyncBlockchain<BlockchainErrorT, StateErrorT>,
+    state: &dyn SyncState<StateErrorT>,
+    evm_config: CfgEnvWithHandlerCfg,
+    trace_config: &DebugTraceConfig,
+    block_env: BlockEnv,
+    tx_env: TxEnv,
+) -> Result<(DebugTraceOutput, State), DebugTraceError<BlockchainErrorT, StateErrorT>>
+where
+    BlockchainErrorT: Debug + Send,
+    StateErrorT: Debug + Send,
+{
+    match trace_config.tracer.clone() {
+        DebugTracer::Eip3155 => {
+            let mut tracer = TracerEip3155::new(trace_config.clone());
+
+            let ResultAndState {
+                result,
+                state: changes,
+            } = {
                 let mut evm = Evm::builder()
                     .with_ref_db(DatabaseComponents {
-                        state: state.as_ref(),
+                        state,
                         block_hash: blockchain,
                     })
                     .with_external_context(&mut tracer)
                     .with_cfg_env_with_handler_cfg(evm_config)
                     .append_handler_register(register_eip_3155_tracer_handles)
                     .with_block_env(block_env)
-                    .with_tx_env(transaction.into())
+                    .with_tx_env(tx_env)
                     .build();
 
                 evm.transact().map_err(TransactionError::from)?
             };
 
-            return Ok(execution_result_to_debug_result(result, tracer));
-        } else {
+            Ok((
+                execution_result_to_debug_result(result, tracer).into(),
+                changes,
+            ))
+        }
+        DebugTracer::Call(config) => {
+            let gas_limit = tx_env.gas_limit;
+            let mut tracer = CallTracer::new(config);
+
+            let ResultAndState {
+                result,
+                state: changes,
+            } = {
+                let mut evm = Evm::builder()
+                    .with_ref_db(DatabaseComponents {
+                        state,
+                        block_hash: blockchain,
+                    })
+                    .with_external_context(&mut tracer)
+                    .with_cfg_env_with_handler_cfg(evm_config)
+                    .append_handler_register(register_call_tracer_handles)
+                    .with_block_env(block_env)
+                    .with_tx_env(tx_env)
+                    .build();
+
+                evm.transact().map_err(TransactionError::from)?
+            };
+
+            Ok((
+                execution_result_to_call_frame(&result, tracer, gas_limit)?.into(),
+                changes,
+            ))
+        }
+        DebugTracer::Prestate(config) => {
             let ResultAndState { state: changes, .. } = {
                 let mut evm = Evm::builder()
                     .with_ref_db(DatabaseComponents {
-                        state: state.as_ref(),
+                        state,
                         block_hash: blockchain,
                     })
-                    .with_cfg_env_with_handler_cfg(evm_config.clone())
-                    .with_block_env(block_env.clone())
-                    .with_tx_env(transaction.into())
+                    .with_cfg_env_with_handler_cfg(evm_config)
+                    .with_block_env(block_env)
+                    .with_tx_env(tx_env)
                     .build();
 
                 evm.transact().map_err(TransactionError::from)?
             };
 
-            state.commit(changes);
+            let trace =
+                prestate_trace(state, &changes, &config).map_err(TransactionError::State)?;
+
+            Ok((trace.into(), changes))
         }
     }
-
-    Err(DebugTraceError::InvalidTransactionHash {
-        transaction_hash: *transaction_hash,
-        block_number: block_env.number,
-    })
 }
 
 /// Convert an `ExecutionResult` to a `DebugTraceResult`.
@@ -122,9 +266,23 @@ pub fn execution_result_to_debug_result(
     }
 }
 
+/// Convert an `ExecutionResult` to the top-level `CallFrame` of a
+/// `callTracer` trace.
+pub fn execution_result_to_call_frame<BlockchainErrorT, StateErrorT>(
+    execution_result: &ExecutionResult,
+    tracer: CallTracer,
+    gas_limit: u64,
+) -> Result<CallFrame, DebugTraceError<BlockchainErrorT, StateErrorT>> {
+    tracer
+        .into_call_frame(execution_result, gas_limit)
+        .ok_or(DebugTraceError::MissingCallFrame)
+}
+
 /// Config options for `debug_traceTransaction`
 #[derive(Debug, Default, Clone)]
 pub struct DebugTraceConfig {
+    /// The tracer to use.
+    pub tracer: DebugTracer,
     /// Disable storage trace.
     pub disable_storage: bool,
     /// Disable memory trace.
@@ -133,6 +291,18 @@ pub struct DebugTraceConfig {
     pub disable_stack: bool,
 }
 
+/// The tracers supported by `debug_traceTransaction`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum DebugTracer {
+    /// The default EIP-3155 struct logger.
+    #[default]
+    Eip3155,
+    /// geth's `callTracer`.
+    Call(CallTracerConfig),
+    /// geth's `prestateTracer`.
+    Prestate(PrestateTracerConfig),
+}
+
 /// Debug trace error.
 #[derive(Debug, thiserror::Error)]
 pub enum DebugTraceError<BlockchainErrorT, StateErrorT> {
@@ -150,6 +320,9 @@ pub enum DebugTraceError<BlockchainErrorT, StateErrorT> {
         /// The block number.
         block_number: U256,
     },
+    /// The `callTracer` did not trace a top-level call frame.
+    #[error("The call tracer did not trace the top-level call frame")]
+    MissingCallFrame,
     /// Signature error.
     #[error(transparent)]
     SignatureError(#[from] SignatureError),
@@ -173,6 +346,47 @@ pub struct DebugTraceResult {
     pub logs: Vec<DebugTraceLogItem>,
 }
 
+/// Output of a `debug_traceTransaction` call, depending on the tracer.
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(untagged)]
+pub enum DebugTraceOutput {
+    /// Output of the default EIP-3155 tracer.
+    Eip3155(DebugTraceResult),
+    /// Output of the `callTracer`.
+    Call(CallFrame),
+    /// Output of the `prestateTracer`.
+    Prestate(PrestateTrace),
+}
+
+impl From<DebugTraceResult> for DebugTraceOutput {
+    fn from(value: DebugTraceResult) -> Self {
+        Self::Eip3155(value)
+    }
+}
+
+impl From<CallFrame> for DebugTraceOutput {
+    fn from(value: CallFrame) -> Self {
+        Self::Call(value)
+    }
+}
+
+impl From<PrestateTrace> for DebugTraceOutput {
+    fn from(value: PrestateTrace) -> Self {
+        Self::Prestate(value)
+    }
+}
+
+/// The trace of a single transaction in the output of
+/// `debug_traceBlockByNumber` and `debug_traceBlockByHash`.
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct TransactionTrace {
+    /// The hash of the traced transaction.
+    #[serde(rename = "txHash")]
+    pub transaction_hash: B256,
+    /// The trace output.
+    pub result: DebugTraceOutput,
+}
+
 /// The output of an EIP-3155 trace.
 /// The required fields match <https://eips.ethereum.org/EIPS/eip-3155#output> except for
 /// `returnData` and `refund` which are not used currently by Hardhat.
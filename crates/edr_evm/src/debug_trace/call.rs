@@ -0,0 +1,469 @@
+use std::sync::Arc;
+
+use edr_eth::{log::Log, Address, Bytes, B256, U256};
+use revm::{
+    handler::register::EvmHandler,
+    interpreter::{
+        opcode::{self, BoxedInstruction, InstructionTables},
+        CallInputs, CallScheme, CreateInputs, InstructionResult, Interpreter, InterpreterResult,
+    },
+    primitives::{CreateScheme, EVMError, ExecutionResult},
+    Database, Evm, FrameOrResult, FrameResult,
+};
+
+use crate::debug::GetContextData;
+
+/// Function selector of Solidity's `Error(string)`.
+const REVERT_REASON_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// Config options for the `callTracer`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CallTracerConfig {
+    /// Only trace the top-level call frame, skipping all sub-calls.
+    pub only_top_call: bool,
+    /// Include the logs emitted by each call frame.
+    pub with_log: bool,
+}
+
+/// The type of a call frame in the output of the `callTracer`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum CallFrameType {
+    /// A `CALL`
+    Call,
+    /// A `CALLCODE`
+    CallCode,
+    /// A `DELEGATECALL`
+    DelegateCall,
+    /// A `STATICCALL`
+    StaticCall,
+    /// A `CREATE`
+    Create,
+    /// A `CREATE2`
+    Create2,
+}
+
+impl From<CallScheme> for CallFrameType {
+    fn from(value: CallScheme) -> Self {
+        match value {
+            CallScheme::Call => Self::Call,
+            CallScheme::CallCode => Self::CallCode,
+            CallScheme::DelegateCall => Self::DelegateCall,
+            CallScheme::StaticCall => Self::StaticCall,
+        }
+    }
+}
+
+impl From<CreateScheme> for CallFrameType {
+    fn from(value: CreateScheme) -> Self {
+        match value {
+            CreateScheme::Create => Self::Create,
+            CreateScheme::Create2 { .. } => Self::Create2,
+        }
+    }
+}
+
+/// A log emitted inside a call frame of the `callTracer`.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct CallLogFrame {
+    /// Address of the contract that emitted the log.
+    pub address: Address,
+    /// Topics of the log.
+    pub topics: Vec<B256>,
+    /// Data of the log.
+    pub data: Bytes,
+}
+
+impl From<&Log> for CallLogFrame {
+    fn from(value: &Log) -> Self {
+        Self {
+            address: value.address,
+            topics: value.topics().to_vec(),
+            data: value.data.data.clone(),
+        }
+    }
+}
+
+/// A call frame in the output of the `callTracer`. Matches the format of
+/// geth's `callTracer`.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallFrame {
+    /// The type of the call frame.
+    #[serde(rename = "type")]
+    pub frame_type: CallFrameType,
+    /// The caller.
+    pub from: Address,
+    /// The callee, or the address of the created contract.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<Address>,
+    /// The value transferred by the call frame.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<U256>,
+    /// The gas provided to the call frame.
+    #[serde(with = "edr_eth::serde::u64")]
+    pub gas: u64,
+    /// The gas used by the call frame.
+    #[serde(with = "edr_eth::serde::u64")]
+    pub gas_used: u64,
+    /// The call data or init code.
+    pub input: Bytes,
+    /// The return data or deployed code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<Bytes>,
+    /// Description of the error, if the call frame failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// The decoded revert reason, if the call frame reverted with one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revert_reason: Option<String>,
+    /// The sub-calls of the call frame.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub calls: Vec<CallFrame>,
+    /// The logs emitted by the call frame. Only populated when `withLog` is
+    /// enabled.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub logs: Vec<CallLogFrame>,
+}
+
+impl CallFrame {
+    fn finalize(&mut self, result: &InterpreterResult, created_address: Option<Address>) {
+        if created_address.is_some() {
+            self.to = created_address;
+        }
+
+        if result.result.is_error() {
+            self.gas_used = self.gas;
+        } else {
+            self.gas_used = result.gas.spent();
+        }
+
+        if !result.output.is_empty() {
+            self.output = Some(result.output.clone());
+        }
+
+        if result.result.is_revert() {
+            self.error = Some(String::from("execution reverted"));
+            self.revert_reason = decode_revert_reason(&result.output);
+        } else if !result.result.is_ok() {
+            self.error = Some(instruction_result_to_error(result.result));
+        }
+    }
+
+    /// Removes the logs of failed call frames, including those of all of
+    /// their sub-calls, as their state changes were reverted.
+    fn clear_failed_logs(&mut self, parent_failed: bool) {
+        let failed = parent_failed || self.error.is_some();
+        if failed {
+            self.logs.clear();
+        }
+
+        self.calls
+            .iter_mut()
+            .for_each(|call| call.clear_failed_logs(failed));
+    }
+}
+
+/// Register `callTracer` handles.
+pub fn register_call_tracer_handles<DatabaseT: Database, ContextT: GetContextData<CallTracer>>(
+    handler: &mut EvmHandler<'_, ContextT, DatabaseT>,
+) {
+    // Only the `LOG*` instructions need to be wrapped, to collect the logs
+    // emitted by each call frame.
+    let table = handler
+        .instruction_table
+        .take()
+        .expect("Handler must have instruction table");
+
+    let table = match table {
+        InstructionTables::Plain(table) => table
+            .into_iter()
+            .enumerate()
+            .map(|(opcode, instruction)| {
+                if is_log_opcode(opcode) {
+                    log_instruction_handler(instruction)
+                } else {
+                    Box::new(instruction) as BoxedInstruction<'_, _>
+                }
+            })
+            .collect::<Vec<_>>(),
+        InstructionTables::Boxed(table) => table
+            .into_iter()
+            .enumerate()
+            .map(|(opcode, instruction)| {
+                if is_log_opcode(opcode) {
+                    log_instruction_handler(instruction)
+                } else {
+                    instruction
+                }
+            })
+            .collect::<Vec<_>>(),
+    };
+
+    // cast vector to array.
+    handler.instruction_table = Some(InstructionTables::Boxed(
+        table.try_into().unwrap_or_else(|_| unreachable!()),
+    ));
+
+    // Create handler
+    let old_handle = handler.execution.create.clone();
+    handler.execution.create = Arc::new(
+        move |ctx, inputs| -> Result<FrameOrResult, EVMError<DatabaseT::Error>> {
+            let tracer = ctx.external.get_context_data();
+            tracer.create(&inputs);
+
+            old_handle(ctx, inputs)
+        },
+    );
+
+    // Call handler
+    let old_handle = handler.execution.call.clone();
+    handler.execution.call = Arc::new(
+        move |ctx, inputs| -> Result<FrameOrResult, EVMError<DatabaseT::Error>> {
+            let tracer = ctx.external.get_context_data();
+            tracer.call(&inputs);
+
+            old_handle(ctx, inputs)
+        },
+    );
+
+    // call outcome
+    let old_handle = handler.execution.insert_call_outcome.clone();
+    handler.execution.insert_call_outcome = Arc::new(move |ctx, frame, shared_memory, outcome| {
+        let tracer = ctx.external.get_context_data();
+        tracer.frame_end(&outcome.result, None);
+
+        old_handle(ctx, frame, shared_memory, outcome)
+    });
+
+    // create outcome
+    let old_handle = handler.execution.insert_create_outcome.clone();
+    handler.execution.insert_create_outcome = Arc::new(move |ctx, frame, outcome| {
+        let tracer = ctx.external.get_context_data();
+        tracer.frame_end(&outcome.result, outcome.address);
+
+        old_handle(ctx, frame, outcome)
+    });
+
+    // last frame outcome
+    let old_handle = handler.execution.last_frame_return.clone();
+    handler.execution.last_frame_return = Arc::new(move |ctx, frame_result| {
+        let tracer = ctx.external.get_context_data();
+        match frame_result {
+            FrameResult::Call(outcome) => tracer.frame_end(&outcome.result, None),
+            FrameResult::Create(outcome) => tracer.frame_end(&outcome.result, outcome.address),
+        }
+
+        old_handle(ctx, frame_result)
+    });
+}
+
+fn is_log_opcode(opcode: usize) -> bool {
+    (usize::from(opcode::LOG0)..=usize::from(opcode::LOG4)).contains(&opcode)
+}
+
+/// Outer closure that collects the log emitted by a `LOG*` instruction.
+fn log_instruction_handler<
+    'a,
+    ContextT: GetContextData<CallTracer>,
+    DatabaseT: Database,
+    Instruction: Fn(&mut Interpreter, &mut Evm<'a, ContextT, DatabaseT>) + 'a,
+>(
+    instruction: Instruction,
+) -> BoxedInstruction<'a, Evm<'a, ContextT, DatabaseT>> {
+    Box::new(
+        move |interpreter: &mut Interpreter, host: &mut Evm<'a, ContextT, DatabaseT>| {
+            // execute instruction.
+            instruction(interpreter, host);
+
+            if interpreter.instruction_result == InstructionResult::Continue {
+                if let Some(log) = host.context.evm.journaled_state.logs.last() {
+                    let log = CallLogFrame::from(log);
+                    host.context.external.get_context_data().log(log);
+                }
+            }
+        },
+    )
+}
+
+/// A tracer that collects nested call frames, matching the output of geth's
+/// `callTracer`.
+#[derive(Debug)]
+pub struct CallTracer {
+    config: CallTracerConfig,
+    // Call frames that haven't finished executing yet
+    stack: Vec<CallFrame>,
+    root: Option<CallFrame>,
+}
+
+impl CallTracer {
+    /// Create a new tracer.
+    pub fn new(config: CallTracerConfig) -> Self {
+        Self {
+            config,
+            stack: Vec::new(),
+            root: None,
+        }
+    }
+
+    /// Consumes the tracer, returning the top-level call frame. The gas fields
+    /// of the top-level call frame are set to the transaction's gas limit and
+    /// gas used, respectively.
+    pub fn into_call_frame(
+        self,
+        execution_result: &ExecutionResult,
+        gas_limit: u64,
+    ) -> Option<CallFrame> {
+        let mut root = self.root?;
+
+        root.gas = gas_limit;
+        root.gas_used = execution_result.gas_used();
+        root.clear_failed_logs(false);
+
+        Some(root)
+    }
+
+    fn call(&mut self, inputs: &CallInputs) {
+        let frame_type = CallFrameType::from(inputs.context.scheme);
+        let value = match frame_type {
+            CallFrameType::DelegateCall | CallFrameType::StaticCall => None,
+            _ => Some(inputs.transfer.value),
+        };
+
+        self.stack.push(CallFrame {
+            frame_type,
+            from: inputs.context.caller,
+            to: Some(inputs.contract),
+            value,
+            gas: inputs.gas_limit,
+            gas_used: 0,
+            input: inputs.input.clone(),
+            output: None,
+            error: None,
+            revert_reason: None,
+            calls: Vec::new(),
+            logs: Vec::new(),
+        });
+    }
+
+    fn create(&mut self, inputs: &CreateInputs) {
+        self.stack.push(CallFrame {
+            frame_type: CallFrameType::from(inputs.scheme),
+            from: inputs.caller,
+            to: None,
+            value: Some(inputs.value),
+            gas: inputs.gas_limit,
+            gas_used: 0,
+            input: inputs.init_code.clone(),
+            output: None,
+            error: None,
+            revert_reason: None,
+            calls: Vec::new(),
+            logs: Vec::new(),
+        });
+    }
+
+    fn frame_end(&mut self, result: &InterpreterResult, created_address: Option<Address>) {
+        let mut frame = self
+            .stack
+            .pop()
+            .expect("Call frames must be pushed before they end");
+
+        frame.finalize(result, created_address);
+
+        if let Some(parent) = self.stack.last_mut() {
+            if !self.config.only_top_call {
+                parent.calls.push(frame);
+            }
+        } else {
+            self.root = Some(frame);
+        }
+    }
+
+    fn log(&mut self, log: CallLogFrame) {
+        if !self.config.with_log {
+            return;
+        }
+
+        if let Some(frame) = self.stack.last_mut() {
+            frame.logs.push(log);
+        }
+    }
+}
+
+impl GetContextData<CallTracer> for CallTracer {
+    fn get_context_data(&mut self) -> &mut CallTracer {
+        self
+    }
+}
+
+/// Decodes the reason string of a Solidity `Error(string)` revert.
+fn decode_revert_reason(output: &Bytes) -> Option<String> {
+    let encoded = output.strip_prefix(REVERT_REASON_SELECTOR.as_slice())?;
+
+    let offset = usize::try_from(U256::try_from_be_slice(encoded.get(0..32)?)?).ok()?;
+    let length_end = offset.checked_add(32)?;
+    let length =
+        usize::try_from(U256::try_from_be_slice(encoded.get(offset..length_end)?)?).ok()?;
+    let reason = encoded.get(length_end..length_end.checked_add(length)?)?;
+
+    String::from_utf8(reason.to_vec()).ok()
+}
+
+/// Converts an unsuccessful instruction result to an error message that
+/// matches geth.
+fn instruction_result_to_error(result: InstructionResult) -> String {
+    let message = match result {
+        InstructionResult::OutOfGas
+        | InstructionResult::MemoryOOG
+        | InstructionResult::MemoryLimitOOG
+        | InstructionResult::PrecompileOOG
+        | InstructionResult::InvalidOperandOOG => "out of gas",
+        InstructionResult::OpcodeNotFound | InstructionResult::InvalidFEOpcode => "invalid opcode",
+        InstructionResult::InvalidJump => "invalid jump destination",
+        InstructionResult::StackUnderflow => "stack underflow",
+        InstructionResult::StackOverflow => "stack overflow",
+        InstructionResult::CallTooDeep => "max call depth exceeded",
+        InstructionResult::OutOfFunds => "insufficient balance for transfer",
+        InstructionResult::CallNotAllowedInsideStatic
+        | InstructionResult::StateChangeDuringStaticCall => "write protection",
+        InstructionResult::CreateCollision => "contract address collision",
+        InstructionResult::CreateContractSizeLimit => "max code size exceeded",
+        InstructionResult::CreateContractStartingWithEF => "invalid code: must not begin with 0xef",
+        _ => return format!("{result:?}"),
+    };
+
+    String::from(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use revm::primitives::hex;
+
+    use super::*;
+
+    #[test]
+    fn decode_revert_reason_error_string() -> anyhow::Result<()> {
+        // `Error("Not enough Ether provided.")`
+        let output = Bytes::from(hex::decode(
+            "08c379a0\
+             0000000000000000000000000000000000000000000000000000000000000020\
+             000000000000000000000000000000000000000000000000000000000000001a\
+             4e6f7420656e6f7567682045746865722070726f76696465642e000000000000",
+        )?);
+
+        assert_eq!(
+            decode_revert_reason(&output),
+            Some(String::from("Not enough Ether provided."))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn decode_revert_reason_custom_error() {
+        let output = Bytes::from_static(&[0xde, 0xad, 0xbe, 0xef]);
+
+        assert_eq!(decode_revert_reason(&output), None);
+    }
+}
@@ -0,0 +1,221 @@
+use std::collections::BTreeMap;
+
+use edr_eth::{Address, Bytes, B256, U256};
+use revm::{
+    db::StateRef,
+    primitives::{Account, AccountInfo, HashMap, KECCAK_EMPTY},
+};
+
+/// Config options for the `prestateTracer`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PrestateTracerConfig {
+    /// Return the differences between the state before and after the
+    /// transaction, instead of only the state before the transaction.
+    pub diff_mode: bool,
+}
+
+/// The state of an account in the output of the `prestateTracer`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct PrestateAccount {
+    /// The account's balance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub balance: Option<U256>,
+    /// The account's nonce.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<u64>,
+    /// The account's code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<Bytes>,
+    /// The storage slots of the account that were accessed.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub storage: BTreeMap<B256, B256>,
+}
+
+impl PrestateAccount {
+    fn is_empty(&self) -> bool {
+        self.balance.is_none()
+            && self.nonce.is_none()
+            && self.code.is_none()
+            && self.storage.is_empty()
+    }
+}
+
+/// Output of the `prestateTracer`. Matches the format of geth's
+/// `prestateTracer`.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(untagged)]
+pub enum PrestateTrace {
+    /// The differences between the state before and after the transaction,
+    /// when `diffMode` is enabled. Only modified accounts and storage slots
+    /// are included.
+    Diff {
+        /// The state before the transaction.
+        pre: BTreeMap<Address, PrestateAccount>,
+        /// The state after the transaction. Only contains the fields that were
+        /// modified.
+        post: BTreeMap<Address, PrestateAccount>,
+    },
+    /// The state before the transaction of all accounts and storage slots
+    /// that were accessed.
+    Prestate(BTreeMap<Address, PrestateAccount>),
+}
+
+/// Constructs the output of the `prestateTracer`, using the state before the
+/// transaction and the state changes produced by it.
+pub fn prestate_trace<StateT: StateRef + ?Sized>(
+    state: &StateT,
+    changes: &HashMap<Address, Account>,
+    config: &PrestateTracerConfig,
+) -> Result<PrestateTrace, StateT::Error> {
+    if config.diff_mode {
+        prestate_diff(state, changes)
+    } else {
+        let prestate = changes
+            .iter()
+            .map(|(address, account)| {
+                let mut prestate =
+                    prestate_account(state, address)?.unwrap_or_else(|| PrestateAccount {
+                        balance: Some(U256::ZERO),
+                        ..PrestateAccount::default()
+                    });
+
+                prestate.storage = account
+                    .storage
+                    .iter()
+                    .map(|(index, slot)| (u256_to_b256(index), u256_to_b256(&slot.original_value)))
+                    .collect();
+
+                Ok((*address, prestate))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(PrestateTrace::Prestate(prestate))
+    }
+}
+
+fn prestate_diff<StateT: StateRef + ?Sized>(
+    state: &StateT,
+    changes: &HashMap<Address, Account>,
+) -> Result<PrestateTrace, StateT::Error> {
+    let mut pre = BTreeMap::new();
+    let mut post = BTreeMap::new();
+
+    for (address, account) in changes {
+        let prestate = prestate_account(state, address)?;
+
+        let modified_slots = account
+            .storage
+            .iter()
+            .filter(|(_index, slot)| slot.is_changed());
+
+        if account.is_selfdestructed() {
+            if let Some(mut prestate) = prestate {
+                prestate.storage = modified_slots
+                    .map(|(index, slot)| (u256_to_b256(index), u256_to_b256(&slot.original_value)))
+                    .collect();
+
+                pre.insert(*address, prestate);
+            }
+
+            continue;
+        }
+
+        let code = account_code(state, &account.info)?;
+
+        let mut poststate = PrestateAccount::default();
+        let mut pre_storage = BTreeMap::new();
+        for (index, slot) in modified_slots {
+            pre_storage.insert(u256_to_b256(index), u256_to_b256(&slot.original_value));
+            poststate
+                .storage
+                .insert(u256_to_b256(index), u256_to_b256(&slot.present_value));
+        }
+
+        match prestate {
+            Some(mut prestate) => {
+                if prestate.balance != Some(account.info.balance) {
+                    poststate.balance = Some(account.info.balance);
+                }
+
+                if prestate.nonce.unwrap_or_default() != account.info.nonce {
+                    poststate.nonce = Some(account.info.nonce);
+                }
+
+                if prestate.code != code {
+                    poststate.code = code;
+                }
+
+                if poststate.is_empty() {
+                    continue;
+                }
+
+                prestate.storage = pre_storage;
+                pre.insert(*address, prestate);
+            }
+            None => {
+                if account.info.balance != U256::ZERO {
+                    poststate.balance = Some(account.info.balance);
+                }
+
+                if account.info.nonce != 0 {
+                    poststate.nonce = Some(account.info.nonce);
+                }
+
+                poststate.code = code;
+
+                if poststate.is_empty() {
+                    continue;
+                }
+            }
+        }
+
+        post.insert(*address, poststate);
+    }
+
+    Ok(PrestateTrace::Diff { pre, post })
+}
+
+/// Retrieves the state of an account before the transaction, without any
+/// storage slots.
+fn prestate_account<StateT: StateRef + ?Sized>(
+    state: &StateT,
+    address: &Address,
+) -> Result<Option<PrestateAccount>, StateT::Error> {
+    state
+        .basic(*address)?
+        .map(|account_info| {
+            let code = account_code(state, &account_info)?;
+
+            Ok(PrestateAccount {
+                balance: Some(account_info.balance),
+                nonce: if account_info.nonce == 0 {
+                    None
+                } else {
+                    Some(account_info.nonce)
+                },
+                code,
+                storage: BTreeMap::new(),
+            })
+        })
+        .transpose()
+}
+
+/// Retrieves the code of an account, if it has any.
+pub(crate) fn account_code<StateT: StateRef + ?Sized>(
+    state: &StateT,
+    account_info: &AccountInfo,
+) -> Result<Option<Bytes>, StateT::Error> {
+    let code = if let Some(code) = account_info.code.as_ref() {
+        code.original_bytes()
+    } else if account_info.code_hash == KECCAK_EMPTY {
+        return Ok(None);
+    } else {
+        state.code_by_hash(account_info.code_hash)?.original_bytes()
+    };
+
+    Ok(if code.is_empty() { None } else { Some(code) })
+}
+
+pub(crate) fn u256_to_b256(value: &U256) -> B256 {
+    B256::new(value.to_be_bytes())
+}
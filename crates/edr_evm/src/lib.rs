@@ -8,14 +8,19 @@
 pub use revm::primitives::*;
 
 pub use crate::{
+    access_list::{register_access_list_tracer_handles, AccessListTracer},
+    blob::{blob_versioned_hash, validate_blob_sidecar, BlobSidecarError},
     block::*,
     debug::{DebugContext, GetContextData},
     debug_trace::{
-        debug_trace_transaction, execution_result_to_debug_result,
-        register_eip_3155_tracer_handles, DebugTraceConfig, DebugTraceError, DebugTraceLogItem,
-        DebugTraceResult, TracerEip3155,
+        debug_trace_block, debug_trace_transaction, execution_result_to_call_frame,
+        execution_result_to_debug_result, prestate_trace, register_call_tracer_handles,
+        register_eip_3155_tracer_handles, CallFrame, CallFrameType, CallLogFrame, CallTracer,
+        CallTracerConfig, DebugTraceConfig, DebugTraceError, DebugTraceLogItem, DebugTraceOutput,
+        DebugTraceResult, DebugTracer, PrestateAccount, PrestateTrace, PrestateTracerConfig,
+        TracerEip3155, TransactionTrace,
     },
-    mempool::{MemPool, MemPoolAddTransactionError, OrderedTransaction},
+    mempool::{MemPool, MemPoolAddTransactionError, MemPoolLimits, OrderedTransaction},
     miner::*,
     random::RandomHashGenerator,
     runtime::{dry_run, guaranteed_dry_run, run, SyncDatabase},
@@ -31,6 +36,8 @@ pub mod state;
 /// Types used for tracing EVM calls
 pub mod trace;
 
+mod access_list;
+mod blob;
 mod block;
 pub(crate) mod collections;
 mod debug;
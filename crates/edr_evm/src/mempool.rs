@@ -1,4 +1,9 @@
-use std::{cmp::Ordering, fmt::Debug, num::NonZeroU64};
+use std::{
+    cmp::Ordering,
+    fmt::Debug,
+    num::{NonZeroU64, NonZeroUsize},
+    time::{Duration, Instant},
+};
 
 use edr_eth::{Address, B256, U256};
 use indexmap::{map::Entry, IndexMap};
@@ -104,6 +109,25 @@ pub enum MemPoolAddTransactionError<SE> {
         /// Sender's nonce.
         sender_nonce: u64,
     },
+    /// The sender already has the maximum number of pending transactions.
+    #[error("Sender has reached the maximum of {limit} pending transactions in the mempool.")]
+    PendingTransactionLimitReached {
+        /// The maximum number of pending transactions per account
+        limit: NonZeroUsize,
+    },
+    /// The sender already has the maximum number of queued transactions.
+    #[error("Sender has reached the maximum of {limit} queued transactions in the mempool.")]
+    QueuedTransactionLimitReached {
+        /// The maximum number of queued transactions per account
+        limit: NonZeroUsize,
+    },
+    /// The mempool is full and the transaction's price is too low to evict
+    /// another transaction.
+    #[error("Transaction underpriced. The mempool is full and a gasPrice/maxFeePerGas higher than {min_gas_price} is necessary to evict a transaction.")]
+    MemPoolFull {
+        /// The gas price of the cheapest transaction that can be evicted
+        min_gas_price: U256,
+    },
     /// Transaction already exists in the mempool.
     #[error("Known transaction: 0x{transaction_hash:x}")]
     TransactionAlreadyExists {
@@ -135,6 +159,7 @@ pub enum MemPoolAddTransactionError<SE> {
 #[derive(Clone, Debug)]
 pub struct OrderedTransaction {
     order_id: usize,
+    inserted_at: Instant,
     transaction: ExecutableTransaction,
 }
 
@@ -162,11 +187,29 @@ impl OrderedTransaction {
     }
 }
 
+/// Limits on the transactions in a [`MemPool`], modelled after geth's
+/// transaction pool. By default, the mempool is unbounded.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MemPoolLimits {
+    /// The maximum number of pending transactions per account
+    pub max_pending_per_account: Option<NonZeroUsize>,
+    /// The maximum number of queued transactions per account
+    pub max_queued_per_account: Option<NonZeroUsize>,
+    /// The maximum number of transactions in the mempool. Once reached, the
+    /// lowest-priced transactions are evicted to make room for higher-priced
+    /// ones.
+    pub max_transactions: Option<NonZeroUsize>,
+    /// The duration after which queued transactions are dropped
+    pub queued_lifetime: Option<Duration>,
+}
+
 /// The mempool contains transactions pending inclusion in the blockchain.
 #[derive(Clone, Debug)]
 pub struct MemPool {
     /// The block's gas limit
     block_gas_limit: NonZeroU64,
+    /// The limits on the mempool's transactions
+    limits: MemPoolLimits,
     /// Transactions that can be executed now
     pending_transactions: IndexMap<Address, Vec<OrderedTransaction>>,
     /// Mapping of transaction hashes to transaction
@@ -180,8 +223,15 @@ pub struct MemPool {
 impl MemPool {
     /// Constructs a new [`MemPool`] with the specified block gas limit.
     pub fn new(block_gas_limit: NonZeroU64) -> Self {
+        Self::with_limits(block_gas_limit, MemPoolLimits::default())
+    }
+
+    /// Constructs a new [`MemPool`] with the specified block gas limit and
+    /// transaction limits.
+    pub fn with_limits(block_gas_limit: NonZeroU64, limits: MemPoolLimits) -> Self {
         Self {
             block_gas_limit,
+            limits,
             pending_transactions: IndexMap::new(),
             hash_to_transaction: HashMap::new(),
             future_transactions: IndexMap::new(),
@@ -194,6 +244,11 @@ impl MemPool {
         self.block_gas_limit
     }
 
+    /// Retrieves the instance's transaction limits.
+    pub fn limits(&self) -> &MemPoolLimits {
+        &self.limits
+    }
+
     /// Sets the instance's block gas limit.
     pub fn set_block_gas_limit<S>(&mut self, state: &S, limit: NonZeroU64) -> Result<(), S::Error>
     where
@@ -262,13 +317,14 @@ impl MemPool {
         !self.pending_transactions.is_empty()
     }
 
-    /// Tries to add the provided transaction to the [`MemPool`].
+    /// Tries to add the provided transaction to the [`MemPool`]. Returns the
+    /// transactions that were evicted to make room for it.
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub fn add_transaction<S: StateRef + ?Sized>(
         &mut self,
         state: &S,
         transaction: ExecutableTransaction,
-    ) -> Result<(), MemPoolAddTransactionError<S::Error>> {
+    ) -> Result<Vec<OrderedTransaction>, MemPoolAddTransactionError<S::Error>> {
         let transaction_gas_limit = transaction.gas_limit();
         if transaction_gas_limit > self.block_gas_limit.get() {
             return Err(MemPoolAddTransactionError::ExceedsBlockGasLimit {
@@ -300,9 +356,21 @@ impl MemPool {
             });
         }
 
+        // Replacements don't increase the number of transactions
+        let evicted_transactions =
+            if self.has_transaction_with_nonce(transaction.caller(), transaction.nonce()) {
+                Vec::new()
+            } else {
+                let next_nonce = account_next_nonce(self, state, transaction.caller())?;
+                self.validate_account_limits(&transaction, next_nonce)?;
+
+                self.evict_transactions(&transaction)?
+            };
+
         let next_nonce = account_next_nonce(self, state, transaction.caller())?;
         let transaction = OrderedTransaction {
             order_id: self.next_order_id,
+            inserted_at: Instant::now(),
             transaction,
         };
 
@@ -317,7 +385,32 @@ impl MemPool {
         self.hash_to_transaction
             .insert(*transaction.hash(), transaction);
 
-        Ok(())
+        Ok(evicted_transactions)
+    }
+
+    /// Removes all queued transactions that have been in the [`MemPool`] for
+    /// longer than the configured lifetime, at the provided point in time.
+    /// Returns the removed transactions.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub fn remove_expired_transactions(&mut self, now: Instant) -> Vec<OrderedTransaction> {
+        let Some(lifetime) = self.limits.queued_lifetime else {
+            return Vec::new();
+        };
+
+        let expired_hashes = self
+            .future_transactions
+            .values()
+            .flatten()
+            .filter(|transaction| {
+                now.saturating_duration_since(transaction.inserted_at) >= lifetime
+            })
+            .map(|transaction| *transaction.hash())
+            .collect::<Vec<_>>();
+
+        expired_hashes
+            .iter()
+            .filter_map(|hash| self.remove_transaction(hash))
+            .collect()
     }
 
     /// Removes the transaction corresponding to the provided transaction hash,
@@ -332,19 +425,14 @@ impl MemPool {
                     .enumerate()
                     .find(|(_, transaction)| *transaction.hash() == *hash)
                 {
-                    let mut invalidated_transactions = pending_transactions.split_off(idx + 1);
+                    let invalidated_transactions = pending_transactions.split_off(idx + 1);
                     let removed = pending_transactions.remove(idx);
 
                     if pending_transactions.is_empty() {
                         self.pending_transactions.shift_remove(caller);
                     }
 
-                    self.future_transactions
-                        .entry(*caller)
-                        .and_modify(|transactions| {
-                            transactions.append(&mut invalidated_transactions);
-                        })
-                        .or_insert(invalidated_transactions);
+                    self.demote_transactions(caller, invalidated_transactions);
 
                     return Some(removed);
                 }
@@ -371,7 +459,8 @@ impl MemPool {
     }
 
     /// Updates the [`MemPool`], moving any future transactions to the pending
-    /// status, if their nonces are high enough.
+    /// status, if their nonces are high enough and the account's pending limit
+    /// allows it.
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub fn update<S>(&mut self, state: &S) -> Result<(), S::Error>
     where
@@ -389,6 +478,7 @@ impl MemPool {
                 && transaction.nonce() >= sender.nonce
         }
 
+        let mut demoted_transactions = Vec::new();
         for entry in self.pending_transactions.iter_mut() {
             let (caller, transactions) = entry;
             let sender = state.basic(*caller)?.unwrap_or_default();
@@ -413,12 +503,7 @@ impl MemPool {
                 .find(|(idx, transaction)| transaction.nonce() != sender.nonce + *idx as u64)
             {
                 // Move all consequent transactions to the future queue
-                let mut invalidated_transactions = transactions.split_off(idx);
-
-                self.future_transactions
-                    .entry(*caller)
-                    .and_modify(|transactions| transactions.append(&mut invalidated_transactions))
-                    .or_insert(invalidated_transactions);
+                demoted_transactions.push((*caller, transactions.split_off(idx)));
             }
         }
 
@@ -426,6 +511,10 @@ impl MemPool {
         self.pending_transactions
             .retain(|_, transactions| !transactions.is_empty());
 
+        for (caller, transactions) in demoted_transactions {
+            self.demote_transactions(&caller, transactions);
+        }
+
         for entry in self.future_transactions.iter_mut() {
             let (caller, transactions) = entry;
             let sender = state.basic(*caller)?.unwrap_or_default();
@@ -446,6 +535,14 @@ impl MemPool {
         self.future_transactions
             .retain(|_, transactions| !transactions.is_empty());
 
+        // Promote future transactions that became executable, e.g. because
+        // pending transactions were mined and freed up slots
+        let callers = self.future_transactions.keys().copied().collect::<Vec<_>>();
+        for caller in callers {
+            let next_nonce = account_next_nonce(self, state, &caller)?;
+            self.promote_future_transactions(&caller, next_nonce);
+        }
+
         Ok(())
     }
 
@@ -456,6 +553,83 @@ impl MemPool {
         self.hash_to_transaction.get(hash)
     }
 
+    fn has_transaction_with_nonce(&self, caller: &Address, nonce: u64) -> bool {
+        self.pending_transactions
+            .get(caller)
+            .into_iter()
+            .chain(self.future_transactions.get(caller))
+            .flatten()
+            .any(|transaction| transaction.nonce() == nonce)
+    }
+
+    fn validate_account_limits<StateError>(
+        &self,
+        transaction: &ExecutableTransaction,
+        next_nonce: u64,
+    ) -> Result<(), MemPoolAddTransactionError<StateError>> {
+        let caller = transaction.caller();
+        if transaction.nonce() > next_nonce {
+            if let Some(limit) = self.limits.max_queued_per_account {
+                let num_queued = self.future_transactions.get(caller).map_or(0, Vec::len);
+                if num_queued >= limit.get() {
+                    return Err(MemPoolAddTransactionError::QueuedTransactionLimitReached {
+                        limit,
+                    });
+                }
+            }
+        } else if let Some(limit) = self.limits.max_pending_per_account {
+            let num_pending = self.pending_transactions.get(caller).map_or(0, Vec::len);
+            if num_pending >= limit.get() {
+                return Err(MemPoolAddTransactionError::PendingTransactionLimitReached { limit });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Evicts the lowest-priced transactions until there is room for the
+    /// provided transaction. Transactions of the same sender are never
+    /// evicted, as that could invalidate the provided transaction.
+    fn evict_transactions<StateError>(
+        &mut self,
+        transaction: &ExecutableTransaction,
+    ) -> Result<Vec<OrderedTransaction>, MemPoolAddTransactionError<StateError>> {
+        let Some(max_transactions) = self.limits.max_transactions else {
+            return Ok(Vec::new());
+        };
+
+        let mut evicted_transactions = Vec::new();
+        while self.hash_to_transaction.len() >= max_transactions.get() {
+            // Evict the newest of the cheapest transactions
+            let cheapest = self
+                .hash_to_transaction
+                .values()
+                .filter(|candidate| candidate.caller() != transaction.caller())
+                .min_by(|lhs, rhs| {
+                    lhs.transaction
+                        .gas_price()
+                        .cmp(&rhs.transaction.gas_price())
+                        .then_with(|| rhs.order_id.cmp(&lhs.order_id))
+                });
+
+            let Some(cheapest) = cheapest else {
+                return Err(MemPoolAddTransactionError::MemPoolFull {
+                    min_gas_price: transaction.gas_price(),
+                });
+            };
+
+            let min_gas_price = cheapest.transaction.gas_price();
+            if transaction.gas_price() <= min_gas_price {
+                return Err(MemPoolAddTransactionError::MemPoolFull { min_gas_price });
+            }
+
+            let cheapest_hash = *cheapest.hash();
+            evicted_transactions.extend(self.remove_transaction(&cheapest_hash));
+        }
+
+        Ok(evicted_transactions)
+    }
+
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     fn insert_pending_transaction<StateError>(
         &mut self,
@@ -485,29 +659,81 @@ impl MemPool {
         }
 
         let caller = *transaction.caller();
-        let mut next_pending_nonce = transaction.nonce() + 1;
+        let next_pending_nonce = transaction.nonce() + 1;
 
-        let pending_transactions = pending_transactions.or_default();
-        pending_transactions.push(transaction);
+        pending_transactions.or_default().push(transaction);
 
-        // Move as many future transactions as possible to the pending status
-        if let Some(future_transactions) = self.future_transactions.get_mut(&caller) {
-            while let Some((idx, _)) = future_transactions
-                .iter()
-                .enumerate()
-                .find(|(_, transaction)| transaction.nonce() == next_pending_nonce)
-            {
-                pending_transactions.push(future_transactions.remove(idx));
+        self.promote_future_transactions(&caller, next_pending_nonce);
 
-                next_pending_nonce += 1;
-            }
+        Ok(())
+    }
 
-            if future_transactions.is_empty() {
-                self.future_transactions.shift_remove(&caller);
-            }
+    /// Moves as many future transactions of the provided account as possible
+    /// to the pending status, starting from the provided nonce, without
+    /// exceeding the account's pending limit.
+    fn promote_future_transactions(&mut self, caller: &Address, mut next_pending_nonce: u64) {
+        let Some(future_transactions) = self.future_transactions.get_mut(caller) else {
+            return;
+        };
+
+        let max_pending = self
+            .limits
+            .max_pending_per_account
+            .map_or(usize::MAX, NonZeroUsize::get);
+
+        let pending_transactions = self.pending_transactions.entry(*caller).or_default();
+        while let Some((idx, _)) = future_transactions
+            .iter()
+            .enumerate()
+            .find(|(_, transaction)| transaction.nonce() == next_pending_nonce)
+            .filter(|_| pending_transactions.len() < max_pending)
+        {
+            pending_transactions.push(future_transactions.remove(idx));
+
+            next_pending_nonce += 1;
         }
 
-        Ok(())
+        if future_transactions.is_empty() {
+            self.future_transactions.shift_remove(caller);
+        }
+
+        if pending_transactions.is_empty() {
+            self.pending_transactions.shift_remove(caller);
+        }
+    }
+
+    /// Moves the provided transactions of the account to the future queue.
+    /// If that exceeds the account's queued limit, the transactions with the
+    /// highest nonces are dropped, as they are the last to become executable.
+    fn demote_transactions(&mut self, caller: &Address, mut transactions: Vec<OrderedTransaction>) {
+        if transactions.is_empty() {
+            return;
+        }
+
+        let future_transactions = self.future_transactions.entry(*caller).or_default();
+        future_transactions.append(&mut transactions);
+
+        if let Some(limit) = self.limits.max_queued_per_account {
+            if future_transactions.len() > limit.get() {
+                let mut nonces = future_transactions
+                    .iter()
+                    .map(OrderedTransaction::nonce)
+                    .collect::<Vec<_>>();
+
+                nonces.sort_unstable();
+                let max_nonce = nonces[limit.get() - 1];
+
+                future_transactions.retain(|transaction| {
+                    let should_retain = transaction.nonce() <= max_nonce;
+
+                    if !should_retain {
+                        self.hash_to_transaction.remove(transaction.hash());
+                    }
+
+                    should_retain
+                });
+            }
+        }
     }
 
     #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
@@ -1,7 +1,9 @@
 use std::{cmp::Ordering, fmt::Debug, sync::Arc};
 
-use edr_eth::{block::BlockOptions, U256};
-use revm::primitives::{CfgEnvWithHandlerCfg, ExecutionResult, InvalidTransaction};
+use edr_eth::{block::BlockOptions, B256, U256};
+use revm::primitives::{
+    keccak256, CfgEnvWithHandlerCfg, ExecutionResult, HashSet, InvalidTransaction,
+};
 use serde::{Deserialize, Serialize};
 
 use crate::{
@@ -49,13 +51,74 @@ pub struct MineBlockResultAndState<StateErrorT> {
     pub transaction_results: Vec<ExecutionResult>,
 }
 
-/// The type of ordering to use when selecting blocks to mine.
-#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+/// A bundle of transactions that is included atomically at the top of a block.
+/// Either all of its transactions are included, or none of them.
+#[derive(Clone, Debug)]
+pub struct TransactionBundle {
+    /// The transactions of the bundle, in order
+    pub transactions: Vec<ExecutableTransaction>,
+    /// The hashes of the transactions that are allowed to revert without
+    /// discarding the bundle
+    pub reverting_transaction_hashes: Vec<B256>,
+}
+
+/// A strategy for ordering pending transactions when mining a block.
+///
+/// Transactions of the same sender are always mined in order of their nonces;
+/// the strategy only determines which sender's next transaction is mined
+/// first.
+pub trait TransactionOrdering: Debug + Send + Sync {
+    /// Compares two pending transactions, given the base fee of the block
+    /// that is being mined. [`Ordering::Less`] means that `lhs` is mined before
+    /// `rhs`.
+    fn compare(
+        &self,
+        lhs: &OrderedTransaction,
+        rhs: &OrderedTransaction,
+        base_fee: Option<U256>,
+    ) -> Ordering;
+}
+
+/// The type of ordering to use when selecting blocks to mine. Orderings are
+/// serialized by their variant name, but their camelCase names are accepted
+/// as well.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum MineOrdering {
     /// Insertion order
+    #[serde(alias = "fifo")]
     Fifo,
     /// Effective miner fee
+    #[serde(alias = "priority")]
     Priority,
+    /// Inverse effective miner fee; i.e. the lowest effective miner fee first
+    #[serde(alias = "reversePriority")]
+    ReversePriority,
+    /// A pseudo-random order, which is determined by the provided seed
+    #[serde(alias = "random")]
+    Random(#[serde(with = "edr_eth::serde::u64")] u64),
+    /// The order of the provided transaction hashes. Transactions that are
+    /// not included are mined afterwards, in insertion order.
+    #[serde(alias = "explicit")]
+    Explicit(Vec<B256>),
+}
+
+impl TransactionOrdering for MineOrdering {
+    fn compare(
+        &self,
+        lhs: &OrderedTransaction,
+        rhs: &OrderedTransaction,
+        base_fee: Option<U256>,
+    ) -> Ordering {
+        match self {
+            MineOrdering::Fifo => first_in_first_out_comparator(lhs, rhs),
+            MineOrdering::Priority => priority_comparator(lhs, rhs, base_fee),
+            MineOrdering::ReversePriority => reverse_priority_comparator(lhs, rhs, base_fee),
+            MineOrdering::Random(seed) => random_comparator(lhs, rhs, *seed),
+            MineOrdering::Explicit(transaction_hashes) => {
+                explicit_comparator(lhs, rhs, transaction_hashes)
+            }
+        }
+    }
 }
 
 /// An error that occurred while mining a block.
@@ -80,16 +143,21 @@ pub enum MineBlockError<BE, SE> {
 }
 
 /// Mines a block using as many transactions as can fit in it.
+///
+/// The provided bundles are placed at the top of the block, in order. A bundle
+/// is skipped if any of its transactions is invalid or reverts without being
+/// allowed to.
 #[allow(clippy::too_many_arguments)]
 #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
 pub fn mine_block<'blockchain, 'evm, BlockchainErrorT, DebugDataT, StateErrorT>(
     blockchain: &'blockchain dyn SyncBlockchain<BlockchainErrorT, StateErrorT>,
     mut state: Box<dyn SyncState<StateErrorT>>,
     mem_pool: &MemPool,
+    bundles: &[TransactionBundle],
     cfg: &CfgEnvWithHandlerCfg,
     options: BlockOptions,
     min_gas_price: U256,
-    mine_ordering: MineOrdering,
+    mine_ordering: &dyn TransactionOrdering,
     reward: U256,
     dao_hardfork_activation_block: Option<u64>,
     mut debug_context: Option<
@@ -112,22 +180,36 @@ where
         dao_hardfork_activation_block,
     )?;
 
-    let mut pending_transactions = {
-        type MineOrderComparator =
-            dyn Fn(&OrderedTransaction, &OrderedTransaction) -> Ordering + Send;
+    let base_fee = block_builder.header().base_fee;
+    let mut pending_transactions =
+        mem_pool.iter(|lhs, rhs| mine_ordering.compare(lhs, rhs, base_fee));
 
-        let base_fee = block_builder.header().base_fee;
-        let comparator: Box<MineOrderComparator> = match mine_ordering {
-            MineOrdering::Fifo => Box::new(first_in_first_out_comparator),
-            MineOrdering::Priority => {
-                Box::new(move |lhs, rhs| priority_comparator(lhs, rhs, base_fee))
-            }
-        };
+    let mut results = Vec::new();
+    let mut bundle_senders = HashSet::new();
 
-        mem_pool.iter(comparator)
-    };
+    for bundle in bundles {
+        if !is_bundle_includable(blockchain, &block_builder, &*state, bundle)? {
+            continue;
+        }
 
-    let mut results = Vec::new();
+        for transaction in &bundle.transactions {
+            bundle_senders.insert(*transaction.caller());
+
+            let ExecutionResultWithContext {
+                result,
+                evm_context,
+            } = block_builder.add_transaction(
+                blockchain,
+                state,
+                transaction.clone(),
+                debug_context,
+            );
+
+            results.push(result?);
+            state = evm_context.state;
+            debug_context = evm_context.debug;
+        }
+    }
 
     while let Some(transaction) = pending_transactions.next() {
         if transaction.gas_price() < min_gas_price {
@@ -142,10 +224,21 @@ where
         } = block_builder.add_transaction(blockchain, state, transaction, debug_context);
 
         match result {
+            // Only an included bundle can have consumed the caller's nonce
+            Err(
+                error @ BlockTransactionError::InvalidTransaction(InvalidTransaction::NonceTooLow {
+                    ..
+                }),
+            ) if !bundle_senders.contains(&caller) => {
+                return Err(MineBlockError::BlockTransaction(error));
+            }
             Err(
                 BlockTransactionError::ExceedsBlockGasLimit
+                | BlockTransactionError::ExceedsBlockBlobGasLimit
                 | BlockTransactionError::InvalidTransaction(
-                    InvalidTransaction::GasPriceLessThanBasefee,
+                    InvalidTransaction::GasPriceLessThanBasefee
+                    | InvalidTransaction::BlobGasPriceGreaterThanMax
+                    | InvalidTransaction::NonceTooLow { .. },
                 ),
             ) => {
                 pending_transactions.remove_caller(&caller);
@@ -178,6 +271,121 @@ where
     })
 }
 
+/// Mines a block that contains exactly the provided transactions, in the
+/// provided order.
+///
+/// Unlike [`mine_block`], no mempool or bundles are consulted and a transaction
+/// that cannot be included results in an error.
+#[allow(clippy::too_many_arguments)]
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn mine_block_with_transactions<'blockchain, 'evm, BlockchainErrorT, DebugDataT, StateErrorT>(
+    blockchain: &'blockchain dyn SyncBlockchain<BlockchainErrorT, StateErrorT>,
+    mut state: Box<dyn SyncState<StateErrorT>>,
+    transactions: Vec<ExecutableTransaction>,
+    cfg: &CfgEnvWithHandlerCfg,
+    options: BlockOptions,
+    reward: U256,
+    dao_hardfork_activation_block: Option<u64>,
+    mut debug_context: Option<
+        DebugContext<'evm, BlockchainErrorT, DebugDataT, Box<dyn SyncState<StateErrorT>>>,
+    >,
+) -> Result<MineBlockResultAndState<StateErrorT>, MineBlockError<BlockchainErrorT, StateErrorT>>
+where
+    'blockchain: 'evm,
+    BlockchainErrorT: Debug + Send,
+    StateErrorT: Debug + Send,
+{
+    let parent_block = blockchain
+        .last_block()
+        .map_err(MineBlockError::Blockchain)?;
+
+    let mut block_builder = BlockBuilder::new(
+        cfg.clone(),
+        &parent_block,
+        options,
+        dao_hardfork_activation_block,
+    )?;
+
+    let mut results = Vec::with_capacity(transactions.len());
+    for transaction in transactions {
+        let ExecutionResultWithContext {
+            result,
+            evm_context,
+        } = block_builder.add_transaction(blockchain, state, transaction, debug_context);
+
+        results.push(result?);
+        state = evm_context.state;
+        debug_context = evm_context.debug;
+    }
+
+    let beneficiary = block_builder.header().beneficiary;
+    let rewards = vec![(beneficiary, reward)];
+    let BuildBlockResult { block, state_diff } = block_builder
+        .finalize(&mut state, rewards)
+        .map_err(MineBlockError::BlockFinalize)?;
+
+    Ok(MineBlockResultAndState {
+        block,
+        state,
+        state_diff,
+        transaction_results: results,
+    })
+}
+
+/// Executes the bundle on top of copies of the block builder and state, to
+/// determine whether it can be included without affecting the block.
+///
+/// The state is cloned for every bundle, which is acceptable as only few
+/// bundles are expected to be pending at a time. The bundle is executed again
+/// when it's added to the block, so that its traces are collected by the
+/// debug context.
+fn is_bundle_includable<BlockchainErrorT, StateErrorT>(
+    blockchain: &dyn SyncBlockchain<BlockchainErrorT, StateErrorT>,
+    block_builder: &BlockBuilder,
+    state: &dyn SyncState<StateErrorT>,
+    bundle: &TransactionBundle,
+) -> Result<bool, MineBlockError<BlockchainErrorT, StateErrorT>>
+where
+    BlockchainErrorT: Debug + Send,
+    StateErrorT: Debug + Send,
+{
+    let mut block_builder = block_builder.clone();
+    let mut state = dyn_clone::clone_box(state);
+
+    for transaction in &bundle.transactions {
+        let transaction_hash = *transaction.hash();
+
+        let debug_context: Option<DebugContext<'_, _, (), _>> = None;
+        let ExecutionResultWithContext {
+            result,
+            evm_context,
+        } = block_builder.add_transaction(blockchain, state, transaction.clone(), debug_context);
+
+        match result {
+            Err(
+                BlockTransactionError::ExceedsBlockGasLimit
+                | BlockTransactionError::ExceedsBlockBlobGasLimit
+                | BlockTransactionError::InsufficientFunds { .. }
+                | BlockTransactionError::InvalidTransaction(_),
+            ) => return Ok(false),
+            Err(error) => return Err(MineBlockError::BlockTransaction(error)),
+            Ok(result) => {
+                if !result.is_success()
+                    && !bundle
+                        .reverting_transaction_hashes
+                        .contains(&transaction_hash)
+                {
+                    return Ok(false);
+                }
+            }
+        }
+
+        state = evm_context.state;
+    }
+
+    Ok(true)
+}
+
 fn effective_miner_fee(transaction: &ExecutableTransaction, base_fee: Option<U256>) -> U256 {
     let max_fee_per_gas = transaction.gas_price();
     let max_priority_fee_per_gas = transaction
@@ -213,6 +421,53 @@ fn priority_comparator(
     }
 }
 
+fn reverse_priority_comparator(
+    lhs: &OrderedTransaction,
+    rhs: &OrderedTransaction,
+    base_fee: Option<U256>,
+) -> Ordering {
+    let ordering = effective_miner_fee(lhs.pending(), base_fee)
+        .cmp(&effective_miner_fee(rhs.pending(), base_fee));
+
+    // If two txs have the same effective miner fee we want to sort them
+    // in increasing order by orderId
+    if ordering == Ordering::Equal {
+        lhs.order_id().cmp(&rhs.order_id())
+    } else {
+        ordering
+    }
+}
+
+fn random_comparator(lhs: &OrderedTransaction, rhs: &OrderedTransaction, seed: u64) -> Ordering {
+    let random_key = |transaction: &OrderedTransaction| {
+        let mut preimage = seed.to_be_bytes().to_vec();
+        preimage.extend_from_slice(transaction.pending().hash().as_slice());
+
+        keccak256(preimage)
+    };
+
+    random_key(lhs)
+        .cmp(&random_key(rhs))
+        .then_with(|| lhs.order_id().cmp(&rhs.order_id()))
+}
+
+fn explicit_comparator(
+    lhs: &OrderedTransaction,
+    rhs: &OrderedTransaction,
+    transaction_hashes: &[B256],
+) -> Ordering {
+    let position = |transaction: &OrderedTransaction| {
+        transaction_hashes
+            .iter()
+            .position(|hash| hash == transaction.pending().hash())
+            .unwrap_or(usize::MAX)
+    };
+
+    position(lhs)
+        .cmp(&position(rhs))
+        .then_with(|| lhs.order_id().cmp(&rhs.order_id()))
+}
+
 #[cfg(test)]
 mod tests {
     use edr_eth::{AccountInfo, Address};
@@ -450,4 +705,124 @@ mod tests {
 
         Ok(())
     }
+
+    fn funded_fixture(senders: &[Address]) -> MemPoolTestFixture {
+        let account_with_balance = AccountInfo {
+            balance: U256::from(100_000_000u64),
+            ..AccountInfo::default()
+        };
+
+        let accounts = senders
+            .iter()
+            .map(|sender| (*sender, account_with_balance.clone()))
+            .collect::<Vec<_>>();
+
+        MemPoolTestFixture::with_accounts(&accounts)
+    }
+
+    #[test]
+    fn reverse_priority_ordering() -> anyhow::Result<()> {
+        let sender1 = Address::random();
+        let sender2 = Address::random();
+        let sender3 = Address::random();
+
+        let mut fixture = funded_fixture(&[sender1, sender2, sender3]);
+
+        let transaction1 = dummy_eip155_transaction_with_price(sender1, 0, U256::from(1_000))?;
+        fixture.add_transaction(transaction1.clone())?;
+
+        let transaction2 = dummy_eip155_transaction_with_price(sender2, 0, U256::from(123))?;
+        fixture.add_transaction(transaction2.clone())?;
+
+        // This has the same gasPrice as tx1, but arrived later, so it's placed later
+        // in the queue
+        let transaction3 = dummy_eip155_transaction_with_price(sender3, 0, U256::from(1_000))?;
+        fixture.add_transaction(transaction3.clone())?;
+
+        let ordering = MineOrdering::ReversePriority;
+        let mut ordered_transactions = fixture
+            .mem_pool
+            .iter(|lhs, rhs| ordering.compare(lhs, rhs, None));
+
+        assert_eq!(ordered_transactions.next(), Some(transaction2));
+        assert_eq!(ordered_transactions.next(), Some(transaction1));
+        assert_eq!(ordered_transactions.next(), Some(transaction3));
+
+        Ok(())
+    }
+
+    #[test]
+    fn random_ordering_is_deterministic() -> anyhow::Result<()> {
+        let senders = (0..10).map(|_| Address::random()).collect::<Vec<_>>();
+        let mut fixture = funded_fixture(&senders);
+
+        for sender in &senders {
+            fixture.add_transaction(dummy_eip155_transaction_with_price(
+                *sender,
+                0,
+                U256::from(100),
+            )?)?;
+        }
+
+        let order = |seed| {
+            let ordering = MineOrdering::Random(seed);
+            fixture
+                .mem_pool
+                .iter(|lhs, rhs| ordering.compare(lhs, rhs, None))
+                .map(|transaction| *transaction.caller())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(order(1), order(1));
+        assert_ne!(order(1), order(2));
+
+        let mut ordered_senders = order(1);
+        ordered_senders.sort();
+
+        let mut expected_senders = senders;
+        expected_senders.sort();
+
+        assert_eq!(ordered_senders, expected_senders);
+
+        Ok(())
+    }
+
+    #[test]
+    fn explicit_ordering() -> anyhow::Result<()> {
+        let sender1 = Address::random();
+        let sender2 = Address::random();
+        let sender3 = Address::random();
+
+        let mut fixture = funded_fixture(&[sender1, sender2, sender3]);
+
+        let transaction1 = dummy_eip155_transaction_with_price(sender1, 0, U256::from(100))?;
+        fixture.add_transaction(transaction1.clone())?;
+
+        let transaction2 = dummy_eip155_transaction_with_price(sender1, 1, U256::from(100))?;
+        fixture.add_transaction(transaction2.clone())?;
+
+        let transaction3 = dummy_eip155_transaction_with_price(sender2, 0, U256::from(100))?;
+        fixture.add_transaction(transaction3.clone())?;
+
+        let transaction4 = dummy_eip155_transaction_with_price(sender3, 0, U256::from(100))?;
+        fixture.add_transaction(transaction4.clone())?;
+
+        // Transactions of the same sender are mined in nonce order, and
+        // transactions that aren't listed are mined last.
+        let ordering = MineOrdering::Explicit(vec![
+            *transaction2.hash(),
+            *transaction4.hash(),
+            *transaction1.hash(),
+        ]);
+        let mut ordered_transactions = fixture
+            .mem_pool
+            .iter(|lhs, rhs| ordering.compare(lhs, rhs, None));
+
+        assert_eq!(ordered_transactions.next(), Some(transaction4));
+        assert_eq!(ordered_transactions.next(), Some(transaction1));
+        assert_eq!(ordered_transactions.next(), Some(transaction2));
+        assert_eq!(ordered_transactions.next(), Some(transaction3));
+
+        Ok(())
+    }
 }
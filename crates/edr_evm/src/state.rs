@@ -1,6 +1,7 @@
 mod account;
 mod debug;
 mod diff;
+mod dump;
 mod fork;
 mod irregular;
 mod r#override;
@@ -17,6 +18,7 @@ use revm::{db::StateRef, DatabaseCommit};
 pub use self::{
     debug::{AccountModifierFn, StateDebug},
     diff::StateDiff,
+    dump::{AccountDump, StateDump},
     fork::ForkState,
     irregular::IrregularState,
     overrides::*,
@@ -42,6 +44,9 @@ pub enum StateError {
         /// Whether the state root was intended for a fork
         is_fork: bool,
     },
+    /// Proofs can't be generated for a forked state that was modified locally
+    #[error("Proofs are not supported for forked state that has been modified locally.")]
+    UnsupportedProof,
     /// Error from the underlying RPC client
     #[error(transparent)]
     Remote(#[from] RpcClientError),
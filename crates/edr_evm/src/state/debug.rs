@@ -1,8 +1,10 @@
 use std::{fmt::Debug, ops::Deref};
 
 use auto_impl::auto_impl;
-use edr_eth::{Address, B256, U256};
-use revm::primitives::{AccountInfo, Bytecode};
+use edr_eth::{account::AccountProof, Address, B256, U256};
+use revm::primitives::{AccountInfo, Bytecode, HashMap};
+
+use super::StateDump;
 
 type BoxedAccountModifierFn = Box<dyn Fn(&mut U256, &mut u64, &mut Option<Bytecode>) + Send>;
 
@@ -42,9 +44,24 @@ pub trait StateDebug {
     /// The state's error type.
     type Error;
 
+    /// Generates the EIP-1186 Merkle proof of the account at the specified
+    /// address and the specified storage slots.
+    fn account_proof(
+        &self,
+        address: &Address,
+        storage_keys: &[U256],
+    ) -> Result<AccountProof, Self::Error>;
+
     /// Retrieves the storage root of the account at the specified address.
     fn account_storage_root(&self, address: &Address) -> Result<Option<B256>, Self::Error>;
 
+    /// Dumps the accounts of the state, including their code and storage, in a
+    /// format that can be loaded back into a state.
+    ///
+    /// For a forked state, only the changes relative to the fork block are
+    /// included.
+    fn dump(&self) -> Result<StateDump, Self::Error>;
+
     /// Inserts the provided account at the specified address.
     fn insert_account(
         &mut self,
@@ -68,6 +85,14 @@ pub trait StateDebug {
     /// Serializes the state using ordering of addresses and storage indices.
     fn serialize(&self) -> String;
 
+    /// Replaces the storage of the account at the specified address with the
+    /// provided storage. Slots that are not included are cleared.
+    fn set_account_storage(
+        &mut self,
+        address: Address,
+        storage: HashMap<U256, U256>,
+    ) -> Result<(), Self::Error>;
+
     /// Sets the storage slot at the specified address and index to the provided
     /// value.
     ///
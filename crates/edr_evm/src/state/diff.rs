@@ -3,7 +3,7 @@ use revm::primitives::{Account, AccountInfo, AccountStatus, HashMap, StorageSlot
 
 /// The difference between two states, which can be applied to a state to get
 /// the new state using [`revm::db::DatabaseCommit::commit`].
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
 pub struct StateDiff {
     inner: HashMap<Address, Account>,
 }
@@ -24,6 +24,44 @@ impl StateDiff {
             });
     }
 
+    /// Applies the removal of the account at the specified address to this
+    /// instance, overwriting any existing change.
+    pub fn apply_account_removal(&mut self, address: Address) {
+        self.inner.insert(
+            address,
+            Account {
+                info: AccountInfo::default(),
+                storage: HashMap::new(),
+                status: AccountStatus::SelfDestructed | AccountStatus::Touched,
+            },
+        );
+    }
+
+    /// Applies the replacement of the account at the specified address -
+    /// including all of its storage - to this instance, overwriting any
+    /// existing change.
+    pub fn apply_account_replacement(
+        &mut self,
+        address: Address,
+        account_info: AccountInfo,
+        storage: HashMap<U256, U256>,
+    ) {
+        let storage = storage
+            .into_iter()
+            .map(|(index, value)| (index, StorageSlot::new(value)))
+            .collect();
+
+        self.inner.insert(
+            address,
+            Account {
+                info: account_info,
+                storage,
+                // Created accounts have their existing storage cleared upon commit
+                status: AccountStatus::Created | AccountStatus::Touched,
+            },
+        );
+    }
+
     /// Applies a single storage change to this instance, combining it with any
     /// existing change.
     ///
@@ -0,0 +1,41 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use edr_eth::{Address, Bytes, U256};
+use serde::{Deserialize, Serialize};
+
+/// A loadable dump of an account, including its code and storage.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountDump {
+    /// The account's balance
+    pub balance: U256,
+    /// The account's nonce
+    #[serde(with = "edr_eth::serde::u64")]
+    pub nonce: u64,
+    /// The account's code, if it's a contract
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<Bytes>,
+    /// The account's storage slots, ordered by index. For dumps that are
+    /// relative to a fork, zero values signal slots that were cleared.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub storage: BTreeMap<U256, U256>,
+}
+
+/// A loadable dump of a state, ordered by address.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StateDump {
+    /// The state's accounts
+    pub accounts: BTreeMap<Address, AccountDump>,
+    /// Accounts that were removed. Accounts that are also included in
+    /// `accounts` were recreated after their removal, so they replace the
+    /// removed account, including its storage. Only used for dumps that are
+    /// relative to a fork.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub removed_accounts: BTreeSet<Address>,
+    /// Accounts of which the storage was cleared or replaced, so their storage
+    /// in `accounts` replaces the original storage. Only used for dumps that
+    /// are relative to a fork.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub cleared_storage: BTreeSet<Address>,
+}
@@ -1,6 +1,8 @@
 use std::sync::Arc;
 
-use edr_eth::{remote::RpcClient, trie::KECCAK_NULL_RLP, Address, B256, U256};
+use edr_eth::{
+    account::AccountProof, remote::RpcClient, trie::KECCAK_NULL_RLP, Address, B256, U256,
+};
 use parking_lot::{Mutex, RwLock, RwLockUpgradableReadGuard};
 use revm::{
     db::components::{State, StateRef},
@@ -9,7 +11,7 @@ use revm::{
 };
 use tokio::runtime;
 
-use super::{remote::CachedRemoteState, RemoteState, StateDebug, StateError, TrieState};
+use super::{remote::CachedRemoteState, RemoteState, StateDebug, StateDump, StateError, TrieState};
 use crate::random::RandomHashGenerator;
 
 /// A database integrating the state from a remote node and the state from a
@@ -19,6 +21,8 @@ pub struct ForkState {
     local_state: TrieState,
     remote_state: Arc<Mutex<CachedRemoteState>>,
     removed_storage_slots: HashSet<(Address, U256)>,
+    /// Accounts of which the remote storage was cleared
+    cleared_remote_storage: HashSet<Address>,
     /// A pair of the latest state root and local state root
     current_state: RwLock<(B256, B256)>,
     hash_generator: Arc<Mutex<RandomHashGenerator>>,
@@ -45,6 +49,7 @@ impl ForkState {
             local_state,
             remote_state: Arc::new(Mutex::new(CachedRemoteState::new(remote_state))),
             removed_storage_slots: HashSet::new(),
+            cleared_remote_storage: HashSet::new(),
             current_state: RwLock::new((state_root, local_root)),
             hash_generator,
             removed_remote_accounts: HashSet::new(),
@@ -66,6 +71,7 @@ impl Clone for ForkState {
             local_state: self.local_state.clone(),
             remote_state: self.remote_state.clone(),
             removed_storage_slots: self.removed_storage_slots.clone(),
+            cleared_remote_storage: self.cleared_remote_storage.clone(),
             current_state: RwLock::new(*self.current_state.read()),
             hash_generator: self.hash_generator.clone(),
             removed_remote_accounts: self.removed_remote_accounts.clone(),
@@ -96,7 +102,10 @@ impl StateRef for ForkState {
 
     fn storage(&self, address: Address, index: U256) -> Result<U256, Self::Error> {
         let local = self.local_state.storage(address, index)?;
-        if local != U256::ZERO || self.removed_storage_slots.contains(&(address, index)) {
+        if local != U256::ZERO
+            || self.removed_storage_slots.contains(&(address, index))
+            || self.cleared_remote_storage.contains(&address)
+        {
             Ok(local)
         } else {
             self.remote_state.lock().storage(address, index)
@@ -107,6 +116,14 @@ impl StateRef for ForkState {
 impl DatabaseCommit for ForkState {
     fn commit(&mut self, changes: HashMap<Address, Account>) {
         changes.iter().for_each(|(address, account)| {
+            // Neither removed nor (re)created accounts retain their remote storage
+            if account.is_selfdestructed() {
+                self.removed_remote_accounts.insert(*address);
+                self.cleared_remote_storage.insert(*address);
+            } else if account.is_created() {
+                self.cleared_remote_storage.insert(*address);
+            }
+
             account.storage.iter().for_each(|(index, value)| {
                 // We never need to remove zero entries as a "removed" entry means that the
                 // lookup for a value in the local state succeeded.
@@ -123,11 +140,60 @@ impl DatabaseCommit for ForkState {
 impl StateDebug for ForkState {
     type Error = StateError;
 
+    fn account_proof(
+        &self,
+        address: &Address,
+        storage_keys: &[U256],
+    ) -> Result<AccountProof, Self::Error> {
+        // The remote's proofs are only valid as long as the local state hasn't
+        // diverged from it.
+        let is_unmodified = self.local_state.state_root()? == KECCAK_NULL_RLP
+            && self.removed_remote_accounts.is_empty()
+            && self.removed_storage_slots.is_empty()
+            && self.cleared_remote_storage.is_empty();
+
+        if is_unmodified {
+            self.remote_state
+                .lock()
+                .account_proof(address, storage_keys)
+        } else {
+            Err(StateError::UnsupportedProof)
+        }
+    }
+
     fn account_storage_root(&self, _address: &Address) -> Result<Option<B256>, Self::Error> {
         // HACK: Hardhat ignores the storage root, so we set it to the default value
         Ok(Some(KECCAK_NULL_RLP))
     }
 
+    fn dump(&self) -> Result<StateDump, Self::Error> {
+        let mut dump = self
+            .local_state
+            .dump_impl(&|code_hash| self.remote_state.lock().code_by_hash(code_hash))?;
+
+        // Storage slots that were cleared locally need to be cleared when loading
+        for (address, index) in &self.removed_storage_slots {
+            if let Some(account) = dump.accounts.get_mut(address) {
+                account.storage.entry(*index).or_insert(U256::ZERO);
+            }
+        }
+
+        // Accounts that were recreated are included as well, so their remote
+        // storage is cleared when loading
+        dump.removed_accounts = self.removed_remote_accounts.iter().copied().collect();
+
+        // Storage that was cleared locally should not be restored from the remote
+        // when loading
+        dump.cleared_storage = self
+            .cleared_remote_storage
+            .iter()
+            .filter(|address| !self.removed_remote_accounts.contains(address))
+            .copied()
+            .collect();
+
+        Ok(dump)
+    }
+
     fn insert_account(
         &mut self,
         address: Address,
@@ -145,14 +211,10 @@ impl StateDebug for ForkState {
             address,
             modifier,
             &|| {
-                self.remote_state.lock().basic(address)?.map_or_else(
-                    || {
-                        Ok(AccountInfo {
-                            code: None,
-                            ..AccountInfo::default()
-                        })
-                    },
-                    Result::Ok,
+                remote_account_or_default(
+                    &self.remote_state,
+                    &self.removed_remote_accounts,
+                    address,
                 )
             },
             &|code_hash| self.remote_state.lock().code_by_hash(code_hash),
@@ -160,22 +222,44 @@ impl StateDebug for ForkState {
     }
 
     fn remove_account(&mut self, address: Address) -> Result<Option<AccountInfo>, Self::Error> {
-        if let Some(account_info) = self.local_state.remove_account(address)? {
-            Ok(Some(account_info))
+        let removed = if let Some(account_info) = self.local_state.remove_account(address)? {
+            Some(account_info)
         } else if self.removed_remote_accounts.contains(&address) {
-            Ok(None)
-        } else if let Some(account_info) = self.remote_state.lock().basic(address)? {
-            self.removed_remote_accounts.insert(address);
-            Ok(Some(account_info))
+            None
         } else {
-            Ok(None)
+            self.remote_state.lock().basic(address)?
+        };
+
+        if removed.is_some() {
+            // Prevent the remote account from resurfacing after removing its local copy
+            self.removed_remote_accounts.insert(address);
+            self.cleared_remote_storage.insert(address);
         }
+
+        Ok(removed)
     }
 
     fn serialize(&self) -> String {
         self.local_state.serialize()
     }
 
+    fn set_account_storage(
+        &mut self,
+        address: Address,
+        storage: HashMap<U256, U256>,
+    ) -> Result<(), Self::Error> {
+        self.cleared_remote_storage.insert(address);
+
+        self.local_state
+            .set_account_storage_impl(address, storage, &|| {
+                remote_account_or_default(
+                    &self.remote_state,
+                    &self.removed_remote_accounts,
+                    address,
+                )
+            })
+    }
+
     fn set_account_storage_slot(
         &mut self,
         address: Address,
@@ -190,14 +274,10 @@ impl StateDebug for ForkState {
 
         self.local_state
             .set_account_storage_slot_impl(address, index, value, &|| {
-                self.remote_state.lock().basic(address)?.map_or_else(
-                    || {
-                        Ok(AccountInfo {
-                            code: None,
-                            ..AccountInfo::default()
-                        })
-                    },
-                    Ok,
+                remote_account_or_default(
+                    &self.remote_state,
+                    &self.removed_remote_accounts,
+                    address,
                 )
             })
     }
@@ -219,6 +299,26 @@ impl StateDebug for ForkState {
     }
 }
 
+/// Retrieves the remote account that a new local account is based on, or a
+/// default account if it doesn't exist. Removed remote accounts are treated as
+/// non-existent.
+fn remote_account_or_default(
+    remote_state: &Mutex<CachedRemoteState>,
+    removed_remote_accounts: &HashSet<Address>,
+    address: Address,
+) -> Result<AccountInfo, StateError> {
+    let remote_account = if removed_remote_accounts.contains(&address) {
+        None
+    } else {
+        remote_state.lock().basic(address)?
+    };
+
+    Ok(remote_account.unwrap_or_else(|| AccountInfo {
+        code: None,
+        ..AccountInfo::default()
+    }))
+}
+
 #[cfg(all(test, feature = "test-remote"))]
 mod tests {
     use std::{
@@ -228,8 +328,10 @@ mod tests {
 
     use edr_eth::remote::PreEip1898BlockSpec;
     use edr_test_utils::env::get_alchemy_url;
+    use revm::primitives::KECCAK_EMPTY;
 
     use super::*;
+    use crate::state::AccountModifierFn;
 
     const FORK_BLOCK: u64 = 16220843;
 
@@ -322,4 +424,30 @@ mod tests {
 
         assert_eq!(fork_state.basic(dai_address).unwrap(), None);
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn modify_removed_remote_account_success() {
+        let mut fork_state = TestForkState::new().await;
+
+        let dai_address = Address::from_str("0x6b175474e89094c44da98b954eedeac495271d0f")
+            .expect("failed to parse address");
+
+        fork_state.remove_account(dai_address).unwrap();
+
+        let balance = U256::from(1_000);
+        fork_state
+            .modify_account(
+                dai_address,
+                AccountModifierFn::new(Box::new(move |account_balance, _, _| {
+                    *account_balance = balance;
+                })),
+            )
+            .unwrap();
+
+        // The removed remote account must not resurface
+        let account_info = fork_state.basic(dai_address).unwrap().unwrap();
+        assert_eq!(account_info.balance, balance);
+        assert_eq!(account_info.nonce, 0);
+        assert_eq!(account_info.code_hash, KECCAK_EMPTY);
+    }
 }
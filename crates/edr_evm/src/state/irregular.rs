@@ -6,7 +6,7 @@ use std::{
 use super::StateOverride;
 
 /// Container for state that was modified outside of mining a block.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
 pub struct IrregularState {
     block_number_to_override: BTreeMap<u64, StateOverride>,
 }
@@ -20,6 +20,11 @@ impl IrregularState {
         self.block_number_to_override.entry(block_number)
     }
 
+    /// Removes all state overrides after the specified block number.
+    pub fn revert_to_block(&mut self, block_number: u64) {
+        self.block_number_to_override.split_off(&(block_number + 1));
+    }
+
     /// Retrieves the irregular state overrides.
     pub fn state_overrides(&self) -> &BTreeMap<u64, StateOverride> {
         &self.block_number_to_override
@@ -4,7 +4,7 @@ use super::StateDiff;
 
 /// Data for overriding a state with a diff and the state's resulting state
 /// root.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 pub struct StateOverride {
     /// The diff to be applied.
     pub diff: StateDiff,
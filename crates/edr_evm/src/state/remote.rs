@@ -4,6 +4,7 @@ use std::sync::Arc;
 
 pub use cached::CachedRemoteState;
 use edr_eth::{
+    account::AccountProof,
     remote::{BlockSpec, PreEip1898BlockSpec, RpcClient, RpcClientError},
     Address, B256, U256,
 };
@@ -52,6 +53,22 @@ impl RemoteState {
         self.block_number = block_number;
     }
 
+    /// Retrieves the EIP-1186 Merkle proof of an account and the specified
+    /// storage slots.
+    pub fn account_proof(
+        &self,
+        address: &Address,
+        storage_keys: &[U256],
+    ) -> Result<AccountProof, StateError> {
+        Ok(tokio::task::block_in_place(move || {
+            self.runtime.block_on(self.client.get_proof(
+                address,
+                storage_keys.to_vec(),
+                Some(BlockSpec::Number(self.block_number)),
+            ))
+        })?)
+    }
+
     /// Retrieve the state root of the given block, if it exists.
     pub fn state_root(&self, block_number: u64) -> Result<Option<B256>, RpcClientError> {
         Ok(tokio::task::block_in_place(move || {
@@ -1,4 +1,4 @@
-use edr_eth::{Address, B256, U256};
+use edr_eth::{account::AccountProof, Address, B256, U256};
 use revm::{
     db::components::{State, StateRef},
     primitives::{hash_map::Entry, AccountInfo, Bytecode, HashMap},
@@ -28,6 +28,18 @@ impl CachedRemoteState {
     }
 }
 
+impl CachedRemoteState {
+    /// Retrieves the EIP-1186 Merkle proof of an account and the specified
+    /// storage slots from the remote node. Proofs are not cached.
+    pub fn account_proof(
+        &self,
+        address: &Address,
+        storage_keys: &[U256],
+    ) -> Result<AccountProof, StateError> {
+        self.remote.account_proof(address, storage_keys)
+    }
+}
+
 impl State for CachedRemoteState {
     type Error = StateError;
 
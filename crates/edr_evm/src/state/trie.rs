@@ -4,15 +4,18 @@ mod state_trie;
 mod storage_trie;
 mod trie_query;
 
-use edr_eth::{account::KECCAK_EMPTY, Address, B256, U256};
+use edr_eth::{
+    account::{AccountProof, KECCAK_EMPTY},
+    Address, B256, U256,
+};
 use revm::{
     db::StateRef,
-    primitives::{Account, AccountInfo, Bytecode, HashMap},
+    primitives::{Account, AccountInfo, AccountStatus, Bytecode, HashMap, StorageSlot},
     DatabaseCommit,
 };
 
 pub use self::account::AccountTrie;
-use super::{StateDebug, StateError};
+use super::{AccountDump, StateDebug, StateDump, StateError};
 use crate::collections::SharedMap;
 
 /// An implementation of revm's state that uses a trie.
@@ -96,6 +99,46 @@ impl TrieState {
         Ok(account_info)
     }
 
+    pub(super) fn dump_impl(
+        &self,
+        external_code_by_hash_fn: &dyn Fn(B256) -> Result<Bytecode, StateError>,
+    ) -> Result<StateDump, StateError> {
+        let accounts = self
+            .accounts
+            .accounts_with_storage()
+            .into_iter()
+            .map(|(address, (account, storage))| {
+                let code = if account.code_hash == KECCAK_EMPTY {
+                    None
+                } else {
+                    let code = match self.code_by_hash(account.code_hash) {
+                        Ok(code) => code,
+                        Err(StateError::InvalidCodeHash(code_hash)) => {
+                            external_code_by_hash_fn(code_hash)?
+                        }
+                        Err(err) => return Err(err),
+                    };
+
+                    Some(code.original_bytes())
+                };
+
+                let account = AccountDump {
+                    balance: account.balance,
+                    nonce: account.nonce,
+                    code,
+                    storage,
+                };
+
+                Ok((address, account))
+            })
+            .collect::<Result<_, StateError>>()?;
+
+        Ok(StateDump {
+            accounts,
+            ..StateDump::default()
+        })
+    }
+
     pub(super) fn set_account_storage_slot_impl(
         &mut self,
         address: Address,
@@ -110,6 +153,35 @@ impl TrieState {
         // If there is no old value, return zero to signal that the slot was empty
         Ok(old_value.unwrap_or(U256::ZERO))
     }
+
+    pub(super) fn set_account_storage_impl(
+        &mut self,
+        address: Address,
+        storage: HashMap<U256, U256>,
+        default_account_fn: &dyn Fn() -> Result<AccountInfo, StateError>,
+    ) -> Result<(), StateError> {
+        let account_info = match self.accounts.account(&address) {
+            Some(account) => AccountInfo::from(account),
+            None => default_account_fn()?,
+        };
+
+        let storage = storage
+            .into_iter()
+            .map(|(index, value)| (index, StorageSlot::new(value)))
+            .collect();
+
+        // Committing a created account clears its existing storage
+        let account = Account {
+            info: account_info,
+            storage,
+            status: AccountStatus::Created | AccountStatus::Touched,
+        };
+
+        self.accounts
+            .commit(&std::iter::once((address, account)).collect());
+
+        Ok(())
+    }
 }
 
 impl Default for TrieState {
@@ -177,10 +249,22 @@ impl DatabaseCommit for TrieState {
 impl StateDebug for TrieState {
     type Error = StateError;
 
+    fn account_proof(
+        &self,
+        address: &Address,
+        storage_keys: &[U256],
+    ) -> Result<AccountProof, Self::Error> {
+        Ok(self.accounts.account_proof(address, storage_keys))
+    }
+
     fn account_storage_root(&self, address: &Address) -> Result<Option<B256>, Self::Error> {
         Ok(self.accounts.storage_root(address))
     }
 
+    fn dump(&self) -> Result<StateDump, Self::Error> {
+        self.dump_impl(&|code_hash| Err(StateError::InvalidCodeHash(code_hash)))
+    }
+
     fn insert_account(
         &mut self,
         address: Address,
@@ -230,6 +314,19 @@ impl StateDebug for TrieState {
         self.accounts.serialize()
     }
 
+    fn set_account_storage(
+        &mut self,
+        address: Address,
+        storage: HashMap<U256, U256>,
+    ) -> Result<(), Self::Error> {
+        self.set_account_storage_impl(address, storage, &|| {
+            Ok(AccountInfo {
+                code: None,
+                ..AccountInfo::default()
+            })
+        })
+    }
+
     fn set_account_storage_slot(
         &mut self,
         address: Address,
@@ -305,4 +402,73 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_trie_state_set_account_storage() -> anyhow::Result<()> {
+        let mut state = TrieState::default();
+
+        let address = Address::random();
+        state.insert_account(
+            address,
+            AccountInfo {
+                balance: U256::from(10),
+                ..AccountInfo::default()
+            },
+        )?;
+        state.set_account_storage_slot(address, U256::from(1), U256::from(100))?;
+        state.set_account_storage_slot(address, U256::from(2), U256::from(200))?;
+
+        let storage = std::iter::once((U256::from(2), U256::from(202))).collect();
+        state.set_account_storage(address, storage)?;
+
+        assert_eq!(state.storage(address, U256::from(1))?, U256::ZERO);
+        assert_eq!(state.storage(address, U256::from(2))?, U256::from(202));
+        assert_eq!(state.basic(address)?.unwrap().balance, U256::from(10));
+
+        // Non-existent accounts are created
+        let new_address = Address::random();
+        let storage = std::iter::once((U256::from(3), U256::from(300))).collect();
+        state.set_account_storage(new_address, storage)?;
+
+        assert!(state.basic(new_address)?.is_some());
+        assert_eq!(state.storage(new_address, U256::from(3))?, U256::from(300));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_trie_state_dump() -> anyhow::Result<()> {
+        let mut state = TrieState::default();
+
+        let code = Bytecode::new_raw(Bytes::from_static(&[0x01]));
+        let address = Address::random();
+        state.insert_account(
+            address,
+            AccountInfo {
+                balance: U256::from(10),
+                nonce: 3,
+                code_hash: code.hash_slow(),
+                code: Some(code),
+            },
+        )?;
+        state.set_account_storage_slot(address, U256::from(1), U256::from(100))?;
+        state.set_account_storage_slot(address, U256::from(2), U256::from(200))?;
+        // Cleared slots are not included
+        state.set_account_storage_slot(address, U256::from(2), U256::ZERO)?;
+
+        let dump = state.dump()?;
+        assert_eq!(dump.accounts.len(), 1);
+        assert!(dump.removed_accounts.is_empty());
+
+        let account = &dump.accounts[&address];
+        assert_eq!(account.balance, U256::from(10));
+        assert_eq!(account.nonce, 3);
+        assert_eq!(account.code, Some(Bytes::from_static(&[0x01])));
+        assert_eq!(
+            account.storage.iter().collect::<Vec<_>>(),
+            vec![(&U256::from(1), &U256::from(100))]
+        );
+
+        Ok(())
+    }
 }
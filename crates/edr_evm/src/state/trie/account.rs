@@ -1,6 +1,9 @@
 use std::{collections::BTreeMap, fmt::Debug};
 
-use edr_eth::{account::BasicAccount, Address, B256, U256};
+use edr_eth::{
+    account::{AccountProof, BasicAccount, StorageProof},
+    Address, Bytes, B256, U256,
+};
 use hasher::{Hasher, HasherKeccak};
 use revm::primitives::{Account, AccountInfo, HashMap};
 use rpds::HashTrieMapSync;
@@ -52,6 +55,60 @@ impl AccountTrie {
             .and_then(|storage_trie| storage_trie.storage_slot(index))
     }
 
+    /// Generates the Merkle proof of the account at the specified address and
+    /// the specified storage slots. Proves the absence of accounts and storage
+    /// slots that don't exist.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn account_proof(&self, address: &Address, storage_keys: &[U256]) -> AccountProof {
+        let account = self.state_trie.account(address).unwrap_or_default();
+        let storage_trie = self.storage_tries.get(address);
+
+        let storage_proof = storage_keys
+            .iter()
+            .map(|index| StorageProof {
+                key: *index,
+                value: storage_trie
+                    .and_then(|storage_trie| storage_trie.storage_slot(index))
+                    .unwrap_or(U256::ZERO),
+                proof: storage_trie
+                    .map_or_else(Vec::new, |storage_trie| storage_trie.storage_proof(index))
+                    .into_iter()
+                    .map(Bytes::from)
+                    .collect(),
+            })
+            .collect();
+
+        AccountProof {
+            address: *address,
+            account_proof: self
+                .state_trie
+                .account_proof(address)
+                .into_iter()
+                .map(Bytes::from)
+                .collect(),
+            balance: account.balance,
+            code_hash: account.code_hash,
+            nonce: account.nonce,
+            storage_hash: account.storage_root,
+            storage_proof,
+        }
+    }
+
+    /// Retrieves all non-empty accounts and their non-zero storage slots,
+    /// ordered by address.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub fn accounts_with_storage(&self) -> BTreeMap<Address, (BasicAccount, BTreeMap<U256, U256>)> {
+        self.storage_tries
+            .iter()
+            .filter_map(|(address, storage_trie)| {
+                self.state_trie
+                    .account(address)
+                    .filter(|account| *account != BasicAccount::default())
+                    .map(|account| (*address, (account, storage_trie.storage_slots())))
+            })
+            .collect()
+    }
+
     /// Commits changes to the state.
     /// Inspired by <https://github.com/bluealloy/revm/blob/688c36ca4525cc44aa7c547b7b0f22a9490c4f2f/crates/revm/src/db/in_memory_db.rs#L131>
     #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
@@ -385,4 +442,28 @@ mod tests {
 
         assert_eq!(state.state_root(), old);
     }
+
+    #[test]
+    fn account_proof_precompiles() {
+        let accounts = precompiled_contracts();
+        let state = AccountTrie::with_accounts(&accounts);
+
+        let mut address = Address::ZERO;
+        address.0[19] = 1;
+
+        let proof = state.account_proof(&address, &[U256::from(1)]);
+
+        assert_eq!(proof.address, address);
+        assert_eq!(proof.storage_hash, KECCAK_NULL_RLP);
+        // The first node of the proof is the root node
+        let root_node = proof.account_proof.first().expect("proof is not empty");
+        assert_eq!(
+            B256::from_slice(&HasherKeccak::new().digest(root_node)),
+            state.state_root()
+        );
+
+        assert_eq!(proof.storage_proof.len(), 1);
+        assert_eq!(proof.storage_proof[0].value, U256::ZERO);
+        assert!(proof.storage_proof[0].proof.is_empty());
+    }
 }
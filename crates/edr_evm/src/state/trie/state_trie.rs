@@ -21,6 +21,11 @@ impl<'a> StateTrie {
         })
     }
 
+    /// Retrieves the Merkle proof of the account at the specified address.
+    pub fn account_proof(&self, address: &Address) -> Vec<Vec<u8>> {
+        self.trie_query().proof(address)
+    }
+
     /// Create a helper struct that allows setting and removing multiple
     /// accounts and then updates the state root when dropped.
     pub fn mutate(&'a mut self) -> StateTrieMutation<'a> {
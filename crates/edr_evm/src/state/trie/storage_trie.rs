@@ -2,6 +2,7 @@ use std::{collections::BTreeMap, sync::Arc};
 
 use alloy_rlp::Decodable;
 use hasher::{Hasher, HasherKeccak};
+use rpds::HashTrieSetSync;
 
 use crate::{
     state::trie::{persistent_memory_db::PersistentMemoryDB, trie_query::TrieQuery},
@@ -12,6 +13,8 @@ use crate::{
 pub(super) struct StorageTrie {
     db: Arc<PersistentMemoryDB>,
     root: B256,
+    /// The unhashed indices of the non-zero storage slots
+    indices: HashTrieSetSync<U256>,
 }
 
 impl<'a> StorageTrie {
@@ -30,6 +33,11 @@ impl<'a> StorageTrie {
             .map(decode_u256)
     }
 
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn storage_proof(&self, index: &U256) -> Vec<Vec<u8>> {
+        self.trie_query().proof(index.to_be_bytes::<32>())
+    }
+
     #[cfg_attr(feature = "tracing", tracing::instrument)]
     pub fn storage(&self) -> BTreeMap<B256, U256> {
         self.trie_query()
@@ -40,6 +48,21 @@ impl<'a> StorageTrie {
             .collect()
     }
 
+    /// Retrieves the non-zero storage slots, ordered by their unhashed index.
+    #[cfg_attr(feature = "tracing", tracing::instrument)]
+    pub fn storage_slots(&self) -> BTreeMap<U256, U256> {
+        let trie_query = self.trie_query();
+
+        self.indices
+            .iter()
+            .filter_map(|index| {
+                trie_query
+                    .get(index.to_be_bytes::<32>())
+                    .map(|encoded_value| (*index, decode_u256(encoded_value)))
+            })
+            .collect()
+    }
+
     pub fn root(&self) -> B256 {
         self.root
     }
@@ -54,6 +77,7 @@ impl Clone for StorageTrie {
         Self {
             db: Arc::new((*self.db).clone()),
             root: self.root,
+            indices: self.indices.clone(),
         }
     }
 }
@@ -65,7 +89,11 @@ impl Default for StorageTrie {
         let mut trie = TrieQuery::empty(Arc::clone(&db));
         let root = trie.root();
 
-        Self { db, root }
+        Self {
+            db,
+            root,
+            indices: HashTrieSetSync::new_sync(),
+        }
     }
 }
 
@@ -95,8 +123,11 @@ impl<'a> StorageTrieMutation<'a> {
             if old_value.is_some() {
                 self.trie_query.remove_hashed_key(&hashed_index);
             }
+
+            self.storage_trie.indices.remove_mut(index);
         } else {
             self.trie_query.insert_hashed_key(hashed_index, value);
+            self.storage_trie.indices.insert_mut(*index);
         }
 
         old_value
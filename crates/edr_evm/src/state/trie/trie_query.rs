@@ -37,6 +37,12 @@ impl TrieQuery {
         self.0.get(key).expect(DB_IS_INFALLIBLE)
     }
 
+    /// Get the Merkle proof of the value at the specified (unhashed) key, as
+    /// RLP-encoded trie nodes from the root to the value.
+    pub fn proof(&self, key: impl AsRef<[u8]>) -> Vec<Vec<u8>> {
+        self.0.get_proof(&hash_key(key)).expect(DB_IS_INFALLIBLE)
+    }
+
     /// Insert a value at the specified (unhashed) key or modify it if it
     /// exists. The value will be RLP-encoded.
     pub fn insert(&mut self, key: impl AsRef<[u8]>, value: impl alloy_rlp::Encodable) {
@@ -1,13 +1,17 @@
 use std::num::NonZeroU64;
 
+use c_kzg::BYTES_PER_BLOB;
 use edr_eth::{
-    transaction::{Eip1559TransactionRequest, Eip155TransactionRequest, TransactionKind},
+    transaction::{
+        BlobSidecar, Eip1559TransactionRequest, Eip155TransactionRequest, TransactionKind,
+    },
     AccountInfo, Address, Bytes, HashMap, SpecId, U256,
 };
 
 use crate::{
     state::{AccountTrie, StateError, TrieState},
-    ExecutableTransaction, MemPool, MemPoolAddTransactionError, TransactionCreationError,
+    ExecutableTransaction, MemPool, MemPoolAddTransactionError, MemPoolLimits, OrderedTransaction,
+    TransactionCreationError,
 };
 
 /// A test fixture for `MemPool`.
@@ -21,21 +25,33 @@ pub struct MemPoolTestFixture {
 impl MemPoolTestFixture {
     /// Constructs an instance with the provided accounts.
     pub fn with_accounts(accounts: &[(Address, AccountInfo)]) -> Self {
+        Self::with_accounts_and_limits(accounts, MemPoolLimits::default())
+    }
+
+    /// Constructs an instance with the provided accounts and mem pool limits.
+    pub fn with_accounts_and_limits(
+        accounts: &[(Address, AccountInfo)],
+        limits: MemPoolLimits,
+    ) -> Self {
         let accounts = accounts.iter().cloned().collect::<HashMap<_, _>>();
         let trie = AccountTrie::with_accounts(&accounts);
 
         MemPoolTestFixture {
-            // SAFETY: literal is non-zero
-            mem_pool: MemPool::new(unsafe { NonZeroU64::new_unchecked(10_000_000u64) }),
+            mem_pool: MemPool::with_limits(
+                // SAFETY: literal is non-zero
+                unsafe { NonZeroU64::new_unchecked(10_000_000u64) },
+                limits,
+            ),
             state: TrieState::with_accounts(trie),
         }
     }
 
-    /// Tries to add the provided transaction to the mem pool.
+    /// Tries to add the provided transaction to the mem pool. Returns the
+    /// evicted transactions.
     pub fn add_transaction(
         &mut self,
         transaction: ExecutableTransaction,
-    ) -> Result<(), MemPoolAddTransactionError<StateError>> {
+    ) -> Result<Vec<OrderedTransaction>, MemPoolAddTransactionError<StateError>> {
         self.mem_pool.add_transaction(&self.state, transaction)
     }
 
@@ -140,3 +156,16 @@ pub fn dummy_eip1559_transaction(
 
     ExecutableTransaction::with_caller(SpecId::LATEST, transaction.into(), caller)
 }
+
+/// Creates a sidecar of blobs filled with zeros, for which the commitments
+/// and proofs are the point at infinity.
+pub fn zero_blob_sidecar(num_blobs: usize) -> BlobSidecar {
+    let mut point_at_infinity = [0u8; 48];
+    point_at_infinity[0] = 0xc0;
+
+    BlobSidecar {
+        blobs: vec![Bytes::from(vec![0u8; BYTES_PER_BLOB]); num_blobs],
+        commitments: vec![point_at_infinity.into(); num_blobs],
+        proofs: vec![point_at_infinity.into(); num_blobs],
+    }
+}
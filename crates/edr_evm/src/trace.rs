@@ -1,3 +1,6 @@
+/// Types for Parity-style `trace_*` traces
+pub mod parity;
+
 use std::{cell::RefCell, fmt::Debug, rc::Rc, sync::Arc};
 
 use edr_eth::{Address, Bytes, U256};
@@ -5,8 +8,8 @@ use revm::{
     handler::register::EvmHandler,
     interpreter::{
         opcode::{self, BoxedInstruction, InstructionTables},
-        return_revert, CallInputs, CallOutcome, CreateInputs, CreateOutcome, InstructionResult,
-        Interpreter, SuccessOrHalt,
+        return_revert, CallInputs, CallOutcome, CallScheme, CreateInputs, CreateOutcome,
+        InstructionResult, Interpreter, SuccessOrHalt,
     },
     primitives::{Bytecode, EVMError, ExecutionResult, Output},
     Database, Evm, EvmContext, FrameOrResult, FrameResult,
@@ -182,6 +185,8 @@ pub struct BeforeMessage {
     pub code_address: Option<Address>,
     /// Bytecode
     pub code: Option<Bytecode>,
+    /// Call scheme. None if it is a create message.
+    pub call_scheme: Option<CallScheme>,
 }
 
 /// A trace for an EVM call.
@@ -314,6 +319,7 @@ impl TraceCollector {
             value: inputs.context.apparent_value,
             code_address: Some(inputs.context.code_address),
             code: Some(code),
+            call_scheme: Some(inputs.context.scheme),
         });
     }
 
@@ -385,6 +391,7 @@ impl TraceCollector {
             value: inputs.value,
             code_address: None,
             code: None,
+            call_scheme: None,
         });
     }
 
@@ -0,0 +1,775 @@
+mod vm;
+
+use std::{collections::BTreeMap, fmt::Debug};
+
+use edr_eth::{Address, Bytes, B256, U256, U64};
+use revm::{
+    db::{DatabaseComponents, StateRef},
+    handler::register::{EvmHandler, HandleRegister},
+    interpreter::CallScheme,
+    primitives::{
+        Account, BlockEnv, CfgEnvWithHandlerCfg, ExecutionResult, HaltReason, HashMap, Output,
+        ResultAndState,
+    },
+    Database, Evm,
+};
+
+pub use self::vm::{register_vm_tracer_handles, VmTracer};
+use super::{register_trace_collector_handles, BeforeMessage, Trace, TraceCollector, TraceMessage};
+use crate::{
+    blockchain::SyncBlockchain,
+    debug::GetContextData,
+    debug_trace::{account_code, u256_to_b256},
+    state::SyncState,
+    ExecutableTransaction, TransactionError,
+};
+
+/// The types of output that can be requested from `trace_call` and
+/// `trace_replayTransaction`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TraceType {
+    /// Flat call traces
+    Trace,
+    /// Traces of the executed instructions
+    VmTrace,
+    /// Differences between the state before and after the transaction
+    StateDiff,
+}
+
+/// The type of a call action.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CallType {
+    /// A `CALL`
+    Call,
+    /// A `CALLCODE`
+    CallCode,
+    /// A `DELEGATECALL`
+    DelegateCall,
+    /// A `STATICCALL`
+    StaticCall,
+}
+
+impl From<CallScheme> for CallType {
+    fn from(value: CallScheme) -> Self {
+        match value {
+            CallScheme::Call => Self::Call,
+            CallScheme::CallCode => Self::CallCode,
+            CallScheme::DelegateCall => Self::DelegateCall,
+            CallScheme::StaticCall => Self::StaticCall,
+        }
+    }
+}
+
+/// A call action.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallAction {
+    /// The type of the call.
+    pub call_type: CallType,
+    /// The caller.
+    pub from: Address,
+    /// The gas provided to the call.
+    #[serde(with = "edr_eth::serde::u64")]
+    pub gas: u64,
+    /// The call data.
+    pub input: Bytes,
+    /// The address of the executed code.
+    pub to: Address,
+    /// The value transferred by the call.
+    pub value: U256,
+}
+
+/// A create action.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateAction {
+    /// The creator.
+    pub from: Address,
+    /// The gas provided to the create.
+    #[serde(with = "edr_eth::serde::u64")]
+    pub gas: u64,
+    /// The init code.
+    pub init: Bytes,
+    /// The value transferred to the created contract.
+    pub value: U256,
+}
+
+/// The action of a flat trace.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(untagged)]
+pub enum Action {
+    /// A call action.
+    Call(CallAction),
+    /// A create action.
+    Create(CreateAction),
+}
+
+impl Action {
+    /// Returns the sender of the action.
+    pub fn sender(&self) -> &Address {
+        match self {
+            Action::Call(action) => &action.from,
+            Action::Create(action) => &action.from,
+        }
+    }
+}
+
+/// The type of a flat trace.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ActionType {
+    /// A call
+    Call,
+    /// A contract creation
+    Create,
+}
+
+/// The output of a successful call action.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallOutput {
+    /// The gas used by the call.
+    #[serde(with = "edr_eth::serde::u64")]
+    pub gas_used: u64,
+    /// The return data.
+    pub output: Bytes,
+}
+
+/// The output of a successful create action.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateOutput {
+    /// The address of the created contract.
+    pub address: Address,
+    /// The deployed code.
+    pub code: Bytes,
+    /// The gas used by the create.
+    #[serde(with = "edr_eth::serde::u64")]
+    pub gas_used: u64,
+}
+
+/// The output of a successful action.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(untagged)]
+pub enum ActionOutput {
+    /// The output of a call action.
+    Call(CallOutput),
+    /// The output of a create action.
+    Create(CreateOutput),
+}
+
+/// A Parity-style flat trace of a single call frame.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlatTrace {
+    /// The action of the call frame.
+    pub action: Action,
+    /// Description of the error, if the call frame failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// The output of the call frame. None if the call frame failed.
+    pub result: Option<ActionOutput>,
+    /// The number of direct sub-traces of the call frame.
+    pub subtraces: usize,
+    /// The position of the call frame in the call tree.
+    pub trace_address: Vec<usize>,
+    /// The type of the action.
+    #[serde(rename = "type")]
+    pub action_type: ActionType,
+}
+
+impl FlatTrace {
+    fn new(message: &BeforeMessage, trace_address: Vec<usize>) -> Self {
+        let (action, action_type) = if let Some(call_scheme) = message.call_scheme {
+            // For `DELEGATECALL` and `CALLCODE`, the callee is the address of the executed
+            // code, rather than the storage context.
+            let to = match call_scheme {
+                CallScheme::DelegateCall | CallScheme::CallCode => message.code_address,
+                CallScheme::Call | CallScheme::StaticCall => message.to,
+            };
+
+            (
+                Action::Call(CallAction {
+                    call_type: call_scheme.into(),
+                    from: message.caller,
+                    gas: message.gas_limit,
+                    input: message.data.clone(),
+                    to: to.unwrap_or_default(),
+                    value: message.value,
+                }),
+                ActionType::Call,
+            )
+        } else {
+            (
+                Action::Create(CreateAction {
+                    from: message.caller,
+                    gas: message.gas_limit,
+                    init: message.data.clone(),
+                    value: message.value,
+                }),
+                ActionType::Create,
+            )
+        };
+
+        Self {
+            action,
+            error: None,
+            result: None,
+            subtraces: 0,
+            trace_address,
+            action_type,
+        }
+    }
+
+    /// Returns the recipient of the action. For create actions, this is the
+    /// address of the created contract, if the creation succeeded.
+    pub fn recipient(&self) -> Option<&Address> {
+        match (&self.action, &self.result) {
+            (Action::Call(action), _) => Some(&action.to),
+            (Action::Create(_), Some(ActionOutput::Create(output))) => Some(&output.address),
+            (Action::Create(_), _) => None,
+        }
+    }
+
+    fn finalize(&mut self, result: &ExecutionResult, subtraces: usize) {
+        self.subtraces = subtraces;
+
+        match result {
+            ExecutionResult::Success {
+                gas_used, output, ..
+            } => {
+                self.result = Some(match output {
+                    Output::Call(output) => ActionOutput::Call(CallOutput {
+                        gas_used: *gas_used,
+                        output: output.clone(),
+                    }),
+                    Output::Create(code, address) => ActionOutput::Create(CreateOutput {
+                        address: address.unwrap_or_default(),
+                        code: code.clone(),
+                        gas_used: *gas_used,
+                    }),
+                });
+            }
+            ExecutionResult::Revert { .. } => {
+                self.error = Some(String::from("Reverted"));
+            }
+            ExecutionResult::Halt { reason, .. } => {
+                self.error = Some(halt_reason_to_error(reason));
+            }
+        }
+    }
+}
+
+/// A flat trace, including the location of its transaction.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LocalizedFlatTrace {
+    /// The flat trace.
+    #[serde(flatten)]
+    pub trace: FlatTrace,
+    /// The hash of the block containing the transaction.
+    pub block_hash: B256,
+    /// The number of the block containing the transaction.
+    pub block_number: u64,
+    /// The hash of the transaction.
+    pub transaction_hash: B256,
+    /// The index of the transaction in the block.
+    pub transaction_position: u64,
+}
+
+/// A trace of the instructions executed by a call frame.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct VmTrace {
+    /// The executed code.
+    pub code: Bytes,
+    /// The executed instructions.
+    pub ops: Vec<VmInstruction>,
+}
+
+/// An executed instruction.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct VmInstruction {
+    /// The program counter.
+    pub pc: u64,
+    /// The name of the opcode.
+    pub op: String,
+    /// The gas cost of the instruction. For `CALL*` and `CREATE*`
+    /// instructions this includes the gas forwarded to the created frame.
+    pub cost: u64,
+    /// The effects of the instruction, if it succeeded.
+    pub ex: Option<VmExecutedOperation>,
+    /// The trace of the call frame created by the instruction, if any.
+    pub sub: Option<VmTrace>,
+}
+
+/// The effects of an executed instruction.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct VmExecutedOperation {
+    /// The remaining gas after the instruction.
+    pub used: u64,
+    /// The stack items pushed by the instruction.
+    pub push: Vec<U256>,
+    /// The memory written by the instruction, if any.
+    pub mem: Option<MemoryDelta>,
+    /// The storage slot written by the instruction, if any.
+    pub store: Option<StorageDelta>,
+}
+
+/// A memory write of an instruction.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct MemoryDelta {
+    /// The offset of the written memory.
+    pub off: usize,
+    /// The written data.
+    pub data: Bytes,
+}
+
+/// A storage write of an instruction.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct StorageDelta {
+    /// The storage slot.
+    pub key: U256,
+    /// The written value.
+    pub val: U256,
+}
+
+/// A change of a value in a [`StateDiff`].
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum Delta<T> {
+    /// The value didn't change.
+    #[serde(rename = "=")]
+    Unchanged,
+    /// The value was created.
+    #[serde(rename = "+")]
+    Born(T),
+    /// The value was removed.
+    #[serde(rename = "-")]
+    Died(T),
+    /// The value was modified.
+    #[serde(rename = "*")]
+    Changed(ChangedValue<T>),
+}
+
+impl<T: PartialEq> Delta<T> {
+    fn new(from: Option<T>, to: Option<T>) -> Self {
+        match (from, to) {
+            (None, None) => Self::Unchanged,
+            (None, Some(to)) => Self::Born(to),
+            (Some(from), None) => Self::Died(from),
+            (Some(from), Some(to)) => {
+                if from == to {
+                    Self::Unchanged
+                } else {
+                    Self::Changed(ChangedValue { from, to })
+                }
+            }
+        }
+    }
+
+    fn is_unchanged(&self) -> bool {
+        matches!(self, Self::Unchanged)
+    }
+}
+
+/// A modified value in a [`StateDiff`].
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct ChangedValue<T> {
+    /// The value before the transaction.
+    pub from: T,
+    /// The value after the transaction.
+    pub to: T,
+}
+
+/// The changes made to an account by a transaction.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct AccountDiff {
+    /// The change of the balance.
+    pub balance: Delta<U256>,
+    /// The change of the code.
+    pub code: Delta<Bytes>,
+    /// The change of the nonce.
+    pub nonce: Delta<U64>,
+    /// The changes of the storage slots.
+    pub storage: BTreeMap<B256, Delta<B256>>,
+}
+
+/// The changes made to the state by a transaction, per account.
+pub type StateDiff = BTreeMap<Address, AccountDiff>;
+
+/// The output of `trace_call` and `trace_replayTransaction`.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceResults {
+    /// The return data of the transaction.
+    pub output: Bytes,
+    /// The state changes, if requested.
+    pub state_diff: Option<StateDiff>,
+    /// The flat call traces, if requested.
+    pub trace: Vec<FlatTrace>,
+    /// The instruction traces, if requested.
+    pub vm_trace: Option<VmTrace>,
+}
+
+impl TraceResults {
+    /// Constructs the requested trace results of a transaction.
+    pub fn new(
+        result: &ExecutionResult,
+        tracer: ParityTracer,
+        state_diff: Option<StateDiff>,
+        trace_types: &[TraceType],
+    ) -> Self {
+        let ParityTracer {
+            collector,
+            vm_tracer,
+        } = tracer;
+
+        Self {
+            output: result.output().cloned().unwrap_or_default(),
+            state_diff,
+            trace: if trace_types.contains(&TraceType::Trace) {
+                flat_traces(&collector.into_traces().pop().unwrap_or_default())
+            } else {
+                Vec::new()
+            },
+            vm_trace: if trace_types.contains(&TraceType::VmTrace) {
+                vm_tracer.into_trace()
+            } else {
+                None
+            },
+        }
+    }
+}
+
+/// Collects the traces needed for `trace_*` requests.
+#[derive(Debug, Default)]
+pub struct ParityTracer {
+    collector: TraceCollector,
+    vm_tracer: VmTracer,
+}
+
+impl GetContextData<TraceCollector> for ParityTracer {
+    fn get_context_data(&mut self) -> &mut TraceCollector {
+        &mut self.collector
+    }
+}
+
+impl GetContextData<VmTracer> for ParityTracer {
+    fn get_context_data(&mut self) -> &mut VmTracer {
+        &mut self.vm_tracer
+    }
+}
+
+/// Registers the handles of a [`ParityTracer`].
+pub fn register_parity_tracer_handles<
+    DatabaseT: Database,
+    ContextT: GetContextData<TraceCollector> + GetContextData<VmTracer>,
+>(
+    handler: &mut EvmHandler<'_, ContextT, DatabaseT>,
+) where
+    DatabaseT::Error: Debug,
+{
+    register_trace_collector_handles(handler);
+    register_vm_tracer_handles(handler);
+}
+
+/// Returns the function that registers the handles needed for the requested
+/// trace types. The instruction tracer is only registered if a `vmTrace` is
+/// requested, as it records every executed instruction.
+pub fn parity_tracer_handle_register<
+    DatabaseT: Database,
+    ContextT: GetContextData<TraceCollector> + GetContextData<VmTracer>,
+>(
+    trace_types: &[TraceType],
+) -> HandleRegister<ContextT, DatabaseT>
+where
+    DatabaseT::Error: Debug,
+{
+    if trace_types.contains(&TraceType::VmTrace) {
+        register_parity_tracer_handles
+    } else {
+        register_trace_collector_handles
+    }
+}
+
+/// Converts the trace of a transaction into Parity-style flat traces, in
+/// depth-first order.
+pub fn flat_traces(trace: &Trace) -> Vec<FlatTrace> {
+    let mut traces: Vec<FlatTrace> = Vec::new();
+    // The indices of the unfinished call frames in `traces`, together with their
+    // number of sub-traces so far
+    let mut stack: Vec<(usize, usize)> = Vec::new();
+
+    for message in &trace.messages {
+        match message {
+            TraceMessage::Before(before) => {
+                let trace_address = if let Some((parent_index, subtraces)) = stack.last_mut() {
+                    let mut trace_address = traces[*parent_index].trace_address.clone();
+                    trace_address.push(*subtraces);
+
+                    *subtraces += 1;
+                    trace_address
+                } else {
+                    Vec::new()
+                };
+
+                stack.push((traces.len(), 0));
+                traces.push(FlatTrace::new(before, trace_address));
+            }
+            TraceMessage::Step(_) => (),
+            TraceMessage::After(result) => {
+                if let Some((index, subtraces)) = stack.pop() {
+                    traces[index].finalize(result, subtraces);
+                }
+            }
+        }
+    }
+
+    traces
+}
+
+/// Computes the Parity-style state diff of a transaction, using the state
+/// before the transaction and the state changes produced by it.
+pub fn state_diff<StateT: StateRef + ?Sized>(
+    state: &StateT,
+    changes: &HashMap<Address, Account>,
+) -> Result<StateDiff, StateT::Error> {
+    let mut diff = BTreeMap::new();
+
+    for (address, account) in changes {
+        if !account.is_touched() {
+            continue;
+        }
+
+        // Empty accounts are considered to be non-existent
+        let pre = state
+            .basic(*address)?
+            .filter(|account_info| !account_info.is_empty());
+
+        let post = if account.is_selfdestructed() || account.info.is_empty() {
+            None
+        } else {
+            Some(&account.info)
+        };
+
+        let pre_code = pre
+            .as_ref()
+            .map(|account_info| account_code(state, account_info))
+            .transpose()?;
+        let post_code = post
+            .map(|account_info| account_code(state, account_info))
+            .transpose()?;
+
+        let storage = account
+            .storage
+            .iter()
+            .filter(|(_index, slot)| slot.is_changed())
+            .map(|(index, slot)| {
+                let delta = Delta::new(
+                    pre.as_ref().map(|_| u256_to_b256(&slot.original_value)),
+                    post.map(|_| u256_to_b256(&slot.present_value)),
+                );
+
+                (u256_to_b256(index), delta)
+            })
+            .filter(|(_index, delta)| !delta.is_unchanged())
+            .collect();
+
+        let account_diff = AccountDiff {
+            balance: Delta::new(
+                pre.as_ref().map(|account_info| account_info.balance),
+                post.map(|account_info| account_info.balance),
+            ),
+            code: Delta::new(
+                pre_code.map(Option::unwrap_or_default),
+                post_code.map(Option::unwrap_or_default),
+            ),
+            nonce: Delta::new(
+                pre.as_ref()
+                    .map(|account_info| U64::from(account_info.nonce)),
+                post.map(|account_info| U64::from(account_info.nonce)),
+            ),
+            storage,
+        };
+
+        let is_unchanged = account_diff.balance.is_unchanged()
+            && account_diff.code.is_unchanged()
+            && account_diff.nonce.is_unchanged()
+            && account_diff.storage.is_empty();
+
+        if !is_unchanged {
+            diff.insert(*address, account_diff);
+        }
+    }
+
+    Ok(diff)
+}
+
+/// Replays the transactions of a block, collecting their traces. If
+/// `transaction_hash` is provided, the replay stops after that transaction.
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub fn replay_block<BlockchainErrorT, StateErrorT>(
+    blockchain: &dyn SyncBlockchain<BlockchainErrorT, StateErrorT>,
+    // Take ownership of the state so that we can apply throw-away modifications on it
+    mut state: Box<dyn SyncState<StateErrorT>>,
+    evm_config: CfgEnvWithHandlerCfg,
+    block_env: BlockEnv,
+    transactions: Vec<ExecutableTransaction>,
+    trace_types: &[TraceType],
+    transaction_hash: Option<&B256>,
+) -> Result<Vec<(B256, TraceResults)>, TransactionError<BlockchainErrorT, StateErrorT>>
+where
+    BlockchainErrorT: Debug + Send,
+    StateErrorT: Debug + Send,
+{
+    let mut results = Vec::new();
+
+    for transaction in transactions {
+        let current_hash = *transaction.hash();
+
+        let mut tracer = ParityTracer::default();
+        let ResultAndState {
+            result,
+            state: changes,
+        } = {
+            let mut evm = Evm::builder()
+                .with_ref_db(DatabaseComponents {
+                    state: state.as_ref(),
+                    block_hash: blockchain,
+                })
+                .with_external_context(&mut tracer)
+                .with_cfg_env_with_handler_cfg(evm_config.clone())
+                .append_handler_register(parity_tracer_handle_register(trace_types))
+                .with_block_env(block_env.clone())
+                .with_tx_env(transaction.into())
+                .build();
+
+            evm.transact().map_err(TransactionError::from)?
+        };
+
+        let state_diff = if trace_types.contains(&TraceType::StateDiff) {
+            Some(state_diff(state.as_ref(), &changes).map_err(TransactionError::State)?)
+        } else {
+            None
+        };
+
+        state.commit(changes);
+
+        results.push((
+            current_hash,
+            TraceResults::new(&result, tracer, state_diff, trace_types),
+        ));
+
+        if transaction_hash == Some(&current_hash) {
+            break;
+        }
+    }
+
+    Ok(results)
+}
+
+/// Converts a halt reason to an error message that matches Parity.
+fn halt_reason_to_error(reason: &HaltReason) -> String {
+    let message = match reason {
+        HaltReason::OutOfGas(_) => "Out of gas",
+        HaltReason::OpcodeNotFound | HaltReason::InvalidFEOpcode => "Bad instruction",
+        HaltReason::InvalidJump => "Bad jump destination",
+        HaltReason::StackUnderflow | HaltReason::StackOverflow => "Out of stack",
+        HaltReason::CallNotAllowedInsideStatic | HaltReason::StateChangeDuringStaticCall => {
+            "Mutable Call In Static Context"
+        }
+        HaltReason::CallTooDeep => "Call too deep",
+        HaltReason::OutOfFunds => "Insufficient balance for transfer",
+        _ => return format!("{reason:?}"),
+    };
+
+    String::from(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use revm::primitives::SuccessReason;
+
+    use super::*;
+
+    fn call_message(depth: usize, caller: Address, to: Address) -> TraceMessage {
+        TraceMessage::Before(BeforeMessage {
+            depth,
+            caller,
+            to: Some(to),
+            gas_limit: 100_000,
+            data: Bytes::new(),
+            value: U256::ZERO,
+            code_address: Some(to),
+            code: None,
+            call_scheme: Some(CallScheme::Call),
+        })
+    }
+
+    fn success_message() -> TraceMessage {
+        TraceMessage::After(ExecutionResult::Success {
+            reason: SuccessReason::Stop,
+            gas_used: 21_000,
+            gas_refunded: 0,
+            logs: Vec::new(),
+            output: Output::Call(Bytes::new()),
+        })
+    }
+
+    #[test]
+    fn flat_traces_nested_calls() {
+        let sender = Address::random();
+        let first = Address::random();
+        let second = Address::random();
+        let third = Address::random();
+
+        let trace = Trace {
+            messages: vec![
+                call_message(0, sender, first),
+                call_message(1, first, second),
+                call_message(2, second, third),
+                success_message(),
+                success_message(),
+                call_message(1, first, third),
+                TraceMessage::After(ExecutionResult::Revert {
+                    gas_used: 100,
+                    output: Bytes::new(),
+                }),
+                success_message(),
+            ],
+            return_value: Bytes::new(),
+        };
+
+        let traces = flat_traces(&trace);
+        assert_eq!(traces.len(), 4);
+
+        assert_eq!(traces[0].trace_address, Vec::<usize>::new());
+        assert_eq!(traces[0].subtraces, 2);
+
+        assert_eq!(traces[1].trace_address, vec![0]);
+        assert_eq!(traces[1].subtraces, 1);
+        assert_eq!(traces[1].recipient(), Some(&second));
+
+        assert_eq!(traces[2].trace_address, vec![0, 0]);
+        assert_eq!(traces[2].subtraces, 0);
+
+        assert_eq!(traces[3].trace_address, vec![1]);
+        assert_eq!(traces[3].error, Some(String::from("Reverted")));
+        assert_eq!(traces[3].result, None);
+    }
+
+    #[test]
+    fn delta_serde() -> anyhow::Result<()> {
+        let unchanged = Delta::<U256>::Unchanged;
+        assert_eq!(serde_json::to_value(unchanged)?, serde_json::json!("="));
+
+        let changed = Delta::new(Some(U256::from(1)), Some(U256::from(2)));
+        assert_eq!(
+            serde_json::to_value(changed)?,
+            serde_json::json!({ "*": { "from": "0x1", "to": "0x2" } })
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,381 @@
+use std::sync::Arc;
+
+use edr_eth::{Bytes, U256};
+use revm::{
+    handler::register::EvmHandler,
+    interpreter::{
+        opcode::{self, BoxedInstruction, InstructionTables},
+        InstructionResult, Interpreter,
+    },
+    primitives::EVMError,
+    Database, Evm, FrameOrResult,
+};
+
+use super::{MemoryDelta, StorageDelta, VmExecutedOperation, VmInstruction, VmTrace};
+use crate::debug::GetContextData;
+
+/// Registers the Parity `vmTrace` tracer handles.
+pub fn register_vm_tracer_handles<DatabaseT: Database, ContextT: GetContextData<VmTracer>>(
+    handler: &mut EvmHandler<'_, ContextT, DatabaseT>,
+) {
+    let table = handler
+        .instruction_table
+        .take()
+        .expect("Handler must have instruction table");
+
+    let table = match table {
+        InstructionTables::Plain(table) => table
+            .into_iter()
+            .map(|i| instruction_handler(i))
+            .collect::<Vec<_>>(),
+        InstructionTables::Boxed(table) => table
+            .into_iter()
+            .map(|i| instruction_handler(i))
+            .collect::<Vec<_>>(),
+    };
+
+    // cast vector to array.
+    handler.instruction_table = Some(InstructionTables::Boxed(
+        table.try_into().unwrap_or_else(|_| unreachable!()),
+    ));
+
+    // Create handler
+    let old_handle = handler.execution.create.clone();
+    handler.execution.create = Arc::new(
+        move |ctx, inputs| -> Result<FrameOrResult, EVMError<DatabaseT::Error>> {
+            let tracer = ctx.external.get_context_data();
+            tracer.frame_start();
+
+            old_handle(ctx, inputs)
+        },
+    );
+
+    // Call handler
+    let old_handle = handler.execution.call.clone();
+    handler.execution.call = Arc::new(
+        move |ctx, inputs| -> Result<FrameOrResult, EVMError<DatabaseT::Error>> {
+            let tracer = ctx.external.get_context_data();
+            tracer.frame_start();
+
+            old_handle(ctx, inputs)
+        },
+    );
+
+    // call outcome
+    let old_handle = handler.execution.insert_call_outcome.clone();
+    handler.execution.insert_call_outcome = Arc::new(move |ctx, frame, shared_memory, outcome| {
+        let tracer = ctx.external.get_context_data();
+        tracer.frame_end();
+
+        old_handle(ctx, frame, shared_memory, outcome)
+    });
+
+    // create outcome
+    let old_handle = handler.execution.insert_create_outcome.clone();
+    handler.execution.insert_create_outcome = Arc::new(move |ctx, frame, outcome| {
+        let tracer = ctx.external.get_context_data();
+        tracer.frame_end();
+
+        old_handle(ctx, frame, outcome)
+    });
+
+    // last frame outcome
+    let old_handle = handler.execution.last_frame_return.clone();
+    handler.execution.last_frame_return = Arc::new(move |ctx, frame_result| {
+        let tracer = ctx.external.get_context_data();
+        tracer.frame_end();
+
+        old_handle(ctx, frame_result)
+    });
+}
+
+/// Outer closure that calls the tracer before and after every instruction.
+fn instruction_handler<
+    'a,
+    ContextT: GetContextData<VmTracer>,
+    DatabaseT: Database,
+    Instruction: Fn(&mut Interpreter, &mut Evm<'a, ContextT, DatabaseT>) + 'a,
+>(
+    instruction: Instruction,
+) -> BoxedInstruction<'a, Evm<'a, ContextT, DatabaseT>> {
+    Box::new(
+        move |interpreter: &mut Interpreter, host: &mut Evm<'a, ContextT, DatabaseT>| {
+            // SAFETY: as the PC was already incremented we need to subtract 1 to preserve
+            // the old Inspector behavior.
+            interpreter.instruction_pointer = unsafe { interpreter.instruction_pointer.sub(1) };
+
+            host.context.external.get_context_data().step(interpreter);
+
+            // return PC to old value
+            interpreter.instruction_pointer = unsafe { interpreter.instruction_pointer.add(1) };
+
+            // execute instruction.
+            instruction(interpreter, host);
+
+            host.context
+                .external
+                .get_context_data()
+                .step_end(interpreter);
+        },
+    )
+}
+
+/// A Parity `vmTrace` tracer.
+///
+/// The effects of an instruction - its pushed stack items, memory write and
+/// remaining gas - are only known once the instruction has completed. For
+/// `CALL*` and `CREATE*` instructions that is after the created frame
+/// returns, so the effects are recorded when the next instruction of the
+/// same frame starts or when the frame ends.
+#[derive(Debug, Default)]
+pub struct VmTracer {
+    frames: Vec<ActiveFrame>,
+    root: Option<VmTrace>,
+}
+
+#[derive(Debug, Default)]
+struct ActiveFrame {
+    trace: VmTrace,
+    pending: Option<PendingInstruction>,
+}
+
+/// The state of the last instruction of a frame, whose effects haven't been
+/// recorded yet.
+#[derive(Debug)]
+struct PendingInstruction {
+    gas_remaining: u64,
+    pushed_items: usize,
+    /// The offset and size of the memory written by the instruction.
+    memory_write: Option<(U256, U256)>,
+    store: Option<StorageDelta>,
+}
+
+impl VmTracer {
+    /// Converts the tracer into the instruction trace of the transaction.
+    pub fn into_trace(self) -> Option<VmTrace> {
+        self.root
+    }
+
+    fn frame_start(&mut self) {
+        self.frames.push(ActiveFrame::default());
+    }
+
+    fn frame_end(&mut self) {
+        let Some(frame) = self.frames.pop() else {
+            return;
+        };
+
+        if let Some(parent) = self.frames.last_mut() {
+            // Calls to precompiles and accounts without code don't execute any
+            // instructions, so they don't have a sub-trace.
+            if !frame.trace.code.is_empty() {
+                if let Some(instruction) = parent.trace.ops.last_mut() {
+                    instruction.sub = Some(frame.trace);
+                }
+            }
+        } else {
+            self.root = Some(frame.trace);
+        }
+    }
+
+    fn step(&mut self, interp: &Interpreter) {
+        let Some(frame) = self.frames.last_mut() else {
+            return;
+        };
+
+        if frame.trace.ops.is_empty() {
+            frame.trace.code = interp.contract.bytecode.original_bytes();
+        }
+
+        frame.record_effects(interp);
+
+        let opcode = interp.current_opcode();
+        let stack = interp.stack.data();
+
+        frame.trace.ops.push(VmInstruction {
+            pc: interp.program_counter() as u64,
+            cost: 0,
+            ex: None,
+            sub: None,
+            op: opcode::OPCODE_JUMPMAP[usize::from(opcode)]
+                .map_or_else(|| format!("0x{opcode:x}"), String::from),
+        });
+
+        frame.pending = Some(PendingInstruction {
+            gas_remaining: interp.gas().remaining(),
+            pushed_items: pushed_stack_items(opcode),
+            memory_write: memory_write(opcode, stack),
+            store: if opcode == opcode::SSTORE {
+                stack_item(stack, 0)
+                    .zip(stack_item(stack, 1))
+                    .map(|(key, val)| StorageDelta { key, val })
+            } else {
+                None
+            },
+        });
+    }
+
+    fn step_end(&mut self, interp: &Interpreter) {
+        let Some(frame) = self.frames.last_mut() else {
+            return;
+        };
+
+        if let (Some(pending), Some(instruction)) =
+            (frame.pending.as_ref(), frame.trace.ops.last_mut())
+        {
+            instruction.cost = pending
+                .gas_remaining
+                .saturating_sub(interp.gas().remaining());
+        }
+
+        match interp.instruction_result {
+            // Recorded when the next instruction starts
+            InstructionResult::Continue | InstructionResult::CallOrCreate => (),
+            result if result.is_ok() || result.is_revert() => frame.record_effects(interp),
+            // Failed instructions don't have any effects
+            _ => frame.pending = None,
+        }
+    }
+}
+
+impl ActiveFrame {
+    /// Records the effects of the pending instruction, using the interpreter
+    /// state after its execution.
+    fn record_effects(&mut self, interp: &Interpreter) {
+        let Some(pending) = self.pending.take() else {
+            return;
+        };
+
+        let Some(instruction) = self.trace.ops.last_mut() else {
+            return;
+        };
+
+        let stack = interp.stack.data();
+        let push = stack[stack.len().saturating_sub(pending.pushed_items)..].to_vec();
+
+        let mem = pending.memory_write.and_then(|(offset, size)| {
+            let offset = usize::try_from(offset).ok()?;
+            let size = usize::try_from(size).ok().filter(|size| *size > 0)?;
+
+            interp
+                .shared_memory
+                .context_memory()
+                .get(offset..offset.checked_add(size)?)
+                .map(|data| MemoryDelta {
+                    off: offset,
+                    data: Bytes::copy_from_slice(data),
+                })
+        });
+
+        instruction.ex = Some(VmExecutedOperation {
+            used: interp.gas().remaining(),
+            push,
+            mem,
+            store: pending.store,
+        });
+    }
+}
+
+impl GetContextData<VmTracer> for VmTracer {
+    fn get_context_data(&mut self) -> &mut VmTracer {
+        self
+    }
+}
+
+/// Returns the `index`-th item from the top of the stack.
+fn stack_item(stack: &[U256], index: usize) -> Option<U256> {
+    stack
+        .len()
+        .checked_sub(index + 1)
+        .map(|position| stack[position])
+}
+
+/// Returns the offset and size of the memory written by an instruction, based
+/// on the stack before its execution.
+fn memory_write(opcode: u8, stack: &[U256]) -> Option<(U256, U256)> {
+    match opcode {
+        opcode::MSTORE => Some((stack_item(stack, 0)?, U256::from(32))),
+        opcode::MSTORE8 => Some((stack_item(stack, 0)?, U256::from(1))),
+        opcode::CALLDATACOPY | opcode::CODECOPY | opcode::RETURNDATACOPY | opcode::MCOPY => {
+            Some((stack_item(stack, 0)?, stack_item(stack, 2)?))
+        }
+        opcode::EXTCODECOPY => Some((stack_item(stack, 1)?, stack_item(stack, 3)?)),
+        opcode::CALL | opcode::CALLCODE => Some((stack_item(stack, 5)?, stack_item(stack, 6)?)),
+        opcode::DELEGATECALL | opcode::STATICCALL => {
+            Some((stack_item(stack, 4)?, stack_item(stack, 5)?))
+        }
+        _ => None,
+    }
+}
+
+/// Returns the number of stack items that Parity reports as pushed by an
+/// instruction. `DUP*` and `SWAP*` report all the stack items they touch.
+fn pushed_stack_items(opcode: u8) -> usize {
+    match opcode {
+        opcode::DUP1..=opcode::DUP16 => usize::from(opcode - opcode::DUP1) + 2,
+        opcode::SWAP1..=opcode::SWAP16 => usize::from(opcode - opcode::SWAP1) + 2,
+        opcode::STOP
+        | opcode::CALLDATACOPY
+        | opcode::CODECOPY
+        | opcode::EXTCODECOPY
+        | opcode::RETURNDATACOPY
+        | opcode::POP
+        | opcode::MSTORE
+        | opcode::MSTORE8
+        | opcode::SSTORE
+        | opcode::JUMP
+        | opcode::JUMPI
+        | opcode::JUMPDEST
+        | opcode::TSTORE
+        | opcode::MCOPY
+        | opcode::LOG0..=opcode::LOG4
+        | opcode::RETURN
+        | opcode::REVERT
+        | opcode::INVALID
+        | opcode::SELFDESTRUCT => 0,
+        opcode if opcode::OPCODE_JUMPMAP[usize::from(opcode)].is_none() => 0,
+        _ => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pushed_stack_items_dup_swap() {
+        assert_eq!(pushed_stack_items(opcode::PUSH1), 1);
+        assert_eq!(pushed_stack_items(opcode::DUP1), 2);
+        assert_eq!(pushed_stack_items(opcode::DUP16), 17);
+        assert_eq!(pushed_stack_items(opcode::SWAP1), 2);
+        assert_eq!(pushed_stack_items(opcode::SWAP16), 17);
+        assert_eq!(pushed_stack_items(opcode::SSTORE), 0);
+        assert_eq!(pushed_stack_items(opcode::LOG2), 0);
+    }
+
+    #[test]
+    fn memory_write_call() {
+        // CALL(gas, to, value, argsOffset, argsSize, retOffset, retSize)
+        let stack: Vec<U256> = [0x20u64, 0x40, 0, 0, 0, 0x1234, 0xffff]
+            .into_iter()
+            .map(U256::from)
+            .collect();
+
+        assert_eq!(
+            memory_write(opcode::CALL, &stack),
+            Some((U256::from(0x40), U256::from(0x20)))
+        );
+
+        // STATICCALL(gas, to, argsOffset, argsSize, retOffset, retSize)
+        let stack: Vec<U256> = [0x20u64, 0x40, 0, 0, 0x1234, 0xffff]
+            .into_iter()
+            .map(U256::from)
+            .collect();
+
+        assert_eq!(
+            memory_write(opcode::STATICCALL, &stack),
+            Some((U256::from(0x40), U256::from(0x20)))
+        );
+        assert_eq!(memory_write(opcode::ADD, &stack), None);
+    }
+}
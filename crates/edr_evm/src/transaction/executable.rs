@@ -20,7 +20,7 @@ use super::TransactionCreationError;
 /// A transaction that can be executed by the EVM. It allows manual
 /// specification of the caller, e.g. to override the caller of a transaction
 /// that can be recovered from a signature.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 pub struct ExecutableTransaction {
     transaction: SignedTransaction,
     caller: Address,
@@ -1,6 +1,9 @@
 #![cfg(feature = "test-utils")]
 
-use std::num::NonZeroU64;
+use std::{
+    num::{NonZeroU64, NonZeroUsize},
+    time::{Duration, Instant},
+};
 
 use edr_eth::{AccountInfo, Address, U256};
 use edr_evm::{
@@ -10,7 +13,7 @@ use edr_evm::{
         dummy_eip155_transaction_with_price, dummy_eip155_transaction_with_price_limit_and_value,
         MemPoolTestFixture,
     },
-    MemPoolAddTransactionError, OrderedTransaction,
+    MemPoolAddTransactionError, MemPoolLimits, OrderedTransaction,
 };
 
 #[test]
@@ -806,3 +809,246 @@ fn update_removes_transactions_with_insufficient_balance() -> anyhow::Result<()>
 
     Ok(())
 }
+
+fn funded_account() -> (Address, AccountInfo) {
+    (
+        Address::random(),
+        AccountInfo {
+            balance: U256::from(100_000_000u64),
+            ..AccountInfo::default()
+        },
+    )
+}
+
+#[test]
+fn add_transaction_rejects_exceeding_account_limits() -> anyhow::Result<()> {
+    let account = funded_account();
+    let sender = account.0;
+
+    let mut fixture = MemPoolTestFixture::with_accounts_and_limits(
+        &[account],
+        MemPoolLimits {
+            max_pending_per_account: NonZeroUsize::new(2),
+            max_queued_per_account: NonZeroUsize::new(1),
+            ..MemPoolLimits::default()
+        },
+    );
+
+    fixture.add_transaction(dummy_eip155_transaction(sender, 0)?)?;
+    fixture.add_transaction(dummy_eip155_transaction(sender, 1)?)?;
+
+    let result = fixture.add_transaction(dummy_eip155_transaction(sender, 2)?);
+    assert!(matches!(
+        result,
+        Err(MemPoolAddTransactionError::PendingTransactionLimitReached { limit }) if limit.get() == 2
+    ));
+
+    fixture.add_transaction(dummy_eip155_transaction(sender, 4)?)?;
+
+    let result = fixture.add_transaction(dummy_eip155_transaction(sender, 5)?);
+    assert!(matches!(
+        result,
+        Err(MemPoolAddTransactionError::QueuedTransactionLimitReached { limit }) if limit.get() == 1
+    ));
+
+    // Replacements don't count towards the limits
+    fixture.add_transaction(dummy_eip155_transaction_with_price(
+        sender,
+        1,
+        U256::from(10),
+    )?)?;
+
+    assert_eq!(fixture.mem_pool.pending_transactions().count(), 2);
+    assert_eq!(fixture.mem_pool.future_transactions().count(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn add_transaction_evicts_lowest_priced_transaction() -> anyhow::Result<()> {
+    let account1 = funded_account();
+    let account2 = funded_account();
+    let account3 = funded_account();
+    let (sender1, sender2, sender3) = (account1.0, account2.0, account3.0);
+
+    let mut fixture = MemPoolTestFixture::with_accounts_and_limits(
+        &[account1, account2, account3],
+        MemPoolLimits {
+            max_transactions: NonZeroUsize::new(2),
+            ..MemPoolLimits::default()
+        },
+    );
+
+    let cheap = dummy_eip155_transaction_with_price(sender1, 0, U256::from(10))?;
+    fixture.add_transaction(cheap.clone())?;
+
+    let expensive = dummy_eip155_transaction_with_price(sender2, 0, U256::from(30))?;
+    fixture.add_transaction(expensive.clone())?;
+
+    // Not priced higher than the cheapest transaction
+    let result = fixture.add_transaction(dummy_eip155_transaction_with_price(
+        sender3,
+        0,
+        U256::from(10),
+    )?);
+    assert!(matches!(
+        result,
+        Err(MemPoolAddTransactionError::MemPoolFull { min_gas_price }) if min_gas_price == U256::from(10)
+    ));
+
+    let transaction = dummy_eip155_transaction_with_price(sender3, 0, U256::from(20))?;
+    let evicted = fixture.add_transaction(transaction.clone())?;
+
+    assert_eq!(evicted.len(), 1);
+    assert_eq!(*evicted[0].pending(), cheap);
+
+    let pending_transactions = fixture
+        .mem_pool
+        .pending_transactions()
+        .map(OrderedTransaction::pending)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    assert_eq!(pending_transactions, vec![expensive, transaction]);
+
+    Ok(())
+}
+
+#[test]
+fn remove_expired_transactions() -> anyhow::Result<()> {
+    let account = funded_account();
+    let sender = account.0;
+
+    let lifetime = Duration::from_secs(60);
+    let mut fixture = MemPoolTestFixture::with_accounts_and_limits(
+        &[account],
+        MemPoolLimits {
+            queued_lifetime: Some(lifetime),
+            ..MemPoolLimits::default()
+        },
+    );
+
+    let pending = dummy_eip155_transaction(sender, 0)?;
+    fixture.add_transaction(pending.clone())?;
+
+    let queued = dummy_eip155_transaction(sender, 2)?;
+    fixture.add_transaction(queued.clone())?;
+
+    assert!(fixture
+        .mem_pool
+        .remove_expired_transactions(Instant::now())
+        .is_empty());
+
+    let expired = fixture
+        .mem_pool
+        .remove_expired_transactions(Instant::now() + lifetime);
+
+    assert_eq!(expired.len(), 1);
+    assert_eq!(*expired[0].pending(), queued);
+
+    // Pending transactions don't expire
+    assert_eq!(fixture.mem_pool.pending_transactions().count(), 1);
+    assert!(!fixture.mem_pool.has_future_transactions());
+
+    Ok(())
+}
+
+#[test]
+fn update_promotes_future_transactions_within_pending_limit() -> anyhow::Result<()> {
+    let account = funded_account();
+    let sender = account.0;
+
+    let mut fixture = MemPoolTestFixture::with_accounts_and_limits(
+        &[account],
+        MemPoolLimits {
+            max_pending_per_account: NonZeroUsize::new(1),
+            ..MemPoolLimits::default()
+        },
+    );
+
+    let transaction1 = dummy_eip155_transaction(sender, 1)?;
+    fixture.add_transaction(transaction1.clone())?;
+
+    let transaction2 = dummy_eip155_transaction(sender, 2)?;
+    fixture.add_transaction(transaction2.clone())?;
+
+    // Fills the account's only pending slot
+    fixture.add_transaction(dummy_eip155_transaction(sender, 0)?)?;
+    assert_eq!(fixture.mem_pool.pending_transactions().count(), 1);
+    assert_eq!(fixture.mem_pool.future_transactions().count(), 2);
+
+    // Mine the pending transaction
+    fixture.state.modify_account(
+        sender,
+        AccountModifierFn::new(Box::new(|_balance, nonce, _code| *nonce = 1)),
+    )?;
+
+    fixture.update()?;
+
+    let pending_transactions = fixture
+        .mem_pool
+        .pending_transactions()
+        .map(OrderedTransaction::pending)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    assert_eq!(pending_transactions, vec![transaction1]);
+
+    let future_transactions = fixture
+        .mem_pool
+        .future_transactions()
+        .map(OrderedTransaction::pending)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    assert_eq!(future_transactions, vec![transaction2]);
+
+    Ok(())
+}
+
+#[test]
+fn remove_transaction_enforces_queued_limit_on_demotion() -> anyhow::Result<()> {
+    let account = funded_account();
+    let sender = account.0;
+
+    let mut fixture = MemPoolTestFixture::with_accounts_and_limits(
+        &[account],
+        MemPoolLimits {
+            max_queued_per_account: NonZeroUsize::new(1),
+            ..MemPoolLimits::default()
+        },
+    );
+
+    let transaction0 = dummy_eip155_transaction(sender, 0)?;
+    fixture.add_transaction(transaction0.clone())?;
+
+    let transaction1 = dummy_eip155_transaction(sender, 1)?;
+    fixture.add_transaction(transaction1.clone())?;
+
+    let transaction2 = dummy_eip155_transaction(sender, 2)?;
+    fixture.add_transaction(transaction2.clone())?;
+
+    let removed = fixture.mem_pool.remove_transaction(transaction0.hash());
+    assert_eq!(
+        removed.map(|removed| removed.pending().clone()),
+        Some(transaction0)
+    );
+
+    assert!(!fixture.mem_pool.has_pending_transactions());
+
+    // Only the lowest nonce fits in the account's queue
+    let future_transactions = fixture
+        .mem_pool
+        .future_transactions()
+        .map(OrderedTransaction::pending)
+        .cloned()
+        .collect::<Vec<_>>();
+
+    assert_eq!(future_transactions, vec![transaction1]);
+    assert!(fixture
+        .mem_pool
+        .transaction_by_hash(transaction2.hash())
+        .is_none());
+
+    Ok(())
+}
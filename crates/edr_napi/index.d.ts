@@ -187,11 +187,50 @@ export const enum MineOrdering {
   /**Insertion order */
   Fifo = 'Fifo',
   /**Effective miner fee */
-  Priority = 'Priority'
+  Priority = 'Priority',
+  /**Lowest effective miner fee first */
+  ReversePriority = 'ReversePriority',
+  /**Pseudo-random order, determined by `MemPoolConfig.randomSeed` */
+  Random = 'Random',
+  /**The order of `MemPoolConfig.explicitOrder`, followed by insertion order */
+  Explicit = 'Explicit'
 }
 /** Configuration for the provider's mempool. */
 export interface MemPoolConfig {
   order: MineOrdering
+  /**
+   * The maximum number of pending transactions per account. Unlimited if
+   * not provided or zero.
+   */
+  maxPendingPerAccount?: number
+  /**
+   * The maximum number of queued transactions per account. Unlimited if
+   * not provided or zero.
+   */
+  maxQueuedPerAccount?: number
+  /**
+   * The maximum number of transactions in the mempool, after which the
+   * lowest-priced transactions are evicted. Unlimited if not provided or
+   * zero.
+   */
+  maxTransactions?: number
+  /**
+   * The number of seconds after which queued transactions are dropped. If
+   * not provided, queued transactions never expire. Expiry is only checked
+   * when a transaction is added or a block is mined (including by interval
+   * mining), so an idle provider doesn't drop expired transactions.
+   */
+  queuedLifetime?: bigint
+  /**
+   * The seed used for the `Random` ordering. Required if, and only used
+   * when, the order is `Random`.
+   */
+  randomSeed?: bigint
+  /**
+   * The transaction hashes used for the `Explicit` ordering. Required if,
+   * and only used when, the order is `Explicit`.
+   */
+  explicitOrder?: Array<Buffer>
 }
 export interface IntervalRange {
   min: bigint
@@ -209,6 +248,11 @@ export interface ProviderConfig {
   allowBlocksWithSameTimestamp: boolean
   /** Whether to allow unlimited contract size */
   allowUnlimitedContractSize: boolean
+  /**
+   * Whether to accept transactions from any address, as if it were
+   * impersonated. Defaults to `false`.
+   */
+  autoImpersonate?: boolean
   /** Whether to return an `Err` when `eth_call` fails */
   bailOnCallFailure: boolean
   /** Whether to return an `Err` when a `eth_sendTransaction` fails */
@@ -252,6 +296,11 @@ export interface ProviderConfig {
   mining: MiningConfig
   /** The network ID of the blockchain */
   networkId: bigint
+  /**
+   * The directory to persist the blockchain to, allowing it to be restored
+   * across restarts. Only supported for local blockchains.
+   */
+  persistencePath?: string
 }
 /** The possible reasons for successful termination of the EVM. */
 export const enum SuccessReason {
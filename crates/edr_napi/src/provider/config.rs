@@ -1,10 +1,10 @@
 use std::{
-    num::NonZeroU64,
+    num::{NonZeroU64, NonZeroUsize},
     path::PathBuf,
     time::{Duration, SystemTime},
 };
 
-use edr_eth::HashMap;
+use edr_eth::{HashMap, B256};
 use edr_provider::AccountConfig;
 use napi::{
     bindgen_prelude::{BigInt, Buffer},
@@ -57,12 +57,39 @@ pub enum MineOrdering {
     Fifo,
     #[doc = "Effective miner fee"]
     Priority,
+    #[doc = "Lowest effective miner fee first"]
+    ReversePriority,
+    #[doc = "Pseudo-random order, determined by `MemPoolConfig.randomSeed`"]
+    Random,
+    #[doc = "The order of `MemPoolConfig.explicitOrder`, followed by insertion order"]
+    Explicit,
 }
 
 /// Configuration for the provider's mempool.
 #[napi(object)]
 pub struct MemPoolConfig {
     pub order: MineOrdering,
+    /// The maximum number of pending transactions per account. Unlimited if
+    /// not provided or zero.
+    pub max_pending_per_account: Option<u32>,
+    /// The maximum number of queued transactions per account. Unlimited if
+    /// not provided or zero.
+    pub max_queued_per_account: Option<u32>,
+    /// The maximum number of transactions in the mempool, after which the
+    /// lowest-priced transactions are evicted. Unlimited if not provided or
+    /// zero.
+    pub max_transactions: Option<u32>,
+    /// The number of seconds after which queued transactions are dropped. If
+    /// not provided, queued transactions never expire. Expiry is only checked
+    /// when a transaction is added or a block is mined (including by interval
+    /// mining), so an idle provider doesn't drop expired transactions.
+    pub queued_lifetime: Option<BigInt>,
+    /// The seed used for the `Random` ordering. Required if, and only used
+    /// when, the order is `Random`.
+    pub random_seed: Option<BigInt>,
+    /// The transaction hashes used for the `Explicit` ordering. Required if,
+    /// and only used when, the order is `Explicit`.
+    pub explicit_order: Option<Vec<Buffer>>,
 }
 
 #[napi(object)]
@@ -86,6 +113,9 @@ pub struct ProviderConfig {
     pub allow_blocks_with_same_timestamp: bool,
     /// Whether to allow unlimited contract size
     pub allow_unlimited_contract_size: bool,
+    /// Whether to accept transactions from any address, as if it were
+    /// impersonated. Defaults to `false`.
+    pub auto_impersonate: Option<bool>,
     /// Whether to return an `Err` when `eth_call` fails
     pub bail_on_call_failure: bool,
     /// Whether to return an `Err` when a `eth_sendTransaction` fails
@@ -123,6 +153,9 @@ pub struct ProviderConfig {
     pub mining: MiningConfig,
     /// The network ID of the blockchain
     pub network_id: BigInt,
+    /// The directory to persist the blockchain to, allowing it to be restored
+    /// across restarts. Only supported for local blockchains.
+    pub persistence_path: Option<String>,
 }
 
 impl TryFrom<ForkConfig> for edr_provider::hardhat_rpc_types::ForkConfig {
@@ -145,20 +178,59 @@ impl TryFrom<ForkConfig> for edr_provider::hardhat_rpc_types::ForkConfig {
     }
 }
 
-impl From<MemPoolConfig> for edr_provider::MemPoolConfig {
-    fn from(value: MemPoolConfig) -> Self {
-        Self {
-            order: value.order.into(),
-        }
-    }
-}
+impl TryFrom<MemPoolConfig> for edr_provider::MemPoolConfig {
+    type Error = napi::Error;
 
-impl From<MineOrdering> for edr_evm::MineOrdering {
-    fn from(value: MineOrdering) -> Self {
-        match value {
-            MineOrdering::Fifo => Self::Fifo,
-            MineOrdering::Priority => Self::Priority,
+    fn try_from(value: MemPoolConfig) -> Result<Self, Self::Error> {
+        fn to_limit(limit: Option<u32>) -> Option<NonZeroUsize> {
+            limit.and_then(|limit| {
+                NonZeroUsize::new(usize::try_from(limit).expect("u32 fits into usize"))
+            })
         }
+
+        let queued_lifetime = value
+            .queued_lifetime
+            .map(|seconds| seconds.try_cast().map(Duration::from_secs))
+            .transpose()?;
+
+        let order = match value.order {
+            MineOrdering::Fifo => edr_evm::MineOrdering::Fifo,
+            MineOrdering::Priority => edr_evm::MineOrdering::Priority,
+            MineOrdering::ReversePriority => edr_evm::MineOrdering::ReversePriority,
+            MineOrdering::Random => {
+                let seed = value.random_seed.ok_or_else(|| {
+                    napi::Error::new(
+                        napi::Status::InvalidArg,
+                        "A random seed must be provided for the Random mempool ordering",
+                    )
+                })?;
+
+                edr_evm::MineOrdering::Random(seed.try_cast()?)
+            }
+            MineOrdering::Explicit => {
+                let transaction_hashes = value.explicit_order.ok_or_else(|| {
+                    napi::Error::new(
+                        napi::Status::InvalidArg,
+                        "Transaction hashes must be provided for the Explicit mempool ordering",
+                    )
+                })?;
+
+                edr_evm::MineOrdering::Explicit(
+                    transaction_hashes
+                        .into_iter()
+                        .map(TryCast::<B256>::try_cast)
+                        .collect::<napi::Result<_>>()?,
+                )
+            }
+        };
+
+        Ok(Self {
+            order,
+            max_pending_per_account: to_limit(value.max_pending_per_account),
+            max_queued_per_account: to_limit(value.max_queued_per_account),
+            max_transactions: to_limit(value.max_transactions),
+            queued_lifetime,
+        })
     }
 }
 
@@ -166,7 +238,7 @@ impl TryFrom<MiningConfig> for edr_provider::MiningConfig {
     type Error = napi::Error;
 
     fn try_from(value: MiningConfig) -> Result<Self, Self::Error> {
-        let mem_pool = value.mem_pool.into();
+        let mem_pool = value.mem_pool.try_into()?;
 
         let interval = value
             .interval
@@ -250,6 +322,7 @@ impl TryFrom<ProviderConfig> for edr_provider::ProviderConfig {
                 .collect::<napi::Result<Vec<_>>>()?,
             allow_blocks_with_same_timestamp: value.allow_blocks_with_same_timestamp,
             allow_unlimited_contract_size: value.allow_unlimited_contract_size,
+            auto_impersonate: value.auto_impersonate.unwrap_or(false),
             bail_on_call_failure: value.bail_on_call_failure,
             bail_on_transaction_failure: value.bail_on_transaction_failure,
             block_gas_limit,
@@ -283,6 +356,7 @@ impl TryFrom<ProviderConfig> for edr_provider::ProviderConfig {
             mining: value.mining.try_into()?,
             min_gas_price: value.min_gas_price.try_cast()?,
             network_id: value.network_id.try_cast()?,
+            persistence_path: value.persistence_path.map(PathBuf::from),
         })
     }
 }
@@ -31,7 +31,8 @@ impl SubscriberCallback {
                         let block = eth::Block::<B256>::from(block);
                         ctx.env.to_js_value(&block)
                     }
-                    edr_provider::SubscriptionEventData::NewPendingTransactions(tx_hash) => {
+                    edr_provider::SubscriptionEventData::NewPendingTransactions(tx_hash)
+                    | edr_provider::SubscriptionEventData::DroppedTransactions(tx_hash) => {
                         ctx.env.to_js_value(&tx_hash)
                     }
                 }?;
@@ -1,9 +1,13 @@
-use std::{num::NonZeroU64, path::PathBuf, time::SystemTime};
+use std::{
+    num::{NonZeroU64, NonZeroUsize},
+    path::PathBuf,
+    time::{Duration, SystemTime},
+};
 
 use edr_eth::{
     block::BlobGas, spec::HardforkActivations, AccountInfo, Address, HashMap, SpecId, B256, U256,
 };
-use edr_evm::{alloy_primitives::ChainId, MineOrdering};
+use edr_evm::{alloy_primitives::ChainId, MemPoolLimits, MineOrdering};
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
@@ -60,6 +64,30 @@ impl TryInto<Option<IntervalConfig>> for IntervalConfigRequest {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MemPoolConfig {
     pub order: MineOrdering,
+    /// The maximum number of pending transactions per account
+    pub max_pending_per_account: Option<NonZeroUsize>,
+    /// The maximum number of queued transactions per account
+    pub max_queued_per_account: Option<NonZeroUsize>,
+    /// The maximum number of transactions in the mempool. Once reached, the
+    /// lowest-priced transactions are evicted.
+    pub max_transactions: Option<NonZeroUsize>,
+    /// The duration after which queued transactions are dropped. Expiry is
+    /// only checked when a transaction is added or a block is mined (including
+    /// by interval mining), so an idle provider doesn't drop expired
+    /// transactions.
+    pub queued_lifetime: Option<Duration>,
+}
+
+impl MemPoolConfig {
+    /// Retrieves the limits on the mempool's transactions.
+    pub fn limits(&self) -> MemPoolLimits {
+        MemPoolLimits {
+            max_pending_per_account: self.max_pending_per_account,
+            max_queued_per_account: self.max_queued_per_account,
+            max_transactions: self.max_transactions,
+            queued_lifetime: self.queued_lifetime,
+        }
+    }
 }
 
 /// Configuration for the provider's miner.
@@ -76,6 +104,9 @@ pub struct ProviderConfig {
     pub allow_blocks_with_same_timestamp: bool,
     pub allow_unlimited_contract_size: bool,
     pub accounts: Vec<AccountConfig>,
+    /// Whether to accept transactions from any address, as if it were
+    /// impersonated
+    pub auto_impersonate: bool,
     /// Whether to return an `Err` when `eth_call` fails
     pub bail_on_call_failure: bool,
     /// Whether to return an `Err` when a `eth_sendTransaction` fails
@@ -96,6 +127,9 @@ pub struct ProviderConfig {
     pub min_gas_price: U256,
     pub mining: MiningConfig,
     pub network_id: u64,
+    /// The directory to persist a local blockchain to, allowing it to be
+    /// restored across restarts
+    pub persistence_path: Option<PathBuf>,
 }
 
 /// Configuration input for a single account
@@ -135,6 +169,10 @@ impl Default for MemPoolConfig {
     fn default() -> Self {
         Self {
             order: MineOrdering::Priority,
+            max_pending_per_account: None,
+            max_queued_per_account: None,
+            max_transactions: None,
+            queued_lifetime: None,
         }
     }
 }
@@ -1,30 +1,41 @@
 mod account;
+mod blob;
+mod bundle;
 mod call;
 mod gas;
+mod persistence;
+mod simulate;
 
 use std::{
     cmp::{self, Ordering},
     collections::BTreeMap,
     ffi::OsString,
     fmt::Debug,
+    io,
     num::{NonZeroU64, NonZeroUsize},
     sync::Arc,
     time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
 use edr_eth::{
-    block::{calculate_next_base_fee, miner_reward, BlobGas, BlockOptions},
-    log::FilterLog,
+    access_list::{AccessList, AccessListItem},
+    account::AccountProof,
+    block::{
+        calculate_next_base_fee, calculate_next_blob_base_fee, miner_reward, BlobGas, BlockOptions,
+        Header,
+    },
+    log::{FilterLog, Log},
     receipt::BlockReceipt,
     remote::{
         client::{HeaderMap, HttpError},
         eth::FeeHistoryResult,
         filter::{FilteredEvents, LogOutput, SubscriptionType},
-        BlockSpec, BlockTag, Eip1898BlockSpec, RpcClient, RpcClientError,
+        BlockOverrideOptions, BlockSpec, BlockTag, Eip1898BlockSpec, RpcClient, RpcClientError,
     },
     reward_percentile::RewardPercentile,
     signature::{RecoveryMessage, Signature},
-    transaction::TransactionRequestAndSender,
+    transaction::{BlobSidecar, TransactionRequestAndSender},
+    withdrawal::Withdrawal,
     Address, Bytes, SpecId, B256, U256,
 };
 use edr_evm::{
@@ -33,17 +44,25 @@ use edr_evm::{
         LocalBlockchain, LocalCreationError, SyncBlockchain,
     },
     db::StateRef,
-    debug_trace_transaction, execution_result_to_debug_result, mempool, mine_block,
+    debug_trace_block, debug_trace_transaction, execution_result_to_call_frame,
+    execution_result_to_debug_result, mempool, mine_block, mine_block_with_transactions,
+    precompile::{PrecompileSpecId, Precompiles},
+    prestate_trace, register_access_list_tracer_handles, register_call_tracer_handles,
     register_eip_3155_tracer_handles,
     state::{
-        AccountModifierFn, IrregularState, StateDiff, StateError, StateOverride, StateOverrides,
-        SyncState,
+        AccountModifierFn, IrregularState, StateDiff, StateDump, StateError, StateOverride,
+        StateOverrides, SyncState,
+    },
+    trace::{
+        parity::{self, LocalizedFlatTrace, ParityTracer, TraceResults, TraceType},
+        Trace,
     },
-    trace::Trace,
-    Account, AccountInfo, BlobExcessGasAndPrice, Block, BlockEnv, Bytecode, CfgEnv,
-    CfgEnvWithHandlerCfg, DebugContext, DebugTraceConfig, DebugTraceResult, ExecutableTransaction,
-    ExecutionResult, HashMap, HashSet, MemPool, OrderedTransaction, RandomHashGenerator,
-    StorageSlot, SyncBlock, TracerEip3155, TxEnv, KECCAK_EMPTY,
+    AccessListTracer, Account, AccountInfo, BlobExcessGasAndPrice, Block, BlockEnv, Bytecode,
+    CallTracer, CfgEnv, CfgEnvWithHandlerCfg, DebugContext, DebugTraceConfig, DebugTraceOutput,
+    DebugTracer, ExecutableTransaction, ExecutionResult, HashMap, HashSet, MemPool,
+    MemPoolAddTransactionError, OrderedTransaction, RandomHashGenerator, ResultAndState,
+    StorageSlot, SyncBlock, TracerEip3155, TransactTo, TransactionOrdering, TransactionTrace,
+    TxEnv, KECCAK_EMPTY,
 };
 use ethers_core::types::transaction::eip712::{Eip712, TypedData};
 use gas::gas_used_ratio;
@@ -54,10 +73,15 @@ use rpds::HashTrieMapSync;
 use tokio::runtime;
 
 use self::account::{create_accounts, InitialAccounts};
+pub(crate) use self::blob::BlobStore;
+pub use self::{blob::MinedBlob, bundle::PendingBundle};
 use crate::{
     data::{
-        call::{run_call, RunCallArgs},
+        bundle::{bundle_hash, call_bundle, CallBundleArgs},
+        call::{run_call, run_call_with_state, RunCallArgs},
         gas::{compute_rewards, BinarySearchEstimationArgs, CheckGasLimitArgs},
+        persistence::PersistencePaths,
+        simulate::{simulate_blocks, SimulateArgs},
     },
     debug_mine::{DebugMineBlockResult, DebugMineBlockResultAndState},
     debugger::{register_debugger_handles, Debugger},
@@ -66,7 +90,13 @@ use crate::{
     logger::SyncLogger,
     mock::{Mocker, SyncCallOverride},
     pending::BlockchainWithPending,
-    requests::hardhat::rpc_types::{ForkConfig, ForkMetadata},
+    requests::{
+        eth::SimulatePayload,
+        hardhat::rpc_types::{
+            AccountState, ForkConfig, ForkMetadata, MineBlockOptions, SerializedBlockContext,
+            SerializedState, SnapshotInfo, SnapshotSelector, SERIALIZED_STATE_VERSION,
+        },
+    },
     snapshot::Snapshot,
     MiningConfig, ProviderConfig, ProviderError, SubscriptionEvent, SubscriptionEventData,
     SyncSubscriberCallback,
@@ -90,6 +120,55 @@ pub struct EstimateGasResult {
     pub traces: Vec<Trace>,
 }
 
+/// The result of simulating a block of calls.
+pub struct SimulatedBlockResult {
+    /// The simulated block
+    pub block: Arc<dyn SyncBlock<Error = BlockchainError>>,
+    /// The results of the block's calls, in order
+    pub call_results: Vec<SimulatedCallResult>,
+}
+
+/// The result of simulating a single call.
+pub enum SimulatedCallResult {
+    /// The call was included in the simulated block.
+    Included {
+        /// The execution result of the call
+        execution_result: ExecutionResult,
+        /// The logs emitted by the call, including synthetic ETH transfer logs
+        /// if requested.
+        logs: Vec<Log>,
+    },
+    /// The call couldn't be included in the simulated block, e.g. due to an
+    /// invalid nonce or insufficient funds.
+    Rejected(String),
+}
+
+/// The result of executing a bundle with `eth_callBundle`.
+pub struct CallBundleResult {
+    /// The hash of the bundle
+    pub bundle_hash: B256,
+    /// The number of the block whose state the bundle was executed on
+    pub state_block_number: u64,
+    /// The results of the bundle's transactions, in order
+    pub transaction_results: Vec<BundleTransactionResult>,
+}
+
+/// The result of executing a single transaction of a bundle.
+pub struct BundleTransactionResult {
+    /// The hash of the transaction
+    pub transaction_hash: B256,
+    /// The sender of the transaction
+    pub caller: Address,
+    /// The recipient of the transaction, if it's not a contract creation
+    pub to: Option<Address>,
+    /// The execution result of the transaction
+    pub execution_result: ExecutionResult,
+    /// The gas fees paid to the coinbase, excluding the base fee
+    pub gas_fees: U256,
+    /// The change of the coinbase's balance caused by the transaction
+    pub coinbase_diff: U256,
+}
+
 pub struct SendTransactionResult {
     pub transaction_hash: B256,
     pub mining_results: Vec<DebugMineBlockResult<BlockchainError>>,
@@ -146,9 +225,21 @@ pub enum CreationError {
     InvalidInitialDate(SystemTime),
     #[error("Invalid max cached states environment variable value: '{0:?}'. Please provide a non-zero integer!")]
     InvalidMaxCachedStates(OsString),
+    /// The timestamp of the persisted blockchain's last block is not
+    /// representable as a signed integer.
+    #[error("The persisted blockchain's last block has an invalid timestamp: {0}")]
+    InvalidPersistedTimestamp(u64),
     /// An error that occurred while constructing a local blockchain.
     #[error(transparent)]
     LocalBlockchainCreation(#[from] LocalCreationError),
+    /// An error that occurred while reading or removing the persisted
+    /// blockchain.
+    #[error("Failed to access the persisted blockchain: {0}")]
+    Persistence(io::Error),
+    /// A persistence path was configured for a forked blockchain. Only local
+    /// blockchains can be persisted.
+    #[error("A persistence path cannot be used in combination with a fork. Only local blockchains can be persisted.")]
+    PersistenceWithFork,
     /// An error that occured while querying the remote state.
     #[error(transparent)]
     RpcClient(#[from] RpcClientError),
@@ -160,7 +251,11 @@ pub struct ProviderData<LoggerErrorT: Debug> {
     blockchain: Box<dyn SyncBlockchain<BlockchainError, StateError>>,
     pub irregular_state: IrregularState,
     mem_pool: MemPool,
+    bundles: Vec<PendingBundle>,
+    blobs: BlobStore,
     beneficiary: Address,
+    // Overrides the blockchain's chain ID, as it can be changed at runtime.
+    chain_id: u64,
     dao_activation_block: Option<u64>,
     min_gas_price: U256,
     parent_beacon_block_root_generator: RandomHashGenerator,
@@ -170,9 +265,14 @@ pub struct ProviderData<LoggerErrorT: Debug> {
     // Must be set if the provider is created with a fork config.
     // Hack to get around the type erasure with the dyn blockchain trait.
     rpc_client: Option<Arc<RpcClient>>,
+    // Set if the local blockchain is persisted to disk.
+    persistence_paths: Option<PersistencePaths>,
     instance_id: B256,
     is_auto_mining: bool,
+    mine_ordering: Box<dyn TransactionOrdering>,
     next_block_base_fee_per_gas: Option<U256>,
+    // Header fields and withdrawals of the next block that aren't tracked separately.
+    next_block_options: MineBlockOptions,
     next_block_timestamp: Option<u64>,
     next_snapshot_id: u64,
     snapshots: BTreeMap<u64, Snapshot>,
@@ -184,6 +284,8 @@ pub struct ProviderData<LoggerErrorT: Debug> {
     last_filter_id: U256,
     logger: Box<dyn SyncLogger<BlockchainError = BlockchainError, LoggerError = LoggerErrorT>>,
     impersonated_accounts: HashSet<Address>,
+    // Whether transactions from any address are accepted, as if it were impersonated.
+    auto_impersonate: bool,
     subscriber_callback: Box<dyn SyncSubscriberCallback>,
     call_override: Option<Arc<dyn SyncCallOverride>>,
     // We need the Arc to let us avoid returning references to the cache entries which need &mut
@@ -210,6 +312,7 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
             blockchain,
             fork_metadata,
             rpc_client,
+            persistence_paths,
             state,
             irregular_state,
             prev_randao_generator,
@@ -236,11 +339,16 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         block_state_cache.push(current_state_id, Arc::new(state));
         block_number_to_state_id.insert_mut(blockchain.last_block_number(), current_state_id);
 
+        let chain_id = blockchain.chain_id();
+
         let allow_blocks_with_same_timestamp = config.allow_blocks_with_same_timestamp;
         let allow_unlimited_contract_size = config.allow_unlimited_contract_size;
+        let auto_impersonate = config.auto_impersonate;
         let beneficiary = config.coinbase;
         let block_gas_limit = config.block_gas_limit;
         let is_auto_mining = config.mining.auto_mine;
+        let mine_ordering = Box::new(config.mining.mem_pool.order.clone());
+        let mem_pool_limits = config.mining.mem_pool.limits();
         let min_gas_price = config.min_gas_price;
 
         let dao_activation_block = config
@@ -261,8 +369,11 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
             initial_config: config,
             blockchain,
             irregular_state,
-            mem_pool: MemPool::new(block_gas_limit),
+            mem_pool: MemPool::with_limits(block_gas_limit, mem_pool_limits),
+            bundles: Vec::new(),
+            blobs: BlobStore::default(),
             beneficiary,
+            chain_id,
             dao_activation_block,
             min_gas_price,
             parent_beacon_block_root_generator,
@@ -270,9 +381,12 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
             block_time_offset_seconds,
             fork_metadata,
             rpc_client,
+            persistence_paths,
             instance_id: B256::random(),
             is_auto_mining,
+            mine_ordering,
             next_block_base_fee_per_gas,
+            next_block_options: MineBlockOptions::default(),
             next_block_timestamp: None,
             // Start with 1 to mimic Ganache
             next_snapshot_id: 1,
@@ -284,6 +398,7 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
             last_filter_id: U256::ZERO,
             logger,
             impersonated_accounts: HashSet::new(),
+            auto_impersonate,
             subscriber_callback,
             call_override,
             block_state_cache,
@@ -300,13 +415,46 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         let mut config = self.initial_config.clone();
         config.fork = fork_config;
 
-        let mut reset_instance = Self::new(
+        // A persisted local blockchain is moved aside, so the new instance starts
+        // from genesis. It's only removed once the new instance was created
+        // successfully.
+        let persistence_paths = self
+            .persistence_paths
+            .as_ref()
+            .filter(|_| config.fork.is_none());
+
+        if let Some(persistence_paths) = persistence_paths {
+            persistence_paths
+                .back_up()
+                .map_err(CreationError::Persistence)?;
+        }
+
+        let reset_instance = Self::new(
             self.runtime_handle.clone(),
             self.logger.clone(),
             self.subscriber_callback.clone(),
             self.call_override.clone(),
             config,
-        )?;
+        );
+
+        let mut reset_instance = match reset_instance {
+            Ok(reset_instance) => reset_instance,
+            Err(error) => {
+                if let Some(persistence_paths) = persistence_paths {
+                    persistence_paths
+                        .restore_backup()
+                        .map_err(CreationError::Persistence)?;
+                }
+
+                return Err(error);
+            }
+        };
+
+        if let Some(persistence_paths) = persistence_paths {
+            persistence_paths
+                .remove_backup()
+                .map_err(CreationError::Persistence)?;
+        }
 
         std::mem::swap(self, &mut reset_instance);
 
@@ -370,6 +518,39 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         self.fork_metadata.as_ref()
     }
 
+    /// Returns the configuration of the forked blockchain, if it exists.
+    pub fn fork_config(&self) -> Option<&ForkConfig> {
+        self.initial_config.fork.as_ref()
+    }
+
+    /// Returns the URL of the remote node, if the blockchain is forked.
+    pub fn rpc_url(&self) -> Option<String> {
+        self.rpc_client
+            .as_ref()
+            .map(|rpc_client| rpc_client.url().to_string())
+    }
+
+    /// Replaces the URL of the remote node that the blockchain is forked
+    /// from. The new remote node is expected to serve the same chain.
+    pub fn set_rpc_url(&mut self, url: &str) -> Result<(), ProviderError<LoggerErrorT>> {
+        let rpc_client = self.rpc_client.as_ref().ok_or_else(|| {
+            ProviderError::InvalidInput(
+                "The RPC URL can only be set when forking a remote network".to_string(),
+            )
+        })?;
+
+        rpc_client
+            .set_url(url)
+            .map_err(|error| ProviderError::InvalidArgument(format!("Invalid RPC URL: {error}")))?;
+
+        // Resetting without a fork config re-forks from the new URL
+        if let Some(fork_config) = self.initial_config.fork.as_mut() {
+            fork_config.json_rpc_url = url.to_string();
+        }
+
+        Ok(())
+    }
+
     /// Returns the last block in the blockchain.
     pub fn last_block(
         &self,
@@ -434,6 +615,34 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         filter_id
     }
 
+    /// Adds a filter for transactions that are dropped from the mempool to the
+    /// provider.
+    pub fn add_dropped_transaction_filter<const IS_SUBSCRIPTION: bool>(&mut self) -> U256 {
+        let filter_id = self.next_filter_id();
+        self.filters.insert(
+            filter_id,
+            Filter::new_dropped_transaction_filter(IS_SUBSCRIPTION),
+        );
+        filter_id
+    }
+
+    /// Adds the blob sidecar of a pending transaction. The sidecar is stored
+    /// once the transaction is mined.
+    pub fn add_pending_blob_sidecar(&mut self, transaction_hash: B256, sidecar: BlobSidecar) {
+        self.blobs.add_pending_sidecar(transaction_hash, sidecar);
+    }
+
+    /// Whether the blob sidecar of the pending transaction is known.
+    pub fn has_pending_blob_sidecar(&self, transaction_hash: &B256) -> bool {
+        self.blobs.has_pending_sidecar(transaction_hash)
+    }
+
+    /// Removes the blob sidecar of a pending transaction, e.g. because it
+    /// failed to be mined.
+    pub fn remove_pending_blob_sidecar(&mut self, transaction_hash: &B256) {
+        self.blobs.remove_pending_sidecar(transaction_hash);
+    }
+
     /// Whether the provider is configured to bail on call failures.
     pub fn bail_on_call_failure(&self) -> bool {
         self.initial_config.bail_on_call_failure
@@ -444,6 +653,17 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         self.initial_config.bail_on_transaction_failure
     }
 
+    /// Retrieves the mined blob with the provided versioned hash.
+    pub fn blob_by_versioned_hash(&self, versioned_hash: &B256) -> Option<MinedBlob> {
+        self.blobs.blob_by_versioned_hash(versioned_hash)
+    }
+
+    /// Retrieves the blobs that were included in the provided block, in
+    /// order. Blobs of remote blocks are not available.
+    pub fn blobs_by_block(&self, block: &dyn SyncBlock<Error = BlockchainError>) -> Vec<MinedBlob> {
+        self.blobs.blobs_by_block_number(block.header().number)
+    }
+
     /// Fetch a block by block spec.
     /// Returns `None` if the block spec is `pending`.
     /// Returns `ProviderError::InvalidBlockSpec` error if the block spec is a
@@ -553,7 +773,14 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
     }
 
     pub fn chain_id(&self) -> u64 {
-        self.blockchain.chain_id()
+        self.chain_id
+    }
+
+    /// Sets the chain ID used for executing and validating transactions. The
+    /// chain ID is retained when resetting the provider.
+    pub fn set_chain_id(&mut self, chain_id: u64) {
+        self.chain_id = chain_id;
+        self.initial_config.chain_id = chain_id;
     }
 
     pub fn coinbase(&self) -> Address {
@@ -565,7 +792,7 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         &mut self,
         transaction_hash: &B256,
         trace_config: DebugTraceConfig,
-    ) -> Result<DebugTraceResult, ProviderError<LoggerErrorT>> {
+    ) -> Result<DebugTraceOutput, ProviderError<LoggerErrorT>> {
         let block = self
             .blockchain
             .block_by_transaction_hash(transaction_hash)?
@@ -578,30 +805,14 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
 
         let transactions = block.transactions().to_vec();
 
+        let block_env = trace_block_env(header, cfg_env.handler_cfg.spec_id);
+
         let prev_block_number = block.header().number - 1;
         let prev_block_spec = Some(BlockSpec::Number(prev_block_number));
 
         self.execute_in_block_context(
             prev_block_spec.as_ref(),
             |blockchain, _prev_block, state| {
-                let block_env = BlockEnv {
-                    number: U256::from(header.number),
-                    coinbase: header.beneficiary,
-                    timestamp: U256::from(header.timestamp),
-                    gas_limit: U256::from(header.gas_limit),
-                    basefee: header.base_fee_per_gas.unwrap_or_default(),
-                    difficulty: U256::from(header.difficulty),
-                    prevrandao: if cfg_env.handler_cfg.spec_id >= SpecId::MERGE {
-                        Some(header.mix_hash)
-                    } else {
-                        None
-                    },
-                    blob_excess_gas_and_price: header
-                        .blob_gas
-                        .as_ref()
-                        .map(|BlobGas { excess_gas, .. }| BlobExcessGasAndPrice::new(*excess_gas)),
-                };
-
                 debug_trace_transaction(
                     blockchain,
                     state.clone(),
@@ -616,41 +827,468 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         )?
     }
 
+    /// Retrieves the mined block with the provided block spec, for tracing.
+    fn block_to_trace(
+        &self,
+        block_spec: &BlockSpec,
+    ) -> Result<Arc<dyn SyncBlock<Error = BlockchainError>>, ProviderError<LoggerErrorT>> {
+        self.block_by_block_spec(block_spec)?.ok_or_else(|| {
+            if matches!(block_spec, BlockSpec::Tag(BlockTag::Pending)) {
+                ProviderError::InvalidArgument(
+                    "Tracing the pending block is not supported".to_string(),
+                )
+            } else {
+                ProviderError::InvalidBlockNumberOrHash {
+                    block_spec: block_spec.clone(),
+                    latest_block_number: self.blockchain.last_block_number(),
+                }
+            }
+        })
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip(self)))]
+    pub fn debug_trace_block(
+        &mut self,
+        block_spec: &BlockSpec,
+        trace_config: DebugTraceConfig,
+    ) -> Result<Vec<TransactionTrace>, ProviderError<LoggerErrorT>> {
+        let block = self.block_to_trace(block_spec)?;
+
+        let header = block.header();
+        // The genesis block doesn't contain any transactions
+        if header.number == 0 {
+            return Ok(Vec::new());
+        }
+
+        let cfg_env = self.create_evm_config(Some(&BlockSpec::Number(header.number)))?;
+        let block_env = trace_block_env(header, cfg_env.handler_cfg.spec_id);
+
+        let transactions = block.transactions().to_vec();
+
+        let prev_block_spec = Some(BlockSpec::Number(header.number - 1));
+
+        self.execute_in_block_context(
+            prev_block_spec.as_ref(),
+            |blockchain, _prev_block, state| {
+                debug_trace_block(
+                    blockchain,
+                    state.clone(),
+                    cfg_env,
+                    trace_config,
+                    block_env,
+                    transactions,
+                )
+                .map_err(ProviderError::DebugTrace)
+            },
+        )?
+    }
+
     pub fn debug_trace_call(
         &mut self,
         transaction: ExecutableTransaction,
         block_spec: &BlockSpec,
         trace_config: DebugTraceConfig,
-    ) -> Result<DebugTraceResult, ProviderError<LoggerErrorT>> {
+    ) -> Result<DebugTraceOutput, ProviderError<LoggerErrorT>> {
+        let cfg_env = self.create_evm_config(Some(block_spec))?;
+
+        let tx_env: TxEnv = transaction.into();
+
+        self.execute_in_block_context(Some(block_spec), |blockchain, block, state| {
+            match trace_config.tracer.clone() {
+                DebugTracer::Eip3155 => {
+                    let mut tracer = TracerEip3155::new(trace_config);
+
+                    let result = run_call(RunCallArgs {
+                        blockchain,
+                        header: block.header(),
+                        state,
+                        state_overrides: &StateOverrides::default(),
+                        block_overrides: &BlockOverrideOptions::default(),
+                        cfg_env: cfg_env.clone(),
+                        tx_env: tx_env.clone(),
+                        debug_context: Some(DebugContext {
+                            data: &mut tracer,
+                            register_handles_fn: register_eip_3155_tracer_handles,
+                        }),
+                    })?;
+
+                    Ok(execution_result_to_debug_result(result, tracer).into())
+                }
+                DebugTracer::Call(config) => {
+                    let mut tracer = CallTracer::new(config);
+
+                    let result = run_call(RunCallArgs {
+                        blockchain,
+                        header: block.header(),
+                        state,
+                        state_overrides: &StateOverrides::default(),
+                        block_overrides: &BlockOverrideOptions::default(),
+                        cfg_env: cfg_env.clone(),
+                        tx_env: tx_env.clone(),
+                        debug_context: Some(DebugContext {
+                            data: &mut tracer,
+                            register_handles_fn: register_call_tracer_handles,
+                        }),
+                    })?;
+
+                    Ok(
+                        execution_result_to_call_frame(&result, tracer, tx_env.gas_limit)
+                            .map_err(ProviderError::DebugTrace)?
+                            .into(),
+                    )
+                }
+                DebugTracer::Prestate(config) => {
+                    let ResultAndState { state: changes, .. } = run_call_with_state(RunCallArgs {
+                        blockchain,
+                        header: block.header(),
+                        state,
+                        state_overrides: &StateOverrides::default(),
+                        block_overrides: &BlockOverrideOptions::default(),
+                        cfg_env: cfg_env.clone(),
+                        tx_env: tx_env.clone(),
+                        debug_context: None::<DebugContext<'_, _, (), _>>,
+                    })?;
+
+                    let trace = prestate_trace(state.as_ref(), &changes, &config)?;
+
+                    Ok(trace.into())
+                }
+            }
+        })?
+    }
+
+    /// Retrieves the Parity-style flat traces of all transactions in a
+    /// block.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip(self)))]
+    pub fn trace_block(
+        &mut self,
+        block_spec: &BlockSpec,
+    ) -> Result<Vec<LocalizedFlatTrace>, ProviderError<LoggerErrorT>> {
+        let block = self.block_to_trace(block_spec)?;
+
+        let results = self.replay_block(&block, &[TraceType::Trace], None)?;
+
+        Ok(localize_flat_traces(&block, results))
+    }
+
+    /// Retrieves the Parity-style flat traces of a transaction. Returns `None`
+    /// if the transaction doesn't exist.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip(self)))]
+    pub fn trace_transaction(
+        &mut self,
+        transaction_hash: &B256,
+    ) -> Result<Option<Vec<LocalizedFlatTrace>>, ProviderError<LoggerErrorT>> {
+        let Some(block) = self
+            .blockchain
+            .block_by_transaction_hash(transaction_hash)?
+        else {
+            return Ok(None);
+        };
+
+        let mut results = self.replay_block(&block, &[TraceType::Trace], Some(transaction_hash))?;
+        results.retain(|(hash, _)| hash == transaction_hash);
+
+        Ok(Some(localize_flat_traces(&block, results)))
+    }
+
+    /// Replays a mined transaction, returning the requested Parity-style
+    /// traces.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip(self)))]
+    pub fn trace_replay_transaction(
+        &mut self,
+        transaction_hash: &B256,
+        trace_types: &[TraceType],
+    ) -> Result<TraceResults, ProviderError<LoggerErrorT>> {
+        let block = self
+            .blockchain
+            .block_by_transaction_hash(transaction_hash)?
+            .ok_or_else(|| ProviderError::InvalidTransactionHash(*transaction_hash))?;
+
+        let results = self.replay_block(&block, trace_types, Some(transaction_hash))?;
+
+        results
+            .into_iter()
+            .find_map(|(hash, result)| (hash == *transaction_hash).then_some(result))
+            .ok_or_else(|| ProviderError::InvalidTransactionHash(*transaction_hash))
+    }
+
+    /// Runs a transaction as a call, returning the requested Parity-style
+    /// traces.
+    pub fn trace_call(
+        &mut self,
+        transaction: ExecutableTransaction,
+        block_spec: &BlockSpec,
+        trace_types: &[TraceType],
+    ) -> Result<TraceResults, ProviderError<LoggerErrorT>> {
         let cfg_env = self.create_evm_config(Some(block_spec))?;
 
         let tx_env: TxEnv = transaction.into();
 
-        let mut tracer = TracerEip3155::new(trace_config);
+        let mut tracer = ParityTracer::default();
 
         self.execute_in_block_context(Some(block_spec), |blockchain, block, state| {
+            let ResultAndState {
+                result,
+                state: changes,
+            } = run_call_with_state(RunCallArgs {
+                blockchain,
+                header: block.header(),
+                state,
+                state_overrides: &StateOverrides::default(),
+                block_overrides: &BlockOverrideOptions::default(),
+                cfg_env,
+                tx_env,
+                debug_context: Some(DebugContext {
+                    data: &mut tracer,
+                    register_handles_fn: parity::parity_tracer_handle_register(trace_types),
+                }),
+            })?;
+
+            let state_diff = if trace_types.contains(&TraceType::StateDiff) {
+                Some(parity::state_diff(state.as_ref(), &changes)?)
+            } else {
+                None
+            };
+
+            Ok(TraceResults::new(&result, tracer, state_diff, trace_types))
+        })?
+    }
+
+    /// Retrieves the Parity-style flat traces of the local blocks in the
+    /// provided range, whose sender and recipient match the provided
+    /// addresses. Empty address lists match any address. Blocks from before
+    /// the fork are skipped.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", skip(self)))]
+    pub fn trace_filter(
+        &mut self,
+        from_block: &BlockSpec,
+        to_block: &BlockSpec,
+        from_addresses: &[Address],
+        to_addresses: &[Address],
+    ) -> Result<Vec<LocalizedFlatTrace>, ProviderError<LoggerErrorT>> {
+        let pending_error = || {
+            ProviderError::InvalidArgument(
+                "Filtering traces of the pending block is not supported".to_string(),
+            )
+        };
+
+        let from_block = self
+            .block_number_by_block_spec(from_block)?
+            .ok_or_else(pending_error)?;
+        let to_block = self
+            .block_number_by_block_spec(to_block)?
+            .ok_or_else(pending_error)?;
+
+        let first_local_block = self
+            .fork_metadata
+            .as_ref()
+            .map_or(0, |metadata| metadata.fork_block_number + 1);
+
+        let to_block = cmp::min(to_block, self.blockchain.last_block_number());
+
+        let mut traces = Vec::new();
+        for block_number in cmp::max(from_block, first_local_block)..=to_block {
+            let Some(block) = self.blockchain.block_by_number(block_number)? else {
+                continue;
+            };
+
+            let results = self.replay_block(&block, &[TraceType::Trace], None)?;
+
+            traces.extend(
+                localize_flat_traces(&block, results)
+                    .into_iter()
+                    .filter(|localized| {
+                        let trace = &localized.trace;
+
+                        (from_addresses.is_empty()
+                            || from_addresses.contains(trace.action.sender()))
+                            && (to_addresses.is_empty()
+                                || trace
+                                    .recipient()
+                                    .map_or(false, |recipient| to_addresses.contains(recipient)))
+                    }),
+            );
+        }
+
+        Ok(traces)
+    }
+
+    /// Creates an EIP-2930 access list for the transaction, by running it
+    /// with the access list recorded in the previous run, until the access
+    /// list no longer changes. Returns the access list and the result of
+    /// running the transaction with it.
+    pub fn create_access_list(
+        &mut self,
+        transaction: ExecutableTransaction,
+        block_spec: &BlockSpec,
+    ) -> Result<(AccessList, ExecutionResult), ProviderError<LoggerErrorT>> {
+        let cfg_env = self.create_evm_config(Some(block_spec))?;
+        if cfg_env.handler_cfg.spec_id < SpecId::BERLIN {
+            return Err(ProviderError::InvalidArgument(format!(
+                "Access lists are not supported by the hardfork of the requested block. You can use them by running Hardhat Network with 'hardfork' {:?} or later.",
+                SpecId::BERLIN
+            )));
+        }
+
+        let mut tx_env: TxEnv = transaction.into();
+
+        // The sender, recipient, and precompiles are always warm, so they don't
+        // need to be included in the access list.
+        let recipient = match tx_env.transact_to {
+            TransactTo::Call(address) => address,
+            TransactTo::Create(_) => tx_env
+                .caller
+                .create(tx_env.nonce.expect("Nonce is always set")),
+        };
+        let precompiles =
+            Precompiles::new(PrecompileSpecId::from_spec_id(cfg_env.handler_cfg.spec_id));
+        let excluded: Vec<Address> = [tx_env.caller, recipient]
+            .into_iter()
+            .chain(precompiles.addresses().copied())
+            .collect();
+
+        let mut access_list = AccessList(
+            tx_env
+                .access_list
+                .iter()
+                .map(|(address, storage_keys)| AccessListItem {
+                    address: *address,
+                    storage_keys: storage_keys
+                        .iter()
+                        .map(|index| B256::from(index.to_be_bytes()))
+                        .collect(),
+                })
+                .collect(),
+        );
+
+        self.execute_in_block_context(Some(block_spec), |blockchain, block, state| loop {
+            tx_env.access_list = access_list.clone().into();
+
+            let mut tracer = AccessListTracer::new(&access_list.0, excluded.iter().copied());
+
             let result = run_call(RunCallArgs {
                 blockchain,
                 header: block.header(),
                 state,
                 state_overrides: &StateOverrides::default(),
+                block_overrides: &BlockOverrideOptions::default(),
                 cfg_env: cfg_env.clone(),
                 tx_env: tx_env.clone(),
                 debug_context: Some(DebugContext {
                     data: &mut tracer,
-                    register_handles_fn: register_eip_3155_tracer_handles,
+                    register_handles_fn: register_access_list_tracer_handles,
                 }),
             })?;
 
-            Ok(execution_result_to_debug_result(result, tracer))
+            let recorded_access_list = tracer.access_list();
+            if recorded_access_list == access_list {
+                return Ok((access_list, result));
+            }
+
+            access_list = recorded_access_list;
+        })?
+    }
+
+    /// Simulates blocks of calls on top of the block corresponding to the
+    /// provided block spec. The simulated blocks are discarded afterwards.
+    pub fn simulate_v1(
+        &mut self,
+        payload: SimulatePayload,
+        block_spec: &BlockSpec,
+    ) -> Result<Vec<SimulatedBlockResult>, ProviderError<LoggerErrorT>> {
+        let SimulatePayload {
+            block_state_calls,
+            trace_transfers,
+            validation,
+            ..
+        } = payload;
+
+        let cfg_env = self.create_evm_config(Some(block_spec))?;
+        let beneficiary = self.beneficiary;
+        let block_gas_limit = self.block_gas_limit();
+        let default_caller = self.default_caller();
+
+        self.execute_in_block_context(Some(block_spec), |blockchain, block, state| {
+            simulate_blocks(SimulateArgs {
+                blockchain,
+                parent: block,
+                state: state.clone(),
+                cfg_env,
+                blocks: block_state_calls,
+                beneficiary,
+                block_gas_limit,
+                default_caller,
+                trace_transfers,
+                validation,
+            })
+        })?
+    }
+
+    /// Executes a bundle of transactions on top of the state of the provided
+    /// block, without committing any changes.
+    pub fn call_bundle(
+        &mut self,
+        transactions: Vec<ExecutableTransaction>,
+        state_block_spec: &BlockSpec,
+        block_number: Option<u64>,
+        timestamp: Option<u64>,
+    ) -> Result<CallBundleResult, ProviderError<LoggerErrorT>> {
+        let cfg_env = self.create_evm_config(Some(state_block_spec))?;
+        let beneficiary = self.beneficiary;
+        let block_gas_limit = self.block_gas_limit();
+
+        self.execute_in_block_context(Some(state_block_spec), |blockchain, block, state| {
+            call_bundle(CallBundleArgs {
+                blockchain,
+                parent: block,
+                state: state.clone(),
+                cfg_env,
+                transactions,
+                beneficiary,
+                block_gas_limit,
+                block_number,
+                timestamp,
+            })
         })?
     }
 
+    /// Dumps the current world state and block context. When forking, only
+    /// the changes relative to the fork block can be dumped.
+    pub fn dump_state(
+        &mut self,
+        only_changes: bool,
+    ) -> Result<SerializedState, ProviderError<LoggerErrorT>> {
+        let fork = match &self.fork_metadata {
+            Some(fork_metadata) if only_changes => Some(fork_metadata.clone()),
+            Some(_) => return Err(ProviderError::FullForkStateDumpUnsupported),
+            None => None,
+        };
+
+        let state = self.current_state()?.dump()?;
+
+        let last_block = self.last_block()?;
+        let last_header = last_block.header();
+
+        Ok(SerializedState {
+            version: SERIALIZED_STATE_VERSION,
+            block: SerializedBlockContext {
+                number: last_header.number,
+                timestamp: last_header.timestamp,
+                coinbase: self.beneficiary,
+                gas_limit: self.block_gas_limit(),
+            },
+            fork,
+            state,
+        })
+    }
+
     /// Estimate the gas cost of a transaction. Matches Hardhat behavior.
     pub fn estimate_gas(
         &mut self,
         transaction: ExecutableTransaction,
         block_spec: &BlockSpec,
+        state_overrides: &StateOverrides,
+        block_overrides: &BlockOverrideOptions,
     ) -> Result<EstimateGasResult, ProviderError<LoggerErrorT>> {
         let cfg_env = self.create_evm_config(Some(block_spec))?;
         // Minimum gas cost that is required for transaction to be included in
@@ -658,8 +1296,6 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         let minimum_cost = transaction.initial_cost(self.spec_id());
         let tx_env: TxEnv = transaction.into();
 
-        let state_overrides = StateOverrides::default();
-
         let mut debugger = Debugger::with_mocker(Mocker::new(self.call_override.clone()));
 
         self.execute_in_block_context(Some(block_spec), |blockchain, block, state| {
@@ -672,7 +1308,8 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
                 blockchain,
                 header,
                 state,
-                state_overrides: &state_overrides,
+                state_overrides,
+                block_overrides,
                 cfg_env: cfg_env.clone(),
                 tx_env: tx_env.clone(),
                 debug_context: Some(DebugContext {
@@ -726,7 +1363,8 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
                 blockchain,
                 header,
                 state,
-                state_overrides: &state_overrides,
+                state_overrides,
+                block_overrides,
                 cfg_env: cfg_env.clone(),
                 tx_env: tx_env.clone(),
                 gas_limit: initial_estimation,
@@ -748,11 +1386,12 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
                 blockchain,
                 header,
                 state,
-                state_overrides: &state_overrides,
+                state_overrides,
+                block_overrides,
                 cfg_env: cfg_env.clone(),
                 tx_env: tx_env.clone(),
                 lower_bound: initial_estimation,
-                upper_bound: header.gas_limit,
+                upper_bound: block_overrides.gas_limit.unwrap_or(header.gas_limit),
                 trace_collector: &mut trace_collector,
             })?;
 
@@ -973,6 +1612,20 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
             .transpose()
     }
 
+    /// Generates the EIP-1186 Merkle proof of an account and the specified
+    /// storage slots at the provided block.
+    pub fn get_proof(
+        &mut self,
+        address: Address,
+        storage_keys: &[U256],
+        block_spec: Option<&BlockSpec>,
+    ) -> Result<AccountProof, ProviderError<LoggerErrorT>> {
+        self.execute_in_block_context::<Result<AccountProof, ProviderError<LoggerErrorT>>>(
+            block_spec,
+            move |_blockchain, _block, state| Ok(state.account_proof(&address, storage_keys)?),
+        )?
+    }
+
     pub fn get_storage_at(
         &mut self,
         address: Address,
@@ -1006,6 +1659,18 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         self.impersonated_accounts.insert(address);
     }
 
+    /// Whether transactions from any address are accepted, as if it were
+    /// impersonated.
+    pub fn is_auto_impersonating(&self) -> bool {
+        self.auto_impersonate
+    }
+
+    /// Sets whether transactions from any address are accepted, as if it
+    /// were impersonated.
+    pub fn set_auto_impersonate(&mut self, enabled: bool) {
+        self.auto_impersonate = enabled;
+    }
+
     pub fn increase_block_time(&mut self, increment: u64) -> i64 {
         self.block_time_offset_seconds += i64::try_from(increment).expect("increment too large");
         self.block_time_offset_seconds
@@ -1026,7 +1691,159 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         Ok(true)
     }
 
-    pub fn logger_mut(
+    /// Loads a state that was dumped using [`ProviderData::dump_state`],
+    /// merging it into the current state. If the dump's latest block number is
+    /// ahead of the current one, blocks are mined to catch up.
+    pub fn load_state(
+        &mut self,
+        serialized_state: SerializedState,
+    ) -> Result<(), ProviderError<LoggerErrorT>> {
+        let SerializedState {
+            version,
+            block,
+            fork,
+            state,
+        } = serialized_state;
+
+        if version != SERIALIZED_STATE_VERSION {
+            return Err(ProviderError::UnsupportedStateDumpVersion {
+                version,
+                supported: SERIALIZED_STATE_VERSION,
+            });
+        }
+
+        if let Some(fork) = fork {
+            let is_same_fork = self.fork_metadata.as_ref().map_or(false, |fork_metadata| {
+                fork_metadata.chain_id == fork.chain_id
+                    && fork_metadata.fork_block_hash == fork.fork_block_hash
+            });
+
+            if !is_same_fork {
+                return Err(ProviderError::StateDumpForkMismatch {
+                    chain_id: fork.chain_id,
+                    fork_block_number: fork.fork_block_number,
+                });
+            }
+        }
+
+        let gas_limit = NonZeroU64::new(block.gas_limit)
+            .ok_or(ProviderError::SetBlockGasLimitMustBeGreaterThanZero)?;
+
+        // Mining the catch-up blocks and applying the state can fail midway, in
+        // which case all changes are reverted.
+        let snapshot = self.create_snapshot(None);
+
+        let result = self.load_state_impl(block, gas_limit, state);
+        if result.is_err() {
+            self.restore_snapshot(snapshot)?;
+        }
+
+        result
+    }
+
+    fn load_state_impl(
+        &mut self,
+        block: SerializedBlockContext,
+        gas_limit: NonZeroU64,
+        state: StateDump,
+    ) -> Result<(), ProviderError<LoggerErrorT>> {
+        let last_block_number = self.last_block_number();
+        if block.number > last_block_number {
+            self.mine_and_commit_blocks(block.number - last_block_number, 1)?;
+        }
+
+        // We clone to automatically revert in case of subsequent errors.
+        let mut modified_state = (*self.current_state()?).clone();
+        let mut diff = StateDiff::default();
+        let mut replaced_accounts = Vec::new();
+
+        for address in &state.removed_accounts {
+            // Recreated accounts are replaced below
+            if !state.accounts.contains_key(address) {
+                modified_state.remove_account(*address)?;
+                diff.apply_account_removal(*address);
+            }
+        }
+
+        for (address, account) in state.accounts {
+            let balance = account.balance;
+            let nonce = account.nonce;
+            let code = account.code.map(Bytecode::new_raw);
+            let irregular_code = code.clone();
+
+            let mut account_info = modified_state.modify_account(
+                address,
+                AccountModifierFn::new(Box::new(
+                    move |account_balance, account_nonce, account_code| {
+                        *account_balance = balance;
+                        *account_nonce = nonce;
+                        *account_code = code.clone();
+                    },
+                )),
+            )?;
+
+            // The code might have been stripped from the account, so we need to re-add it
+            // for the irregular state.
+            account_info.code = irregular_code;
+
+            // Recreated accounts don't retain the storage of the removed account and
+            // cleared storage isn't merged with the existing storage
+            if state.removed_accounts.contains(&address) || state.cleared_storage.contains(&address)
+            {
+                let storage = account.storage.into_iter().collect::<HashMap<_, _>>();
+                modified_state.set_account_storage(address, storage.clone())?;
+
+                replaced_accounts.push((address, account_info, storage));
+                continue;
+            }
+
+            diff.apply_account_change(address, account_info.clone());
+
+            for (index, value) in account.storage {
+                let old_value = modified_state.set_account_storage_slot(address, index, value)?;
+
+                diff.apply_storage_change(
+                    address,
+                    index,
+                    StorageSlot::new_changed(old_value, value),
+                    Some(account_info.clone()),
+                );
+            }
+        }
+
+        let state_root = modified_state.state_root()?;
+
+        self.mem_pool.update(&modified_state)?;
+
+        let block_number = self.blockchain.last_block_number();
+        self.modify_irregular_state(|irregular_state| {
+            let irregular_diff = &mut irregular_state
+                .state_override_at_block_number(block_number)
+                .or_insert_with(|| StateOverride::with_state_root(state_root))
+                .diff;
+
+            irregular_diff.apply_diff(diff.into());
+            for (address, account_info, storage) in replaced_accounts {
+                irregular_diff.apply_account_replacement(address, account_info, storage);
+            }
+        })?;
+
+        self.add_state_to_cache(modified_state, block_number);
+
+        self.set_coinbase(block.coinbase);
+        self.set_block_gas_limit(gas_limit)?;
+
+        // Continue the chain's time from the dumped block, unless it's already
+        // further along.
+        let last_timestamp = self.last_block()?.header().timestamp;
+        if block.timestamp >= last_timestamp {
+            self.set_next_block_timestamp(block.timestamp + 1)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn logger_mut(
         &mut self,
     ) -> &mut dyn SyncLogger<BlockchainError = BlockchainError, LoggerError = LoggerErrorT> {
         &mut *self.logger
@@ -1046,30 +1863,161 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
     }
 
     pub fn make_snapshot(&mut self) -> u64 {
+        self.insert_snapshot(None)
+    }
+
+    /// Creates a snapshot with the provided name, which can be used to revert
+    /// to or delete the snapshot.
+    pub fn make_named_snapshot(
+        &mut self,
+        name: String,
+    ) -> Result<u64, ProviderError<LoggerErrorT>> {
+        // Names that start with `0x` would be ambiguous with IDs
+        if name.starts_with("0x") {
+            return Err(ProviderError::InvalidSnapshotName(name));
+        }
+
+        if self
+            .snapshots
+            .values()
+            .any(|snapshot| snapshot.name.as_ref() == Some(&name))
+        {
+            return Err(ProviderError::DuplicateSnapshotName(name));
+        }
+
+        Ok(self.insert_snapshot(Some(name)))
+    }
+
+    fn insert_snapshot(&mut self, name: Option<String>) -> u64 {
         let id = self.next_snapshot_id;
         self.next_snapshot_id += 1;
 
-        let snapshot = Snapshot {
+        let snapshot = self.create_snapshot(name);
+        self.snapshots.insert(id, snapshot);
+
+        id
+    }
+
+    /// Creates a snapshot of the current state, without registering it.
+    fn create_snapshot(&self, name: Option<String>) -> Snapshot {
+        Snapshot {
             block_number: self.blockchain.last_block_number(),
             block_number_to_state_id: self.block_number_to_state_id.clone(),
             block_time_offset_seconds: self.block_time_offset_seconds,
+            blobs: self.blobs.clone(),
+            bundles: self.bundles.clone(),
             coinbase: self.beneficiary,
+            created_at: SystemTime::now(),
             irregular_state: self.irregular_state.clone(),
             mem_pool: self.mem_pool.clone(),
+            name,
             next_block_base_fee_per_gas: self.next_block_base_fee_per_gas,
+            next_block_options: self.next_block_options.clone(),
             next_block_timestamp: self.next_block_timestamp,
             parent_beacon_block_root_generator: self.parent_beacon_block_root_generator.clone(),
             prev_randao_generator: self.prev_randao_generator.clone(),
             time: Instant::now(),
-        };
-        self.snapshots.insert(id, snapshot);
+        }
+    }
 
-        id
+    /// Retrieves the ID of the snapshot corresponding to the provided
+    /// selector, if it exists.
+    pub fn snapshot_id(&self, selector: &SnapshotSelector) -> Option<u64> {
+        match selector {
+            SnapshotSelector::Id(id) => self.snapshots.contains_key(id).then_some(*id),
+            SnapshotSelector::Name(name) => self
+                .snapshots
+                .iter()
+                .find(|(_, snapshot)| snapshot.name.as_ref() == Some(name))
+                .map(|(id, _)| *id),
+        }
+    }
+
+    /// Retrieves information about the existing snapshots, ordered by
+    /// creation.
+    pub fn snapshots(&self) -> Vec<SnapshotInfo> {
+        self.snapshots
+            .iter()
+            .map(|(id, snapshot)| SnapshotInfo {
+                id: *id,
+                name: snapshot.name.clone(),
+                block_number: snapshot.block_number,
+                created_at: snapshot
+                    .created_at
+                    .duration_since(UNIX_EPOCH)
+                    .expect("snapshot must be created after UNIX epoch")
+                    .as_secs(),
+            })
+            .collect()
+    }
+
+    /// Deletes the snapshot with the provided ID. Returns whether the snapshot
+    /// existed.
+    pub fn delete_snapshot(&mut self, snapshot_id: u64) -> bool {
+        self.snapshots.remove(&snapshot_id).is_some()
+    }
+
+    /// Deletes the account at the provided address, including its code and
+    /// storage. Returns whether the account existed.
+    pub fn delete_account(
+        &mut self,
+        address: Address,
+    ) -> Result<bool, ProviderError<LoggerErrorT>> {
+        // We clone to automatically revert in case of subsequent errors.
+        let mut modified_state = (*self.current_state()?).clone();
+        let existed = modified_state.remove_account(address)?.is_some();
+
+        let state_root = modified_state.state_root()?;
+
+        self.mem_pool.update(&modified_state)?;
+
+        let block_number = self.blockchain.last_block_number();
+        self.modify_irregular_state(|irregular_state| {
+            irregular_state
+                .state_override_at_block_number(block_number)
+                .or_insert_with(|| StateOverride::with_state_root(state_root))
+                .diff
+                .apply_account_removal(address);
+        })?;
+
+        self.add_state_to_cache(modified_state, block_number);
+
+        Ok(existed)
     }
 
     pub fn mine_and_commit_block(
+        &mut self,
+        options: BlockOptions,
+    ) -> Result<DebugMineBlockResult<BlockchainError>, ProviderError<LoggerErrorT>> {
+        self.mine_and_commit_block_impl(options, BlockTransactions::MemPool)
+    }
+
+    /// Mines and commits a block containing the transactions of the provided
+    /// mempool, instead of the provider's mempool. Afterwards, the provided
+    /// mempool only contains the transactions that were not mined.
+    pub fn mine_and_commit_block_with_mem_pool(
+        &mut self,
+        mem_pool: &mut MemPool,
+        options: BlockOptions,
+    ) -> Result<DebugMineBlockResult<BlockchainError>, ProviderError<LoggerErrorT>> {
+        self.mine_and_commit_block_impl(options, BlockTransactions::CustomMemPool(mem_pool))
+    }
+
+    /// Mines and commits a block containing exactly the provided transactions,
+    /// in the provided order. The provider's mempool and bundles are not
+    /// included in the block.
+    pub fn mine_and_commit_block_with_transactions(
+        &mut self,
+        transactions: Vec<ExecutableTransaction>,
+        options: BlockOptions,
+    ) -> Result<DebugMineBlockResult<BlockchainError>, ProviderError<LoggerErrorT>> {
+        self.mine_and_commit_block_impl(options, BlockTransactions::Explicit(transactions))
+    }
+
+    fn mine_and_commit_block_impl(
         &mut self,
         mut options: BlockOptions,
+        transactions: BlockTransactions<'_>,
     ) -> Result<DebugMineBlockResult<BlockchainError>, ProviderError<LoggerErrorT>> {
         let (block_timestamp, new_offset) = self.next_block_timestamp(options.timestamp)?;
         options.timestamp = Some(block_timestamp);
@@ -1078,7 +2026,21 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
             options.mix_hash = Some(self.prev_randao_generator.next_value());
         }
 
-        let result = self.mine_block(options)?;
+        let (result, mut custom_mem_pool) = match transactions {
+            BlockTransactions::MemPool => (self.mine_block(options)?, None),
+            BlockTransactions::CustomMemPool(custom_mem_pool) => {
+                // Only mine from the custom mempool; the provider's mempool is
+                // restored before committing the block.
+                std::mem::swap(&mut self.mem_pool, custom_mem_pool);
+                let result = self.mine_block(options);
+                std::mem::swap(&mut self.mem_pool, custom_mem_pool);
+
+                (result?, Some(custom_mem_pool))
+            }
+            BlockTransactions::Explicit(transactions) => {
+                (self.mine_block_impl(options, Some(transactions))?, None)
+            }
+        };
 
         let block_and_total_difficulty = self
             .blockchain
@@ -1089,6 +2051,34 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
             .update(&result.state)
             .map_err(ProviderError::MemPoolUpdate)?;
 
+        if let Some(custom_mem_pool) = custom_mem_pool.as_deref_mut() {
+            custom_mem_pool
+                .update(&result.state)
+                .map_err(ProviderError::MemPoolUpdate)?;
+        }
+
+        self.remove_expired_transactions(Instant::now());
+
+        let mined_block_number = block_and_total_difficulty.block.header().number;
+        self.bundles
+            .retain(|bundle| !bundle.is_expired(mined_block_number));
+
+        let mem_pool = &self.mem_pool;
+        let custom_mem_pool = custom_mem_pool.as_deref();
+        let bundles = &self.bundles;
+        self.blobs
+            .commit_block(&*block_and_total_difficulty.block, |transaction_hash| {
+                mem_pool.transaction_by_hash(transaction_hash).is_some()
+                    || custom_mem_pool.is_some_and(|custom_mem_pool| {
+                        custom_mem_pool
+                            .transaction_by_hash(transaction_hash)
+                            .is_some()
+                    })
+                    || bundles
+                        .iter()
+                        .any(|bundle| bundle.contains_transaction(transaction_hash))
+            });
+
         if let Some(new_offset) = new_offset {
             self.block_time_offset_seconds = new_offset;
         }
@@ -1096,6 +2086,9 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         // Reset the next block base fee per gas upon successful execution
         self.next_block_base_fee_per_gas.take();
 
+        // Reset the next block options
+        self.next_block_options = MineBlockOptions::default();
+
         // Reset next block time stamp
         self.next_block_timestamp.take();
 
@@ -1134,7 +2127,7 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
                         block_hashes.push(*block.hash());
                     }
                 }
-                FilterData::NewPendingTransactions(_) => (),
+                FilterData::NewPendingTransactions(_) | FilterData::DroppedTransactions(_) => (),
             }
         }
 
@@ -1251,6 +2244,34 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         self.initial_config.network_id.to_string()
     }
 
+    /// Returns the blob gas price of the block with the provided spec, or zero
+    /// if the block has no blob gas fields.
+    pub fn blob_gas_price(
+        &mut self,
+        block_spec: &BlockSpec,
+    ) -> Result<U256, ProviderError<LoggerErrorT>> {
+        self.execute_in_block_context(Some(block_spec), |_blockchain, block, _state| {
+            block
+                .header()
+                .blob_gas
+                .as_ref()
+                .map_or(U256::ZERO, |BlobGas { excess_gas, .. }| {
+                    U256::from(BlobExcessGasAndPrice::new(*excess_gas).blob_gasprice)
+                })
+        })
+    }
+
+    /// Calculates the next block's blob base fee, if the hardfork is
+    /// post-Cancun.
+    pub fn next_block_blob_base_fee(&self) -> Result<Option<U256>, BlockchainError> {
+        if self.spec_id() < SpecId::CANCUN {
+            return Ok(None);
+        }
+
+        let last_block = self.last_block()?;
+        Ok(Some(calculate_next_blob_base_fee(last_block.header())))
+    }
+
     /// Calculates the next block's base fee per gas.
     pub fn next_block_base_fee_per_gas(&self) -> Result<Option<U256>, BlockchainError> {
         if self.spec_id() < SpecId::LONDON {
@@ -1315,6 +2336,57 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         self.mem_pool.transactions()
     }
 
+    /// Retrieves the transactions in the mempool that can be included in the
+    /// next block; i.e. for which the nonces are high enough.
+    pub fn executable_transactions(&self) -> impl Iterator<Item = &ExecutableTransaction> {
+        self.mem_pool
+            .pending_transactions()
+            .map(OrderedTransaction::pending)
+    }
+
+    /// Retrieves the transactions in the mempool that are waiting for a nonce
+    /// gap to be filled.
+    pub fn queued_transactions(&self) -> impl Iterator<Item = &ExecutableTransaction> {
+        self.mem_pool
+            .future_transactions()
+            .map(OrderedTransaction::pending)
+    }
+
+    /// Queues withdrawals to be included in the next mined block, after any
+    /// previously queued withdrawals. Their amounts, denominated in Gwei, are
+    /// credited to their recipients when the block is mined.
+    pub fn queue_withdrawals(
+        &mut self,
+        withdrawals: Vec<Withdrawal>,
+    ) -> Result<(), ProviderError<LoggerErrorT>> {
+        let spec_id = self.spec_id();
+        if spec_id < SpecId::SHANGHAI {
+            return Err(ProviderError::UnmetHardfork {
+                actual: spec_id,
+                minimum: SpecId::SHANGHAI,
+            });
+        }
+
+        // Withdrawal amounts are 64-bit Gwei values (EIP-4895). Invalid amounts are
+        // rejected here, instead of failing the next block.
+        if let Some(withdrawal) = withdrawals
+            .iter()
+            .find(|withdrawal| withdrawal.amount > U256::from(u64::MAX))
+        {
+            return Err(ProviderError::InvalidArgument(format!(
+                "Withdrawal {} has an amount of {} Gwei, which exceeds the maximum of 2^64 - 1 Gwei.",
+                withdrawal.index, withdrawal.amount
+            )));
+        }
+
+        self.next_block_options
+            .withdrawals
+            .get_or_insert_with(Vec::new)
+            .extend(withdrawals);
+
+        Ok(())
+    }
+
     pub fn remove_filter(&mut self, filter_id: &U256) -> bool {
         self.remove_filter_impl::</* IS_SUBSCRIPTION */ false>(filter_id)
     }
@@ -1323,6 +2395,31 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         self.remove_filter_impl::</* IS_SUBSCRIPTION */ true>(filter_id)
     }
 
+    /// Adds a bundle of transactions that is included atomically at the top of
+    /// its target block. Returns the hash of the bundle.
+    ///
+    /// Adding a bundle doesn't trigger automining, as a bundle can target a
+    /// future block. The bundle is included in the first block that is mined
+    /// for its target.
+    pub fn send_bundle(
+        &mut self,
+        bundle: PendingBundle,
+    ) -> Result<B256, ProviderError<LoggerErrorT>> {
+        let last_block_number = self.last_block_number();
+        if let Some(block_number) = bundle.block_number {
+            if block_number <= last_block_number {
+                return Err(ProviderError::InvalidInput(format!(
+                    "Bundle target block number {block_number} must be greater than the latest block number {last_block_number}"
+                )));
+            }
+        }
+
+        let hash = bundle_hash(&bundle.bundle.transactions);
+        self.bundles.push(bundle);
+
+        Ok(hash)
+    }
+
     /// Removes the transaction with the provided hash from the mem pool, if it
     /// exists.
     pub fn remove_pending_transaction(
@@ -1332,57 +2429,204 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         self.mem_pool.remove_transaction(transaction_hash)
     }
 
-    pub fn revert_to_snapshot(&mut self, snapshot_id: u64) -> bool {
-        // Ensure that, if the snapshot exists, we also remove all subsequent snapshots,
-        // as they can only be used once in Ganache.
-        let mut removed_snapshots = self.snapshots.split_off(&snapshot_id);
+    /// Simulates a chain reorganization by removing the last `depth` blocks
+    /// and mining `depth` replacement blocks. The first replacement block
+    /// includes the provided transactions; the others are empty.
+    ///
+    /// Transactions of the removed blocks are added back to the mempool, as
+    /// are provided transactions that could not be mined. Log filters are
+    /// notified of the removed logs and snapshots of removed blocks are
+    /// discarded.
+    ///
+    /// The provided transactions are validated before any blocks are removed.
+    /// Removed blocks cannot be restored, so if mining a replacement block
+    /// fails, the blockchain only contains the replacement blocks that were
+    /// mined successfully.
+    pub fn reorg(
+        &mut self,
+        depth: u64,
+        transactions: Vec<ExecutableTransaction>,
+    ) -> Result<Vec<DebugMineBlockResult<BlockchainError>>, ProviderError<LoggerErrorT>> {
+        let first_local_block = self
+            .fork_metadata
+            .as_ref()
+            .map_or(1, |metadata| metadata.fork_block_number + 1);
 
-        if let Some(snapshot) = removed_snapshots.remove(&snapshot_id) {
-            let Snapshot {
-                block_number,
-                block_number_to_state_id,
-                block_time_offset_seconds,
-                coinbase,
-                irregular_state,
-                mem_pool,
-                next_block_base_fee_per_gas,
-                next_block_timestamp,
-                parent_beacon_block_root_generator,
-                prev_randao_generator,
-                time,
-            } = snapshot;
-
-            self.block_number_to_state_id = block_number_to_state_id;
-
-            // We compute a new offset such that:
-            // now + new_offset == snapshot_date + old_offset
-            let duration_since_snapshot = Instant::now().duration_since(time);
-            self.block_time_offset_seconds = block_time_offset_seconds
-                + i64::try_from(duration_since_snapshot.as_secs()).expect("duration too large");
-
-            self.beneficiary = coinbase;
-            self.blockchain
-                .revert_to_block(block_number)
-                .expect("Snapshotted block should exist");
+        let last_block_number = self.blockchain.last_block_number();
+        let max_depth = (last_block_number + 1).saturating_sub(first_local_block);
+        if depth == 0 || depth > max_depth {
+            return Err(ProviderError::InvalidReorgDepth { depth, max_depth });
+        }
 
-            self.irregular_state = irregular_state;
-            self.mem_pool = mem_pool;
-            self.next_block_base_fee_per_gas = next_block_base_fee_per_gas;
-            self.next_block_timestamp = next_block_timestamp;
-            self.parent_beacon_block_root_generator = parent_beacon_block_root_generator;
-            self.prev_randao_generator = prev_randao_generator;
+        let common_ancestor = last_block_number - depth;
+
+        // Validate the provided transactions before modifying any state
+        let mut replacement_mem_pool = MemPool::new(self.mem_pool.block_gas_limit());
+        {
+            let state = self.get_or_compute_state(common_ancestor)?;
+            for transaction in transactions {
+                replacement_mem_pool.add_transaction(&*state, transaction)?;
+            }
+        }
+
+        let mut removed_transactions = Vec::new();
+        for block_number in (common_ancestor + 1)..=last_block_number {
+            let block = self
+                .blockchain
+                .block_by_number(block_number)?
+                .expect("Block must exist, as its number is not greater than the last block");
+
+            removed_transactions.extend(block.transactions().iter().cloned());
+        }
+
+        let removed_logs = self.removed_logs(common_ancestor)?;
+        self.notify_removed_logs(removed_logs);
+
+        self.blockchain.revert_to_block(common_ancestor)?;
+        self.modify_irregular_state(|irregular_state| {
+            irregular_state.revert_to_block(common_ancestor);
+        })?;
+
+        let reverted_block_numbers = self
+            .block_number_to_state_id
+            .keys()
+            .filter(|block_number| **block_number > common_ancestor)
+            .copied()
+            .collect::<Vec<_>>();
+
+        for block_number in reverted_block_numbers {
+            self.block_number_to_state_id.remove_mut(&block_number);
+        }
+
+        self.blobs.revert_to_block(common_ancestor);
+
+        // Snapshots of removed blocks can no longer be reverted to
+        self.snapshots
+            .retain(|_, snapshot| snapshot.block_number <= common_ancestor);
+
+        let state = self.current_state()?;
+        self.mem_pool
+            .update(&*state)
+            .map_err(ProviderError::MemPoolUpdate)?;
+
+        let mut mined_blocks = Vec::new();
+        mined_blocks.push(self.mine_and_commit_block_with_mem_pool(
+            &mut replacement_mem_pool,
+            BlockOptions::default(),
+        )?);
+
+        for _ in 1..depth {
+            let mut empty_mem_pool = MemPool::new(self.mem_pool.block_gas_limit());
+            mined_blocks.push(self.mine_and_commit_block_with_mem_pool(
+                &mut empty_mem_pool,
+                BlockOptions::default(),
+            )?);
+        }
+
+        let unmined_transactions = replacement_mem_pool
+            .transactions()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        for transaction in unmined_transactions.into_iter().chain(removed_transactions) {
+            self.readd_pending_transaction(transaction)?;
+        }
+
+        Ok(mined_blocks)
+    }
+
+    pub fn revert_to_snapshot(
+        &mut self,
+        snapshot_id: u64,
+    ) -> Result<bool, ProviderError<LoggerErrorT>> {
+        if let Some(snapshot) = self.snapshots.get(&snapshot_id).cloned() {
+            self.restore_snapshot(snapshot)?;
 
-            true
+            // Ensure that we also remove all subsequent snapshots, as they can only be
+            // used once in Ganache.
+            self.snapshots.split_off(&snapshot_id);
+
+            Ok(true)
         } else {
-            false
+            Ok(false)
+        }
+    }
+
+    /// Reverts to the snapshot with the provided ID, without removing it - so
+    /// it can be reverted to again. Subsequent snapshots are removed, as the
+    /// blocks they refer to are reverted. Returns whether the snapshot
+    /// existed.
+    pub fn revert_to_snapshot_and_keep(
+        &mut self,
+        snapshot_id: u64,
+    ) -> Result<bool, ProviderError<LoggerErrorT>> {
+        if let Some(snapshot) = self.snapshots.get(&snapshot_id).cloned() {
+            self.restore_snapshot(snapshot)?;
+            self.snapshots.split_off(&(snapshot_id + 1));
+
+            Ok(true)
+        } else {
+            Ok(false)
         }
     }
 
+    fn restore_snapshot(&mut self, snapshot: Snapshot) -> Result<(), ProviderError<LoggerErrorT>> {
+        let Snapshot {
+            block_number,
+            block_number_to_state_id,
+            block_time_offset_seconds,
+            blobs,
+            bundles,
+            coinbase,
+            created_at: _,
+            irregular_state,
+            mem_pool,
+            name: _,
+            next_block_base_fee_per_gas,
+            next_block_options,
+            next_block_timestamp,
+            parent_beacon_block_root_generator,
+            prev_randao_generator,
+            time,
+        } = snapshot;
+
+        // Perform all fallible operations first, to avoid partially restoring the
+        // snapshot. The removed logs need to be collected before their blocks are
+        // reverted, but are only emitted once the snapshot has been restored.
+        let removed_logs = self.removed_logs(block_number)?;
+        self.persist_irregular_state(&irregular_state)?;
+        self.blockchain.revert_to_block(block_number)?;
+
+        self.block_number_to_state_id = block_number_to_state_id;
+
+        // We compute a new offset such that:
+        // now + new_offset == snapshot_date + old_offset
+        let duration_since_snapshot = Instant::now().duration_since(time);
+        self.block_time_offset_seconds = block_time_offset_seconds
+            + i64::try_from(duration_since_snapshot.as_secs()).expect("duration too large");
+
+        self.beneficiary = coinbase;
+        self.blobs = blobs;
+        self.bundles = bundles;
+        self.irregular_state = irregular_state;
+        self.mem_pool = mem_pool;
+        self.next_block_base_fee_per_gas = next_block_base_fee_per_gas;
+        self.next_block_options = next_block_options;
+        self.next_block_timestamp = next_block_timestamp;
+        self.parent_beacon_block_root_generator = parent_beacon_block_root_generator;
+        self.prev_randao_generator = prev_randao_generator;
+
+        self.notify_removed_logs(removed_logs);
+
+        Ok(())
+    }
+
     pub fn run_call(
         &mut self,
         transaction: ExecutableTransaction,
         block_spec: &BlockSpec,
         state_overrides: &StateOverrides,
+        block_overrides: &BlockOverrideOptions,
     ) -> Result<CallResult, ProviderError<LoggerErrorT>> {
         let cfg_env = self.create_evm_config(Some(block_spec))?;
         let tx_env = transaction.into();
@@ -1395,6 +2639,7 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
                 header: block.header(),
                 state,
                 state_overrides,
+                block_overrides,
                 cfg_env,
                 tx_env,
                 debug_context: Some(DebugContext {
@@ -1421,6 +2666,39 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         })?
     }
 
+    /// Retrieves the receipts of the provided block. The receipts of forked
+    /// blocks are requested from the remote node in a single call.
+    pub fn block_receipts(
+        &self,
+        block: &Arc<dyn SyncBlock<Error = BlockchainError>>,
+    ) -> Result<Vec<Arc<BlockReceipt>>, ProviderError<LoggerErrorT>> {
+        let block_number = block.header().number;
+        let is_remote_block = self
+            .fork_metadata
+            .as_ref()
+            .map_or(false, |metadata| block_number <= metadata.fork_block_number);
+
+        if is_remote_block {
+            let rpc_client = self
+                .rpc_client
+                .as_ref()
+                .expect("we checked that there is a fork");
+
+            let receipts = tokio::task::block_in_place(|| {
+                self.runtime_handle
+                    .block_on(rpc_client.get_block_receipts(BlockSpec::Number(block_number)))
+            })?;
+
+            if let Some(receipts) = receipts {
+                return Ok(receipts.into_iter().map(Arc::new).collect());
+            }
+        }
+
+        block
+            .transaction_receipts()
+            .map_err(ProviderError::Blockchain)
+    }
+
     pub fn transaction_receipt(
         &self,
         transaction_hash: &B256,
@@ -1459,7 +2737,8 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
             self.add_pending_transaction(signed_transaction)
                 .map_err(|error| {
                     if let Some(snapshot_id) = snapshot_id {
-                        self.revert_to_snapshot(snapshot_id);
+                        // The original error takes precedence over a failed revert
+                        let _reverted = self.revert_to_snapshot(snapshot_id);
                     }
 
                     error
@@ -1472,7 +2751,7 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
                     let result = self
                         .mine_and_commit_block(BlockOptions::default())
                         .map_err(|error| {
-                            self.revert_to_snapshot(snapshot_id);
+                            let _reverted = self.revert_to_snapshot(snapshot_id);
 
                             error
                         })?;
@@ -1490,7 +2769,7 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
                     let result = self
                         .mine_and_commit_block(BlockOptions::default())
                         .map_err(|error| {
-                            self.revert_to_snapshot(snapshot_id);
+                            let _reverted = self.revert_to_snapshot(snapshot_id);
 
                             error
                         })?;
@@ -1515,7 +2794,13 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         self.is_auto_mining = enabled;
     }
 
-    pub fn set_balance(
+    /// Sets the strategy used to order pending transactions when mining
+    /// blocks.
+    pub fn set_mine_ordering(&mut self, mine_ordering: Box<dyn TransactionOrdering>) {
+        self.mine_ordering = mine_ordering;
+    }
+
+    pub fn set_balance(
         &mut self,
         address: Address,
         balance: U256,
@@ -1533,11 +2818,13 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         self.mem_pool.update(&modified_state)?;
 
         let block_number = self.blockchain.last_block_number();
-        self.irregular_state
-            .state_override_at_block_number(block_number)
-            .or_insert_with(|| StateOverride::with_state_root(state_root))
-            .diff
-            .apply_account_change(address, account_info.clone());
+        self.modify_irregular_state(|irregular_state| {
+            irregular_state
+                .state_override_at_block_number(block_number)
+                .or_insert_with(|| StateOverride::with_state_root(state_root))
+                .diff
+                .apply_account_change(address, account_info.clone());
+        })?;
 
         self.add_state_to_cache(modified_state, block_number);
 
@@ -1579,11 +2866,13 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         let state_root = modified_state.state_root()?;
 
         let block_number = self.blockchain.last_block_number();
-        self.irregular_state
-            .state_override_at_block_number(block_number)
-            .or_insert_with(|| StateOverride::with_state_root(state_root))
-            .diff
-            .apply_account_change(address, account_info.clone());
+        self.modify_irregular_state(|irregular_state| {
+            irregular_state
+                .state_override_at_block_number(block_number)
+                .or_insert_with(|| StateOverride::with_state_root(state_root))
+                .diff
+                .apply_account_change(address, account_info.clone());
+        })?;
 
         self.add_state_to_cache(modified_state, block_number);
 
@@ -1610,6 +2899,84 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         Ok(())
     }
 
+    /// Sets the header fields and withdrawals of the next block. Omitted fields
+    /// retain their current values. The options only apply to the next mined
+    /// block.
+    pub fn set_next_block_options(
+        &mut self,
+        options: MineBlockOptions,
+    ) -> Result<(), ProviderError<LoggerErrorT>> {
+        let MineBlockOptions {
+            coinbase,
+            timestamp,
+            gas_limit,
+            base_fee_per_gas,
+            difficulty,
+            mix_hash,
+            extra_data,
+            parent_beacon_block_root,
+            withdrawals,
+        } = options;
+
+        let spec_id = self.spec_id();
+
+        // Validate all options before applying any of them
+        if base_fee_per_gas.is_some() && spec_id < SpecId::LONDON {
+            return Err(ProviderError::SetNextBlockBaseFeePerGasUnsupported { spec_id });
+        }
+
+        if gas_limit == Some(0) {
+            return Err(ProviderError::SetBlockGasLimitMustBeGreaterThanZero);
+        }
+
+        if withdrawals.is_some() && spec_id < SpecId::SHANGHAI {
+            return Err(ProviderError::UnmetHardfork {
+                actual: spec_id,
+                minimum: SpecId::SHANGHAI,
+            });
+        }
+
+        if parent_beacon_block_root.is_some() && spec_id < SpecId::CANCUN {
+            return Err(ProviderError::UnmetHardfork {
+                actual: spec_id,
+                minimum: SpecId::CANCUN,
+            });
+        }
+
+        if let Some(timestamp) = timestamp {
+            self.set_next_block_timestamp(timestamp)?;
+        }
+
+        if let Some(base_fee_per_gas) = base_fee_per_gas {
+            self.next_block_base_fee_per_gas = Some(base_fee_per_gas);
+        }
+
+        if let Some(parent_beacon_block_root) = parent_beacon_block_root {
+            self.parent_beacon_block_root_generator
+                .set_next(parent_beacon_block_root);
+        }
+
+        // Post-merge, the mix hash is the prevrandao, which is tracked by its
+        // generator.
+        let mix_hash = match mix_hash {
+            Some(prev_randao) if spec_id >= SpecId::MERGE => {
+                self.prev_randao_generator.set_next(prev_randao);
+                None
+            }
+            mix_hash => mix_hash,
+        };
+
+        let next_block_options = &mut self.next_block_options;
+        next_block_options.coinbase = coinbase.or(next_block_options.coinbase);
+        next_block_options.gas_limit = gas_limit.or(next_block_options.gas_limit);
+        next_block_options.difficulty = difficulty.or(next_block_options.difficulty);
+        next_block_options.mix_hash = mix_hash.or(next_block_options.mix_hash);
+        next_block_options.extra_data = extra_data.or(next_block_options.extra_data.take());
+        next_block_options.withdrawals = withdrawals.or(next_block_options.withdrawals.take());
+
+        Ok(())
+    }
+
     /// Set the next block timestamp.
     pub fn set_next_block_timestamp(
         &mut self,
@@ -1655,21 +3022,7 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         address: Address,
         nonce: u64,
     ) -> Result<(), ProviderError<LoggerErrorT>> {
-        if mempool::has_transactions(&self.mem_pool) {
-            return Err(ProviderError::SetAccountNonceWithPendingTransactions);
-        }
-
-        let previous_nonce = self
-            .current_state()?
-            .basic(address)?
-            .map_or(0, |account| account.nonce);
-
-        if nonce < previous_nonce {
-            return Err(ProviderError::SetAccountNonceLowerThanCurrent {
-                previous: previous_nonce,
-                proposed: nonce,
-            });
-        }
+        self.validate_nonce_change(address, nonce)?;
 
         // We clone to automatically revert in case of subsequent errors.
         let mut modified_state = (*self.current_state()?).clone();
@@ -1683,11 +3036,115 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         self.mem_pool.update(&modified_state)?;
 
         let block_number = self.last_block_number();
-        self.irregular_state
-            .state_override_at_block_number(block_number)
-            .or_insert_with(|| StateOverride::with_state_root(state_root))
-            .diff
-            .apply_account_change(address, account_info.clone());
+        self.modify_irregular_state(|irregular_state| {
+            irregular_state
+                .state_override_at_block_number(block_number)
+                .or_insert_with(|| StateOverride::with_state_root(state_root))
+                .diff
+                .apply_account_change(address, account_info.clone());
+        })?;
+
+        self.add_state_to_cache(modified_state, block_number);
+
+        Ok(())
+    }
+
+    /// Atomically sets the balance, nonce, code, and storage of the account at
+    /// the provided address. Omitted fields retain their current value.
+    pub fn set_account(
+        &mut self,
+        address: Address,
+        account: AccountState,
+    ) -> Result<(), ProviderError<LoggerErrorT>> {
+        let AccountState {
+            balance,
+            nonce,
+            code,
+            storage,
+        } = account;
+
+        if let Some(nonce) = nonce {
+            self.validate_nonce_change(address, nonce)?;
+        }
+
+        let code = code.map(Bytecode::new_raw);
+
+        // We clone to automatically revert in case of subsequent errors.
+        let mut modified_state = (*self.current_state()?).clone();
+        modified_state.modify_account(
+            address,
+            AccountModifierFn::new(Box::new(
+                move |account_balance, account_nonce, account_code| {
+                    if let Some(balance) = balance {
+                        *account_balance = balance;
+                    }
+
+                    if let Some(nonce) = nonce {
+                        *account_nonce = nonce;
+                    }
+
+                    if let Some(code) = &code {
+                        *account_code = Some(code.clone());
+                    }
+                },
+            )),
+        )?;
+
+        let storage = storage.map(|storage| storage.into_iter().collect::<HashMap<_, _>>());
+        if let Some(storage) = &storage {
+            modified_state.set_account_storage(address, storage.clone())?;
+        }
+
+        let account_info = account_info_with_code(&*modified_state, address)?
+            .expect("Account must exist, as it was just modified");
+
+        let state_root = modified_state.state_root()?;
+
+        self.mem_pool.update(&modified_state)?;
+
+        let block_number = self.blockchain.last_block_number();
+        self.modify_irregular_state(|irregular_state| {
+            let diff = &mut irregular_state
+                .state_override_at_block_number(block_number)
+                .or_insert_with(|| StateOverride::with_state_root(state_root))
+                .diff;
+
+            if let Some(storage) = storage {
+                diff.apply_account_replacement(address, account_info, storage);
+            } else {
+                diff.apply_account_change(address, account_info);
+            }
+        })?;
+
+        self.add_state_to_cache(modified_state, block_number);
+
+        Ok(())
+    }
+
+    /// Replaces the storage of the account at the provided address. Slots
+    /// that are not included are cleared.
+    pub fn set_account_storage(
+        &mut self,
+        address: Address,
+        storage: HashMap<U256, U256>,
+    ) -> Result<(), ProviderError<LoggerErrorT>> {
+        // We clone to automatically revert in case of subsequent errors.
+        let mut modified_state = (*self.current_state()?).clone();
+        modified_state.set_account_storage(address, storage.clone())?;
+
+        let account_info = account_info_with_code(&*modified_state, address)?
+            .expect("Account must exist, as its storage was just set");
+
+        let state_root = modified_state.state_root()?;
+
+        let block_number = self.blockchain.last_block_number();
+        self.modify_irregular_state(|irregular_state| {
+            irregular_state
+                .state_override_at_block_number(block_number)
+                .or_insert_with(|| StateOverride::with_state_root(state_root))
+                .diff
+                .apply_account_replacement(address, account_info, storage);
+        })?;
 
         self.add_state_to_cache(modified_state, block_number);
 
@@ -1705,25 +3162,18 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         let old_value = modified_state.set_account_storage_slot(address, index, value)?;
 
         let slot = StorageSlot::new_changed(old_value, value);
-        let account_info = modified_state.basic(address).and_then(|mut account_info| {
-            // Retrieve the code if it's not empty. This is needed for the irregular state.
-            if let Some(account_info) = &mut account_info {
-                if account_info.code_hash != KECCAK_EMPTY {
-                    account_info.code = Some(modified_state.code_by_hash(account_info.code_hash)?);
-                }
-            }
-
-            Ok(account_info)
-        })?;
+        let account_info = account_info_with_code(&*modified_state, address)?;
 
         let state_root = modified_state.state_root()?;
 
         let block_number = self.blockchain.last_block_number();
-        self.irregular_state
-            .state_override_at_block_number(block_number)
-            .or_insert_with(|| StateOverride::with_state_root(state_root))
-            .diff
-            .apply_storage_change(address, index, slot, account_info);
+        self.modify_irregular_state(|irregular_state| {
+            irregular_state
+                .state_override_at_block_number(block_number)
+                .or_insert_with(|| StateOverride::with_state_root(state_root))
+                .diff
+                .apply_storage_change(address, index, slot, account_info);
+        })?;
 
         self.add_state_to_cache(modified_state, block_number);
 
@@ -1820,9 +3270,12 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
     ) -> Result<B256, ProviderError<LoggerErrorT>> {
         let transaction_hash = *transaction.hash();
 
+        self.remove_expired_transactions(Instant::now());
+
         let state = self.current_state()?;
         // Handles validation
-        self.mem_pool.add_transaction(&*state, transaction)?;
+        let evicted_transactions = self.mem_pool.add_transaction(&*state, transaction)?;
+        self.notify_dropped_transactions(evicted_transactions);
 
         for (filter_id, filter) in self.filters.iter_mut() {
             if let FilterData::NewPendingTransactions(events) = &mut filter.data {
@@ -1840,6 +3293,137 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         Ok(transaction_hash)
     }
 
+    /// Adds a transaction back to the mempool after a reorg. Transactions that
+    /// the mempool rejects - e.g. because their nonce was used by a replacement
+    /// block or their account's limit was reached - are discarded.
+    fn readd_pending_transaction(
+        &mut self,
+        transaction: ExecutableTransaction,
+    ) -> Result<(), ProviderError<LoggerErrorT>> {
+        match self.add_pending_transaction(transaction) {
+            Ok(_) => Ok(()),
+            Err(
+                error @ ProviderError::MemPoolAddTransaction(MemPoolAddTransactionError::State(_)),
+            ) => Err(error),
+            Err(ProviderError::MemPoolAddTransaction(_)) => Ok(()),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Validates that the nonce of the account at the provided address can be
+    /// changed to the provided nonce.
+    fn validate_nonce_change(
+        &mut self,
+        address: Address,
+        nonce: u64,
+    ) -> Result<(), ProviderError<LoggerErrorT>> {
+        if mempool::has_transactions(&self.mem_pool) {
+            return Err(ProviderError::SetAccountNonceWithPendingTransactions);
+        }
+
+        let previous_nonce = self
+            .current_state()?
+            .basic(address)?
+            .map_or(0, |account| account.nonce);
+
+        if nonce < previous_nonce {
+            return Err(ProviderError::SetAccountNonceLowerThanCurrent {
+                previous: previous_nonce,
+                proposed: nonce,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Notifies subscribers of transactions that were dropped from the
+    /// mempool.
+    fn notify_dropped_transactions(&mut self, transactions: Vec<OrderedTransaction>) {
+        for transaction in transactions {
+            let transaction_hash = *transaction.pending().hash();
+
+            for (filter_id, filter) in self.filters.iter_mut() {
+                if let FilterData::DroppedTransactions(events) = &mut filter.data {
+                    if filter.is_subscription {
+                        (self.subscriber_callback)(SubscriptionEvent {
+                            filter_id: *filter_id,
+                            result: SubscriptionEventData::DroppedTransactions(transaction_hash),
+                        });
+                    } else {
+                        events.push(transaction_hash);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Notifies log filters of the provided removed logs.
+    fn notify_removed_logs(&mut self, removed_logs: Vec<FilterLog>) {
+        if removed_logs.is_empty() {
+            return;
+        }
+
+        for (filter_id, filter) in self.filters.iter_mut() {
+            if let FilterData::Logs { criteria, logs } = &mut filter.data {
+                let mut filtered_logs = filter_logs(removed_logs.iter(), criteria);
+                if filtered_logs.is_empty() {
+                    continue;
+                }
+
+                if filter.is_subscription {
+                    (self.subscriber_callback)(SubscriptionEvent {
+                        filter_id: *filter_id,
+                        result: SubscriptionEventData::Logs(filtered_logs),
+                    });
+                } else {
+                    logs.append(&mut filtered_logs);
+                }
+            }
+        }
+    }
+
+    /// Collects the logs of the blocks after the provided block number, marked
+    /// as removed. Only collects logs if there are log filters to notify.
+    fn removed_logs(
+        &self,
+        block_number: u64,
+    ) -> Result<Vec<FilterLog>, ProviderError<LoggerErrorT>> {
+        let last_block_number = self.blockchain.last_block_number();
+        let has_log_filters = self
+            .filters
+            .values()
+            .any(|filter| matches!(filter.data, FilterData::Logs { .. }));
+
+        if !has_log_filters || last_block_number <= block_number {
+            return Ok(Vec::new());
+        }
+
+        let mut removed_logs = self
+            .blockchain
+            .logs(
+                block_number + 1,
+                last_block_number,
+                &HashSet::default(),
+                &[],
+            )
+            .map_err(ProviderError::Blockchain)?;
+
+        for log in removed_logs.iter_mut() {
+            log.removed = true;
+        }
+
+        Ok(removed_logs)
+    }
+
+    /// Drops queued transactions that have exceeded their lifetime at the
+    /// provided point in time. This is only called when a transaction is
+    /// added or a block is mined, so an idle provider only notifies dropped
+    /// transactions once that happens, e.g. through interval mining.
+    fn remove_expired_transactions(&mut self, now: Instant) {
+        let expired_transactions = self.mem_pool.remove_expired_transactions(now);
+        self.notify_dropped_transactions(expired_transactions);
+    }
+
     /// Creates a configuration, taking into the hardfork at the provided
     /// `BlockSpec`. If none is provided, assumes the hardfork for newly
     /// mined blocks.
@@ -1859,7 +3443,7 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         };
 
         let mut cfg_env = CfgEnv::default();
-        cfg_env.chain_id = self.blockchain.chain_id();
+        cfg_env.chain_id = self.chain_id;
         cfg_env.limit_contract_code_size = if self.allow_unlimited_contract_size {
             Some(usize::MAX)
         } else {
@@ -1870,6 +3454,45 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         Ok(CfgEnvWithHandlerCfg::new_with_spec_id(cfg_env, spec_id))
     }
 
+    /// Replays the transactions of a block on top of its parent's state,
+    /// collecting the requested Parity-style traces. If `transaction_hash` is
+    /// provided, the replay stops after that transaction.
+    fn replay_block(
+        &mut self,
+        block: &Arc<dyn SyncBlock<Error = BlockchainError>>,
+        trace_types: &[TraceType],
+        transaction_hash: Option<&B256>,
+    ) -> Result<Vec<(B256, TraceResults)>, ProviderError<LoggerErrorT>> {
+        let header = block.header();
+        // The genesis block doesn't contain any transactions
+        if header.number == 0 {
+            return Ok(Vec::new());
+        }
+
+        let cfg_env = self.create_evm_config(Some(&BlockSpec::Number(header.number)))?;
+        let block_env = trace_block_env(header, cfg_env.handler_cfg.spec_id);
+
+        let transactions = block.transactions().to_vec();
+
+        let prev_block_spec = Some(BlockSpec::Number(header.number - 1));
+
+        self.execute_in_block_context(
+            prev_block_spec.as_ref(),
+            |blockchain, _prev_block, state| {
+                parity::replay_block(
+                    blockchain,
+                    state.clone(),
+                    cfg_env,
+                    block_env,
+                    transactions,
+                    trace_types,
+                    transaction_hash,
+                )
+                .map_err(ProviderError::RunTransaction)
+            },
+        )?
+    }
+
     fn execute_in_block_context<T>(
         &mut self,
         block_spec: Option<&BlockSpec>,
@@ -1910,12 +3533,42 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
     /// Mine a block using the provided options. If an option has not been
     /// specified, it will be set using the provider's configuration values.
     fn mine_block(
+        &mut self,
+        options: BlockOptions,
+    ) -> Result<DebugMineBlockResultAndState<StateError>, ProviderError<LoggerErrorT>> {
+        self.mine_block_impl(options, None)
+    }
+
+    /// Mines a block using the provided options. If transactions are provided,
+    /// the block contains exactly those transactions. Otherwise, it contains
+    /// the pending bundles and transactions of the mempool.
+    fn mine_block_impl(
         &mut self,
         mut options: BlockOptions,
+        transactions: Option<Vec<ExecutableTransaction>>,
     ) -> Result<DebugMineBlockResultAndState<StateError>, ProviderError<LoggerErrorT>> {
+        let next_block_options = &self.next_block_options;
         options.base_fee = options.base_fee.or(self.next_block_base_fee_per_gas);
-        options.beneficiary = Some(options.beneficiary.unwrap_or(self.beneficiary));
-        options.gas_limit = Some(options.gas_limit.unwrap_or_else(|| self.block_gas_limit()));
+        options.beneficiary = Some(
+            options
+                .beneficiary
+                .or(next_block_options.coinbase)
+                .unwrap_or(self.beneficiary),
+        );
+        options.gas_limit = Some(
+            options
+                .gas_limit
+                .or(next_block_options.gas_limit)
+                .unwrap_or_else(|| self.block_gas_limit()),
+        );
+        options.difficulty = options.difficulty.or(next_block_options.difficulty);
+        options.mix_hash = options.mix_hash.or(next_block_options.mix_hash);
+        options.extra_data = options
+            .extra_data
+            .or_else(|| next_block_options.extra_data.clone());
+        options.withdrawals = options
+            .withdrawals
+            .or_else(|| next_block_options.withdrawals.clone());
 
         let evm_config = self.create_evm_config(None)?;
 
@@ -1928,22 +3581,47 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
         let mut debugger = Debugger::with_mocker(Mocker::new(self.call_override.clone()));
 
         let state_to_be_modified = (*self.current_state()?).clone();
+        let reward = miner_reward(evm_config.handler_cfg.spec_id).unwrap_or(U256::ZERO);
+        let debug_context = Some(DebugContext {
+            data: &mut debugger,
+            register_handles_fn: register_debugger_handles,
+        });
 
-        let result = mine_block(
-            self.blockchain.as_ref(),
-            state_to_be_modified,
-            &self.mem_pool,
-            &evm_config,
-            options,
-            self.min_gas_price,
-            self.initial_config.mining.mem_pool.order,
-            miner_reward(evm_config.handler_cfg.spec_id).unwrap_or(U256::ZERO),
-            self.dao_activation_block,
-            Some(DebugContext {
-                data: &mut debugger,
-                register_handles_fn: register_debugger_handles,
-            }),
-        )?;
+        let result = if let Some(transactions) = transactions {
+            mine_block_with_transactions(
+                self.blockchain.as_ref(),
+                state_to_be_modified,
+                transactions,
+                &evm_config,
+                options,
+                reward,
+                self.dao_activation_block,
+                debug_context,
+            )?
+        } else {
+            let block_number = self.blockchain.last_block_number() + 1;
+            let timestamp = options.timestamp.unwrap_or_default();
+            let bundles = self
+                .bundles
+                .iter()
+                .filter(|bundle| bundle.is_includable(block_number, timestamp))
+                .map(|bundle| bundle.bundle.clone())
+                .collect::<Vec<_>>();
+
+            mine_block(
+                self.blockchain.as_ref(),
+                state_to_be_modified,
+                &self.mem_pool,
+                &bundles,
+                &evm_config,
+                options,
+                self.min_gas_price,
+                &*self.mine_ordering,
+                reward,
+                self.dao_activation_block,
+                debug_context,
+            )?
+        };
 
         let Debugger {
             console_logger,
@@ -2046,7 +3724,12 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
     ) -> Result<ExecutableTransaction, ProviderError<LoggerErrorT>> {
         let TransactionRequestAndSender { request, sender } = transaction_request;
 
-        if self.impersonated_accounts.contains(&sender) {
+        let secret_key = self.local_accounts.get(&sender);
+        // Local accounts are signed with their secret key when auto-impersonating
+        let is_impersonated = self.impersonated_accounts.contains(&sender)
+            || (self.auto_impersonate && secret_key.is_none());
+
+        if is_impersonated {
             let signed_transaction = request.fake_sign(&sender);
 
             Ok(ExecutableTransaction::with_caller(
@@ -2055,10 +3738,7 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
                 sender,
             )?)
         } else {
-            let secret_key = self
-                .local_accounts
-                .get(&sender)
-                .ok_or(ProviderError::UnknownAddress { address: sender })?;
+            let secret_key = secret_key.ok_or(ProviderError::UnknownAddress { address: sender })?;
 
             let signed_transaction = request.sign(secret_key)?;
             Ok(ExecutableTransaction::with_caller(
@@ -2156,6 +3836,40 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
             .clone())
     }
 
+    /// Applies the provided modification to the irregular state. If
+    /// persistence is enabled, the modification is only applied in-memory
+    /// once the modified irregular state has been written to disk.
+    fn modify_irregular_state(
+        &mut self,
+        modifier: impl FnOnce(&mut IrregularState),
+    ) -> Result<(), ProviderError<LoggerErrorT>> {
+        if self.persistence_paths.is_some() {
+            let mut irregular_state = self.irregular_state.clone();
+            modifier(&mut irregular_state);
+
+            self.persist_irregular_state(&irregular_state)?;
+            self.irregular_state = irregular_state;
+        } else {
+            modifier(&mut self.irregular_state);
+        }
+
+        Ok(())
+    }
+
+    /// Persists the irregular state, if the blockchain is persisted to disk.
+    fn persist_irregular_state(
+        &self,
+        irregular_state: &IrregularState,
+    ) -> Result<(), ProviderError<LoggerErrorT>> {
+        if let Some(persistence_paths) = &self.persistence_paths {
+            persistence_paths
+                .write_irregular_state(irregular_state)
+                .map_err(ProviderError::Persistence)?;
+        }
+
+        Ok(())
+    }
+
     fn add_state_to_cache(
         &mut self,
         state: Box<dyn SyncState<StateError>>,
@@ -2169,6 +3883,16 @@ impl<LoggerErrorT: Debug> ProviderData<LoggerErrorT> {
     }
 }
 
+/// The source of the transactions of a mined block.
+enum BlockTransactions<'mem_pool> {
+    /// The pending bundles and transactions of the provider's mempool
+    MemPool,
+    /// The transactions of a custom mempool
+    CustomMemPool(&'mem_pool mut MemPool),
+    /// Exactly the provided transactions, in order
+    Explicit(Vec<ExecutableTransaction>),
+}
+
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
 #[repr(transparent)]
 pub(crate) struct StateId(u64);
@@ -2181,17 +3905,89 @@ impl StateId {
     }
 }
 
-fn block_time_offset_seconds(config: &ProviderConfig) -> Result<i64, CreationError> {
-    config.initial_date.map_or(Ok(0), |initial_date| {
-        let initial_timestamp = i64::try_from(
-            initial_date
-                .duration_since(UNIX_EPOCH)
-                .map_err(|_e| CreationError::InvalidInitialDate(initial_date))?
-                .as_secs(),
-        )
-        .expect("initial date must be representable as i64");
-
-        let current_timestamp = i64::try_from(
+/// Adds the location of their transactions to Parity-style flat traces.
+fn localize_flat_traces(
+    block: &Arc<dyn SyncBlock<Error = BlockchainError>>,
+    results: Vec<(B256, TraceResults)>,
+) -> Vec<LocalizedFlatTrace> {
+    let transaction_positions: HashMap<B256, u64> = block
+        .transactions()
+        .iter()
+        .enumerate()
+        .map(|(index, transaction)| (*transaction.hash(), index as u64))
+        .collect();
+
+    results
+        .into_iter()
+        .flat_map(|(transaction_hash, result)| {
+            let transaction_position = transaction_positions
+                .get(&transaction_hash)
+                .copied()
+                .unwrap_or_default();
+
+            result
+                .trace
+                .into_iter()
+                .map(move |trace| LocalizedFlatTrace {
+                    trace,
+                    block_hash: *block.hash(),
+                    block_number: block.header().number,
+                    transaction_hash,
+                    transaction_position,
+                })
+        })
+        .collect()
+}
+
+/// Constructs the block environment for re-executing the transactions of the
+/// block with the provided header.
+fn trace_block_env(header: &Header, spec_id: SpecId) -> BlockEnv {
+    BlockEnv {
+        number: U256::from(header.number),
+        coinbase: header.beneficiary,
+        timestamp: U256::from(header.timestamp),
+        gas_limit: U256::from(header.gas_limit),
+        basefee: header.base_fee_per_gas.unwrap_or_default(),
+        difficulty: U256::from(header.difficulty),
+        prevrandao: if spec_id >= SpecId::MERGE {
+            Some(header.mix_hash)
+        } else {
+            None
+        },
+        blob_excess_gas_and_price: header
+            .blob_gas
+            .as_ref()
+            .map(|BlobGas { excess_gas, .. }| BlobExcessGasAndPrice::new(*excess_gas)),
+    }
+}
+
+/// Retrieves the account at the provided address, including its code. The
+/// code is needed for the irregular state.
+fn account_info_with_code(
+    state: &dyn SyncState<StateError>,
+    address: Address,
+) -> Result<Option<AccountInfo>, StateError> {
+    let mut account_info = state.basic(address)?;
+    if let Some(account_info) = &mut account_info {
+        if account_info.code_hash != KECCAK_EMPTY {
+            account_info.code = Some(state.code_by_hash(account_info.code_hash)?);
+        }
+    }
+
+    Ok(account_info)
+}
+
+fn block_time_offset_seconds(config: &ProviderConfig) -> Result<i64, CreationError> {
+    config.initial_date.map_or(Ok(0), |initial_date| {
+        let initial_timestamp = i64::try_from(
+            initial_date
+                .duration_since(UNIX_EPOCH)
+                .map_err(|_e| CreationError::InvalidInitialDate(initial_date))?
+                .as_secs(),
+        )
+        .expect("initial date must be representable as i64");
+
+        let current_timestamp = i64::try_from(
             SystemTime::now()
                 .duration_since(UNIX_EPOCH)
                 .expect("current time must be after UNIX epoch")
@@ -2207,6 +4003,7 @@ struct BlockchainAndState {
     blockchain: Box<dyn SyncBlockchain<BlockchainError, StateError>>,
     fork_metadata: Option<ForkMetadata>,
     rpc_client: Option<Arc<RpcClient>>,
+    persistence_paths: Option<PersistencePaths>,
     state: Box<dyn SyncState<StateError>>,
     irregular_state: IrregularState,
     prev_randao_generator: RandomHashGenerator,
@@ -2219,6 +4016,10 @@ fn create_blockchain_and_state(
     config: &ProviderConfig,
     mut genesis_accounts: HashMap<Address, Account>,
 ) -> Result<BlockchainAndState, CreationError> {
+    if config.fork.is_some() && config.persistence_path.is_some() {
+        return Err(CreationError::PersistenceWithFork);
+    }
+
     let mut prev_randao_generator = RandomHashGenerator::with_seed(edr_defaults::MIX_HASH_SEED);
 
     if let Some(fork_config) = &config.fork {
@@ -2355,6 +4156,7 @@ fn create_blockchain_and_state(
                     .hash(),
             }),
             rpc_client: Some(rpc_client),
+            persistence_paths: None,
             blockchain: Box::new(blockchain),
             state: Box::new(state),
             irregular_state,
@@ -2369,33 +4171,78 @@ fn create_blockchain_and_state(
             None
         };
 
-        let blockchain = LocalBlockchain::new(
-            StateDiff::from(genesis_accounts),
-            config.chain_id,
-            config.hardfork,
-            GenesisBlockOptions {
-                gas_limit: Some(config.block_gas_limit.get()),
-                timestamp: config.initial_date.map(|d| {
-                    d.duration_since(UNIX_EPOCH)
-                        .expect("initial date must be after UNIX epoch")
-                        .as_secs()
-                }),
-                mix_hash,
-                base_fee: config.initial_base_fee_per_gas,
-                blob_gas: config.initial_blob_gas.clone(),
-            },
-        )?;
+        let genesis_diff = StateDiff::from(genesis_accounts);
+        let genesis_options = GenesisBlockOptions {
+            gas_limit: Some(config.block_gas_limit.get()),
+            timestamp: config.initial_date.map(|d| {
+                d.duration_since(UNIX_EPOCH)
+                    .expect("initial date must be after UNIX epoch")
+                    .as_secs()
+            }),
+            mix_hash,
+            base_fee: config.initial_base_fee_per_gas,
+            blob_gas: config.initial_blob_gas.clone(),
+        };
+
+        let persistence_paths = config.persistence_path.clone().map(PersistencePaths::new);
+        let (blockchain, irregular_state) = if let Some(persistence_paths) = &persistence_paths {
+            let blockchain = LocalBlockchain::persistent(
+                &persistence_paths.blockchain(),
+                genesis_diff,
+                config.chain_id,
+                config.hardfork,
+                genesis_options,
+            )?;
+
+            let irregular_state = persistence_paths
+                .read_irregular_state()
+                .map_err(CreationError::Persistence)?;
+
+            (blockchain, irregular_state)
+        } else {
+            let blockchain = LocalBlockchain::new(
+                genesis_diff,
+                config.chain_id,
+                config.hardfork,
+                genesis_options,
+            )?;
+
+            (blockchain, IrregularState::default())
+        };
 
-        let irregular_state = IrregularState::default();
+        let last_block_number = blockchain.last_block_number();
         let state = blockchain
-            .state_at_block_number(0, irregular_state.state_overrides())
-            .expect("Genesis state must exist");
+            .state_at_block_number(last_block_number, irregular_state.state_overrides())
+            .map_err(CreationError::Blockchain)?;
+
+        let mut block_time_offset_seconds = block_time_offset_seconds(config)?;
+        if last_block_number > 0 {
+            // Ensure that blocks mined after restoring a persisted blockchain don't
+            // precede its last block.
+            let last_block_timestamp = blockchain
+                .last_block()
+                .map_err(CreationError::Blockchain)?
+                .header()
+                .timestamp;
+            let last_block_timestamp = i64::try_from(last_block_timestamp)
+                .map_err(|_e| CreationError::InvalidPersistedTimestamp(last_block_timestamp))?;
+
+            let current_timestamp = i64::try_from(
+                SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .expect("current time must be after UNIX epoch")
+                    .as_secs(),
+            )
+            .expect("Current timestamp must be representable as i64");
 
-        let block_time_offset_seconds = block_time_offset_seconds(config)?;
+            block_time_offset_seconds =
+                block_time_offset_seconds.max(last_block_timestamp - current_timestamp);
+        }
 
         Ok(BlockchainAndState {
             fork_metadata: None,
             rpc_client: None,
+            persistence_paths,
             blockchain: Box::new(blockchain),
             state,
             irregular_state,
@@ -2468,7 +4315,12 @@ pub(crate) mod test_utils {
             });
 
             let config = create_test_config_with_fork(fork);
+            Self::with_config(config)
+        }
 
+        /// Creates a fixture with the provided config, running on a new
+        /// runtime with a single worker thread.
+        pub(crate) fn with_config(config: ProviderConfig) -> anyhow::Result<Self> {
             let runtime = runtime::Builder::new_multi_thread()
                 .worker_threads(1)
                 .enable_all()
@@ -2571,16 +4423,22 @@ mod tests {
     use std::convert::Infallible;
 
     use alloy_sol_types::{sol, SolCall};
-    use anyhow::Context;
-    use edr_eth::remote::eth::CallRequest;
-    use edr_evm::{hex, MineOrdering, TransactionError};
+    use anyhow::{anyhow, Context};
+    use edr_eth::{
+        remote::{eth::CallRequest, AccountOverrideOptions},
+        transaction::{Eip155TransactionRequest, TransactionKind, TransactionRequest},
+        trie::KECCAK_NULL_RLP,
+    };
+    use edr_evm::{
+        hex, MemPoolAddTransactionError, MineOrdering, TransactionBundle, TransactionError,
+    };
     use edr_test_utils::env::get_alchemy_url;
     use serde_json::json;
 
     use super::{test_utils::ProviderTestFixture, *};
     use crate::{
         console_log::tests::{deploy_console_log_contract, ConsoleLogTransaction},
-        requests::eth::resolve_call_request,
+        requests::eth::{resolve_call_request, SimulateBlock},
         test_utils::{
             create_test_config, create_test_config_with_fork, one_ether, FORK_BLOCK_NUMBER,
         },
@@ -2629,6 +4487,43 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn get_proof_local_account() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let transaction = fixture.signed_dummy_transaction(0, None)?;
+        let sender = *transaction.caller();
+
+        fixture.provider_data.add_pending_transaction(transaction)?;
+        fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+
+        let proof =
+            fixture
+                .provider_data
+                .get_proof(sender, &[U256::ZERO], Some(&BlockSpec::latest()))?;
+
+        assert_eq!(proof.address, sender);
+        assert_eq!(proof.nonce, 1);
+        assert_eq!(proof.code_hash, KECCAK_EMPTY);
+        assert_eq!(proof.storage_hash, edr_eth::trie::KECCAK_NULL_RLP);
+        assert!(!proof.account_proof.is_empty());
+        assert_eq!(proof.storage_proof.len(), 1);
+        assert_eq!(proof.storage_proof[0].value, U256::ZERO);
+
+        // The proof at the previous block reflects the account before the
+        // transaction
+        let proof = fixture
+            .provider_data
+            .get_proof(sender, &[], Some(&BlockSpec::Number(0)))?;
+
+        assert_eq!(proof.nonce, 0);
+        assert_eq!(proof.balance, one_ether());
+
+        Ok(())
+    }
+
     #[test]
     fn test_sign_transaction_request() -> anyhow::Result<()> {
         let fixture = ProviderTestFixture::new_local()?;
@@ -2655,6 +4550,117 @@ mod tests {
         Ok(())
     }
 
+    fn fixture_with_mem_pool_config(
+        mem_pool: MemPoolConfig,
+    ) -> anyhow::Result<ProviderTestFixture> {
+        let default_config = create_test_config();
+        let config = ProviderConfig {
+            mining: MiningConfig {
+                auto_mine: false,
+                mem_pool,
+                ..default_config.mining
+            },
+            ..default_config
+        };
+
+        ProviderTestFixture::with_config(config)
+    }
+
+    fn dropped_transactions(
+        fixture: &mut ProviderTestFixture,
+        filter_id: &U256,
+    ) -> anyhow::Result<Vec<B256>> {
+        match fixture.provider_data.get_filter_changes(filter_id) {
+            Some(FilteredEvents::DroppedTransactions(hashes)) => Ok(hashes),
+            _ => Err(anyhow!("expected dropped transactions")),
+        }
+    }
+
+    #[test]
+    fn add_pending_transaction_notifies_evicted_transactions() -> anyhow::Result<()> {
+        let mut fixture = fixture_with_mem_pool_config(MemPoolConfig {
+            max_transactions: NonZeroUsize::new(1),
+            ..MemPoolConfig::default()
+        })?;
+
+        let filter_id = fixture
+            .provider_data
+            .add_dropped_transaction_filter::<false>();
+
+        let cheap_transaction = fixture.signed_dummy_transaction(0, None)?;
+        let cheap_transaction_hash = fixture
+            .provider_data
+            .add_pending_transaction(cheap_transaction)?;
+
+        let mut request = fixture.dummy_transaction_request(1, 30_000, None)?;
+        if let TransactionRequest::Eip155(request) = &mut request.request {
+            request.gas_price = U256::from(50_000_000_000_u64);
+        }
+        let expensive_transaction = fixture.provider_data.sign_transaction_request(request)?;
+        let expensive_transaction_hash = fixture
+            .provider_data
+            .add_pending_transaction(expensive_transaction)?;
+
+        assert_eq!(
+            dropped_transactions(&mut fixture, &filter_id)?,
+            vec![cheap_transaction_hash]
+        );
+
+        let pending_transactions = fixture
+            .provider_data
+            .pending_transactions()
+            .map(|transaction| *transaction.hash())
+            .collect::<Vec<_>>();
+        assert_eq!(pending_transactions, vec![expensive_transaction_hash]);
+
+        // Transactions that aren't priced high enough are rejected
+        let transaction = fixture.signed_dummy_transaction(0, None)?;
+        let result = fixture.provider_data.add_pending_transaction(transaction);
+        assert!(matches!(
+            result,
+            Err(ProviderError::MemPoolAddTransaction(
+                MemPoolAddTransactionError::MemPoolFull { .. }
+            ))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn remove_expired_transactions_notifies_dropped_transactions() -> anyhow::Result<()> {
+        let lifetime = Duration::from_secs(60);
+        let mut fixture = fixture_with_mem_pool_config(MemPoolConfig {
+            queued_lifetime: Some(lifetime),
+            ..MemPoolConfig::default()
+        })?;
+
+        let filter_id = fixture
+            .provider_data
+            .add_dropped_transaction_filter::<false>();
+
+        let pending_transaction = fixture.signed_dummy_transaction(0, Some(0))?;
+        fixture
+            .provider_data
+            .add_pending_transaction(pending_transaction)?;
+
+        let queued_transaction = fixture.signed_dummy_transaction(0, Some(2))?;
+        let queued_transaction_hash = fixture
+            .provider_data
+            .add_pending_transaction(queued_transaction)?;
+
+        fixture
+            .provider_data
+            .remove_expired_transactions(Instant::now() + lifetime);
+
+        assert_eq!(
+            dropped_transactions(&mut fixture, &filter_id)?,
+            vec![queued_transaction_hash]
+        );
+        assert_eq!(fixture.provider_data.pending_transactions().count(), 1);
+
+        Ok(())
+    }
+
     fn test_add_pending_transaction(
         fixture: &mut ProviderTestFixture,
         transaction: ExecutableTransaction,
@@ -2703,6 +4709,32 @@ mod tests {
         test_add_pending_transaction(&mut fixture, transaction)
     }
 
+    #[test]
+    fn add_pending_transaction_with_auto_impersonation() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let sender = Address::random();
+        fixture.provider_data.set_balance(sender, one_ether())?;
+
+        let mut request = fixture.dummy_transaction_request(0, 30_000, None)?;
+        request.sender = sender;
+
+        assert!(matches!(
+            fixture
+                .provider_data
+                .sign_transaction_request(request.clone()),
+            Err(ProviderError::UnknownAddress { .. })
+        ));
+
+        fixture.provider_data.set_auto_impersonate(true);
+        assert!(fixture.provider_data.is_auto_impersonating());
+
+        let transaction = fixture.provider_data.sign_transaction_request(request)?;
+        assert_eq!(*transaction.caller(), sender);
+
+        test_add_pending_transaction(&mut fixture, transaction)
+    }
+
     #[test]
     fn block_by_block_spec_earliest() -> anyhow::Result<()> {
         let fixture = ProviderTestFixture::new_local()?;
@@ -2800,6 +4832,24 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn set_chain_id() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let chain_id = fixture.config.chain_id + 1;
+        fixture.provider_data.set_chain_id(chain_id);
+        assert_eq!(fixture.provider_data.chain_id(), chain_id);
+
+        let cfg_env = fixture.provider_data.create_evm_config(None)?;
+        assert_eq!(cfg_env.chain_id, chain_id);
+
+        // The chain ID is retained when resetting
+        fixture.provider_data.reset(None)?;
+        assert_eq!(fixture.provider_data.chain_id(), chain_id);
+
+        Ok(())
+    }
+
     #[test]
     fn fork_metadata_fork_mode() -> anyhow::Result<()> {
         let fixture = ProviderTestFixture::new_forked(None)?;
@@ -2813,6 +4863,66 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn block_receipts_local() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let transaction1 = fixture.signed_dummy_transaction(0, None)?;
+        let transaction2 = fixture.signed_dummy_transaction(1, None)?;
+        let transaction_hashes = vec![*transaction1.hash(), *transaction2.hash()];
+
+        fixture
+            .provider_data
+            .add_pending_transaction(transaction1)?;
+        fixture
+            .provider_data
+            .add_pending_transaction(transaction2)?;
+
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+
+        let receipts = fixture.provider_data.block_receipts(&result.block)?;
+        assert_eq!(
+            receipts
+                .iter()
+                .map(|receipt| receipt.transaction_hash)
+                .collect::<Vec<_>>(),
+            transaction_hashes
+        );
+
+        for receipt in receipts {
+            assert_eq!(
+                fixture
+                    .provider_data
+                    .transaction_receipt(&receipt.transaction_hash)?,
+                Some(receipt)
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn block_receipts_fork_mode() -> anyhow::Result<()> {
+        let fixture = ProviderTestFixture::new_forked(None)?;
+
+        let block = fixture
+            .provider_data
+            .block_by_block_spec(&BlockSpec::Number(FORK_BLOCK_NUMBER))?
+            .expect("fork block should exist");
+
+        let receipts = fixture.provider_data.block_receipts(&block)?;
+        assert_eq!(receipts.len(), block.transactions().len());
+
+        for (receipt, transaction) in receipts.iter().zip(block.transactions()) {
+            assert_eq!(receipt.transaction_hash, *transaction.hash());
+            assert_eq!(receipt.block_hash, *block.hash());
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn console_log_mine_block() -> anyhow::Result<()> {
         let mut fixture = ProviderTestFixture::new_local()?;
@@ -2858,6 +4968,7 @@ mod tests {
             pending_transaction,
             &BlockSpec::latest(),
             &StateOverrides::default(),
+            &BlockOverrideOptions::default(),
         )?;
 
         let console_log_inputs = result.console_log_inputs;
@@ -2868,393 +4979,2067 @@ mod tests {
     }
 
     #[test]
-    fn mine_and_commit_block_empty() -> anyhow::Result<()> {
+    fn run_call_block_overrides() -> anyhow::Result<()> {
         let mut fixture = ProviderTestFixture::new_local()?;
 
-        let previous_block_number = fixture.provider_data.last_block_number();
+        let contract_address = Address::random();
 
-        let result = fixture
+        // NUMBER PUSH1 0x00 MSTORE TIMESTAMP PUSH1 0x20 MSTORE PUSH1 0x40 PUSH1 0x00 RETURN
+        let code = vec![
+            0x43, 0x60, 0x00, 0x52, 0x42, 0x60, 0x20, 0x52, 0x60, 0x40, 0x60, 0x00, 0xf3,
+        ];
+        fixture
             .provider_data
-            .mine_and_commit_block(BlockOptions::default())?;
-        assert!(result.block.transactions().is_empty());
-
-        let current_block_number = fixture.provider_data.last_block_number();
-        assert_eq!(current_block_number, previous_block_number + 1);
+            .set_code(contract_address, Bytes::from(code))?;
 
-        let cached_state = fixture
-            .provider_data
-            .get_or_compute_state(result.block.header().number)?;
+        let state_overrides = StateOverrides::default();
+        let transaction = resolve_call_request(
+            &mut fixture.provider_data,
+            CallRequest {
+                from: Some(fixture.nth_local_account(0)?),
+                to: Some(contract_address),
+                ..CallRequest::default()
+            },
+            &BlockSpec::latest(),
+            &state_overrides,
+        )?;
 
-        let calculated_state = fixture.provider_data.blockchain.state_at_block_number(
-            fixture.provider_data.last_block_number(),
-            fixture.provider_data.irregular_state.state_overrides(),
+        let result = fixture.provider_data.run_call(
+            transaction,
+            &BlockSpec::latest(),
+            &state_overrides,
+            &BlockOverrideOptions {
+                number: Some(1_000),
+                timestamp: Some(2_000),
+                ..BlockOverrideOptions::default()
+            },
         )?;
 
-        assert_eq!(cached_state.state_root()?, calculated_state.state_root()?);
+        let output = result
+            .execution_result
+            .into_output()
+            .expect("Call should succeed");
+
+        assert_eq!(U256::from_be_slice(&output[..32]), U256::from(1_000));
+        assert_eq!(U256::from_be_slice(&output[32..]), U256::from(2_000));
 
         Ok(())
     }
 
     #[test]
-    fn mine_and_commit_blocks_empty() -> anyhow::Result<()> {
+    fn simulate_v1_rejected_call() -> anyhow::Result<()> {
         let mut fixture = ProviderTestFixture::new_local()?;
 
-        fixture
-            .provider_data
-            .mine_and_commit_blocks(1_000_000_000, 1)?;
+        let caller = fixture.nth_local_account(0)?;
+        let recipient = Address::random();
+        let block_gas_limit = fixture.provider_data.block_gas_limit();
+
+        let payload = SimulatePayload {
+            block_state_calls: vec![SimulateBlock {
+                block_overrides: None,
+                state_overrides: None,
+                calls: vec![
+                    CallRequest {
+                        from: Some(caller),
+                        to: Some(recipient),
+                        gas: Some(block_gas_limit + 1),
+                        ..CallRequest::default()
+                    },
+                    CallRequest {
+                        from: Some(caller),
+                        to: Some(recipient),
+                        ..CallRequest::default()
+                    },
+                ],
+            }],
+            trace_transfers: false,
+            validation: false,
+            return_full_transactions: false,
+        };
 
-        let cached_state = fixture
+        let results = fixture
             .provider_data
-            .get_or_compute_state(fixture.provider_data.last_block_number())?;
+            .simulate_v1(payload, &BlockSpec::latest())?;
 
-        let calculated_state = fixture.provider_data.blockchain.state_at_block_number(
-            fixture.provider_data.last_block_number(),
-            fixture.provider_data.irregular_state.state_overrides(),
-        )?;
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].block.transactions().len(), 1);
 
-        assert_eq!(cached_state.state_root()?, calculated_state.state_root()?);
+        let call_results = &results[0].call_results;
+        assert_eq!(call_results.len(), 2);
+        assert!(matches!(
+            &call_results[0],
+            SimulatedCallResult::Rejected(error) if error.contains("gas limit")
+        ));
+        assert!(matches!(
+            &call_results[1],
+            SimulatedCallResult::Included { execution_result, .. } if execution_result.is_success()
+        ));
 
         Ok(())
     }
 
     #[test]
-    fn mine_and_commit_block_single_transaction() -> anyhow::Result<()> {
+    fn simulate_v1_chained_blocks() -> anyhow::Result<()> {
         let mut fixture = ProviderTestFixture::new_local()?;
 
-        let transaction = fixture.signed_dummy_transaction(0, None)?;
-        let expected = transaction.value();
-        let receiver = transaction
-            .to()
-            .expect("Dummy transaction should have a receiver");
-
-        fixture.provider_data.add_pending_transaction(transaction)?;
+        let last_block_number = fixture.provider_data.last_block_number();
+        let caller = fixture.nth_local_account(0)?;
+        let counter_address = Address::random();
+        let recipient = Address::random();
+
+        // PUSH1 0x00 SLOAD PUSH1 0x01 ADD DUP1 PUSH1 0x00 SSTORE PUSH1 0x00 MSTORE
+        // PUSH1 0x20 PUSH1 0x00 RETURN
+        let counter_code = Bytes::from(vec![
+            0x60, 0x00, 0x54, 0x60, 0x01, 0x01, 0x80, 0x60, 0x00, 0x55, 0x60, 0x00, 0x52, 0x60,
+            0x20, 0x60, 0x00, 0xf3,
+        ]);
+
+        let increment = CallRequest {
+            from: Some(caller),
+            to: Some(counter_address),
+            ..CallRequest::default()
+        };
 
-        let result = fixture
-            .provider_data
-            .mine_and_commit_block(BlockOptions::default())?;
-
-        assert_eq!(result.block.transactions().len(), 1);
+        let payload = SimulatePayload {
+            block_state_calls: vec![
+                SimulateBlock {
+                    block_overrides: None,
+                    state_overrides: Some(
+                        [(
+                            counter_address,
+                            AccountOverrideOptions {
+                                balance: None,
+                                nonce: None,
+                                code: Some(counter_code),
+                                storage: None,
+                                storage_diff: None,
+                            },
+                        )]
+                        .into_iter()
+                        .collect(),
+                    ),
+                    calls: vec![
+                        increment.clone(),
+                        increment.clone(),
+                        CallRequest {
+                            from: Some(caller),
+                            to: Some(recipient),
+                            value: Some(U256::from(1_000)),
+                            ..CallRequest::default()
+                        },
+                    ],
+                },
+                SimulateBlock {
+                    block_overrides: Some(BlockOverrideOptions {
+                        number: Some(last_block_number + 10),
+                        ..BlockOverrideOptions::default()
+                    }),
+                    state_overrides: None,
+                    calls: vec![increment],
+                },
+            ],
+            trace_transfers: true,
+            validation: false,
+            return_full_transactions: false,
+        };
 
-        let balance = fixture
+        let results = fixture
             .provider_data
-            .balance(receiver, Some(&BlockSpec::latest()))?;
+            .simulate_v1(payload, &BlockSpec::latest())?;
 
-        assert_eq!(balance, expected);
+        // The gap between the simulated blocks is filled with empty blocks
+        assert_eq!(results.len(), 10);
+        for (offset, result) in (1..).zip(&results) {
+            assert_eq!(result.block.header().number, last_block_number + offset);
+        }
+        for window in results.windows(2) {
+            assert_eq!(
+                window[1].block.header().parent_hash,
+                *window[0].block.hash()
+            );
+        }
+        assert!(results[1..9]
+            .iter()
+            .all(|result| result.call_results.is_empty()));
 
-        Ok(())
-    }
+        let counter_values = results
+            .iter()
+            .flat_map(|result| &result.call_results)
+            .filter_map(|call_result| match call_result {
+                SimulatedCallResult::Included {
+                    execution_result, ..
+                } => execution_result
+                    .output()
+                    .filter(|output| output.len() == 32)
+                    .map(|output| U256::from_be_slice(output)),
+                SimulatedCallResult::Rejected(_) => None,
+            })
+            .collect::<Vec<_>>();
 
-    #[test]
-    fn mine_and_commit_block_two_transactions_different_senders() -> anyhow::Result<()> {
-        let mut fixture = ProviderTestFixture::new_local()?;
+        assert_eq!(
+            counter_values,
+            vec![U256::from(1), U256::from(2), U256::from(3)]
+        );
 
-        let transaction1 = fixture.signed_dummy_transaction(0, None)?;
-        let transaction2 = fixture.signed_dummy_transaction(1, None)?;
+        // The value transfer is reported as a synthetic log
+        let SimulatedCallResult::Included {
+            logs: transfer_logs,
+            ..
+        } = &results[0].call_results[2]
+        else {
+            return Err(anyhow!("Value transfer should be included"));
+        };
+        assert_eq!(transfer_logs.len(), 1);
+        assert_eq!(transfer_logs[0].data.topics()[2], recipient.into_word());
 
-        let receiver = transaction1
-            .to()
-            .expect("Dummy transaction should have a receiver");
+        // Nothing was committed
+        assert_eq!(fixture.provider_data.last_block_number(), last_block_number);
+        assert_eq!(
+            fixture
+                .provider_data
+                .balance(recipient, Some(&BlockSpec::latest()))?,
+            U256::ZERO
+        );
 
-        let expected = transaction1.value() + transaction2.value();
+        Ok(())
+    }
 
-        fixture
+    fn reverting_bundle_transaction(
+        fixture: &ProviderTestFixture,
+        local_account_index: usize,
+        nonce: u64,
+    ) -> anyhow::Result<ExecutableTransaction> {
+        // PUSH1 0x00 PUSH1 0x00 REVERT
+        let request = TransactionRequest::Eip155(Eip155TransactionRequest {
+            kind: TransactionKind::Create,
+            gas_limit: 100_000,
+            gas_price: U256::from(42_000_000_000_u64),
+            value: U256::ZERO,
+            input: Bytes::from(vec![0x60, 0x00, 0x60, 0x00, 0xfd]),
+            nonce,
+            chain_id: fixture.config.chain_id,
+        });
+
+        let sender = fixture.nth_local_account(local_account_index)?;
+        Ok(fixture
             .provider_data
-            .add_pending_transaction(transaction1)?;
+            .sign_transaction_request(TransactionRequestAndSender { request, sender })?)
+    }
+
+    fn pending_bundle(
+        transactions: Vec<ExecutableTransaction>,
+        reverting_transaction_hashes: Vec<B256>,
+        block_number: Option<u64>,
+    ) -> PendingBundle {
+        PendingBundle {
+            bundle: TransactionBundle {
+                transactions,
+                reverting_transaction_hashes,
+            },
+            block_number,
+            min_timestamp: None,
+            max_timestamp: None,
+        }
+    }
+
+    #[test]
+    fn send_bundle_mined_at_top_of_block() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let pending_transaction = fixture.signed_dummy_transaction(0, None)?;
         fixture
             .provider_data
-            .add_pending_transaction(transaction2)?;
+            .add_pending_transaction(pending_transaction.clone())?;
+
+        let bundle_transaction1 = fixture.signed_dummy_transaction(1, Some(0))?;
+        let bundle_transaction2 = fixture.signed_dummy_transaction(1, Some(1))?;
+        fixture.provider_data.send_bundle(pending_bundle(
+            vec![bundle_transaction1.clone(), bundle_transaction2.clone()],
+            Vec::new(),
+            None,
+        ))?;
 
         let result = fixture
             .provider_data
             .mine_and_commit_block(BlockOptions::default())?;
 
-        assert_eq!(result.block.transactions().len(), 2);
-
-        let balance = fixture
-            .provider_data
-            .balance(receiver, Some(&BlockSpec::latest()))?;
+        let transaction_hashes = result
+            .block
+            .transactions()
+            .iter()
+            .map(|transaction| *transaction.hash())
+            .collect::<Vec<_>>();
 
-        assert_eq!(balance, expected);
+        assert_eq!(
+            transaction_hashes,
+            vec![
+                *bundle_transaction1.hash(),
+                *bundle_transaction2.hash(),
+                *pending_transaction.hash()
+            ]
+        );
+        assert!(fixture.provider_data.bundles.is_empty());
 
         Ok(())
     }
 
     #[test]
-    fn mine_and_commit_block_two_transactions_same_sender() -> anyhow::Result<()> {
+    fn send_bundle_discarded_on_revert() -> anyhow::Result<()> {
         let mut fixture = ProviderTestFixture::new_local()?;
 
-        let transaction1 = fixture.signed_dummy_transaction(0, Some(0))?;
-        let transaction2 = fixture.signed_dummy_transaction(0, Some(1))?;
-
-        let receiver = transaction1
-            .to()
-            .expect("Dummy transaction should have a receiver");
-
-        let expected = transaction1.value() + transaction2.value();
+        let transaction = fixture.signed_dummy_transaction(1, Some(0))?;
+        let reverting_transaction = reverting_bundle_transaction(&fixture, 1, 1)?;
 
-        fixture
-            .provider_data
-            .add_pending_transaction(transaction1)?;
-        fixture
-            .provider_data
-            .add_pending_transaction(transaction2)?;
+        fixture.provider_data.send_bundle(pending_bundle(
+            vec![transaction.clone(), reverting_transaction.clone()],
+            Vec::new(),
+            None,
+        ))?;
 
         let result = fixture
             .provider_data
             .mine_and_commit_block(BlockOptions::default())?;
 
-        assert_eq!(result.block.transactions().len(), 2);
+        assert!(result.block.transactions().is_empty());
 
-        let balance = fixture
+        // The bundle is included if the transaction is allowed to revert
+        fixture.provider_data.send_bundle(pending_bundle(
+            vec![transaction, reverting_transaction.clone()],
+            vec![*reverting_transaction.hash()],
+            None,
+        ))?;
+
+        let result = fixture
             .provider_data
-            .balance(receiver, Some(&BlockSpec::latest()))?;
+            .mine_and_commit_block(BlockOptions::default())?;
 
-        assert_eq!(balance, expected);
+        assert_eq!(result.block.transactions().len(), 2);
+        assert!(!result.transaction_results[1].is_success());
 
         Ok(())
     }
 
     #[test]
-    fn mine_and_commit_block_removes_mined_transactions() -> anyhow::Result<()> {
+    fn send_bundle_target_block_number() -> anyhow::Result<()> {
         let mut fixture = ProviderTestFixture::new_local()?;
 
+        let last_block_number = fixture.provider_data.last_block_number();
         let transaction = fixture.signed_dummy_transaction(0, None)?;
 
-        fixture
+        assert!(fixture
             .provider_data
-            .add_pending_transaction(transaction.clone())?;
+            .send_bundle(pending_bundle(
+                vec![transaction.clone()],
+                Vec::new(),
+                Some(last_block_number)
+            ))
+            .is_err());
 
-        let num_pending_transactions = fixture.provider_data.pending_transactions().count();
-        assert_eq!(num_pending_transactions, 1);
+        fixture.provider_data.send_bundle(pending_bundle(
+            vec![transaction],
+            Vec::new(),
+            Some(last_block_number + 2),
+        ))?;
 
         let result = fixture
             .provider_data
             .mine_and_commit_block(BlockOptions::default())?;
+        assert!(result.block.transactions().is_empty());
 
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
         assert_eq!(result.block.transactions().len(), 1);
 
-        let num_pending_transactions = fixture.provider_data.pending_transactions().count();
-        assert_eq!(num_pending_transactions, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn call_bundle_does_not_commit() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let caller = fixture.nth_local_account(0)?;
+        let transaction = fixture.signed_dummy_transaction(0, None)?;
+        let reverting_transaction = reverting_bundle_transaction(&fixture, 0, 1)?;
+
+        let result = fixture.provider_data.call_bundle(
+            vec![transaction.clone(), reverting_transaction],
+            &BlockSpec::latest(),
+            None,
+            None,
+        )?;
+
+        assert_eq!(
+            result.state_block_number,
+            fixture.provider_data.last_block_number()
+        );
+        assert_eq!(result.transaction_results.len(), 2);
+
+        let transfer_result = &result.transaction_results[0];
+        assert_eq!(transfer_result.transaction_hash, *transaction.hash());
+        assert_eq!(transfer_result.caller, caller);
+        assert!(transfer_result.execution_result.is_success());
+        assert_eq!(transfer_result.execution_result.gas_used(), 21_000);
+        assert_eq!(transfer_result.coinbase_diff, transfer_result.gas_fees);
+
+        assert!(!result.transaction_results[1].execution_result.is_success());
+
+        // Nothing was committed
+        assert!(fixture.provider_data.bundles.is_empty());
+        assert_eq!(
+            fixture
+                .provider_data
+                .get_transaction_count(caller, Some(&BlockSpec::latest()))?,
+            0
+        );
 
         Ok(())
     }
 
     #[test]
-    fn mine_and_commit_block_leaves_unmined_transactions() -> anyhow::Result<()> {
+    fn create_access_list_storage_and_balance() -> anyhow::Result<()> {
         let mut fixture = ProviderTestFixture::new_local()?;
 
-        // SAFETY: literal is non-zero
+        let contract_address = Address::random();
+        let other_address = Address::random();
+
+        // PUSH1 0x01 SLOAD PUSH20 <other_address> BALANCE STOP
+        let mut code = vec![0x60, 0x01, 0x54, 0x73];
+        code.extend_from_slice(other_address.as_slice());
+        code.extend_from_slice(&[0x31, 0x00]);
+
         fixture
             .provider_data
-            .set_block_gas_limit(unsafe { NonZeroU64::new_unchecked(55_000) })?;
+            .set_code(contract_address, Bytes::from(code))?;
 
-        // Actual gas usage is 21_000
-        let transaction1 = fixture.signed_dummy_transaction(0, Some(0))?;
-        let transaction3 = fixture.signed_dummy_transaction(0, Some(1))?;
+        let transaction = resolve_call_request(
+            &mut fixture.provider_data,
+            CallRequest {
+                from: Some(fixture.nth_local_account(0)?),
+                to: Some(contract_address),
+                ..CallRequest::default()
+            },
+            &BlockSpec::latest(),
+            &StateOverrides::default(),
+        )?;
 
-        // Too expensive to mine
-        let transaction2 = {
-            let request = fixture.dummy_transaction_request(1, 40_000, None)?;
-            fixture.provider_data.sign_transaction_request(request)?
+        let (access_list, result) = fixture
+            .provider_data
+            .create_access_list(transaction, &BlockSpec::latest())?;
+
+        assert!(result.is_success());
+
+        let mut expected = vec![
+            AccessListItem {
+                address: contract_address,
+                storage_keys: vec![B256::from(U256::from(1).to_be_bytes())],
+            },
+            AccessListItem {
+                address: other_address,
+                storage_keys: Vec::new(),
+            },
+        ];
+        expected.sort_by_key(|item| item.address);
+
+        assert_eq!(access_list, AccessList(expected));
+
+        Ok(())
+    }
+
+    #[test]
+    fn debug_trace_call_call_tracer() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let transaction = fixture.signed_dummy_transaction(0, None)?;
+        let sender = *transaction.caller();
+
+        let trace = fixture.provider_data.debug_trace_call(
+            transaction,
+            &BlockSpec::latest(),
+            DebugTraceConfig {
+                tracer: DebugTracer::Call(edr_evm::CallTracerConfig::default()),
+                ..DebugTraceConfig::default()
+            },
+        )?;
+
+        let DebugTraceOutput::Call(call_frame) = trace else {
+            panic!("Expected call tracer output");
         };
 
+        assert_eq!(call_frame.frame_type, edr_evm::CallFrameType::Call);
+        assert_eq!(call_frame.from, sender);
+        assert_eq!(call_frame.to, Some(Address::ZERO));
+        assert_eq!(call_frame.value, Some(U256::from(1)));
+        assert_eq!(call_frame.gas, 30_000);
+        assert_eq!(call_frame.gas_used, 21_000);
+        assert_eq!(call_frame.error, None);
+        assert!(call_frame.calls.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn debug_trace_transaction_call_tracer() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let transaction = fixture.signed_dummy_transaction(0, None)?;
+        let transaction_hash = fixture.provider_data.add_pending_transaction(transaction)?;
         fixture
             .provider_data
-            .add_pending_transaction(transaction1.clone())?;
-        fixture
+            .mine_and_commit_block(BlockOptions::default())?;
+
+        let trace = fixture.provider_data.debug_trace_transaction(
+            &transaction_hash,
+            DebugTraceConfig {
+                tracer: DebugTracer::Call(edr_evm::CallTracerConfig {
+                    only_top_call: true,
+                    with_log: true,
+                }),
+                ..DebugTraceConfig::default()
+            },
+        )?;
+
+        let DebugTraceOutput::Call(call_frame) = trace else {
+            panic!("Expected call tracer output");
+        };
+
+        assert_eq!(call_frame.to, Some(Address::ZERO));
+        assert_eq!(call_frame.gas_used, 21_000);
+        assert!(call_frame.logs.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn debug_trace_block_two_transactions() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let transaction1 = fixture.signed_dummy_transaction(0, None)?;
+        let transaction2 = fixture.signed_dummy_transaction(1, None)?;
+
+        let transaction_hash1 = fixture
             .provider_data
-            .add_pending_transaction(transaction2.clone())?;
-        fixture
+            .add_pending_transaction(transaction1)?;
+        let transaction_hash2 = fixture
             .provider_data
-            .add_pending_transaction(transaction3.clone())?;
+            .add_pending_transaction(transaction2)?;
 
-        let pending_transactions = fixture
+        let result = fixture
             .provider_data
-            .pending_transactions()
-            .cloned()
-            .collect::<Vec<_>>();
+            .mine_and_commit_block(BlockOptions::default())?;
+        let block_number = result.block.header().number;
 
-        assert!(pending_transactions.contains(&transaction1));
-        assert!(pending_transactions.contains(&transaction2));
-        assert!(pending_transactions.contains(&transaction3));
+        let traces = fixture.provider_data.debug_trace_block(
+            &BlockSpec::Number(block_number),
+            DebugTraceConfig {
+                tracer: DebugTracer::Call(edr_evm::CallTracerConfig::default()),
+                ..DebugTraceConfig::default()
+            },
+        )?;
+
+        assert_eq!(traces.len(), 2);
+        assert_eq!(traces[0].transaction_hash, transaction_hash1);
+        assert_eq!(traces[1].transaction_hash, transaction_hash2);
+
+        for trace in traces {
+            let DebugTraceOutput::Call(call_frame) = trace.result else {
+                panic!("Expected call tracer output");
+            };
+
+            assert_eq!(call_frame.gas_used, 21_000);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn debug_trace_block_pending() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let result = fixture.provider_data.debug_trace_block(
+            &BlockSpec::Tag(BlockTag::Pending),
+            DebugTraceConfig::default(),
+        );
+
+        assert!(matches!(result, Err(ProviderError::InvalidArgument(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn debug_trace_block_unknown() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let last_block_number = fixture.provider_data.last_block_number();
+        let result = fixture.provider_data.debug_trace_block(
+            &BlockSpec::Number(last_block_number + 1),
+            DebugTraceConfig::default(),
+        );
+
+        assert!(matches!(
+            result,
+            Err(ProviderError::InvalidBlockNumberOrHash { .. })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn trace_block_two_transactions() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let transaction1 = fixture.signed_dummy_transaction(0, None)?;
+        let transaction2 = fixture.signed_dummy_transaction(1, None)?;
+        let sender2 = *transaction2.caller();
+
+        let transaction_hash1 = fixture
+            .provider_data
+            .add_pending_transaction(transaction1)?;
+        let transaction_hash2 = fixture
+            .provider_data
+            .add_pending_transaction(transaction2)?;
 
         let result = fixture
             .provider_data
             .mine_and_commit_block(BlockOptions::default())?;
+        let block_number = result.block.header().number;
 
-        // Check that only the first and third transactions were mined
-        assert_eq!(result.block.transactions().len(), 2);
-        assert!(fixture
-            .provider_data
-            .transaction_receipt(transaction1.hash())?
-            .is_some());
-        assert!(fixture
+        let traces = fixture
             .provider_data
-            .transaction_receipt(transaction3.hash())?
-            .is_some());
+            .trace_block(&BlockSpec::Number(block_number))?;
+
+        assert_eq!(traces.len(), 2);
+        assert_eq!(traces[0].transaction_hash, transaction_hash1);
+        assert_eq!(traces[0].transaction_position, 0);
+        assert_eq!(traces[1].transaction_hash, transaction_hash2);
+        assert_eq!(traces[1].transaction_position, 1);
+        assert_eq!(traces[1].block_number, block_number);
+        assert_eq!(traces[1].trace.action.sender(), &sender2);
+        assert_eq!(traces[1].trace.recipient(), Some(&Address::ZERO));
+
+        let filtered = fixture.provider_data.trace_filter(
+            &BlockSpec::Tag(BlockTag::Earliest),
+            &BlockSpec::latest(),
+            &[sender2],
+            &[],
+        )?;
+
+        assert_eq!(filtered, vec![traces[1].clone()]);
+
+        let transaction_traces = fixture
+            .provider_data
+            .trace_transaction(&transaction_hash1)?
+            .context("transaction should exist")?;
+
+        assert_eq!(transaction_traces, vec![traces[0].clone()]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn trace_replay_transaction_state_diff() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let transaction = fixture.signed_dummy_transaction(0, None)?;
+        let sender = *transaction.caller();
+
+        let transaction_hash = fixture.provider_data.add_pending_transaction(transaction)?;
+
+        fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+
+        let result = fixture.provider_data.trace_replay_transaction(
+            &transaction_hash,
+            &[TraceType::Trace, TraceType::StateDiff],
+        )?;
+
+        assert_eq!(result.trace.len(), 1);
+        assert!(result.vm_trace.is_none());
+
+        let state_diff = result.state_diff.context("state diff should be included")?;
+        assert!(state_diff.contains_key(&sender));
+        assert!(state_diff.contains_key(&Address::ZERO));
+
+        Ok(())
+    }
+
+    #[test]
+    fn trace_call_vm_trace() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let contract_address = Address::random();
+
+        // PUSH1 0x2a PUSH1 0x00 MSTORE PUSH1 0x01 PUSH1 0x00 SSTORE STOP
+        let code = vec![
+            0x60, 0x2a, 0x60, 0x00, 0x52, 0x60, 0x01, 0x60, 0x00, 0x55, 0x00,
+        ];
+
+        fixture
+            .provider_data
+            .set_code(contract_address, Bytes::from(code.clone()))?;
+
+        let transaction = resolve_call_request(
+            &mut fixture.provider_data,
+            CallRequest {
+                from: Some(fixture.nth_local_account(0)?),
+                to: Some(contract_address),
+                ..CallRequest::default()
+            },
+            &BlockSpec::latest(),
+            &StateOverrides::default(),
+        )?;
+
+        let result = fixture.provider_data.trace_call(
+            transaction,
+            &BlockSpec::latest(),
+            &[TraceType::VmTrace],
+        )?;
+
+        assert!(result.trace.is_empty());
+
+        let vm_trace = result.vm_trace.context("vm trace should be included")?;
+        assert_eq!(vm_trace.code, Bytes::from(code));
+
+        let ops: Vec<_> = vm_trace.ops.iter().map(|op| op.op.as_str()).collect();
+        assert_eq!(
+            ops,
+            ["PUSH1", "PUSH1", "MSTORE", "PUSH1", "PUSH1", "SSTORE", "STOP"]
+        );
+
+        let push = &vm_trace.ops[0];
+        assert_eq!(push.cost, 3);
+        let push_effects = push.ex.as_ref().context("PUSH1 should succeed")?;
+        assert_eq!(push_effects.push, vec![U256::from(0x2a)]);
+        assert!(push_effects.mem.is_none());
+
+        let mstore_effects = vm_trace.ops[2]
+            .ex
+            .as_ref()
+            .context("MSTORE should succeed")?;
+        let mem = mstore_effects
+            .mem
+            .as_ref()
+            .context("MSTORE should write memory")?;
+        assert_eq!(mem.off, 0);
+        assert_eq!(
+            mem.data,
+            Bytes::from(U256::from(0x2a).to_be_bytes::<32>().to_vec())
+        );
+        assert!(mstore_effects.push.is_empty());
+
+        let sstore_effects = vm_trace.ops[5]
+            .ex
+            .as_ref()
+            .context("SSTORE should succeed")?;
+        let store = sstore_effects
+            .store
+            .as_ref()
+            .context("SSTORE should write storage")?;
+        assert_eq!(store.key, U256::ZERO);
+        assert_eq!(store.val, U256::from(1));
+
+        let second_push_effects = vm_trace.ops[1]
+            .ex
+            .as_ref()
+            .context("PUSH1 should succeed")?;
+        assert_eq!(push_effects.used - 3, second_push_effects.used);
+
+        Ok(())
+    }
+
+    #[test]
+    fn debug_trace_call_prestate_tracer() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let transaction = fixture.signed_dummy_transaction(0, None)?;
+        let sender = *transaction.caller();
+
+        let trace = fixture.provider_data.debug_trace_call(
+            transaction,
+            &BlockSpec::latest(),
+            DebugTraceConfig {
+                tracer: DebugTracer::Prestate(edr_evm::PrestateTracerConfig::default()),
+                ..DebugTraceConfig::default()
+            },
+        )?;
+
+        let DebugTraceOutput::Prestate(edr_evm::PrestateTrace::Prestate(prestate)) = trace else {
+            panic!("Expected prestate tracer output");
+        };
+
+        let sender_prestate = prestate.get(&sender).expect("sender must be traced");
+        assert_eq!(sender_prestate.balance, Some(one_ether()));
+        assert_eq!(sender_prestate.nonce, None);
+        assert!(prestate.contains_key(&Address::ZERO));
+
+        Ok(())
+    }
+
+    #[test]
+    fn debug_trace_transaction_prestate_tracer_diff_mode() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let transaction = fixture.signed_dummy_transaction(0, None)?;
+        let sender = *transaction.caller();
+        let transaction_hash = fixture.provider_data.add_pending_transaction(transaction)?;
+        fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+
+        let trace = fixture.provider_data.debug_trace_transaction(
+            &transaction_hash,
+            DebugTraceConfig {
+                tracer: DebugTracer::Prestate(edr_evm::PrestateTracerConfig { diff_mode: true }),
+                ..DebugTraceConfig::default()
+            },
+        )?;
+
+        let DebugTraceOutput::Prestate(edr_evm::PrestateTrace::Diff { pre, post }) = trace else {
+            panic!("Expected prestate tracer diff output");
+        };
+
+        assert_eq!(pre.get(&sender).and_then(|account| account.nonce), None);
+        assert_eq!(post.get(&sender).and_then(|account| account.nonce), Some(1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn mine_and_commit_block_empty() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let previous_block_number = fixture.provider_data.last_block_number();
+
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+        assert!(result.block.transactions().is_empty());
+
+        let current_block_number = fixture.provider_data.last_block_number();
+        assert_eq!(current_block_number, previous_block_number + 1);
+
+        let cached_state = fixture
+            .provider_data
+            .get_or_compute_state(result.block.header().number)?;
+
+        let calculated_state = fixture.provider_data.blockchain.state_at_block_number(
+            fixture.provider_data.last_block_number(),
+            fixture.provider_data.irregular_state.state_overrides(),
+        )?;
+
+        assert_eq!(cached_state.state_root()?, calculated_state.state_root()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mine_and_commit_blocks_empty() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        fixture
+            .provider_data
+            .mine_and_commit_blocks(1_000_000_000, 1)?;
+
+        let cached_state = fixture
+            .provider_data
+            .get_or_compute_state(fixture.provider_data.last_block_number())?;
+
+        let calculated_state = fixture.provider_data.blockchain.state_at_block_number(
+            fixture.provider_data.last_block_number(),
+            fixture.provider_data.irregular_state.state_overrides(),
+        )?;
+
+        assert_eq!(cached_state.state_root()?, calculated_state.state_root()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mine_and_commit_block_single_transaction() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let transaction = fixture.signed_dummy_transaction(0, None)?;
+        let expected = transaction.value();
+        let receiver = transaction
+            .to()
+            .expect("Dummy transaction should have a receiver");
+
+        fixture.provider_data.add_pending_transaction(transaction)?;
+
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+
+        assert_eq!(result.block.transactions().len(), 1);
+
+        let balance = fixture
+            .provider_data
+            .balance(receiver, Some(&BlockSpec::latest()))?;
+
+        assert_eq!(balance, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mine_and_commit_block_two_transactions_different_senders() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let transaction1 = fixture.signed_dummy_transaction(0, None)?;
+        let transaction2 = fixture.signed_dummy_transaction(1, None)?;
+
+        let receiver = transaction1
+            .to()
+            .expect("Dummy transaction should have a receiver");
+
+        let expected = transaction1.value() + transaction2.value();
+
+        fixture
+            .provider_data
+            .add_pending_transaction(transaction1)?;
+        fixture
+            .provider_data
+            .add_pending_transaction(transaction2)?;
+
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+
+        assert_eq!(result.block.transactions().len(), 2);
+
+        let balance = fixture
+            .provider_data
+            .balance(receiver, Some(&BlockSpec::latest()))?;
+
+        assert_eq!(balance, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mine_and_commit_block_two_transactions_same_sender() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let transaction1 = fixture.signed_dummy_transaction(0, Some(0))?;
+        let transaction2 = fixture.signed_dummy_transaction(0, Some(1))?;
+
+        let receiver = transaction1
+            .to()
+            .expect("Dummy transaction should have a receiver");
+
+        let expected = transaction1.value() + transaction2.value();
+
+        fixture
+            .provider_data
+            .add_pending_transaction(transaction1)?;
+        fixture
+            .provider_data
+            .add_pending_transaction(transaction2)?;
+
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+
+        assert_eq!(result.block.transactions().len(), 2);
+
+        let balance = fixture
+            .provider_data
+            .balance(receiver, Some(&BlockSpec::latest()))?;
+
+        assert_eq!(balance, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mine_and_commit_block_removes_mined_transactions() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let transaction = fixture.signed_dummy_transaction(0, None)?;
+
+        fixture
+            .provider_data
+            .add_pending_transaction(transaction.clone())?;
+
+        let num_pending_transactions = fixture.provider_data.pending_transactions().count();
+        assert_eq!(num_pending_transactions, 1);
+
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+
+        assert_eq!(result.block.transactions().len(), 1);
+
+        let num_pending_transactions = fixture.provider_data.pending_transactions().count();
+        assert_eq!(num_pending_transactions, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mine_and_commit_block_leaves_unmined_transactions() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        // SAFETY: literal is non-zero
+        fixture
+            .provider_data
+            .set_block_gas_limit(unsafe { NonZeroU64::new_unchecked(55_000) })?;
+
+        // Actual gas usage is 21_000
+        let transaction1 = fixture.signed_dummy_transaction(0, Some(0))?;
+        let transaction3 = fixture.signed_dummy_transaction(0, Some(1))?;
+
+        // Too expensive to mine
+        let transaction2 = {
+            let request = fixture.dummy_transaction_request(1, 40_000, None)?;
+            fixture.provider_data.sign_transaction_request(request)?
+        };
+
+        fixture
+            .provider_data
+            .add_pending_transaction(transaction1.clone())?;
+        fixture
+            .provider_data
+            .add_pending_transaction(transaction2.clone())?;
+        fixture
+            .provider_data
+            .add_pending_transaction(transaction3.clone())?;
+
+        let pending_transactions = fixture
+            .provider_data
+            .pending_transactions()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        assert!(pending_transactions.contains(&transaction1));
+        assert!(pending_transactions.contains(&transaction2));
+        assert!(pending_transactions.contains(&transaction3));
+
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+
+        // Check that only the first and third transactions were mined
+        assert_eq!(result.block.transactions().len(), 2);
+        assert!(fixture
+            .provider_data
+            .transaction_receipt(transaction1.hash())?
+            .is_some());
+        assert!(fixture
+            .provider_data
+            .transaction_receipt(transaction3.hash())?
+            .is_some());
+
+        // Check that the second transaction is still pending
+        let pending_transactions = fixture
+            .provider_data
+            .pending_transactions()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        assert_eq!(pending_transactions, vec![transaction2]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mine_and_commit_block_fifo_ordering() -> anyhow::Result<()> {
+        let default_config = create_test_config();
+        let config = ProviderConfig {
+            mining: MiningConfig {
+                mem_pool: MemPoolConfig {
+                    order: MineOrdering::Fifo,
+                    ..MemPoolConfig::default()
+                },
+                ..default_config.mining
+            },
+            ..default_config
+        };
+
+        let mut fixture = ProviderTestFixture::with_config(config)?;
+
+        let transaction1 = fixture.signed_dummy_transaction(0, None)?;
+        let transaction2 = fixture.signed_dummy_transaction(1, None)?;
+
+        fixture
+            .provider_data
+            .add_pending_transaction(transaction1.clone())?;
+        fixture
+            .provider_data
+            .add_pending_transaction(transaction2.clone())?;
+
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+
+        assert_eq!(result.block.transactions().len(), 2);
+
+        let receipt1 = fixture
+            .provider_data
+            .transaction_receipt(transaction1.hash())?
+            .expect("receipt should exist");
+
+        assert_eq!(receipt1.transaction_index, 0);
+
+        let receipt2 = fixture
+            .provider_data
+            .transaction_receipt(transaction2.hash())?
+            .expect("receipt should exist");
+
+        assert_eq!(receipt2.transaction_index, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mine_and_commit_block_runtime_ordering() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let transaction1 = fixture.signed_dummy_transaction(0, None)?;
+        let transaction2 = fixture.signed_dummy_transaction(1, None)?;
+
+        fixture
+            .provider_data
+            .add_pending_transaction(transaction1.clone())?;
+        fixture
+            .provider_data
+            .add_pending_transaction(transaction2.clone())?;
+
+        fixture
+            .provider_data
+            .set_mine_ordering(Box::new(MineOrdering::Explicit(vec![
+                *transaction2.hash(),
+                *transaction1.hash(),
+            ])));
+
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+
+        assert_eq!(result.block.transactions().len(), 2);
+
+        let receipt1 = fixture
+            .provider_data
+            .transaction_receipt(transaction1.hash())?
+            .expect("receipt should exist");
+
+        assert_eq!(receipt1.transaction_index, 1);
+
+        let receipt2 = fixture
+            .provider_data
+            .transaction_receipt(transaction2.hash())?
+            .expect("receipt should exist");
+
+        assert_eq!(receipt2.transaction_index, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mine_and_commit_block_correct_gas_used() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let transaction1 = fixture.signed_dummy_transaction(0, None)?;
+        let transaction2 = fixture.signed_dummy_transaction(1, None)?;
+
+        fixture
+            .provider_data
+            .add_pending_transaction(transaction1.clone())?;
+        fixture
+            .provider_data
+            .add_pending_transaction(transaction2.clone())?;
+
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+
+        let receipt1 = fixture
+            .provider_data
+            .transaction_receipt(transaction1.hash())?
+            .expect("receipt should exist");
+        let receipt2 = fixture
+            .provider_data
+            .transaction_receipt(transaction2.hash())?
+            .expect("receipt should exist");
+
+        assert_eq!(receipt1.gas_used, 21_000);
+        assert_eq!(receipt2.gas_used, 21_000);
+        assert_eq!(
+            result.block.header().gas_used,
+            receipt1.gas_used + receipt2.gas_used
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn next_block_blob_base_fee() -> anyhow::Result<()> {
+        let fixture = ProviderTestFixture::new_local()?;
+        assert_eq!(
+            fixture.provider_data.next_block_blob_base_fee()?,
+            Some(U256::from(1))
+        );
+
+        let default_config = create_test_config();
+        let config = ProviderConfig {
+            hardfork: SpecId::SHANGHAI,
+            ..default_config
+        };
+
+        let fixture = ProviderTestFixture::with_config(config)?;
+        assert_eq!(fixture.provider_data.next_block_blob_base_fee()?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn dump_and_load_state() -> anyhow::Result<()> {
+        let address = Address::random();
+        let code = Bytes::from_static(&[0x60, 0x00]);
+        let coinbase = Address::random();
+
+        let mut source = ProviderTestFixture::new_local()?;
+        source
+            .provider_data
+            .set_balance(address, U256::from(1_000))?;
+        source.provider_data.set_code(address, code.clone())?;
+        source
+            .provider_data
+            .set_account_storage_slot(address, U256::from(7), U256::from(42))?;
+        source.provider_data.set_coinbase(coinbase);
+        source.provider_data.mine_and_commit_blocks(3, 1)?;
+
+        let dump = source.provider_data.dump_state(false)?;
+        assert_eq!(dump.block.number, 3);
+        assert_eq!(dump.block.coinbase, coinbase);
+
+        // The dump survives a JSON round trip
+        let dump: SerializedState = serde_json::from_value(serde_json::to_value(&dump)?)?;
+
+        let mut target = ProviderTestFixture::new_local()?;
+        target.provider_data.load_state(dump.clone())?;
+
+        assert_eq!(target.provider_data.last_block_number(), 3);
+        assert_eq!(target.provider_data.coinbase(), coinbase);
+        assert_eq!(
+            target.provider_data.balance(address, None)?,
+            U256::from(1_000)
+        );
+        assert_eq!(target.provider_data.get_code(address, None)?, code);
+        assert_eq!(
+            target
+                .provider_data
+                .get_storage_at(address, U256::from(7), None)?,
+            U256::from(42)
+        );
+        assert!(
+            target
+                .provider_data
+                .mine_and_commit_block(BlockOptions::default())?
+                .block
+                .header()
+                .timestamp
+                > dump.block.timestamp
+        );
+
+        let unsupported_version = SerializedState {
+            version: SERIALIZED_STATE_VERSION + 1,
+            ..dump
+        };
+        assert!(matches!(
+            target.provider_data.load_state(unsupported_version),
+            Err(ProviderError::UnsupportedStateDumpVersion { .. })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn dump_and_load_state_fork_changes() -> anyhow::Result<()> {
+        let address = Address::random();
+
+        let mut source = ProviderTestFixture::new_forked(None)?;
+        source
+            .provider_data
+            .set_balance(address, U256::from(1_000))?;
+
+        assert!(matches!(
+            source.provider_data.dump_state(false),
+            Err(ProviderError::FullForkStateDumpUnsupported)
+        ));
+
+        let dump = source.provider_data.dump_state(true)?;
+        assert_eq!(dump.fork.as_ref(), source.provider_data.fork_metadata());
+        assert!(dump.state.accounts.contains_key(&address));
+
+        let mut local = ProviderTestFixture::new_local()?;
+        assert!(matches!(
+            local.provider_data.load_state(dump.clone()),
+            Err(ProviderError::StateDumpForkMismatch { .. })
+        ));
+
+        let mut target = ProviderTestFixture::new_forked(None)?;
+        target.provider_data.load_state(dump)?;
+        assert_eq!(
+            target.provider_data.balance(address, None)?,
+            U256::from(1_000)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn dump_and_load_state_fork_recreated_account() -> anyhow::Result<()> {
+        // WETH, of which the first storage slot holds its name
+        let address: Address = "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2".parse()?;
+
+        let mut source = ProviderTestFixture::new_forked(None)?;
+        assert_ne!(
+            source
+                .provider_data
+                .get_storage_at(address, U256::ZERO, None)?,
+            U256::ZERO
+        );
+
+        assert!(source.provider_data.delete_account(address)?);
+        source.provider_data.set_account(
+            address,
+            AccountState {
+                balance: Some(U256::from(1_000)),
+                ..AccountState::default()
+            },
+        )?;
+
+        let dump = source.provider_data.dump_state(true)?;
+        assert!(dump.state.accounts.contains_key(&address));
+        assert!(dump.state.removed_accounts.contains(&address));
+
+        let mut target = ProviderTestFixture::new_forked(None)?;
+        target.provider_data.load_state(dump)?;
+        assert_eq!(
+            target.provider_data.balance(address, None)?,
+            U256::from(1_000)
+        );
+        assert_eq!(
+            target
+                .provider_data
+                .get_storage_at(address, U256::ZERO, None)?,
+            U256::ZERO
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn dump_and_load_state_fork_cleared_storage() -> anyhow::Result<()> {
+        // WETH, of which the first storage slot holds its name
+        let address: Address = "0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2".parse()?;
+
+        let mut source = ProviderTestFixture::new_forked(None)?;
+        source.provider_data.set_account_storage(
+            address,
+            [(U256::from(1), U256::from(7))].into_iter().collect(),
+        )?;
+
+        let dump = source.provider_data.dump_state(true)?;
+        assert!(dump.state.cleared_storage.contains(&address));
+        assert!(!dump.state.removed_accounts.contains(&address));
+
+        let mut target = ProviderTestFixture::new_forked(None)?;
+        target.provider_data.load_state(dump)?;
+        assert_eq!(
+            target
+                .provider_data
+                .get_storage_at(address, U256::ZERO, None)?,
+            U256::ZERO
+        );
+        assert_eq!(
+            target
+                .provider_data
+                .get_storage_at(address, U256::from(1), None)?,
+            U256::from(7)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn persistent_blockchain_restores_after_restart() -> anyhow::Result<()> {
+        let directory = tempfile::tempdir()?;
+        let config = ProviderConfig {
+            persistence_path: Some(directory.path().to_path_buf()),
+            ..create_test_config()
+        };
+
+        let address = Address::random();
+        let (block_hash, transaction_hash) = {
+            let mut fixture = ProviderTestFixture::with_config(config.clone())?;
+
+            let transaction = fixture.signed_dummy_transaction(0, None)?;
+            let transaction_hash = fixture.provider_data.add_pending_transaction(transaction)?;
+            let result = fixture
+                .provider_data
+                .mine_and_commit_block(BlockOptions::default())?;
+
+            fixture
+                .provider_data
+                .set_balance(address, U256::from(1_000))?;
+
+            // Reverted changes should not be restored
+            let snapshot_id = fixture.provider_data.make_snapshot();
+            fixture.provider_data.mine_and_commit_blocks(2, 1)?;
+            fixture
+                .provider_data
+                .set_balance(address, U256::from(2_000))?;
+            assert!(fixture.provider_data.revert_to_snapshot(snapshot_id)?);
+
+            (*result.block.hash(), transaction_hash)
+        };
+
+        let mut fixture = ProviderTestFixture::with_config(config.clone())?;
+        assert_eq!(fixture.provider_data.last_block_number(), 1);
+        assert_eq!(*fixture.provider_data.last_block()?.hash(), block_hash);
+        assert!(fixture
+            .provider_data
+            .transaction_receipt(&transaction_hash)?
+            .is_some());
+        assert_eq!(
+            fixture.provider_data.balance(address, None)?,
+            U256::from(1_000)
+        );
+
+        // Resetting should discard the persisted blockchain
+        fixture.provider_data.reset(None)?;
+        drop(fixture);
+
+        let fixture = ProviderTestFixture::with_config(config)?;
+        assert_eq!(fixture.provider_data.last_block_number(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn persistent_blockchain_rejects_fork() -> anyhow::Result<()> {
+        let directory = tempfile::tempdir()?;
+        let config = ProviderConfig {
+            persistence_path: Some(directory.path().to_path_buf()),
+            ..create_test_config()
+        };
+
+        let fork_config = ForkConfig {
+            json_rpc_url: "http://127.0.0.1:1".to_string(),
+            block_number: None,
+            http_headers: None,
+        };
+
+        let error = ProviderTestFixture::with_config(ProviderConfig {
+            fork: Some(fork_config.clone()),
+            ..config.clone()
+        })
+        .err()
+        .expect("A fork cannot be persisted");
+
+        assert!(matches!(
+            error.downcast_ref::<CreationError>(),
+            Some(CreationError::PersistenceWithFork)
+        ));
+
+        // Resetting to a fork fails without discarding the persisted blockchain
+        let mut fixture = ProviderTestFixture::with_config(config.clone())?;
+        fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+
+        assert!(matches!(
+            fixture.provider_data.reset(Some(fork_config)),
+            Err(CreationError::PersistenceWithFork)
+        ));
+        assert_eq!(fixture.provider_data.last_block_number(), 1);
+        drop(fixture);
+
+        let fixture = ProviderTestFixture::with_config(config)?;
+        assert_eq!(fixture.provider_data.last_block_number(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn persistent_irregular_state_unchanged_after_failed_write() -> anyhow::Result<()> {
+        let directory = tempfile::tempdir()?;
+        let config = ProviderConfig {
+            persistence_path: Some(directory.path().to_path_buf()),
+            ..create_test_config()
+        };
+
+        let mut fixture = ProviderTestFixture::with_config(config)?;
+
+        // Occupy the temporary file's path, to make writing the irregular state fail
+        std::fs::create_dir(directory.path().join("irregular_state.json.tmp"))?;
+
+        let address = Address::random();
+        assert!(matches!(
+            fixture
+                .provider_data
+                .set_balance(address, U256::from(1_000)),
+            Err(ProviderError::Persistence(_))
+        ));
+
+        assert!(fixture
+            .provider_data
+            .irregular_state
+            .state_overrides()
+            .is_empty());
+        assert_eq!(fixture.provider_data.balance(address, None)?, U256::ZERO);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mine_and_commit_block_rewards_miner() -> anyhow::Result<()> {
+        let default_config = create_test_config();
+        let config = ProviderConfig {
+            hardfork: SpecId::BERLIN,
+            ..default_config
+        };
+
+        let mut fixture = ProviderTestFixture::with_config(config)?;
+
+        let miner = fixture.provider_data.beneficiary;
+        let previous_miner_balance = fixture
+            .provider_data
+            .balance(miner, Some(&BlockSpec::latest()))?;
+
+        let transaction = fixture.signed_dummy_transaction(0, None)?;
+        fixture
+            .provider_data
+            .add_pending_transaction(transaction.clone())?;
+
+        fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+
+        let miner_balance = fixture
+            .provider_data
+            .balance(miner, Some(&BlockSpec::latest()))?;
+
+        assert!(miner_balance > previous_miner_balance);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mine_and_commit_blocks_increases_block_number() -> anyhow::Result<()> {
+        const NUM_MINED_BLOCKS: u64 = 10;
+
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let previous_block_number = fixture.provider_data.last_block_number();
+
+        fixture
+            .provider_data
+            .mine_and_commit_blocks(NUM_MINED_BLOCKS, 1)?;
+
+        assert_eq!(
+            fixture.provider_data.last_block_number(),
+            previous_block_number + NUM_MINED_BLOCKS
+        );
+        assert_eq!(
+            fixture.provider_data.last_block()?.header().number,
+            previous_block_number + NUM_MINED_BLOCKS
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn named_snapshot_can_be_reverted_repeatedly() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let fixture_block_number = fixture.provider_data.last_block_number();
+        let snapshot_id = fixture
+            .provider_data
+            .make_named_snapshot(String::from("fixture"))?;
+
+        assert!(matches!(
+            fixture
+                .provider_data
+                .make_named_snapshot(String::from("fixture")),
+            Err(ProviderError::DuplicateSnapshotName(_))
+        ));
+        assert!(matches!(
+            fixture
+                .provider_data
+                .make_named_snapshot(String::from("0x1")),
+            Err(ProviderError::InvalidSnapshotName(_))
+        ));
+
+        let selector = SnapshotSelector::Name(String::from("fixture"));
+        assert_eq!(
+            fixture.provider_data.snapshot_id(&selector),
+            Some(snapshot_id)
+        );
+
+        for _ in 0..2 {
+            fixture.provider_data.mine_and_commit_blocks(3, 1)?;
+            let later_snapshot_id = fixture.provider_data.make_snapshot();
+
+            assert!(fixture
+                .provider_data
+                .revert_to_snapshot_and_keep(snapshot_id)?);
+            assert_eq!(
+                fixture.provider_data.last_block_number(),
+                fixture_block_number
+            );
+
+            // Snapshots after the reverted one are removed
+            assert_eq!(
+                fixture
+                    .provider_data
+                    .snapshot_id(&SnapshotSelector::Id(later_snapshot_id)),
+                None
+            );
+        }
+
+        let snapshots = fixture.provider_data.snapshots();
+        assert_eq!(snapshots.len(), 1);
+        assert_eq!(snapshots[0].id, snapshot_id);
+        assert_eq!(snapshots[0].name.as_deref(), Some("fixture"));
+        assert_eq!(snapshots[0].block_number, fixture_block_number);
+
+        assert!(fixture.provider_data.delete_snapshot(snapshot_id));
+        assert!(fixture.provider_data.snapshots().is_empty());
+        assert!(!fixture
+            .provider_data
+            .revert_to_snapshot_and_keep(snapshot_id)?);
+
+        Ok(())
+    }
+
+    fn logging_transaction(
+        fixture: &ProviderTestFixture,
+        nonce: u64,
+    ) -> anyhow::Result<ExecutableTransaction> {
+        // PUSH1 0x00 PUSH1 0x00 LOG0
+        let request = TransactionRequest::Eip155(Eip155TransactionRequest {
+            kind: TransactionKind::Create,
+            gas_limit: 100_000,
+            gas_price: U256::from(42_000_000_000_u64),
+            value: U256::ZERO,
+            input: Bytes::from(vec![0x60, 0x00, 0x60, 0x00, 0xa0]),
+            nonce,
+            chain_id: fixture.config.chain_id,
+        });
+
+        let sender = fixture.nth_local_account(0)?;
+        Ok(fixture
+            .provider_data
+            .sign_transaction_request(TransactionRequestAndSender { request, sender })?)
+    }
+
+    fn add_unfiltered_log_filter(fixture: &mut ProviderTestFixture) -> anyhow::Result<U256> {
+        Ok(fixture.provider_data.add_log_filter::<false>(LogFilter {
+            from_block: 0,
+            to_block: None,
+            addresses: HashSet::default(),
+            normalized_topics: Vec::new(),
+        })?)
+    }
+
+    fn filter_logs_changes(
+        fixture: &mut ProviderTestFixture,
+        filter_id: &U256,
+    ) -> anyhow::Result<Vec<LogOutput>> {
+        match fixture.provider_data.get_filter_changes(filter_id) {
+            Some(FilteredEvents::Logs(logs)) => Ok(logs),
+            _ => Err(anyhow!("expected log filter")),
+        }
+    }
+
+    #[test]
+    fn reorg_replaces_blocks_and_emits_removed_logs() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let transaction = logging_transaction(&fixture, 0)?;
+        let transaction_hash = fixture.provider_data.add_pending_transaction(transaction)?;
+        let mined = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+        assert_eq!(mined.block.transactions().len(), 1);
+
+        let filter_id = add_unfiltered_log_filter(&mut fixture)?;
+        let logs = filter_logs_changes(&mut fixture, &filter_id)?;
+        assert_eq!(logs.len(), 1);
+        assert!(!logs[0].removed);
+
+        let last_block_number = fixture.provider_data.last_block_number();
+        let reorged = fixture.provider_data.reorg(1, Vec::new())?;
+
+        assert_eq!(reorged.len(), 1);
+        assert_eq!(reorged[0].block.header().number, last_block_number);
+        assert_ne!(reorged[0].block.hash(), mined.block.hash());
+        assert!(reorged[0].block.transactions().is_empty());
+
+        // The removed transaction is pending again
+        assert!(fixture
+            .provider_data
+            .pending_transactions()
+            .any(|transaction| *transaction.hash() == transaction_hash));
+
+        let removed_logs = filter_logs_changes(&mut fixture, &filter_id)?;
+        assert_eq!(removed_logs.len(), 1);
+        assert!(removed_logs[0].removed);
+        assert_eq!(removed_logs[0].transaction_hash, Some(transaction_hash));
+
+        Ok(())
+    }
+
+    #[test]
+    fn mine_and_commit_block_with_transactions() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+        fixture.provider_data.set_auto_mining(false);
+
+        let pending_transaction = fixture.signed_dummy_transaction(0, None)?;
+        let pending_transaction_hash = *pending_transaction.hash();
+        fixture
+            .provider_data
+            .add_pending_transaction(pending_transaction)?;
+
+        let first_transaction = fixture.signed_dummy_transaction(2, None)?;
+        let second_transaction = fixture.signed_dummy_transaction(1, None)?;
+        let expected_hashes = vec![*first_transaction.hash(), *second_transaction.hash()];
+
+        let timestamp = fixture.provider_data.last_block()?.header().timestamp + 100;
+        let extra_data = Bytes::from(&b"explicit"[..]);
+
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block_with_transactions(
+                vec![first_transaction, second_transaction],
+                BlockOptions {
+                    timestamp: Some(timestamp),
+                    extra_data: Some(extra_data.clone()),
+                    ..BlockOptions::default()
+                },
+            )?;
 
-        // Check that the second transaction is still pending
-        let pending_transactions = fixture
+        assert_eq!(
+            result
+                .block
+                .transactions()
+                .iter()
+                .map(|transaction| *transaction.hash())
+                .collect::<Vec<_>>(),
+            expected_hashes
+        );
+        assert_eq!(result.block.header().timestamp, timestamp);
+        assert_eq!(result.block.header().extra_data, extra_data);
+
+        let pending_hashes = fixture
             .provider_data
             .pending_transactions()
-            .cloned()
+            .map(|transaction| *transaction.hash())
             .collect::<Vec<_>>();
+        assert_eq!(pending_hashes, vec![pending_transaction_hash]);
 
-        assert_eq!(pending_transactions, vec![transaction2]);
+        // An invalid transaction fails the whole block
+        let last_block_number = fixture.provider_data.last_block_number();
+        let reused_nonce_transaction = fixture.signed_dummy_transaction(1, Some(0))?;
+        assert!(fixture
+            .provider_data
+            .mine_and_commit_block_with_transactions(
+                vec![reused_nonce_transaction],
+                BlockOptions::default()
+            )
+            .is_err());
+        assert_eq!(fixture.provider_data.last_block_number(), last_block_number);
 
         Ok(())
     }
 
     #[test]
-    fn mine_and_commit_block_fifo_ordering() -> anyhow::Result<()> {
-        let default_config = create_test_config();
-        let config = ProviderConfig {
-            mining: MiningConfig {
-                mem_pool: MemPoolConfig {
-                    order: MineOrdering::Fifo,
-                },
-                ..default_config.mining
-            },
-            ..default_config
-        };
-
-        let runtime = runtime::Builder::new_multi_thread()
-            .worker_threads(1)
-            .enable_all()
-            .thread_name("provider-data-test")
-            .build()?;
+    fn set_next_block_options_applies_to_next_block_only() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
 
-        let mut fixture = ProviderTestFixture::new(runtime, config)?;
+        assert!(matches!(
+            fixture
+                .provider_data
+                .set_next_block_options(MineBlockOptions {
+                    gas_limit: Some(0),
+                    ..MineBlockOptions::default()
+                }),
+            Err(ProviderError::SetBlockGasLimitMustBeGreaterThanZero)
+        ));
 
-        let transaction1 = fixture.signed_dummy_transaction(0, None)?;
-        let transaction2 = fixture.signed_dummy_transaction(1, None)?;
+        let coinbase = Address::random();
+        let extra_data = Bytes::from(&b"next block"[..]);
+        let mix_hash = B256::random();
+        let parent_beacon_block_root = B256::random();
+        let withdrawals = vec![Withdrawal {
+            index: 0,
+            validator_index: 1,
+            address: Address::random(),
+            amount: U256::from(1_000_000_000),
+        }];
 
         fixture
             .provider_data
-            .add_pending_transaction(transaction1.clone())?;
-        fixture
+            .set_next_block_options(MineBlockOptions {
+                coinbase: Some(coinbase),
+                gas_limit: Some(1_000_000),
+                mix_hash: Some(mix_hash),
+                extra_data: Some(extra_data.clone()),
+                parent_beacon_block_root: Some(parent_beacon_block_root),
+                withdrawals: Some(withdrawals.clone()),
+                ..MineBlockOptions::default()
+            })?;
+
+        let result = fixture
             .provider_data
-            .add_pending_transaction(transaction2.clone())?;
+            .mine_and_commit_block(BlockOptions::default())?;
+
+        let header = result.block.header();
+        assert_eq!(header.beneficiary, coinbase);
+        assert_eq!(header.gas_limit, 1_000_000);
+        assert_eq!(header.extra_data, extra_data);
+        assert_eq!(header.mix_hash, mix_hash);
+        assert_eq!(
+            header.parent_beacon_block_root,
+            Some(parent_beacon_block_root)
+        );
+        assert_eq!(result.block.withdrawals(), Some(withdrawals.as_slice()));
 
         let result = fixture
             .provider_data
             .mine_and_commit_block(BlockOptions::default())?;
 
-        assert_eq!(result.block.transactions().len(), 2);
+        let header = result.block.header();
+        assert_eq!(header.beneficiary, fixture.provider_data.coinbase());
+        assert_eq!(header.gas_limit, fixture.provider_data.block_gas_limit());
+        assert_ne!(header.extra_data, extra_data);
+        assert_ne!(header.mix_hash, mix_hash);
+        assert_eq!(result.block.withdrawals(), Some(&[][..]));
 
-        let receipt1 = fixture
+        Ok(())
+    }
+
+    #[test]
+    fn set_next_block_options_restored_by_snapshot() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let extra_data = Bytes::from(&b"next block"[..]);
+        fixture
             .provider_data
-            .transaction_receipt(transaction1.hash())?
-            .expect("receipt should exist");
+            .set_next_block_options(MineBlockOptions {
+                extra_data: Some(extra_data.clone()),
+                ..MineBlockOptions::default()
+            })?;
 
-        assert_eq!(receipt1.transaction_index, 0);
+        let snapshot_id = fixture.provider_data.make_snapshot();
 
-        let receipt2 = fixture
+        let result = fixture
             .provider_data
-            .transaction_receipt(transaction2.hash())?
-            .expect("receipt should exist");
+            .mine_and_commit_block(BlockOptions::default())?;
+        assert_eq!(result.block.header().extra_data, extra_data);
 
-        assert_eq!(receipt2.transaction_index, 1);
+        assert!(fixture.provider_data.revert_to_snapshot(snapshot_id)?);
+
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+        assert_eq!(result.block.header().extra_data, extra_data);
 
         Ok(())
     }
 
     #[test]
-    fn mine_and_commit_block_correct_gas_used() -> anyhow::Result<()> {
+    fn queue_withdrawals_credits_recipients() -> anyhow::Result<()> {
         let mut fixture = ProviderTestFixture::new_local()?;
 
-        let transaction1 = fixture.signed_dummy_transaction(0, None)?;
-        let transaction2 = fixture.signed_dummy_transaction(1, None)?;
+        let recipient = Address::random();
+        let withdrawals = vec![
+            Withdrawal {
+                index: 0,
+                validator_index: 1,
+                address: recipient,
+                amount: U256::from(1_000),
+            },
+            Withdrawal {
+                index: 1,
+                validator_index: 2,
+                address: recipient,
+                amount: U256::from(2_000),
+            },
+        ];
 
         fixture
             .provider_data
-            .add_pending_transaction(transaction1.clone())?;
+            .queue_withdrawals(withdrawals[..1].to_vec())?;
         fixture
             .provider_data
-            .add_pending_transaction(transaction2.clone())?;
+            .queue_withdrawals(withdrawals[1..].to_vec())?;
 
         let result = fixture
             .provider_data
             .mine_and_commit_block(BlockOptions::default())?;
 
-        let receipt1 = fixture
-            .provider_data
-            .transaction_receipt(transaction1.hash())?
-            .expect("receipt should exist");
-        let receipt2 = fixture
-            .provider_data
-            .transaction_receipt(transaction2.hash())?
-            .expect("receipt should exist");
+        assert_eq!(result.block.withdrawals(), Some(withdrawals.as_slice()));
+        assert_ne!(
+            result.block.header().withdrawals_root,
+            Some(KECCAK_NULL_RLP)
+        );
 
-        assert_eq!(receipt1.gas_used, 21_000);
-        assert_eq!(receipt2.gas_used, 21_000);
+        // Amounts are denominated in Gwei
+        let balance = fixture.provider_data.balance(recipient, None)?;
+        assert_eq!(balance, U256::from(3_000_000_000_000u64));
+
+        // Withdrawals are only included in the next block
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+        assert_eq!(result.block.withdrawals(), Some(&[][..]));
         assert_eq!(
-            result.block.header().gas_used,
-            receipt1.gas_used + receipt2.gas_used
+            result.block.header().withdrawals_root,
+            Some(KECCAK_NULL_RLP)
         );
 
         Ok(())
     }
 
     #[test]
-    fn mine_and_commit_block_rewards_miner() -> anyhow::Result<()> {
-        let default_config = create_test_config();
-        let config = ProviderConfig {
-            hardfork: SpecId::BERLIN,
-            ..default_config
-        };
-
-        let runtime = runtime::Builder::new_multi_thread()
-            .worker_threads(1)
-            .enable_all()
-            .thread_name("provider-data-test")
-            .build()?;
+    fn queue_withdrawals_rejects_amount_exceeding_u64() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
 
-        let mut fixture = ProviderTestFixture::new(runtime, config)?;
+        let result = fixture.provider_data.queue_withdrawals(vec![Withdrawal {
+            index: 0,
+            validator_index: 1,
+            address: Address::random(),
+            amount: U256::from(u64::MAX) + U256::from(1),
+        }]);
+        assert!(matches!(result, Err(ProviderError::InvalidArgument(_))));
 
-        let miner = fixture.provider_data.beneficiary;
-        let previous_miner_balance = fixture
+        // Nothing was queued, so the next block can be mined
+        let result = fixture
             .provider_data
-            .balance(miner, Some(&BlockSpec::latest()))?;
+            .mine_and_commit_block(BlockOptions::default())?;
+        assert_eq!(result.block.withdrawals(), Some(&[][..]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn reorg_mines_provided_transactions() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        fixture.provider_data.mine_and_commit_blocks(2, 1)?;
+        let last_block_number = fixture.provider_data.last_block_number();
+
+        let transaction = fixture.signed_dummy_transaction(0, None)?;
+        let transaction_hash = *transaction.hash();
+
+        let reorged = fixture.provider_data.reorg(2, vec![transaction])?;
+        assert_eq!(reorged.len(), 2);
+        assert_eq!(
+            reorged[0]
+                .block
+                .transactions()
+                .iter()
+                .map(|transaction| *transaction.hash())
+                .collect::<Vec<_>>(),
+            vec![transaction_hash]
+        );
+        assert!(reorged[1].block.transactions().is_empty());
+        assert_eq!(fixture.provider_data.last_block_number(), last_block_number);
+        assert_eq!(fixture.provider_data.pending_transactions().count(), 0);
+
+        let max_depth = last_block_number;
+        assert!(matches!(
+            fixture.provider_data.reorg(max_depth + 1, Vec::new()),
+            Err(ProviderError::InvalidReorgDepth { .. })
+        ));
+        assert!(matches!(
+            fixture.provider_data.reorg(0, Vec::new()),
+            Err(ProviderError::InvalidReorgDepth { .. })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn reorg_discards_replaced_transactions_and_snapshots() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let snapshot_before_reorg = fixture.provider_data.make_snapshot();
 
         let transaction = fixture.signed_dummy_transaction(0, None)?;
         fixture
             .provider_data
             .add_pending_transaction(transaction.clone())?;
+        fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+
+        let snapshot_of_removed_block = fixture.provider_data.make_snapshot();
+
+        // The removed transaction's nonce is used by the replacement block
+        let reorged = fixture.provider_data.reorg(1, vec![transaction])?;
+        assert_eq!(reorged[0].block.transactions().len(), 1);
+        assert_eq!(fixture.provider_data.pending_transactions().count(), 0);
+
+        assert!(!fixture
+            .provider_data
+            .revert_to_snapshot(snapshot_of_removed_block)?);
+        assert!(fixture
+            .provider_data
+            .revert_to_snapshot(snapshot_before_reorg)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn reorg_discards_removed_transactions_rejected_by_mem_pool() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
 
+        let transaction = fixture.signed_dummy_transaction(0, None)?;
+        fixture.provider_data.add_pending_transaction(transaction)?;
         fixture
             .provider_data
             .mine_and_commit_block(BlockOptions::default())?;
 
-        let miner_balance = fixture
+        // The removed transaction's gas limit exceeds the new block gas limit
+        fixture
             .provider_data
-            .balance(miner, Some(&BlockSpec::latest()))?;
+            .set_block_gas_limit(NonZeroU64::new(25_000).expect("literal is non-zero"))?;
 
-        assert!(miner_balance > previous_miner_balance);
+        let reorged = fixture.provider_data.reorg(1, Vec::new())?;
+        assert!(reorged[0].block.transactions().is_empty());
+        assert_eq!(fixture.provider_data.pending_transactions().count(), 0);
 
         Ok(())
     }
 
     #[test]
-    fn mine_and_commit_blocks_increases_block_number() -> anyhow::Result<()> {
-        const NUM_MINED_BLOCKS: u64 = 10;
-
+    fn revert_to_snapshot_emits_removed_logs() -> anyhow::Result<()> {
         let mut fixture = ProviderTestFixture::new_local()?;
 
-        let previous_block_number = fixture.provider_data.last_block_number();
+        let snapshot_id = fixture.provider_data.make_snapshot();
+        let filter_id = add_unfiltered_log_filter(&mut fixture)?;
 
+        let transaction = logging_transaction(&fixture, 0)?;
+        fixture.provider_data.add_pending_transaction(transaction)?;
         fixture
             .provider_data
-            .mine_and_commit_blocks(NUM_MINED_BLOCKS, 1)?;
+            .mine_and_commit_block(BlockOptions::default())?;
 
-        assert_eq!(
-            fixture.provider_data.last_block_number(),
-            previous_block_number + NUM_MINED_BLOCKS
-        );
-        assert_eq!(
-            fixture.provider_data.last_block()?.header().number,
-            previous_block_number + NUM_MINED_BLOCKS
-        );
+        let logs = filter_logs_changes(&mut fixture, &filter_id)?;
+        assert_eq!(logs.len(), 1);
+        assert!(!logs[0].removed);
+
+        assert!(fixture.provider_data.revert_to_snapshot(snapshot_id)?);
+
+        let removed_logs = filter_logs_changes(&mut fixture, &filter_id)?;
+        assert_eq!(removed_logs.len(), 1);
+        assert!(removed_logs[0].removed);
 
         Ok(())
     }
@@ -3290,7 +7075,7 @@ mod tests {
             original_block_number + NUM_MINED_BLOCKS
         );
 
-        let reverted = fixture.provider_data.revert_to_snapshot(snapshot_id);
+        let reverted = fixture.provider_data.revert_to_snapshot(snapshot_id)?;
         assert!(reverted);
 
         assert_eq!(
@@ -3313,7 +7098,7 @@ mod tests {
 
         fixture.provider_data.mine_and_commit_blocks(1, 1)?;
 
-        let reverted = fixture.provider_data.revert_to_snapshot(snapshot_id);
+        let reverted = fixture.provider_data.revert_to_snapshot(snapshot_id)?;
         assert!(reverted);
 
         assert_eq!(
@@ -3397,6 +7182,156 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn set_account_replaces_account_state() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let address = Address::random();
+        fixture
+            .provider_data
+            .set_account_storage_slot(address, U256::from(1), U256::from(100))?;
+        fixture
+            .provider_data
+            .set_account_storage_slot(address, U256::from(2), U256::from(200))?;
+
+        let code = Bytes::from_static(&[0x60, 0x00]);
+        fixture.provider_data.set_account(
+            address,
+            AccountState {
+                balance: Some(U256::from(1_000)),
+                nonce: Some(3),
+                code: Some(code.clone()),
+                storage: Some(BTreeMap::from([(U256::from(2), U256::from(202))])),
+            },
+        )?;
+
+        let override_block_number = fixture.provider_data.last_block_number();
+        fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+
+        // Force the states to be recomputed from the irregular state
+        fixture.provider_data.block_state_cache.clear();
+
+        for block_spec in [
+            BlockSpec::Number(override_block_number),
+            BlockSpec::latest(),
+        ] {
+            let provider_data = &mut fixture.provider_data;
+            assert_eq!(
+                provider_data.balance(address, Some(&block_spec))?,
+                U256::from(1_000)
+            );
+            assert_eq!(
+                provider_data.get_transaction_count(address, Some(&block_spec))?,
+                3
+            );
+            assert_eq!(provider_data.get_code(address, Some(&block_spec))?, code);
+            assert_eq!(
+                provider_data.get_storage_at(address, U256::from(1), Some(&block_spec))?,
+                U256::ZERO
+            );
+            assert_eq!(
+                provider_data.get_storage_at(address, U256::from(2), Some(&block_spec))?,
+                U256::from(202)
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_rpc_url_fork_mode() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_forked(None)?;
+
+        let new_url = "http://localhost:8545/";
+        fixture.provider_data.set_rpc_url(new_url)?;
+        assert_eq!(fixture.provider_data.rpc_url().as_deref(), Some(new_url));
+        assert_eq!(
+            fixture
+                .provider_data
+                .fork_config()
+                .map(|fork_config| fork_config.json_rpc_url.as_str()),
+            Some(new_url)
+        );
+
+        assert!(matches!(
+            fixture.provider_data.set_rpc_url("not a url"),
+            Err(ProviderError::InvalidArgument(_))
+        ));
+        assert_eq!(fixture.provider_data.rpc_url().as_deref(), Some(new_url));
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_rpc_url_local_mode() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        assert_eq!(fixture.provider_data.rpc_url(), None);
+        assert!(matches!(
+            fixture.provider_data.set_rpc_url("http://localhost:8545"),
+            Err(ProviderError::InvalidInput(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_account_storage_and_delete_account() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let address = Address::random();
+        fixture
+            .provider_data
+            .set_balance(address, U256::from(1_000))?;
+        fixture
+            .provider_data
+            .set_account_storage_slot(address, U256::from(1), U256::from(100))?;
+
+        fixture.provider_data.set_account_storage(
+            address,
+            std::iter::once((U256::from(2), U256::from(200))).collect(),
+        )?;
+
+        assert_eq!(
+            fixture
+                .provider_data
+                .get_storage_at(address, U256::from(1), None)?,
+            U256::ZERO
+        );
+        assert_eq!(
+            fixture
+                .provider_data
+                .get_storage_at(address, U256::from(2), None)?,
+            U256::from(200)
+        );
+        assert_eq!(
+            fixture.provider_data.balance(address, None)?,
+            U256::from(1_000)
+        );
+
+        assert!(fixture.provider_data.delete_account(address)?);
+        assert!(!fixture.provider_data.delete_account(Address::random())?);
+
+        fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+
+        // Force the states to be recomputed from the irregular state
+        fixture.provider_data.block_state_cache.clear();
+
+        assert_eq!(fixture.provider_data.balance(address, None)?, U256::ZERO);
+        assert_eq!(
+            fixture
+                .provider_data
+                .get_storage_at(address, U256::from(2), None)?,
+            U256::ZERO
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn transaction_by_invalid_hash() -> anyhow::Result<()> {
         let fixture = ProviderTestFixture::new_local()?;
@@ -3590,7 +7525,12 @@ mod tests {
 
             let transaction = resolve_call_request(data, request, &block_spec, &state_overrides)?;
 
-            data.run_call(transaction, &block_spec, &state_overrides)
+            data.run_call(
+                transaction,
+                &block_spec,
+                &state_overrides,
+                &BlockOverrideOptions::default(),
+            )
         }
 
         const EIP_1559_ACTIVATION_BLOCK: u64 = 12_965_000;
@@ -3599,12 +7539,6 @@ mod tests {
         let hello_world_contract_address: Address = HELLO_WORLD_CONTRACT_ADDRESS.parse()?;
         let hello_world_contract_call = HelloCall::new(());
 
-        let runtime = runtime::Builder::new_multi_thread()
-            .worker_threads(1)
-            .enable_all()
-            .thread_name("provider-data-test")
-            .build()?;
-
         let default_config = create_test_config_with_fork(Some(ForkConfig {
             json_rpc_url: get_alchemy_url(),
             block_number: Some(EIP_1559_ACTIVATION_BLOCK),
@@ -3621,7 +7555,7 @@ mod tests {
             ..default_config
         };
 
-        let mut fixture = ProviderTestFixture::new(runtime, config)?;
+        let mut fixture = ProviderTestFixture::with_config(config)?;
 
         let default_call = CallRequest {
             from: Some(fixture.nth_local_account(0)?),
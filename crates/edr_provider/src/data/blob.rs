@@ -0,0 +1,182 @@
+use std::sync::Arc;
+
+use edr_eth::{transaction::BlobSidecar, Bytes, B256};
+use edr_evm::{blob_versioned_hash, blockchain::BlockchainError, FixedBytes, SyncBlock};
+use rpds::HashTrieMapSync;
+
+/// A blob that was included in a mined block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MinedBlob {
+    /// The index of the blob within the block
+    pub index: u64,
+    /// The hash of the transaction that included the blob
+    pub transaction_hash: B256,
+    /// The versioned hash of the blob
+    pub versioned_hash: B256,
+    /// The blob
+    pub blob: Bytes,
+    /// The KZG commitment of the blob
+    pub commitment: FixedBytes<48>,
+    /// The KZG proof of the blob
+    pub proof: FixedBytes<48>,
+}
+
+/// Store for the sidecars of blob transactions, both pending and mined.
+///
+/// The store is backed by persistent maps, so it's cheap to clone for
+/// snapshots.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct BlobStore {
+    /// Mapping of transaction hashes to the sidecars of pending transactions
+    pending_sidecars: HashTrieMapSync<B256, Arc<BlobSidecar>>,
+    /// Mapping of block numbers to the blobs included in the block
+    block_number_to_blobs: HashTrieMapSync<u64, Arc<Vec<MinedBlob>>>,
+    /// Mapping of versioned hashes to the block number and index of the blob
+    versioned_hash_to_location: HashTrieMapSync<B256, (u64, usize)>,
+}
+
+impl BlobStore {
+    /// Adds the sidecar of a pending transaction.
+    pub fn add_pending_sidecar(&mut self, transaction_hash: B256, sidecar: BlobSidecar) {
+        self.pending_sidecars
+            .insert_mut(transaction_hash, Arc::new(sidecar));
+    }
+
+    /// Whether the sidecar of the pending transaction is known.
+    pub fn has_pending_sidecar(&self, transaction_hash: &B256) -> bool {
+        self.pending_sidecars.contains_key(transaction_hash)
+    }
+
+    /// Removes the sidecar of a pending transaction.
+    pub fn remove_pending_sidecar(&mut self, transaction_hash: &B256) {
+        self.pending_sidecars.remove_mut(transaction_hash);
+    }
+
+    /// Moves the sidecars of the block's transactions from the pending
+    /// sidecars to the mined blobs. Sidecars of transactions that are no
+    /// longer pending, according to the provided predicate, are discarded.
+    pub fn commit_block(
+        &mut self,
+        block: &dyn SyncBlock<Error = BlockchainError>,
+        is_pending: impl Fn(&B256) -> bool,
+    ) {
+        let mut blobs = Vec::new();
+        for transaction in block.transactions() {
+            let transaction_hash = transaction.hash();
+            let Some(sidecar) = self.pending_sidecars.get(transaction_hash).cloned() else {
+                continue;
+            };
+
+            self.pending_sidecars.remove_mut(transaction_hash);
+
+            let BlobSidecar {
+                blobs: transaction_blobs,
+                commitments,
+                proofs,
+            } = sidecar.as_ref();
+
+            for ((blob, commitment), proof) in transaction_blobs.iter().zip(commitments).zip(proofs)
+            {
+                blobs.push(MinedBlob {
+                    index: u64::try_from(blobs.len()).expect("usize fits into u64"),
+                    transaction_hash: *transaction_hash,
+                    versioned_hash: blob_versioned_hash(commitment),
+                    blob: blob.clone(),
+                    commitment: *commitment,
+                    proof: *proof,
+                });
+            }
+        }
+
+        let stale_transaction_hashes = self
+            .pending_sidecars
+            .keys()
+            .filter(|transaction_hash| !is_pending(transaction_hash))
+            .copied()
+            .collect::<Vec<_>>();
+
+        for transaction_hash in stale_transaction_hashes {
+            self.pending_sidecars.remove_mut(&transaction_hash);
+        }
+
+        if blobs.is_empty() {
+            return;
+        }
+
+        let block_number = block.header().number;
+        for (index, blob) in blobs.iter().enumerate() {
+            self.versioned_hash_to_location
+                .insert_mut(blob.versioned_hash, (block_number, index));
+        }
+
+        self.block_number_to_blobs
+            .insert_mut(block_number, Arc::new(blobs));
+    }
+
+    /// Moves the blobs of blocks after the provided block number back to the
+    /// pending sidecars, as their transactions are pending again.
+    pub fn revert_to_block(&mut self, block_number: u64) {
+        let reverted_block_numbers = self
+            .block_number_to_blobs
+            .keys()
+            .filter(|number| **number > block_number)
+            .copied()
+            .collect::<Vec<_>>();
+
+        for number in reverted_block_numbers {
+            let Some(blobs) = self.block_number_to_blobs.get(&number).cloned() else {
+                continue;
+            };
+
+            self.block_number_to_blobs.remove_mut(&number);
+
+            let mut sidecars: Vec<(B256, BlobSidecar)> = Vec::new();
+            for blob in blobs.iter() {
+                self.versioned_hash_to_location
+                    .remove_mut(&blob.versioned_hash);
+
+                let is_new_transaction = sidecars.last().map_or(true, |(transaction_hash, _)| {
+                    *transaction_hash != blob.transaction_hash
+                });
+
+                if is_new_transaction {
+                    sidecars.push((
+                        blob.transaction_hash,
+                        BlobSidecar {
+                            blobs: Vec::new(),
+                            commitments: Vec::new(),
+                            proofs: Vec::new(),
+                        },
+                    ));
+                }
+
+                let (_, sidecar) = sidecars.last_mut().expect("a sidecar was pushed");
+                sidecar.blobs.push(blob.blob.clone());
+                sidecar.commitments.push(blob.commitment);
+                sidecar.proofs.push(blob.proof);
+            }
+
+            for (transaction_hash, sidecar) in sidecars {
+                self.add_pending_sidecar(transaction_hash, sidecar);
+            }
+        }
+    }
+
+    /// Retrieves the blobs that were included in the block with the provided
+    /// number.
+    pub fn blobs_by_block_number(&self, block_number: u64) -> Vec<MinedBlob> {
+        self.block_number_to_blobs
+            .get(&block_number)
+            .map_or_else(Vec::new, |blobs| blobs.as_ref().clone())
+    }
+
+    /// Retrieves the mined blob with the provided versioned hash.
+    pub fn blob_by_versioned_hash(&self, versioned_hash: &B256) -> Option<MinedBlob> {
+        let (block_number, index) = self.versioned_hash_to_location.get(versioned_hash)?;
+
+        self.block_number_to_blobs
+            .get(block_number)
+            .and_then(|blobs| blobs.get(*index))
+            .cloned()
+    }
+}
@@ -0,0 +1,208 @@
+use core::fmt::Debug;
+use std::sync::Arc;
+
+use edr_eth::{block::BlockOptions, Address, B256, U256};
+use edr_evm::{
+    blockchain::{BlockchainError, SyncBlockchain},
+    db::StateRef,
+    keccak256,
+    state::{StateError, SyncState},
+    BlockBuilder, CfgEnvWithHandlerCfg, DebugContext, ExecutableTransaction,
+    ExecutionResultWithContext, MineBlockError, SyncBlock, TransactionBundle,
+};
+
+use crate::{
+    data::{BundleTransactionResult, CallBundleResult},
+    ProviderError,
+};
+
+/// A bundle of transactions submitted with `eth_sendBundle`, that is waiting
+/// to be mined.
+#[derive(Clone, Debug)]
+pub struct PendingBundle {
+    /// The transactions of the bundle
+    pub bundle: TransactionBundle,
+    /// The number of the block that the bundle targets. If not provided, the
+    /// bundle targets the next block.
+    pub block_number: Option<u64>,
+    /// The minimum timestamp of the block that the bundle can be included in
+    pub min_timestamp: Option<u64>,
+    /// The maximum timestamp of the block that the bundle can be included in
+    pub max_timestamp: Option<u64>,
+}
+
+impl PendingBundle {
+    /// Returns whether the bundle can be included in a block with the
+    /// provided number and timestamp.
+    pub fn is_includable(&self, block_number: u64, timestamp: u64) -> bool {
+        self.block_number
+            .map_or(true, |target| target == block_number)
+            && self.min_timestamp.map_or(true, |min| timestamp >= min)
+            && self.max_timestamp.map_or(true, |max| timestamp <= max)
+    }
+
+    /// Returns whether the bundle contains the transaction with the provided
+    /// hash.
+    pub fn contains_transaction(&self, transaction_hash: &B256) -> bool {
+        self.bundle
+            .transactions
+            .iter()
+            .any(|transaction| transaction.hash() == transaction_hash)
+    }
+
+    /// Returns whether the bundle can still be included after the block with
+    /// the provided number has been mined.
+    pub fn is_expired(&self, mined_block_number: u64) -> bool {
+        self.block_number
+            .map_or(true, |target| target <= mined_block_number)
+    }
+}
+
+/// Computes the hash of a bundle, which is the Keccak-256 hash of the
+/// concatenation of its transaction hashes.
+pub(super) fn bundle_hash(transactions: &[ExecutableTransaction]) -> B256 {
+    let transaction_hashes = transactions
+        .iter()
+        .flat_map(|transaction| transaction.hash().0)
+        .collect::<Vec<u8>>();
+
+    keccak256(transaction_hashes)
+}
+
+pub(super) struct CallBundleArgs<'a> {
+    pub blockchain: &'a dyn SyncBlockchain<BlockchainError, StateError>,
+    pub parent: &'a Arc<dyn SyncBlock<Error = BlockchainError>>,
+    pub state: Box<dyn SyncState<StateError>>,
+    pub cfg_env: CfgEnvWithHandlerCfg,
+    pub transactions: Vec<ExecutableTransaction>,
+    pub beneficiary: Address,
+    pub block_gas_limit: u64,
+    pub block_number: Option<u64>,
+    pub timestamp: Option<u64>,
+}
+
+/// Executes the bundle's transactions in a block on top of the parent block,
+/// without committing any changes.
+pub(super) fn call_bundle<LoggerErrorT: Debug>(
+    args: CallBundleArgs<'_>,
+) -> Result<CallBundleResult, ProviderError<LoggerErrorT>> {
+    let CallBundleArgs {
+        blockchain,
+        parent,
+        mut state,
+        cfg_env,
+        transactions,
+        beneficiary,
+        block_gas_limit,
+        block_number,
+        timestamp,
+    } = args;
+
+    let parent_header = parent.header();
+    let options = BlockOptions {
+        beneficiary: Some(beneficiary),
+        number: Some(block_number.unwrap_or(parent_header.number + 1)),
+        gas_limit: Some(block_gas_limit),
+        timestamp: Some(timestamp.unwrap_or(parent_header.timestamp + 1)),
+        ..BlockOptions::default()
+    };
+
+    let mut block_builder =
+        BlockBuilder::new(cfg_env, &**parent, options, None).map_err(MineBlockError::from)?;
+
+    let base_fee = block_builder.header().base_fee;
+    let bundle_hash = bundle_hash(&transactions);
+
+    let mut transaction_results = Vec::with_capacity(transactions.len());
+    for transaction in transactions {
+        let transaction_hash = *transaction.hash();
+        let caller = *transaction.caller();
+        let to = transaction.to();
+        let miner_tip = effective_miner_tip(&transaction, base_fee);
+
+        let coinbase_balance_before = coinbase_balance(&*state, beneficiary)?;
+
+        let debug_context: Option<DebugContext<'_, _, (), _>> = None;
+        let ExecutionResultWithContext {
+            result,
+            evm_context,
+        } = block_builder.add_transaction(blockchain, state, transaction, debug_context);
+
+        state = evm_context.state;
+
+        let execution_result = result.map_err(MineBlockError::from)?;
+        let coinbase_balance_after = coinbase_balance(&*state, beneficiary)?;
+
+        transaction_results.push(BundleTransactionResult {
+            transaction_hash,
+            caller,
+            to,
+            gas_fees: miner_tip * U256::from(execution_result.gas_used()),
+            coinbase_diff: coinbase_balance_after.saturating_sub(coinbase_balance_before),
+            execution_result,
+        });
+    }
+
+    Ok(CallBundleResult {
+        bundle_hash,
+        state_block_number: parent_header.number,
+        transaction_results,
+    })
+}
+
+fn coinbase_balance<LoggerErrorT: Debug>(
+    state: &dyn SyncState<StateError>,
+    coinbase: Address,
+) -> Result<U256, ProviderError<LoggerErrorT>> {
+    Ok(state
+        .basic(coinbase)?
+        .map_or(U256::ZERO, |account| account.balance))
+}
+
+/// Returns the part of the gas price that is paid to the miner.
+fn effective_miner_tip(transaction: &ExecutableTransaction, base_fee: Option<U256>) -> U256 {
+    let max_fee_per_gas = transaction.gas_price();
+    let max_priority_fee_per_gas = transaction
+        .max_priority_fee_per_gas()
+        .unwrap_or(max_fee_per_gas);
+
+    base_fee.map_or(max_fee_per_gas, |base_fee| {
+        max_priority_fee_per_gas.min(max_fee_per_gas.saturating_sub(base_fee))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pending_bundle(
+        block_number: Option<u64>,
+        min_timestamp: Option<u64>,
+        max_timestamp: Option<u64>,
+    ) -> PendingBundle {
+        PendingBundle {
+            bundle: TransactionBundle {
+                transactions: Vec::new(),
+                reverting_transaction_hashes: Vec::new(),
+            },
+            block_number,
+            min_timestamp,
+            max_timestamp,
+        }
+    }
+
+    #[test]
+    fn pending_bundle_inclusion() {
+        let untargeted = pending_bundle(None, None, None);
+        assert!(untargeted.is_includable(5, 100));
+        assert!(untargeted.is_expired(5));
+
+        let targeted = pending_bundle(Some(5), Some(100), Some(200));
+        assert!(!targeted.is_includable(4, 150));
+        assert!(targeted.is_includable(5, 150));
+        assert!(!targeted.is_includable(5, 99));
+        assert!(!targeted.is_includable(5, 201));
+        assert!(!targeted.is_expired(4));
+        assert!(targeted.is_expired(5));
+    }
+}
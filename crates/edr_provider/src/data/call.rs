@@ -2,13 +2,15 @@ use core::fmt::Debug;
 
 use edr_eth::{
     block::{BlobGas, Header},
+    remote::BlockOverrideOptions,
     SpecId, U256,
 };
 use edr_evm::{
     blockchain::{BlockchainError, SyncBlockchain},
     guaranteed_dry_run,
     state::{StateError, StateOverrides, StateRefOverrider, SyncState},
-    BlobExcessGasAndPrice, BlockEnv, CfgEnvWithHandlerCfg, DebugContext, ExecutionResult, TxEnv,
+    BlobExcessGasAndPrice, BlockEnv, CfgEnvWithHandlerCfg, DebugContext, ExecutionResult,
+    ResultAndState, TxEnv,
 };
 
 use crate::ProviderError;
@@ -21,6 +23,7 @@ where
     pub header: &'a Header,
     pub state: &'a dyn SyncState<StateError>,
     pub state_overrides: &'a StateOverrides,
+    pub block_overrides: &'a BlockOverrideOptions,
     pub cfg_env: CfgEnvWithHandlerCfg,
     pub tx_env: TxEnv,
     pub debug_context: Option<
@@ -37,6 +40,17 @@ where
 pub(super) fn run_call<'a, 'evm, DebugDataT, LoggerErrorT: Debug>(
     args: RunCallArgs<'a, 'evm, DebugDataT>,
 ) -> Result<ExecutionResult, ProviderError<LoggerErrorT>>
+where
+    'a: 'evm,
+{
+    run_call_with_state(args).map(|ResultAndState { result, .. }| result)
+}
+
+/// Execute a transaction as a call. Returns the execution result and the
+/// resulting state changes, without committing them.
+pub(super) fn run_call_with_state<'a, 'evm, DebugDataT, LoggerErrorT: Debug>(
+    args: RunCallArgs<'a, 'evm, DebugDataT>,
+) -> Result<ResultAndState, ProviderError<LoggerErrorT>>
 where
     'a: 'evm,
 {
@@ -45,12 +59,13 @@ where
         header,
         state,
         state_overrides,
+        block_overrides,
         cfg_env,
         tx_env,
         debug_context,
     } = args;
 
-    let block = BlockEnv {
+    let mut block = BlockEnv {
         number: U256::from(header.number),
         coinbase: header.beneficiary,
         timestamp: U256::from(header.timestamp),
@@ -68,6 +83,8 @@ where
             .map(|BlobGas { excess_gas, .. }| BlobExcessGasAndPrice::new(*excess_gas)),
     };
 
+    apply_block_overrides(&mut block, block_overrides);
+
     guaranteed_dry_run(
         blockchain,
         state,
@@ -77,8 +94,41 @@ where
         block,
         debug_context,
     )
-    .map_or_else(
-        |error| Err(ProviderError::RunTransaction(error)),
-        |result| Ok(result.result),
-    )
+    .map_err(ProviderError::RunTransaction)
+}
+
+/// Applies the provided overrides to the block environment of a call.
+fn apply_block_overrides(block: &mut BlockEnv, overrides: &BlockOverrideOptions) {
+    let BlockOverrideOptions {
+        number,
+        timestamp,
+        base_fee,
+        coinbase,
+        prev_randao,
+        gas_limit,
+    } = overrides;
+
+    if let Some(number) = number {
+        block.number = U256::from(*number);
+    }
+
+    if let Some(timestamp) = timestamp {
+        block.timestamp = U256::from(*timestamp);
+    }
+
+    if let Some(base_fee) = base_fee {
+        block.basefee = *base_fee;
+    }
+
+    if let Some(coinbase) = coinbase {
+        block.coinbase = *coinbase;
+    }
+
+    if let Some(prev_randao) = prev_randao {
+        block.prevrandao = Some(*prev_randao);
+    }
+
+    if let Some(gas_limit) = gas_limit {
+        block.gas_limit = U256::from(*gas_limit);
+    }
 }
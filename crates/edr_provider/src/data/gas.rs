@@ -1,7 +1,9 @@
 use core::fmt::Debug;
 use std::cmp;
 
-use edr_eth::{block::Header, reward_percentile::RewardPercentile, U256};
+use edr_eth::{
+    block::Header, remote::BlockOverrideOptions, reward_percentile::RewardPercentile, U256,
+};
 use edr_evm::{
     blockchain::{BlockchainError, SyncBlockchain},
     state::{StateError, StateOverrides, SyncState},
@@ -20,6 +22,7 @@ pub(super) struct CheckGasLimitArgs<'a> {
     pub header: &'a Header,
     pub state: &'a dyn SyncState<StateError>,
     pub state_overrides: &'a StateOverrides,
+    pub block_overrides: &'a BlockOverrideOptions,
     pub cfg_env: CfgEnvWithHandlerCfg,
     pub tx_env: TxEnv,
     pub gas_limit: u64,
@@ -37,6 +40,7 @@ pub(super) fn check_gas_limit<LoggerErrorT: Debug>(
         header,
         state,
         state_overrides,
+        block_overrides,
         cfg_env,
         mut tx_env,
         gas_limit,
@@ -50,6 +54,7 @@ pub(super) fn check_gas_limit<LoggerErrorT: Debug>(
         header,
         state,
         state_overrides,
+        block_overrides,
         cfg_env,
         tx_env,
         debug_context: Some(DebugContext {
@@ -66,6 +71,7 @@ pub(super) struct BinarySearchEstimationArgs<'a> {
     pub header: &'a Header,
     pub state: &'a dyn SyncState<StateError>,
     pub state_overrides: &'a StateOverrides,
+    pub block_overrides: &'a BlockOverrideOptions,
     pub cfg_env: CfgEnvWithHandlerCfg,
     pub tx_env: TxEnv,
     pub lower_bound: u64,
@@ -86,6 +92,7 @@ pub(super) fn binary_search_estimation<LoggerErrorT: Debug>(
         header,
         state,
         state_overrides,
+        block_overrides,
         cfg_env,
         tx_env,
         mut lower_bound,
@@ -109,6 +116,7 @@ pub(super) fn binary_search_estimation<LoggerErrorT: Debug>(
             header,
             state,
             state_overrides,
+            block_overrides,
             cfg_env: cfg_env.clone(),
             tx_env: tx_env.clone(),
             gas_limit: mid,
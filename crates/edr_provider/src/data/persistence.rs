@@ -0,0 +1,112 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use edr_evm::state::IrregularState;
+
+/// The file that the blockchain's journal is persisted to, relative to the
+/// persistence directory.
+const BLOCKCHAIN_FILE: &str = "blockchain.jsonl";
+/// The file that the irregular state is persisted to, relative to the
+/// persistence directory.
+const IRREGULAR_STATE_FILE: &str = "irregular_state.json";
+
+/// The on-disk location of a persisted local blockchain.
+#[derive(Clone, Debug)]
+pub(crate) struct PersistencePaths {
+    directory: PathBuf,
+}
+
+impl PersistencePaths {
+    /// Constructs a new instance for the provided directory.
+    pub fn new(directory: PathBuf) -> Self {
+        Self { directory }
+    }
+
+    /// The path of the blockchain's journal.
+    pub fn blockchain(&self) -> PathBuf {
+        self.directory.join(BLOCKCHAIN_FILE)
+    }
+
+    /// Reads the persisted irregular state, defaulting to an empty state if
+    /// none has been persisted.
+    pub fn read_irregular_state(&self) -> io::Result<IrregularState> {
+        match fs::read(self.irregular_state()) {
+            Ok(contents) => Ok(serde_json::from_slice(&contents)?),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(IrregularState::default()),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Persists the provided irregular state.
+    pub fn write_irregular_state(&self, irregular_state: &IrregularState) -> io::Result<()> {
+        let path = self.irregular_state();
+        let contents = serde_json::to_vec(irregular_state)?;
+
+        // Write to a temporary file first, so an interrupted write cannot
+        // corrupt the previously persisted state.
+        let temp_path = path.with_extension("json.tmp");
+        fs::write(&temp_path, contents)?;
+        fs::rename(temp_path, path)
+    }
+
+    /// Moves the persisted blockchain and irregular state aside, so a new
+    /// blockchain can be persisted. Any previous backup is overwritten.
+    pub fn back_up(&self) -> io::Result<()> {
+        for path in self.files() {
+            match fs::rename(&path, backup_path(&path)) {
+                Err(error) if error.kind() != io::ErrorKind::NotFound => return Err(error),
+                _ => (),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Restores the persisted blockchain and irregular state from their
+    /// backup, discarding anything that was persisted since.
+    pub fn restore_backup(&self) -> io::Result<()> {
+        for path in self.files() {
+            remove_if_exists(&path)?;
+
+            match fs::rename(backup_path(&path), &path) {
+                Err(error) if error.kind() != io::ErrorKind::NotFound => return Err(error),
+                _ => (),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes the backup of the persisted blockchain and irregular state.
+    pub fn remove_backup(&self) -> io::Result<()> {
+        for path in self.files() {
+            remove_if_exists(&backup_path(&path))?;
+        }
+
+        Ok(())
+    }
+
+    fn files(&self) -> [PathBuf; 2] {
+        [self.blockchain(), self.irregular_state()]
+    }
+
+    fn irregular_state(&self) -> PathBuf {
+        self.directory.join(IRREGULAR_STATE_FILE)
+    }
+}
+
+fn backup_path(path: &Path) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(".bak");
+
+    path.with_file_name(file_name)
+}
+
+fn remove_if_exists(path: &Path) -> io::Result<()> {
+    match fs::remove_file(path) {
+        Err(error) if error.kind() != io::ErrorKind::NotFound => Err(error),
+        _ => Ok(()),
+    }
+}
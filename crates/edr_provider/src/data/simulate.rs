@@ -0,0 +1,572 @@
+use core::fmt::Debug;
+use std::sync::Arc;
+
+use edr_eth::{
+    block::{BlockOptions, Header},
+    log::Log,
+    remote::{eth::CallRequest, BlockOverrideOptions, StateOverrideOptions},
+    transaction::{
+        Eip1559TransactionRequest, Eip155TransactionRequest, Eip2930TransactionRequest,
+        TransactionRequest,
+    },
+    Address, Bytes, SpecId, B256, U256,
+};
+use edr_evm::{
+    blockchain::{Blockchain, BlockchainError, SyncBlockchain},
+    db::StateRef,
+    interpreter::{opcode, CallScheme},
+    state::{AccountModifierFn, AccountOverride, StateError, StorageOverride, SyncState},
+    trace::{register_trace_collector_handles, Trace, TraceCollector, TraceMessage},
+    BlockBuilder, BlockTransactionError, BuildBlockResult, CfgEnvWithHandlerCfg, DebugContext,
+    ExecutableTransaction, ExecutionResult, ExecutionResultWithContext, MineBlockError, Output,
+    SyncBlock,
+};
+
+use crate::{
+    data::{SimulatedBlockResult, SimulatedCallResult},
+    pending::BlockchainWithPending,
+    requests::eth::SimulateBlock,
+    ProviderError,
+};
+
+/// The address that synthetic ETH transfer logs are attributed to, following
+/// the convention used by geth.
+const TRANSFER_LOG_ADDRESS: Address = Address::new([0xee; 20]);
+
+/// Keccak-256 hash of `Transfer(address,address,uint256)`
+const TRANSFER_EVENT_TOPIC: B256 = B256::new([
+    0xdd, 0xf2, 0x52, 0xad, 0x1b, 0xe2, 0xc8, 0x9b, 0x69, 0xc2, 0xb0, 0x68, 0xfc, 0x37, 0x8d, 0xaa,
+    0x95, 0x2b, 0xa7, 0xf1, 0x63, 0xc4, 0xa1, 0x16, 0x28, 0xf5, 0x5a, 0x4d, 0xf5, 0x23, 0xb3, 0xef,
+]);
+
+/// The number of seconds between consecutive simulated blocks, if no
+/// timestamp override is provided.
+const SIMULATED_BLOCK_INTERVAL: u64 = 12;
+
+/// The maximum number of blocks that can be simulated in a single request,
+/// including filler blocks.
+const MAX_SIMULATED_BLOCKS: u64 = 256;
+
+pub(super) struct SimulateArgs<'a> {
+    pub blockchain: &'a dyn SyncBlockchain<BlockchainError, StateError>,
+    pub parent: &'a Arc<dyn SyncBlock<Error = BlockchainError>>,
+    pub state: Box<dyn SyncState<StateError>>,
+    pub cfg_env: CfgEnvWithHandlerCfg,
+    pub blocks: Vec<SimulateBlock>,
+    pub beneficiary: Address,
+    pub block_gas_limit: u64,
+    pub default_caller: Address,
+    pub trace_transfers: bool,
+    pub validation: bool,
+}
+
+/// Simulates the provided blocks of calls on top of the parent block. Every
+/// simulated block builds on top of the previous one, but none of them are
+/// committed to the blockchain.
+pub(super) fn simulate_blocks<LoggerErrorT: Debug>(
+    args: SimulateArgs<'_>,
+) -> Result<Vec<SimulatedBlockResult>, ProviderError<LoggerErrorT>> {
+    let SimulateArgs {
+        blockchain,
+        parent,
+        mut state,
+        mut cfg_env,
+        blocks,
+        beneficiary,
+        block_gas_limit,
+        default_caller,
+        trace_transfers,
+        validation,
+    } = args;
+
+    if !validation {
+        cfg_env.disable_balance_check = true;
+        cfg_env.disable_base_fee = true;
+    }
+
+    let spec_id = cfg_env.handler_cfg.spec_id;
+
+    let mut blockchain = BlockchainWithPending::without_pending(blockchain);
+    let mut parent = parent.clone();
+    let mut results = Vec::with_capacity(blocks.len());
+
+    for SimulateBlock {
+        block_overrides,
+        state_overrides,
+        calls,
+    } in blocks
+    {
+        let block_overrides = block_overrides.unwrap_or_default();
+
+        // Gaps between the simulated block numbers are filled with empty blocks
+        if let Some(number) = block_overrides.number {
+            let filler_blocks = number.saturating_sub(parent.header().number + 1);
+            if results.len() as u64 + filler_blocks >= MAX_SIMULATED_BLOCKS {
+                return Err(ProviderError::InvalidInput(format!(
+                    "Too many blocks to simulate. At most {MAX_SIMULATED_BLOCKS} blocks, including filler blocks, can be simulated"
+                )));
+            }
+
+            for _ in 0..filler_blocks {
+                let options = simulated_block_options(
+                    parent.header(),
+                    BlockOverrideOptions::default(),
+                    beneficiary,
+                    block_gas_limit,
+                    spec_id,
+                    validation,
+                )?;
+
+                let block_builder = BlockBuilder::new(cfg_env.clone(), &*parent, options, None)
+                    .map_err(MineBlockError::from)?;
+
+                parent = finalize_simulated_block(&mut blockchain, &mut state, block_builder)?;
+                results.push(SimulatedBlockResult {
+                    block: parent.clone(),
+                    call_results: Vec::new(),
+                });
+            }
+        }
+
+        let options = simulated_block_options(
+            parent.header(),
+            block_overrides,
+            beneficiary,
+            block_gas_limit,
+            spec_id,
+            validation,
+        )?;
+
+        if let Some(state_overrides) = state_overrides {
+            apply_state_overrides(&mut state, state_overrides)?;
+        }
+
+        let mut block_builder = BlockBuilder::new(cfg_env.clone(), &*parent, options, None)
+            .map_err(MineBlockError::from)?;
+
+        let mut call_results = Vec::with_capacity(calls.len());
+        for request in calls {
+            let base_fee = block_builder.header().base_fee.unwrap_or(U256::ZERO);
+            let caller = request.from.unwrap_or(default_caller);
+            let nonce = state.basic(caller)?.map_or(0, |account| account.nonce);
+            let gas_limit = request.gas.unwrap_or_else(|| block_builder.gas_remaining());
+
+            let transaction = resolve_simulated_call(
+                request,
+                spec_id,
+                cfg_env.chain_id,
+                caller,
+                nonce,
+                gas_limit,
+                base_fee,
+            )?;
+
+            let mut trace_collector = TraceCollector::default();
+            let debug_context = if trace_transfers {
+                Some(DebugContext {
+                    data: &mut trace_collector,
+                    register_handles_fn: register_trace_collector_handles,
+                })
+            } else {
+                None
+            };
+
+            let ExecutionResultWithContext {
+                result,
+                evm_context,
+            } = block_builder.add_transaction(&blockchain, state, transaction, debug_context);
+
+            state = evm_context.state;
+
+            let execution_result = match result {
+                Ok(execution_result) => execution_result,
+                Err(BlockTransactionError::BlockHash(error)) => {
+                    return Err(ProviderError::Blockchain(error))
+                }
+                Err(BlockTransactionError::State(error)) => {
+                    return Err(ProviderError::State(error))
+                }
+                // Calls that can't be included in the block don't abort the simulation
+                Err(error) => {
+                    call_results.push(SimulatedCallResult::Rejected(error.to_string()));
+                    continue;
+                }
+            };
+
+            let logs = if trace_transfers {
+                let trace = trace_collector
+                    .into_traces()
+                    .pop()
+                    .expect("Each transaction must have a trace");
+
+                logs_with_transfers(&trace, execution_result.logs())
+            } else {
+                execution_result.logs().to_vec()
+            };
+
+            call_results.push(SimulatedCallResult::Included {
+                execution_result,
+                logs,
+            });
+        }
+
+        parent = finalize_simulated_block(&mut blockchain, &mut state, block_builder)?;
+        results.push(SimulatedBlockResult {
+            block: parent.clone(),
+            call_results,
+        });
+    }
+
+    Ok(results)
+}
+
+/// Finalizes a simulated block and adds it to the pending blocks, returning
+/// the new block.
+fn finalize_simulated_block<LoggerErrorT: Debug>(
+    blockchain: &mut BlockchainWithPending<'_>,
+    state: &mut Box<dyn SyncState<StateError>>,
+    block_builder: BlockBuilder,
+) -> Result<Arc<dyn SyncBlock<Error = BlockchainError>>, ProviderError<LoggerErrorT>> {
+    // Simulated blocks don't pay out block rewards.
+    let BuildBlockResult { block, state_diff } = block_builder
+        .finalize(state, Vec::new())
+        .map_err(ProviderError::State)?;
+
+    blockchain.push_pending_block(block, state_diff);
+    Ok(blockchain.last_block()?)
+}
+
+/// Creates the options for a simulated block, taking into account the block
+/// overrides.
+fn simulated_block_options<LoggerErrorT: Debug>(
+    parent: &Header,
+    block_overrides: BlockOverrideOptions,
+    beneficiary: Address,
+    block_gas_limit: u64,
+    spec_id: SpecId,
+    validation: bool,
+) -> Result<BlockOptions, ProviderError<LoggerErrorT>> {
+    let BlockOverrideOptions {
+        number,
+        timestamp,
+        base_fee,
+        coinbase,
+        prev_randao,
+        gas_limit,
+    } = block_overrides;
+
+    let number = number.unwrap_or(parent.number + 1);
+    if number <= parent.number {
+        return Err(ProviderError::InvalidInput(format!(
+            "Simulated block number {number} must be greater than the previous block number {}",
+            parent.number
+        )));
+    }
+
+    let timestamp = timestamp.unwrap_or(parent.timestamp + SIMULATED_BLOCK_INTERVAL);
+    if timestamp <= parent.timestamp {
+        return Err(ProviderError::InvalidInput(format!(
+            "Simulated block timestamp {timestamp} must be greater than the previous block timestamp {}",
+            parent.timestamp
+        )));
+    }
+
+    // Without validation, transactions don't need to pay the base fee, so it's
+    // reported as zero.
+    let base_fee = if spec_id >= SpecId::LONDON && !validation {
+        Some(base_fee.unwrap_or(U256::ZERO))
+    } else {
+        base_fee
+    };
+
+    Ok(BlockOptions {
+        beneficiary: Some(coinbase.unwrap_or(beneficiary)),
+        number: Some(number),
+        gas_limit: Some(gas_limit.unwrap_or(block_gas_limit)),
+        timestamp: Some(timestamp),
+        mix_hash: prev_randao,
+        base_fee,
+        ..BlockOptions::default()
+    })
+}
+
+/// Applies the state overrides of a simulated block directly to the state, as
+/// they persist for subsequent blocks.
+fn apply_state_overrides<LoggerErrorT: Debug>(
+    state: &mut Box<dyn SyncState<StateError>>,
+    state_overrides: StateOverrideOptions,
+) -> Result<(), ProviderError<LoggerErrorT>> {
+    for (address, account_override) in state_overrides {
+        let AccountOverride {
+            balance,
+            nonce,
+            code,
+            storage,
+        } = AccountOverride::try_from(account_override)?;
+
+        if let Some(StorageOverride::Full(_)) = &storage {
+            // Clear the existing storage, by re-inserting the account.
+            let account_info = state.remove_account(address)?.unwrap_or_default();
+            state.insert_account(address, account_info)?;
+        }
+
+        state.modify_account(
+            address,
+            AccountModifierFn::new(Box::new(
+                move |account_balance, account_nonce, account_code| {
+                    if let Some(balance) = balance {
+                        *account_balance = balance;
+                    }
+
+                    if let Some(nonce) = nonce {
+                        *account_nonce = nonce;
+                    }
+
+                    if let Some(code) = &code {
+                        *account_code = Some(code.clone());
+                    }
+                },
+            )),
+        )?;
+
+        if let Some(StorageOverride::Diff(storage) | StorageOverride::Full(storage)) = storage {
+            for (index, value) in storage {
+                state.set_account_storage_slot(address, index, value)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Converts a call request into a transaction that can be included in a
+/// simulated block.
+fn resolve_simulated_call<LoggerErrorT: Debug>(
+    request: CallRequest,
+    spec_id: SpecId,
+    chain_id: u64,
+    caller: Address,
+    nonce: u64,
+    gas_limit: u64,
+    base_fee: U256,
+) -> Result<ExecutableTransaction, ProviderError<LoggerErrorT>> {
+    let CallRequest {
+        to,
+        gas_price,
+        max_fee_per_gas,
+        max_priority_fee_per_gas,
+        value,
+        data: input,
+        access_list,
+        ..
+    } = request;
+
+    let input = input.map_or(Bytes::new(), Bytes::from);
+    let value = value.unwrap_or(U256::ZERO);
+
+    let transaction = if spec_id < SpecId::LONDON || gas_price.is_some() {
+        let gas_price = gas_price.unwrap_or(base_fee);
+        match access_list {
+            Some(access_list) if spec_id >= SpecId::BERLIN => {
+                TransactionRequest::Eip2930(Eip2930TransactionRequest {
+                    nonce,
+                    gas_price,
+                    gas_limit,
+                    value,
+                    input,
+                    kind: to.into(),
+                    chain_id,
+                    access_list,
+                })
+            }
+            _ => TransactionRequest::Eip155(Eip155TransactionRequest {
+                nonce,
+                gas_price,
+                gas_limit,
+                kind: to.into(),
+                value,
+                input,
+                chain_id,
+            }),
+        }
+    } else {
+        let max_priority_fee_per_gas = max_priority_fee_per_gas.unwrap_or(U256::ZERO);
+        let max_fee_per_gas =
+            max_fee_per_gas.unwrap_or_else(|| base_fee + max_priority_fee_per_gas);
+
+        TransactionRequest::Eip1559(Eip1559TransactionRequest {
+            chain_id,
+            nonce,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            gas_limit,
+            kind: to.into(),
+            value,
+            input,
+            access_list: access_list.unwrap_or_default(),
+        })
+    };
+
+    let transaction = transaction.fake_sign(&caller);
+    ExecutableTransaction::with_caller(spec_id, transaction, caller)
+        .map_err(ProviderError::TransactionCreationError)
+}
+
+/// A log entry of a call frame.
+enum FrameLog {
+    /// A synthetic ETH transfer log
+    Transfer(Log),
+    /// A log emitted by the EVM
+    Emitted,
+}
+
+struct Frame {
+    transfer: Option<(Address, Option<Address>, U256)>,
+    logs: Vec<FrameLog>,
+}
+
+/// Interleaves the emitted logs of a transaction with synthetic logs for its
+/// ETH transfers, in order of execution. Transfers and logs of reverted call
+/// frames are discarded.
+fn logs_with_transfers(trace: &Trace, emitted_logs: &[Log]) -> Vec<Log> {
+    let mut stack: Vec<Frame> = Vec::new();
+    let mut root_logs = Vec::new();
+
+    for message in &trace.messages {
+        match message {
+            TraceMessage::Before(message) => {
+                let is_transfer = message.value > U256::ZERO
+                    && !matches!(
+                        message.call_scheme,
+                        Some(CallScheme::DelegateCall | CallScheme::StaticCall)
+                    );
+
+                stack.push(Frame {
+                    transfer: is_transfer.then_some((message.caller, message.to, message.value)),
+                    logs: Vec::new(),
+                });
+            }
+            TraceMessage::Step(step) => {
+                if (opcode::LOG0..=opcode::LOG4).contains(&step.opcode) {
+                    if let Some(frame) = stack.last_mut() {
+                        frame.logs.push(FrameLog::Emitted);
+                    }
+                }
+            }
+            TraceMessage::After(result) => {
+                let Some(frame) = stack.pop() else {
+                    continue;
+                };
+
+                if !result.is_success() {
+                    continue;
+                }
+
+                let parent_logs = stack
+                    .last_mut()
+                    .map_or(&mut root_logs, |parent| &mut parent.logs);
+
+                if let Some((from, to, value)) = frame.transfer {
+                    let to = to.or_else(|| match result {
+                        ExecutionResult::Success {
+                            output: Output::Create(_, address),
+                            ..
+                        } => *address,
+                        _ => None,
+                    });
+
+                    if let Some(to) = to {
+                        parent_logs.push(FrameLog::Transfer(transfer_log(from, to, value)));
+                    }
+                }
+
+                parent_logs.extend(frame.logs);
+            }
+        }
+    }
+
+    let mut emitted_logs = emitted_logs.iter();
+    let mut logs: Vec<Log> = root_logs
+        .into_iter()
+        .filter_map(|log| match log {
+            FrameLog::Transfer(log) => Some(log),
+            FrameLog::Emitted => emitted_logs.next().cloned(),
+        })
+        .collect();
+
+    // Any logs that couldn't be matched to a step are appended, to never lose
+    // emitted logs.
+    logs.extend(emitted_logs.cloned());
+
+    logs
+}
+
+fn transfer_log(from: Address, to: Address, value: U256) -> Log {
+    Log::new_unchecked(
+        TRANSFER_LOG_ADDRESS,
+        vec![TRANSFER_EVENT_TOPIC, from.into_word(), to.into_word()],
+        Bytes::from(value.to_be_bytes::<32>()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use edr_evm::{trace::BeforeMessage, SuccessReason};
+
+    use super::*;
+
+    fn before(caller: Address, to: Address, value: U256) -> TraceMessage {
+        TraceMessage::Before(BeforeMessage {
+            depth: 0,
+            caller,
+            to: Some(to),
+            gas_limit: 21_000,
+            data: Bytes::new(),
+            value,
+            code_address: Some(to),
+            code: None,
+            call_scheme: Some(CallScheme::Call),
+        })
+    }
+
+    fn success() -> TraceMessage {
+        TraceMessage::After(ExecutionResult::Success {
+            reason: SuccessReason::Stop,
+            gas_used: 21_000,
+            gas_refunded: 0,
+            logs: Vec::new(),
+            output: Output::Call(Bytes::new()),
+        })
+    }
+
+    #[test]
+    fn logs_with_transfers_discards_reverted_frames() {
+        let sender = Address::random();
+        let contract = Address::random();
+        let recipient = Address::random();
+
+        let trace = Trace {
+            messages: vec![
+                before(sender, contract, U256::from(10)),
+                before(contract, recipient, U256::from(3)),
+                TraceMessage::After(ExecutionResult::Revert {
+                    gas_used: 0,
+                    output: Bytes::new(),
+                }),
+                before(contract, recipient, U256::from(5)),
+                success(),
+                success(),
+            ],
+            return_value: Bytes::new(),
+        };
+
+        let logs = logs_with_transfers(&trace, &[]);
+        assert_eq!(
+            logs,
+            vec![
+                transfer_log(sender, contract, U256::from(10)),
+                transfer_log(contract, recipient, U256::from(5)),
+            ]
+        );
+    }
+}
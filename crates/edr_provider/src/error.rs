@@ -11,8 +11,8 @@ use edr_evm::{
     hex,
     state::{AccountOverrideConversionError, StateError},
     trace::Trace,
-    DebugTraceError, ExecutionResult, HaltReason, MemPoolAddTransactionError, MineBlockError,
-    OutOfGasError, TransactionCreationError, TransactionError,
+    BlobSidecarError, DebugTraceError, ExecutionResult, HaltReason, MemPoolAddTransactionError,
+    MineBlockError, OutOfGasError, TransactionCreationError, TransactionError,
 };
 use ethers_core::types::transaction::eip712::Eip712Error;
 
@@ -41,6 +41,9 @@ pub enum ProviderError<LoggerErrorT> {
     /// The transaction nonce is too high, while automatically mining.
     #[error("Nonce too low. Expected nonce to be {expected} but got {actual}. Note that transactions can't be queued when automining.")]
     AutoMineNonceTooLow { expected: u64, actual: u64 },
+    /// The blob sidecar of an EIP-4844 transaction is invalid.
+    #[error(transparent)]
+    BlobSidecar(#[from] BlobSidecarError),
     /// Blockchain error
     #[error(transparent)]
     Blockchain(#[from] BlockchainError),
@@ -48,13 +51,19 @@ pub enum ProviderError<LoggerErrorT> {
     Creation(#[from] CreationError),
     #[error(transparent)]
     DebugTrace(#[from] DebugTraceError<BlockchainError, StateError>),
-    #[error("An EIP-4844 (shard blob) transaction was received, but Hardhat doesn't have support for them yet.")]
+    /// A snapshot with the provided name already exists.
+    #[error("A snapshot named '{0}' already exists.")]
+    DuplicateSnapshotName(String),
+    #[error("An EIP-4844 (shard blob) transaction was received, but Hardhat only supports them as raw transactions with their blobs, commitments, and proofs.")]
     Eip4844TransactionUnsupported,
     #[error(transparent)]
     Eip712Error(#[from] Eip712Error),
     /// A transaction error occurred while estimating gas.
     #[error(transparent)]
     EstimateGasTransactionFailure(#[from] EstimateGasFailure),
+    /// The full state of a forked network cannot be dumped.
+    #[error("Dumping the full state of a forked network is not supported. Use the 'onlyChanges' option to dump the changes relative to the fork block.")]
+    FullForkStateDumpUnsupported,
     #[error("{0}")]
     InvalidArgument(String),
     /// Block number or hash doesn't exist in blockchain
@@ -87,6 +96,12 @@ pub enum ProviderError<LoggerErrorT> {
     },
     #[error("{0}")]
     InvalidInput(String),
+    /// The reorg depth is out of range.
+    #[error("Invalid reorg depth {depth}. The depth must be between 1 and {max_depth}.")]
+    InvalidReorgDepth { depth: u64, max_depth: u64 },
+    /// The snapshot name is ambiguous with a snapshot ID.
+    #[error("Invalid snapshot name '{0}'. Snapshot names must not start with '0x'.")]
+    InvalidSnapshotName(String),
     /// Transaction hash doesn't exist on the blockchain.
     #[error("Transaction hash '{0}' doesn't exist on the blockchain.")]
     InvalidTransactionHash(B256),
@@ -110,6 +125,9 @@ pub enum ProviderError<LoggerErrorT> {
     /// An error occurred while mining a block.
     #[error(transparent)]
     MineBlock(#[from] MineBlockError<BlockchainError, StateError>),
+    /// An error occurred while persisting the irregular state to disk.
+    #[error("Failed to persist the irregular state: {0}")]
+    Persistence(std::io::Error),
     /// Rpc client error
     #[error(transparent)]
     RpcClientError(#[from] RpcClientError),
@@ -151,6 +169,13 @@ pub enum ProviderError<LoggerErrorT> {
     /// State error
     #[error(transparent)]
     State(#[from] StateError),
+    /// A state dump that is relative to a fork was loaded into a provider that
+    /// isn't forked from the same block.
+    #[error("The state dump only contains the changes relative to block {fork_block_number} of chain {chain_id}, so it can only be loaded when forking from that block.")]
+    StateDumpForkMismatch {
+        chain_id: u64,
+        fork_block_number: u64,
+    },
     /// System time error
     #[error(transparent)]
     SystemTime(#[from] SystemTimeError),
@@ -189,8 +214,16 @@ pub enum ProviderError<LoggerErrorT> {
         current_hardfork: SpecId,
         minimum_hardfork: SpecId,
     },
+    #[error("The transaction contains EIP-4844 parameters, but they are not supported by the current hardfork: {current_hardfork:?}")]
+    UnsupportedEip4844Parameters {
+        current_hardfork: SpecId,
+        minimum_hardfork: SpecId,
+    },
     #[error("{method_name} - Method not supported")]
     UnsupportedMethod { method_name: String },
+    /// The state dump has an unsupported version.
+    #[error("Unsupported state dump version {version}. Only version {supported} is supported.")]
+    UnsupportedStateDumpVersion { version: u64, supported: u64 },
 }
 
 impl<LoggerErrorT: Debug> From<ProviderError<LoggerErrorT>> for jsonrpc::Error {
@@ -207,12 +240,15 @@ impl<LoggerErrorT: Debug> From<ProviderError<LoggerErrorT>> for jsonrpc::Error {
             ProviderError::AutoMineNonceTooHigh { .. } => INVALID_INPUT,
             ProviderError::AutoMineNonceTooLow { .. } => INVALID_INPUT,
             ProviderError::AutoMinePriorityFeeTooLow { .. } => INVALID_INPUT,
+            ProviderError::BlobSidecar(_) => INVALID_INPUT,
             ProviderError::Blockchain(_) => INVALID_INPUT,
             ProviderError::Creation(_) => INVALID_INPUT,
             ProviderError::DebugTrace(_) => INTERNAL_ERROR,
+            ProviderError::DuplicateSnapshotName(_) => INVALID_PARAMS,
             ProviderError::Eip4844TransactionUnsupported => INVALID_INPUT,
             ProviderError::Eip712Error(_) => INVALID_INPUT,
             ProviderError::EstimateGasTransactionFailure(_) => INVALID_INPUT,
+            ProviderError::FullForkStateDumpUnsupported => INVALID_PARAMS,
             ProviderError::InvalidArgument(_) => INVALID_PARAMS,
             ProviderError::InvalidBlockNumberOrHash { .. } => INVALID_INPUT,
             ProviderError::InvalidBlockTag { .. } => INVALID_PARAMS,
@@ -221,6 +257,8 @@ impl<LoggerErrorT: Debug> From<ProviderError<LoggerErrorT>> for jsonrpc::Error {
             ProviderError::InvalidEip155TransactionChainId => INVALID_PARAMS,
             ProviderError::InvalidFilterSubscriptionType { .. } => INVALID_PARAMS,
             ProviderError::InvalidInput(_) => INVALID_INPUT,
+            ProviderError::InvalidReorgDepth { .. } => INVALID_PARAMS,
+            ProviderError::InvalidSnapshotName(_) => INVALID_PARAMS,
             ProviderError::InvalidTransactionHash { .. } => INVALID_PARAMS,
             ProviderError::InvalidTransactionIndex(_) => INVALID_PARAMS,
             ProviderError::InvalidTransactionInput(_) => INVALID_INPUT,
@@ -229,6 +267,7 @@ impl<LoggerErrorT: Debug> From<ProviderError<LoggerErrorT>> for jsonrpc::Error {
             ProviderError::MemPoolAddTransaction(_) => INVALID_INPUT,
             ProviderError::MemPoolUpdate(_) => INVALID_INPUT,
             ProviderError::MineBlock(_) => INVALID_INPUT,
+            ProviderError::Persistence(_) => INTERNAL_ERROR,
             ProviderError::RpcClientError(_) => INTERNAL_ERROR,
             ProviderError::RpcVersion(_) => INVALID_INPUT,
             ProviderError::RunTransaction(_) => INVALID_INPUT,
@@ -242,6 +281,7 @@ impl<LoggerErrorT: Debug> From<ProviderError<LoggerErrorT>> for jsonrpc::Error {
             ProviderError::SetNextPrevRandaoUnsupported { .. } => INVALID_INPUT,
             ProviderError::Signature(_) => INVALID_INPUT,
             ProviderError::State(_) => INVALID_INPUT,
+            ProviderError::StateDumpForkMismatch { .. } => INVALID_PARAMS,
             ProviderError::SystemTime(_) => INVALID_INPUT,
             ProviderError::TimestampLowerThanPrevious { .. } => INVALID_INPUT,
             ProviderError::TimestampEqualsPrevious { .. } => INVALID_INPUT,
@@ -253,7 +293,9 @@ impl<LoggerErrorT: Debug> From<ProviderError<LoggerErrorT>> for jsonrpc::Error {
             ProviderError::UnmetHardfork { .. } => INVALID_PARAMS,
             ProviderError::UnsupportedAccessListParameter { .. } => INVALID_PARAMS,
             ProviderError::UnsupportedEIP1559Parameters { .. } => INVALID_PARAMS,
+            ProviderError::UnsupportedEip4844Parameters { .. } => INVALID_PARAMS,
             ProviderError::UnsupportedMethod { .. } => -32004,
+            ProviderError::UnsupportedStateDumpVersion { .. } => INVALID_PARAMS,
         };
 
         let data = match &value {
@@ -41,6 +41,11 @@ impl Filter {
         )
     }
 
+    /// Constructs a new dropped transaction filter.
+    pub fn new_dropped_transaction_filter(is_subscription: bool) -> Self {
+        Self::new_filter(FilterData::DroppedTransactions(Vec::new()), is_subscription)
+    }
+
     fn new_filter(data: FilterData, is_subscription: bool) -> Self {
         Self {
             deadline: new_filter_deadline(),
@@ -84,6 +89,8 @@ pub enum FilterData {
     NewHeads(Vec<B256>),
     /// new pending transactions
     NewPendingTransactions(Vec<B256>),
+    /// transactions dropped from the mempool
+    DroppedTransactions(Vec<B256>),
 }
 
 impl FilterData {
@@ -93,6 +100,7 @@ impl FilterData {
             Self::Logs { logs, .. } => FilteredEvents::Logs(mem::take(logs)),
             Self::NewHeads(v) => FilteredEvents::NewHeads(mem::take(v)),
             Self::NewPendingTransactions(v) => FilteredEvents::NewPendingTransactions(mem::take(v)),
+            Self::DroppedTransactions(v) => FilteredEvents::DroppedTransactions(mem::take(v)),
         }
     }
 
@@ -102,6 +110,7 @@ impl FilterData {
             Self::Logs { .. } => SubscriptionType::Logs,
             Self::NewHeads(_) => SubscriptionType::NewHeads,
             Self::NewPendingTransactions(_) => SubscriptionType::NewPendingTransactions,
+            Self::DroppedTransactions(_) => SubscriptionType::DroppedTransactions,
         }
     }
 }
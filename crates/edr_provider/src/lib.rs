@@ -35,20 +35,25 @@ pub use self::{
     logger::{Logger, NoopLogger},
     mock::CallOverrideResult,
     requests::{
-        hardhat::rpc_types as hardhat_rpc_types, IntervalConfig as IntervalConfigRequest,
-        InvalidRequestReason, MethodInvocation, ProviderRequest, U64OrUsize,
+        anvil::{AnvilForkConfig, NodeEnvironment, NodeForkConfig, NodeInfo},
+        debug::DebugTraceConfig,
+        hardhat::rpc_types as hardhat_rpc_types,
+        trace::TraceFilter,
+        IntervalConfig as IntervalConfigRequest, InvalidRequestReason, MethodInvocation,
+        ProviderRequest, U64OrUsize,
     },
     subscribe::*,
 };
 use self::{
     data::{CreationError, ProviderData},
     interval::IntervalMiner,
-    requests::{debug, eth, hardhat},
+    requests::{anvil, debug, eth, hardhat, trace, txpool},
 };
 
 lazy_static! {
     pub static ref PRIVATE_RPC_METHODS: HashSet<&'static str> = {
         [
+            "anvil_setLoggingEnabled",
             "hardhat_getStackTraceFailuresCount",
             "hardhat_setLoggingEnabled",
         ]
@@ -202,19 +207,42 @@ impl<LoggerErrorT: Debug + Send + Sync + 'static> Provider<LoggerErrorT> {
         let result = match request {
             // eth_* method
             MethodInvocation::Accounts(()) => eth::handle_accounts_request(data).and_then(to_json),
+            MethodInvocation::BlobBaseFee(()) => eth::handle_blob_base_fee(data).and_then(to_json),
             MethodInvocation::BlockNumber(()) => {
                 eth::handle_block_number_request(data).and_then(to_json)
             }
-            MethodInvocation::Call(request, block_spec, state_overrides) => {
-                eth::handle_call_request(data, request, block_spec, state_overrides)
-                    .and_then(to_json_with_trace)
+            MethodInvocation::Call(request, block_spec, state_overrides, block_overrides) => {
+                eth::handle_call_request(
+                    data,
+                    request,
+                    block_spec,
+                    state_overrides,
+                    block_overrides,
+                )
+                .and_then(to_json_with_trace)
+            }
+            MethodInvocation::CallBundle(request) => {
+                eth::handle_call_bundle_request(data, request).and_then(to_json)
             }
             MethodInvocation::ChainId(()) => eth::handle_chain_id_request(data).and_then(to_json),
             MethodInvocation::Coinbase(()) => eth::handle_coinbase_request(data).and_then(to_json),
-            MethodInvocation::EstimateGas(call_request, block_spec) => {
-                eth::handle_estimate_gas(data, call_request, block_spec)
-                    .and_then(to_json_with_traces)
+            MethodInvocation::CreateAccessList(call_request, block_spec) => {
+                eth::handle_create_access_list_request(data, call_request, block_spec)
+                    .and_then(to_json)
             }
+            MethodInvocation::EstimateGas(
+                call_request,
+                block_spec,
+                state_overrides,
+                block_overrides,
+            ) => eth::handle_estimate_gas(
+                data,
+                call_request,
+                block_spec,
+                state_overrides,
+                block_overrides,
+            )
+            .and_then(to_json_with_traces),
             MethodInvocation::FeeHistory(block_count, newest_block, reward_percentiles) => {
                 eth::handle_fee_history(data, block_count, newest_block, reward_percentiles)
                     .and_then(to_json)
@@ -231,6 +259,9 @@ impl<LoggerErrorT: Debug + Send + Sync + 'static> Provider<LoggerErrorT> {
                 eth::handle_get_block_by_hash_request(data, block_hash, transaction_detail_flag)
                     .and_then(to_json)
             }
+            MethodInvocation::GetBlockReceipts(block_spec) => {
+                eth::handle_get_block_receipts_request(data, block_spec).and_then(to_json)
+            }
             MethodInvocation::GetBlockTransactionCountByHash(block_hash) => {
                 eth::handle_get_block_transaction_count_by_hash_request(data, block_hash)
                     .and_then(to_json)
@@ -251,6 +282,10 @@ impl<LoggerErrorT: Debug + Send + Sync + 'static> Provider<LoggerErrorT> {
             MethodInvocation::GetLogs(filter_options) => {
                 eth::handle_get_logs_request(data, filter_options).and_then(to_json)
             }
+            MethodInvocation::GetProof(address, storage_keys, block_spec) => {
+                eth::handle_get_proof_request(data, address, storage_keys, block_spec)
+                    .and_then(to_json)
+            }
             MethodInvocation::GetStorageAt(address, index, block_spec) => {
                 eth::handle_get_storage_at_request(data, address, index, block_spec)
                     .and_then(to_json)
@@ -295,6 +330,9 @@ impl<LoggerErrorT: Debug + Send + Sync + 'static> Provider<LoggerErrorT> {
             MethodInvocation::PendingTransactions(()) => {
                 eth::handle_pending_transactions(data).and_then(to_json)
             }
+            MethodInvocation::SendBundle(request) => {
+                eth::handle_send_bundle_request(data, request).and_then(to_json)
+            }
             MethodInvocation::SendRawTransaction(raw_transaction) => {
                 eth::handle_send_raw_transaction_request(data, raw_transaction)
                     .and_then(to_json_with_traces)
@@ -309,6 +347,9 @@ impl<LoggerErrorT: Debug + Send + Sync + 'static> Provider<LoggerErrorT> {
             MethodInvocation::SignTypedDataV4(address, message) => {
                 eth::handle_sign_typed_data_v4(data, address, message).and_then(to_json)
             }
+            MethodInvocation::SimulateV1(payload, block_spec) => {
+                eth::handle_simulate_v1_request(data, payload, block_spec).and_then(to_json)
+            }
             MethodInvocation::Subscribe(subscription_type, filter_options) => {
                 eth::handle_subscribe_request(data, subscription_type, filter_options)
                     .and_then(to_json)
@@ -360,6 +401,13 @@ impl<LoggerErrorT: Debug + Send + Sync + 'static> Provider<LoggerErrorT> {
             }
 
             // debug_* methods
+            MethodInvocation::DebugTraceBlockByHash(block_hash, config) => {
+                debug::handle_debug_trace_block_by_hash(data, block_hash, config).and_then(to_json)
+            }
+            MethodInvocation::DebugTraceBlockByNumber(block_spec, config) => {
+                debug::handle_debug_trace_block_by_number(data, block_spec, config)
+                    .and_then(to_json)
+            }
             MethodInvocation::DebugTraceTransaction(transaction_hash, config) => {
                 debug::handle_debug_trace_transaction(data, transaction_hash, config)
                     .and_then(to_json)
@@ -369,16 +417,131 @@ impl<LoggerErrorT: Debug + Send + Sync + 'static> Provider<LoggerErrorT> {
                     .and_then(to_json)
             }
 
+            // trace_* methods
+            MethodInvocation::TraceBlock(block_spec) => {
+                trace::handle_trace_block(data, block_spec).and_then(to_json)
+            }
+            MethodInvocation::TraceCall(call_request, trace_types, block_spec) => {
+                trace::handle_trace_call(data, call_request, trace_types, block_spec)
+                    .and_then(to_json)
+            }
+            MethodInvocation::TraceFilter(filter) => {
+                trace::handle_trace_filter(data, filter).and_then(to_json)
+            }
+            MethodInvocation::TraceReplayTransaction(transaction_hash, trace_types) => {
+                trace::handle_trace_replay_transaction(data, transaction_hash, trace_types)
+                    .and_then(to_json)
+            }
+            MethodInvocation::TraceTransaction(transaction_hash) => {
+                trace::handle_trace_transaction(data, transaction_hash).and_then(to_json)
+            }
+
+            // txpool_* methods
+            MethodInvocation::TxPoolContent(()) => {
+                txpool::handle_txpool_content(data).and_then(to_json)
+            }
+            MethodInvocation::TxPoolInspect(()) => {
+                txpool::handle_txpool_inspect(data).and_then(to_json)
+            }
+            MethodInvocation::TxPoolStatus(()) => {
+                txpool::handle_txpool_status(data).and_then(to_json)
+            }
+
+            // anvil_* methods
+            MethodInvocation::AnvilAutoImpersonateAccount(enabled) => {
+                anvil::handle_auto_impersonate_account(data, enabled).and_then(to_json)
+            }
+            MethodInvocation::AnvilDropTransaction(transaction_hash) => {
+                anvil::handle_drop_transaction(data, transaction_hash).and_then(to_json)
+            }
+            MethodInvocation::AnvilDumpState(()) => {
+                anvil::handle_dump_state(data).and_then(to_json)
+            }
+            MethodInvocation::AnvilImpersonateAccount(address) => {
+                anvil::handle_impersonate_account(data, *address).and_then(to_json)
+            }
+            MethodInvocation::AnvilLoadState(state) => {
+                anvil::handle_load_state(data, state).and_then(to_json)
+            }
+            MethodInvocation::AnvilMine(number_of_blocks, interval) => {
+                anvil::handle_mine(data, number_of_blocks, interval).and_then(to_json_with_traces)
+            }
+            MethodInvocation::AnvilNodeInfo(()) => anvil::handle_node_info(data).and_then(to_json),
+            MethodInvocation::AnvilRemovePoolTransactions(address) => {
+                anvil::handle_remove_pool_transactions(data, address).and_then(to_json)
+            }
+            MethodInvocation::AnvilReset(fork_config) => {
+                anvil::reset_provider_config(data, fork_config)
+                    .and_then(|config| self.reset(data, Some(config)))
+                    .and_then(|_| to_json(()))
+            }
+            MethodInvocation::AnvilSetAutomine(enabled) => {
+                anvil::handle_set_automine(data, enabled).and_then(to_json)
+            }
+            MethodInvocation::AnvilSetBalance(address, balance) => {
+                anvil::handle_set_balance(data, address, balance).and_then(to_json)
+            }
+            MethodInvocation::AnvilSetChainId(chain_id) => {
+                anvil::handle_set_chain_id(data, chain_id).and_then(to_json)
+            }
+            MethodInvocation::AnvilSetCode(address, code) => {
+                anvil::handle_set_code(data, address, code).and_then(to_json)
+            }
+            MethodInvocation::AnvilSetCoinbase(coinbase) => {
+                anvil::handle_set_coinbase(data, coinbase).and_then(to_json)
+            }
+            MethodInvocation::AnvilSetIntervalMining(interval) => handle_set_interval_mining(
+                self.data.clone(),
+                &mut self.interval_miner.lock(),
+                self.runtime.clone(),
+                anvil::interval_config(interval),
+            )
+            .and_then(|_| to_json(())),
+            MethodInvocation::AnvilSetLoggingEnabled(is_enabled) => {
+                anvil::handle_set_logging_enabled(data, is_enabled).and_then(to_json)
+            }
+            MethodInvocation::AnvilSetMinGasPrice(min_gas_price) => {
+                anvil::handle_set_min_gas_price(data, min_gas_price).and_then(to_json)
+            }
+            MethodInvocation::AnvilSetNextBlockBaseFeePerGas(base_fee_per_gas) => {
+                anvil::handle_set_next_block_base_fee_per_gas(data, base_fee_per_gas)
+                    .and_then(to_json)
+            }
+            MethodInvocation::AnvilSetNonce(address, nonce) => {
+                anvil::handle_set_nonce(data, address, nonce).and_then(to_json)
+            }
+            MethodInvocation::AnvilSetRpcUrl(url) => {
+                anvil::handle_set_rpc_url(data, url).and_then(to_json)
+            }
+            MethodInvocation::AnvilStopImpersonatingAccount(address) => {
+                anvil::handle_stop_impersonating_account(data, *address).and_then(to_json)
+            }
+
             // hardhat_* methods
             MethodInvocation::AddCompilationResult(_, _, _) => Err(ProviderError::Unimplemented(
                 "AddCompilationResult".to_string(),
             )),
+            MethodInvocation::AutoImpersonateAccount(enabled) => {
+                hardhat::handle_auto_impersonate_account_request(data, enabled).and_then(to_json)
+            }
+            MethodInvocation::DeleteAccount(address) => {
+                hardhat::handle_delete_account(data, address).and_then(to_json)
+            }
+            MethodInvocation::DeleteSnapshot(selector) => {
+                hardhat::handle_delete_snapshot(data, selector).and_then(to_json)
+            }
             MethodInvocation::DropTransaction(transaction_hash) => {
                 hardhat::handle_drop_transaction(data, transaction_hash).and_then(to_json)
             }
+            MethodInvocation::DumpState(options) => {
+                hardhat::handle_dump_state(data, options).and_then(to_json)
+            }
             MethodInvocation::GetAutomine(()) => {
                 hardhat::handle_get_automine_request(data).and_then(to_json)
             }
+            MethodInvocation::GetBlobSidecars(block_spec) => {
+                hardhat::handle_get_blob_sidecars(data, block_spec).and_then(to_json)
+            }
             MethodInvocation::GetStackTraceFailuresCount(()) => Err(ProviderError::Unimplemented(
                 "GetStackTraceFailuresCount".to_string(),
             )),
@@ -389,13 +552,35 @@ impl<LoggerErrorT: Debug + Send + Sync + 'static> Provider<LoggerErrorT> {
             MethodInvocation::IntervalMine(()) => {
                 hardhat::handle_interval_mine_request(data).and_then(to_json)
             }
+            MethodInvocation::ListSnapshots(()) => {
+                hardhat::handle_list_snapshots(data).and_then(to_json)
+            }
+            MethodInvocation::LoadState(serialized_state) => {
+                hardhat::handle_load_state(data, serialized_state).and_then(to_json)
+            }
             MethodInvocation::Metadata(()) => {
                 hardhat::handle_metadata_request(data).and_then(to_json)
             }
             MethodInvocation::Mine(number_of_blocks, interval) => {
                 hardhat::handle_mine(data, number_of_blocks, interval).and_then(to_json_with_traces)
             }
+            MethodInvocation::MineBlock(transactions, options) => {
+                hardhat::handle_mine_block(data, transactions, options)
+                    .and_then(to_json_with_traces)
+            }
+            MethodInvocation::QueueWithdrawals(withdrawals) => {
+                hardhat::handle_queue_withdrawals_request(data, withdrawals).and_then(to_json)
+            }
+            MethodInvocation::Reorg(depth, transactions) => {
+                hardhat::handle_reorg(data, depth, transactions).and_then(to_json_with_traces)
+            }
             MethodInvocation::Reset(config) => self.reset(data, config).and_then(to_json),
+            MethodInvocation::RevertToSnapshot(selector) => {
+                hardhat::handle_revert_to_snapshot(data, selector).and_then(to_json)
+            }
+            MethodInvocation::SetAccount(address, account) => {
+                hardhat::handle_set_account(data, address, account).and_then(to_json)
+            }
             MethodInvocation::SetBalance(address, balance) => {
                 hardhat::handle_set_balance(data, address, balance).and_then(to_json)
             }
@@ -408,6 +593,9 @@ impl<LoggerErrorT: Debug + Send + Sync + 'static> Provider<LoggerErrorT> {
             MethodInvocation::SetLoggingEnabled(is_enabled) => {
                 hardhat::handle_set_logging_enabled_request(data, is_enabled).and_then(to_json)
             }
+            MethodInvocation::SetMempoolOrdering(mine_ordering) => {
+                hardhat::handle_set_mempool_ordering(data, mine_ordering).and_then(to_json)
+            }
             MethodInvocation::SetMinGasPrice(min_gas_price) => {
                 hardhat::handle_set_min_gas_price(data, min_gas_price).and_then(to_json)
             }
@@ -415,15 +603,24 @@ impl<LoggerErrorT: Debug + Send + Sync + 'static> Provider<LoggerErrorT> {
                 hardhat::handle_set_next_block_base_fee_per_gas_request(data, base_fee_per_gas)
                     .and_then(to_json)
             }
+            MethodInvocation::SetNextBlockOptions(options) => {
+                hardhat::handle_set_next_block_options_request(data, options).and_then(to_json)
+            }
             MethodInvocation::SetNonce(address, nonce) => {
                 hardhat::handle_set_nonce(data, address, nonce).and_then(to_json)
             }
             MethodInvocation::SetPrevRandao(prev_randao) => {
                 hardhat::handle_set_prev_randao_request(data, prev_randao).and_then(to_json)
             }
+            MethodInvocation::SetStorage(address, storage) => {
+                hardhat::handle_set_storage(data, address, storage).and_then(to_json)
+            }
             MethodInvocation::SetStorageAt(address, index, value) => {
                 hardhat::handle_set_storage_at(data, address, index, value).and_then(to_json)
             }
+            MethodInvocation::Snapshot(name) => {
+                hardhat::handle_snapshot(data, name).and_then(to_json)
+            }
             MethodInvocation::StopImpersonatingAccount(address) => {
                 hardhat::handle_stop_impersonating_account_request(data, *address).and_then(to_json)
             }
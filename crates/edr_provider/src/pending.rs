@@ -8,11 +8,11 @@ use edr_evm::{
     BlockAndTotalDifficulty, LocalBlock, SyncBlock,
 };
 
-/// A blockchain with a pending block.
+/// A blockchain with one or more pending blocks.
 ///
 /// # Panics
 ///
-/// Panics if a state override is provided to `state_at_block_number` for the
+/// Panics if a state override is provided to `state_at_block_number` for a
 /// pending block; or if the `BlockchainMut` methods are called.
 ///
 /// WORKAROUND: This struct needs to implement all sub-traits of
@@ -21,8 +21,13 @@ use edr_evm::{
 #[derive(Debug)]
 pub(crate) struct BlockchainWithPending<'blockchain> {
     blockchain: &'blockchain dyn SyncBlockchain<BlockchainError, StateError>,
-    pending_block: Arc<dyn SyncBlock<Error = BlockchainError>>,
-    pending_state_diff: StateDiff,
+    pending_blocks: Vec<PendingBlock>,
+}
+
+#[derive(Debug)]
+struct PendingBlock {
+    block: Arc<dyn SyncBlock<Error = BlockchainError>>,
+    state_diff: StateDiff,
 }
 
 impl<'blockchain> BlockchainWithPending<'blockchain> {
@@ -32,13 +37,42 @@ impl<'blockchain> BlockchainWithPending<'blockchain> {
         blockchain: &'blockchain dyn SyncBlockchain<BlockchainError, StateError>,
         pending_block: LocalBlock,
         pending_state_diff: StateDiff,
+    ) -> Self {
+        let mut blockchain = Self::without_pending(blockchain);
+        blockchain.push_pending_block(pending_block, pending_state_diff);
+        blockchain
+    }
+
+    /// Constructs a new instance with the provided blockchain, without any
+    /// pending blocks.
+    pub fn without_pending(
+        blockchain: &'blockchain dyn SyncBlockchain<BlockchainError, StateError>,
     ) -> Self {
         Self {
             blockchain,
-            pending_block: Arc::new(pending_block),
-            pending_state_diff,
+            pending_blocks: Vec::new(),
         }
     }
+
+    /// Appends a pending block on top of the existing (pending) blocks.
+    pub fn push_pending_block(&mut self, pending_block: LocalBlock, pending_state_diff: StateDiff) {
+        self.pending_blocks.push(PendingBlock {
+            block: Arc::new(pending_block),
+            state_diff: pending_state_diff,
+        });
+    }
+
+    fn pending_block_index_by_number(&self, number: u64) -> Option<usize> {
+        self.pending_blocks
+            .iter()
+            .position(|pending| pending.block.header().number == number)
+    }
+
+    fn pending_block_index_by_hash(&self, hash: &B256) -> Option<usize> {
+        self.pending_blocks
+            .iter()
+            .position(|pending| pending.block.hash() == hash)
+    }
 }
 
 impl<'blockchain> Blockchain for BlockchainWithPending<'blockchain> {
@@ -51,8 +85,8 @@ impl<'blockchain> Blockchain for BlockchainWithPending<'blockchain> {
         hash: &B256,
     ) -> Result<Option<Arc<dyn SyncBlock<Error = Self::BlockchainError>>>, Self::BlockchainError>
     {
-        if hash == self.pending_block.hash() {
-            Ok(Some(self.pending_block.clone()))
+        if let Some(index) = self.pending_block_index_by_hash(hash) {
+            Ok(Some(self.pending_blocks[index].block.clone()))
         } else {
             self.blockchain.block_by_hash(hash)
         }
@@ -63,8 +97,8 @@ impl<'blockchain> Blockchain for BlockchainWithPending<'blockchain> {
         number: u64,
     ) -> Result<Option<Arc<dyn SyncBlock<Error = Self::BlockchainError>>>, Self::BlockchainError>
     {
-        if number == self.pending_block.header().number {
-            Ok(Some(self.pending_block.clone()))
+        if let Some(index) = self.pending_block_index_by_number(number) {
+            Ok(Some(self.pending_blocks[index].block.clone()))
         } else {
             self.blockchain.block_by_number(number)
         }
@@ -75,14 +109,16 @@ impl<'blockchain> Blockchain for BlockchainWithPending<'blockchain> {
         transaction_hash: &B256,
     ) -> Result<Option<Arc<dyn SyncBlock<Error = Self::BlockchainError>>>, Self::BlockchainError>
     {
-        let contains_transaction = self
-            .pending_block
-            .transactions()
-            .iter()
-            .any(|tx| tx.hash() == transaction_hash);
+        let pending_block = self.pending_blocks.iter().find(|pending| {
+            pending
+                .block
+                .transactions()
+                .iter()
+                .any(|tx| tx.hash() == transaction_hash)
+        });
 
-        if contains_transaction {
-            Ok(Some(self.pending_block.clone()))
+        if let Some(pending) = pending_block {
+            Ok(Some(pending.block.clone()))
         } else {
             self.blockchain.block_by_transaction_hash(transaction_hash)
         }
@@ -95,11 +131,18 @@ impl<'blockchain> Blockchain for BlockchainWithPending<'blockchain> {
     fn last_block(
         &self,
     ) -> Result<Arc<dyn SyncBlock<Error = Self::BlockchainError>>, Self::BlockchainError> {
-        Ok(self.pending_block.clone())
+        if let Some(pending) = self.pending_blocks.last() {
+            Ok(pending.block.clone())
+        } else {
+            self.blockchain.last_block()
+        }
     }
 
     fn last_block_number(&self) -> u64 {
-        self.pending_block.header().number
+        self.pending_blocks.last().map_or_else(
+            || self.blockchain.last_block_number(),
+            |pending| pending.block.header().number,
+        )
     }
 
     fn logs(
@@ -120,22 +163,24 @@ impl<'blockchain> Blockchain for BlockchainWithPending<'blockchain> {
         &self,
         transaction_hash: &B256,
     ) -> Result<Option<Arc<BlockReceipt>>, Self::BlockchainError> {
-        let pending_receipt = self
-            .pending_block
-            .transaction_receipts()?
-            .into_iter()
-            .find(|receipt| receipt.transaction_hash == *transaction_hash);
-
-        if let Some(pending_receipt) = pending_receipt {
-            Ok(Some(pending_receipt))
-        } else {
-            self.blockchain
-                .receipt_by_transaction_hash(transaction_hash)
+        for pending in &self.pending_blocks {
+            let pending_receipt = pending
+                .block
+                .transaction_receipts()?
+                .into_iter()
+                .find(|receipt| receipt.transaction_hash == *transaction_hash);
+
+            if pending_receipt.is_some() {
+                return Ok(pending_receipt);
+            }
         }
+
+        self.blockchain
+            .receipt_by_transaction_hash(transaction_hash)
     }
 
     fn spec_at_block_number(&self, block_number: u64) -> Result<SpecId, Self::BlockchainError> {
-        if block_number == self.pending_block.header().number {
+        if self.pending_block_index_by_number(block_number).is_some() {
             Ok(self.blockchain.spec_id())
         } else {
             self.blockchain.spec_at_block_number(block_number)
@@ -151,17 +196,20 @@ impl<'blockchain> Blockchain for BlockchainWithPending<'blockchain> {
         block_number: u64,
         state_overrides: &BTreeMap<u64, StateOverride>,
     ) -> Result<Box<dyn SyncState<Self::StateError>>, Self::BlockchainError> {
-        if block_number == self.pending_block.header().number {
+        if let Some(index) = self.pending_block_index_by_number(block_number) {
             assert!(
                 state_overrides.get(&block_number).is_none(),
                 "State overrides are not supported for a pending block."
             );
 
+            let first_pending_number = self.pending_blocks[0].block.header().number;
             let mut state = self
                 .blockchain
-                .state_at_block_number(block_number - 1, state_overrides)?;
+                .state_at_block_number(first_pending_number - 1, state_overrides)?;
 
-            state.commit(self.pending_state_diff.as_inner().clone());
+            for pending in &self.pending_blocks[..=index] {
+                state.commit(pending.state_diff.as_inner().clone());
+            }
 
             Ok(state)
         } else {
@@ -171,15 +219,17 @@ impl<'blockchain> Blockchain for BlockchainWithPending<'blockchain> {
     }
 
     fn total_difficulty_by_hash(&self, hash: &B256) -> Result<Option<U256>, Self::BlockchainError> {
-        if hash == self.pending_block.hash() {
+        if let Some(index) = self.pending_block_index_by_hash(hash) {
+            let first_pending_header = self.pending_blocks[0].block.header();
             let previous_total_difficulty = self
                 .blockchain
-                .total_difficulty_by_hash(&self.pending_block.header().parent_hash)?
+                .total_difficulty_by_hash(&first_pending_header.parent_hash)?
                 .expect("At least one block should exist before the pending block.");
 
-            Ok(Some(
-                previous_total_difficulty + self.pending_block.header().difficulty,
-            ))
+            Ok(Some(self.pending_blocks[..=index].iter().fold(
+                previous_total_difficulty,
+                |total_difficulty, pending| total_difficulty + pending.block.header().difficulty,
+            )))
         } else {
             self.blockchain.total_difficulty_by_hash(hash)
         }
@@ -210,8 +260,13 @@ impl<'blockchain> BlockHashRef for BlockchainWithPending<'blockchain> {
     type Error = BlockchainError;
 
     fn block_hash(&self, number: U256) -> Result<B256, Self::Error> {
-        if number == U256::from(self.pending_block.header().number) {
-            Ok(*self.pending_block.hash())
+        let pending_block = self
+            .pending_blocks
+            .iter()
+            .find(|pending| U256::from(pending.block.header().number) == number);
+
+        if let Some(pending) = pending_block {
+            Ok(*pending.block.hash())
         } else {
             self.blockchain.block_hash(number)
         }
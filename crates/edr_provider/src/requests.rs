@@ -1,3 +1,5 @@
+/// Anvil-compatible RPC request types
+pub mod anvil;
 pub mod debug;
 /// Ethereum RPC request types
 pub mod eth;
@@ -5,6 +7,10 @@ pub mod eth;
 pub mod hardhat;
 mod methods;
 mod serde;
+/// Parity-style trace RPC request types
+pub mod trace;
+/// Geth-style txpool RPC request types
+pub mod txpool;
 mod validation;
 
 use std::fmt;
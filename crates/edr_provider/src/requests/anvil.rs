@@ -0,0 +1,634 @@
+use core::fmt::Debug;
+use std::{
+    collections::BTreeMap,
+    io::{Read, Write},
+};
+
+use edr_eth::{Address, Bytes, B256, U256, U64};
+use edr_evm::{
+    state::{AccountDump, StateDump},
+    trace::Trace,
+    SpecId,
+};
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+
+use crate::{
+    data::ProviderData,
+    requests::{
+        hardhat::{
+            self,
+            rpc_types::{
+                ForkConfig, ResetProviderConfig, SerializedBlockContext, SerializedState,
+                SERIALIZED_STATE_VERSION,
+            },
+        },
+        IntervalConfig,
+    },
+    ProviderError, U64OrUsize,
+};
+
+/// The fork configuration of `anvil_reset`. Omitted fields default to those
+/// of the current fork.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnvilForkConfig {
+    /// The URL of the remote node to fork from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub json_rpc_url: Option<String>,
+    /// The number of the block to fork from
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "edr_eth::serde::optional_u64"
+    )]
+    pub block_number: Option<u64>,
+}
+
+/// The result of `anvil_nodeInfo`
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeInfo {
+    /// The number of the latest block
+    #[serde(with = "edr_eth::serde::u64")]
+    pub current_block_number: u64,
+    /// The timestamp of the latest block
+    pub current_block_timestamp: u64,
+    /// The hash of the latest block
+    pub current_block_hash: B256,
+    /// The active hardfork
+    pub hard_fork: String,
+    /// The execution environment of the next block
+    pub environment: NodeEnvironment,
+    /// The fork configuration
+    pub fork_config: NodeForkConfig,
+}
+
+/// The execution environment of the next block, as reported by
+/// `anvil_nodeInfo`
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeEnvironment {
+    /// The base fee per gas; zero before EIP-1559
+    pub base_fee: U256,
+    /// The chain ID
+    pub chain_id: u64,
+    /// The block gas limit
+    pub gas_limit: u64,
+    /// The suggested gas price
+    pub gas_price: U256,
+}
+
+/// The fork configuration, as reported by `anvil_nodeInfo`
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeForkConfig {
+    /// The URL of the remote node, if forking
+    pub fork_url: Option<String>,
+    /// The number of the block that was forked from, if forking
+    pub fork_block_number: Option<u64>,
+}
+
+/// The state of `anvil_dumpState` and `anvil_loadState`, in anvil's
+/// `SerializableState` format.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct AnvilSerializableState {
+    /// The block environment at the time of the dump
+    #[serde(default)]
+    pub block: Option<AnvilBlockEnv>,
+    /// The accounts, ordered by address
+    pub accounts: BTreeMap<Address, AnvilAccountRecord>,
+    /// The number of the latest block
+    #[serde(default)]
+    pub best_block_number: Option<U64>,
+}
+
+/// The block environment of an [`AnvilSerializableState`]. Fields that EDR
+/// doesn't restore are only included for compatibility with anvil.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct AnvilBlockEnv {
+    /// The block number
+    pub number: U256,
+    /// The beneficiary of the block
+    pub coinbase: Address,
+    /// The block timestamp
+    pub timestamp: U256,
+    /// The block gas limit
+    pub gas_limit: U256,
+    /// The base fee per gas; zero before EIP-1559
+    pub basefee: U256,
+    /// The block difficulty
+    pub difficulty: U256,
+    /// The block's prevrandao; only set after the merge
+    #[serde(default)]
+    pub prevrandao: Option<B256>,
+}
+
+/// An account of an [`AnvilSerializableState`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct AnvilAccountRecord {
+    /// The account's nonce
+    pub nonce: u64,
+    /// The account's balance
+    pub balance: U256,
+    /// The account's code; empty for externally owned accounts
+    pub code: Bytes,
+    /// The account's storage slots, ordered by index
+    pub storage: BTreeMap<U256, U256>,
+}
+
+impl From<AccountDump> for AnvilAccountRecord {
+    fn from(account: AccountDump) -> Self {
+        Self {
+            nonce: account.nonce,
+            balance: account.balance,
+            code: account.code.unwrap_or_default(),
+            storage: account.storage,
+        }
+    }
+}
+
+impl From<AnvilAccountRecord> for AccountDump {
+    fn from(record: AnvilAccountRecord) -> Self {
+        Self {
+            balance: record.balance,
+            nonce: record.nonce,
+            code: Some(record.code).filter(|code| !code.is_empty()),
+            storage: record.storage,
+        }
+    }
+}
+
+pub fn handle_auto_impersonate_account<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    enabled: bool,
+) -> Result<(), ProviderError<LoggerErrorT>> {
+    data.set_auto_impersonate(enabled);
+
+    Ok(())
+}
+
+pub fn handle_drop_transaction<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    transaction_hash: B256,
+) -> Result<Option<B256>, ProviderError<LoggerErrorT>> {
+    Ok(data
+        .remove_pending_transaction(&transaction_hash)
+        .map(|_| transaction_hash))
+}
+
+/// Dumps the state as gzip-compressed JSON in anvil's format. When forking,
+/// only the changes relative to the fork block are included, with removed
+/// accounts dumped as empty accounts.
+pub fn handle_dump_state<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+) -> Result<Bytes, ProviderError<LoggerErrorT>> {
+    let only_changes = data.fork_metadata().is_some();
+    let SerializedState { block, state, .. } = data.dump_state(only_changes)?;
+
+    let mut accounts = state
+        .accounts
+        .into_iter()
+        .map(|(address, account)| (address, AnvilAccountRecord::from(account)))
+        .collect::<BTreeMap<_, _>>();
+
+    for address in state.removed_accounts {
+        accounts.entry(address).or_default();
+    }
+
+    let last_block = data.last_block()?;
+    let last_header = last_block.header();
+
+    let anvil_state = AnvilSerializableState {
+        block: Some(AnvilBlockEnv {
+            number: U256::from(block.number),
+            coinbase: block.coinbase,
+            timestamp: U256::from(block.timestamp),
+            gas_limit: U256::from(block.gas_limit),
+            basefee: last_header.base_fee_per_gas.unwrap_or_default(),
+            difficulty: last_header.difficulty,
+            prevrandao: (data.spec_id() >= SpecId::MERGE).then_some(last_header.mix_hash),
+        }),
+        accounts,
+        best_block_number: Some(U64::from(block.number)),
+    };
+
+    let json = serde_json::to_vec(&anvil_state).map_err(ProviderError::Serialization)?;
+
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(&json)
+        .expect("Writing to a vector cannot fail");
+
+    let compressed = encoder.finish().expect("Writing to a vector cannot fail");
+
+    Ok(Bytes::from(compressed))
+}
+
+pub fn handle_impersonate_account<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    address: Address,
+) -> Result<(), ProviderError<LoggerErrorT>> {
+    data.impersonate_account(address);
+
+    Ok(())
+}
+
+/// Loads a state that was dumped using `anvil_dumpState`. Like anvil, both
+/// gzip-compressed and plain JSON are accepted.
+pub fn handle_load_state<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    state: Bytes,
+) -> Result<bool, ProviderError<LoggerErrorT>> {
+    let mut decompressed = Vec::new();
+    let json = if GzDecoder::new(state.as_ref())
+        .read_to_end(&mut decompressed)
+        .is_ok()
+    {
+        decompressed.as_slice()
+    } else {
+        state.as_ref()
+    };
+
+    let anvil_state: AnvilSerializableState = serde_json::from_slice(json)
+        .map_err(|error| ProviderError::InvalidArgument(format!("Invalid state dump: {error}")))?;
+
+    let serialized_state = serialized_state_from_anvil(data, anvil_state)?;
+    data.load_state(serialized_state)?;
+
+    Ok(true)
+}
+
+pub fn handle_mine<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    number_of_blocks: Option<u64>,
+    interval: Option<u64>,
+) -> Result<((), Vec<Trace>), ProviderError<LoggerErrorT>> {
+    let (_, traces) = hardhat::handle_mine(data, number_of_blocks, interval)?;
+
+    Ok(((), traces))
+}
+
+pub fn handle_node_info<LoggerErrorT: Debug>(
+    data: &ProviderData<LoggerErrorT>,
+) -> Result<NodeInfo, ProviderError<LoggerErrorT>> {
+    let last_block = data.last_block()?;
+
+    Ok(NodeInfo {
+        current_block_number: last_block.header().number,
+        current_block_timestamp: last_block.header().timestamp,
+        current_block_hash: *last_block.hash(),
+        hard_fork: hardfork_name(data.spec_id()).to_string(),
+        environment: NodeEnvironment {
+            base_fee: data.next_block_base_fee_per_gas()?.unwrap_or_default(),
+            chain_id: data.chain_id(),
+            gas_limit: data.block_gas_limit(),
+            gas_price: data.next_gas_price()?,
+        },
+        fork_config: NodeForkConfig {
+            fork_url: data.rpc_url(),
+            fork_block_number: data
+                .fork_metadata()
+                .map(|metadata| metadata.fork_block_number),
+        },
+    })
+}
+
+/// Removes all pending transactions sent by the provided address from the
+/// mempool.
+pub fn handle_remove_pool_transactions<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    address: Address,
+) -> Result<(), ProviderError<LoggerErrorT>> {
+    let transaction_hashes = data
+        .pending_transactions()
+        .filter(|transaction| *transaction.caller() == address)
+        .map(|transaction| *transaction.hash())
+        .collect::<Vec<_>>();
+
+    for transaction_hash in transaction_hashes {
+        data.remove_pending_transaction(&transaction_hash);
+    }
+
+    Ok(())
+}
+
+pub fn handle_set_automine<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    enabled: bool,
+) -> Result<(), ProviderError<LoggerErrorT>> {
+    data.set_auto_mining(enabled);
+
+    Ok(())
+}
+
+pub fn handle_set_balance<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    address: Address,
+    balance: U256,
+) -> Result<(), ProviderError<LoggerErrorT>> {
+    data.set_balance(address, balance)
+}
+
+pub fn handle_set_chain_id<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    chain_id: U64OrUsize,
+) -> Result<(), ProviderError<LoggerErrorT>> {
+    data.set_chain_id(chain_id.into());
+
+    Ok(())
+}
+
+pub fn handle_set_code<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    address: Address,
+    code: Bytes,
+) -> Result<(), ProviderError<LoggerErrorT>> {
+    data.set_code(address, code)
+}
+
+pub fn handle_set_coinbase<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    coinbase: Address,
+) -> Result<(), ProviderError<LoggerErrorT>> {
+    data.set_coinbase(coinbase);
+
+    Ok(())
+}
+
+pub fn handle_set_logging_enabled<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    is_enabled: bool,
+) -> Result<(), ProviderError<LoggerErrorT>> {
+    data.logger_mut().set_is_enabled(is_enabled);
+
+    Ok(())
+}
+
+pub fn handle_set_min_gas_price<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    min_gas_price: U256,
+) -> Result<(), ProviderError<LoggerErrorT>> {
+    data.set_min_gas_price(min_gas_price)
+}
+
+pub fn handle_set_next_block_base_fee_per_gas<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    base_fee_per_gas: U256,
+) -> Result<(), ProviderError<LoggerErrorT>> {
+    data.set_next_block_base_fee_per_gas(base_fee_per_gas)
+}
+
+pub fn handle_set_nonce<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    address: Address,
+    nonce: u64,
+) -> Result<(), ProviderError<LoggerErrorT>> {
+    data.set_nonce(address, nonce)
+}
+
+pub fn handle_set_rpc_url<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    url: String,
+) -> Result<(), ProviderError<LoggerErrorT>> {
+    data.set_rpc_url(&url)
+}
+
+pub fn handle_stop_impersonating_account<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    address: Address,
+) -> Result<(), ProviderError<LoggerErrorT>> {
+    data.stop_impersonating_account(address);
+
+    Ok(())
+}
+
+/// Converts the interval of `anvil_setIntervalMining`, which is expressed in
+/// seconds, to an interval mining configuration. An interval of zero disables
+/// interval mining.
+pub fn interval_config(interval_in_seconds: u64) -> IntervalConfig {
+    IntervalConfig::FixedOrDisabled(interval_in_seconds.saturating_mul(1000))
+}
+
+/// Converts the fork configuration of `anvil_reset` to a reset configuration.
+/// Unlike `hardhat_reset`, omitting the fork configuration resets to the
+/// current fork, if there is one.
+pub fn reset_provider_config<LoggerErrorT: Debug>(
+    data: &ProviderData<LoggerErrorT>,
+    fork_config: Option<AnvilForkConfig>,
+) -> Result<ResetProviderConfig, ProviderError<LoggerErrorT>> {
+    let current_fork_config = data.fork_config();
+
+    let forking = match fork_config {
+        None => current_fork_config.cloned(),
+        Some(AnvilForkConfig {
+            json_rpc_url,
+            block_number,
+        }) => {
+            let json_rpc_url = json_rpc_url.or_else(|| data.rpc_url()).ok_or_else(|| {
+                ProviderError::InvalidArgument(
+                    "A JSON-RPC URL is required to fork a remote network".to_string(),
+                )
+            })?;
+
+            Some(ForkConfig {
+                json_rpc_url,
+                block_number,
+                http_headers: current_fork_config
+                    .and_then(|fork_config| fork_config.http_headers.clone()),
+            })
+        }
+    };
+
+    Ok(ResetProviderConfig { forking })
+}
+
+/// Returns anvil's name of the hardfork.
+fn hardfork_name(spec_id: SpecId) -> &'static str {
+    match spec_id {
+        SpecId::FRONTIER | SpecId::FRONTIER_THAWING => "Frontier",
+        SpecId::HOMESTEAD => "Homestead",
+        SpecId::DAO_FORK => "Dao",
+        SpecId::TANGERINE => "Tangerine",
+        SpecId::SPURIOUS_DRAGON => "SpuriousDragon",
+        SpecId::BYZANTIUM => "Byzantium",
+        SpecId::CONSTANTINOPLE => "Constantinople",
+        SpecId::PETERSBURG => "Petersburg",
+        SpecId::ISTANBUL => "Istanbul",
+        SpecId::MUIR_GLACIER => "MuirGlacier",
+        SpecId::BERLIN => "Berlin",
+        SpecId::LONDON => "London",
+        SpecId::ARROW_GLACIER => "ArrowGlacier",
+        SpecId::GRAY_GLACIER => "GrayGlacier",
+        SpecId::MERGE => "Paris",
+        SpecId::SHANGHAI => "Shanghai",
+        SpecId::CANCUN => "Cancun",
+        // Hardforks that anvil doesn't know yet
+        _ => "Latest",
+    }
+}
+
+/// Converts a state in anvil's format to a [`SerializedState`]. Block fields
+/// that are missing default to those of the latest block.
+fn serialized_state_from_anvil<LoggerErrorT: Debug>(
+    data: &ProviderData<LoggerErrorT>,
+    anvil_state: AnvilSerializableState,
+) -> Result<SerializedState, ProviderError<LoggerErrorT>> {
+    let AnvilSerializableState {
+        block,
+        accounts,
+        best_block_number,
+    } = anvil_state;
+
+    let last_block = data.last_block()?;
+    let last_header = last_block.header();
+
+    let mut block_context = SerializedBlockContext {
+        number: last_header.number,
+        timestamp: last_header.timestamp,
+        coinbase: data.coinbase(),
+        gas_limit: data.block_gas_limit(),
+    };
+
+    if let Some(block) = block {
+        block_context.number = block_field_to_u64(block.number, "number")?;
+        block_context.timestamp = block_field_to_u64(block.timestamp, "timestamp")?;
+        block_context.coinbase = block.coinbase;
+        block_context.gas_limit = block_field_to_u64(block.gas_limit, "gas limit")?;
+    }
+
+    if let Some(best_block_number) = best_block_number {
+        block_context.number = best_block_number.as_limbs()[0];
+    }
+
+    Ok(SerializedState {
+        version: SERIALIZED_STATE_VERSION,
+        block: block_context,
+        fork: None,
+        state: StateDump {
+            accounts: accounts
+                .into_iter()
+                .map(|(address, record)| (address, AccountDump::from(record)))
+                .collect(),
+            ..StateDump::default()
+        },
+    })
+}
+
+fn block_field_to_u64<LoggerErrorT: Debug>(
+    value: U256,
+    field: &str,
+) -> Result<u64, ProviderError<LoggerErrorT>> {
+    u64::try_from(value).map_err(|_error| {
+        ProviderError::InvalidArgument(format!(
+            "Invalid state dump: the block {field} {value} doesn't fit in 64 bits"
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::test_utils::ProviderTestFixture;
+
+    #[test]
+    fn dump_and_load_state() -> anyhow::Result<()> {
+        let address = Address::random();
+        let code = Bytes::from_static(&[0x60, 0x00]);
+        let coinbase = Address::random();
+
+        let mut source = ProviderTestFixture::new_local()?;
+        source
+            .provider_data
+            .set_balance(address, U256::from(1_000))?;
+        source.provider_data.set_code(address, code.clone())?;
+        source
+            .provider_data
+            .set_account_storage_slot(address, U256::from(7), U256::from(42))?;
+        source.provider_data.set_coinbase(coinbase);
+        source.provider_data.mine_and_commit_blocks(3, 1)?;
+
+        let dump = handle_dump_state(&mut source.provider_data)?;
+
+        // Anvil dumps are gzip-compressed
+        assert_eq!(dump[..2], [0x1f, 0x8b]);
+
+        let mut target = ProviderTestFixture::new_local()?;
+        assert!(handle_load_state(&mut target.provider_data, dump)?);
+
+        assert_eq!(target.provider_data.last_block_number(), 3);
+        assert_eq!(target.provider_data.coinbase(), coinbase);
+        assert_eq!(
+            target.provider_data.balance(address, None)?,
+            U256::from(1_000)
+        );
+        assert_eq!(target.provider_data.get_code(address, None)?, code);
+        assert_eq!(
+            target
+                .provider_data
+                .get_storage_at(address, U256::from(7), None)?,
+            U256::from(42)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn load_state_uncompressed() -> anyhow::Result<()> {
+        let address = Address::random();
+
+        let state = serde_json::json!({
+            "block": null,
+            "accounts": {
+                address.to_string(): {
+                    "nonce": 2,
+                    "balance": "0x3e8",
+                    "code": "0x",
+                    "storage": {}
+                }
+            },
+            "best_block_number": "0x2",
+        });
+
+        let mut fixture = ProviderTestFixture::new_local()?;
+        handle_load_state(
+            &mut fixture.provider_data,
+            Bytes::from(serde_json::to_vec(&state)?),
+        )?;
+
+        assert_eq!(fixture.provider_data.last_block_number(), 2);
+        assert_eq!(
+            fixture.provider_data.balance(address, None)?,
+            U256::from(1_000)
+        );
+        assert_eq!(fixture.provider_data.get_code(address, None)?, Bytes::new());
+
+        Ok(())
+    }
+
+    #[test]
+    fn load_state_invalid() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        assert!(matches!(
+            handle_load_state(&mut fixture.provider_data, Bytes::from_static(b"invalid")),
+            Err(ProviderError::InvalidArgument(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn node_info_hard_fork() -> anyhow::Result<()> {
+        let fixture = ProviderTestFixture::new_local()?;
+
+        let node_info = handle_node_info(&fixture.provider_data)?;
+        assert_eq!(
+            node_info.hard_fork,
+            hardfork_name(fixture.provider_data.spec_id())
+        );
+        assert_eq!(hardfork_name(SpecId::CANCUN), "Cancun");
+        assert_eq!(hardfork_name(SpecId::MERGE), "Paris");
+
+        Ok(())
+    }
+}
@@ -1,10 +1,10 @@
 use core::fmt::Debug;
 
 use edr_eth::{
-    remote::{eth::CallRequest, BlockSpec},
+    remote::{eth::CallRequest, BlockSpec, Eip1898BlockSpec},
     B256,
 };
-use edr_evm::{state::StateOverrides, DebugTraceResult};
+use edr_evm::{state::StateOverrides, DebugTraceOutput, TransactionTrace};
 use serde::{Deserialize, Deserializer};
 
 use crate::{
@@ -20,7 +20,7 @@ pub fn handle_debug_trace_transaction<LoggerErrorT: Debug>(
     data: &mut ProviderData<LoggerErrorT>,
     transaction_hash: B256,
     config: Option<DebugTraceConfig>,
-) -> Result<DebugTraceResult, ProviderError<LoggerErrorT>> {
+) -> Result<DebugTraceOutput, ProviderError<LoggerErrorT>> {
     data.debug_trace_transaction(
         &transaction_hash,
         config.map(Into::into).unwrap_or_default(),
@@ -33,12 +33,33 @@ pub fn handle_debug_trace_transaction<LoggerErrorT: Debug>(
     })
 }
 
+pub fn handle_debug_trace_block_by_hash<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    block_hash: B256,
+    config: Option<DebugTraceConfig>,
+) -> Result<Vec<TransactionTrace>, ProviderError<LoggerErrorT>> {
+    let block_spec = BlockSpec::Eip1898(Eip1898BlockSpec::Hash {
+        block_hash,
+        require_canonical: None,
+    });
+
+    data.debug_trace_block(&block_spec, config.map(Into::into).unwrap_or_default())
+}
+
+pub fn handle_debug_trace_block_by_number<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    block_spec: BlockSpec,
+    config: Option<DebugTraceConfig>,
+) -> Result<Vec<TransactionTrace>, ProviderError<LoggerErrorT>> {
+    data.debug_trace_block(&block_spec, config.map(Into::into).unwrap_or_default())
+}
+
 pub fn handle_debug_trace_call<LoggerErrorT: Debug>(
     data: &mut ProviderData<LoggerErrorT>,
     call_request: CallRequest,
     block_spec: Option<BlockSpec>,
     config: Option<DebugTraceConfig>,
-) -> Result<DebugTraceResult, ProviderError<LoggerErrorT>> {
+) -> Result<DebugTraceOutput, ProviderError<LoggerErrorT>> {
     let block_spec = resolve_block_spec_for_call_request(block_spec);
     validate_call_request(data.spec_id(), &call_request, &block_spec)?;
 
@@ -55,11 +76,15 @@ pub fn handle_debug_trace_call<LoggerErrorT: Debug>(
 #[derive(Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DebugTraceConfig {
-    /// Which tracer to use. This argument is currently unsupported.
+    /// Which tracer to use.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(deserialize_with = "deserialize_tracer")]
     #[serde(default)]
     pub tracer: Option<Tracer>,
+    /// Config options for the selected tracer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub tracer_config: Option<TracerConfig>,
     /// Disable storage trace.
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(default)]
@@ -79,6 +104,30 @@ pub enum Tracer {
     #[default]
     #[serde(rename = "default")]
     Default,
+    #[serde(rename = "callTracer")]
+    Call,
+    #[serde(rename = "prestateTracer")]
+    Prestate,
+}
+
+/// Config options for the `callTracer` and `prestateTracer`. Ignored by the
+/// default tracer.
+#[derive(Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TracerConfig {
+    /// Only trace the top-level call frame.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub only_top_call: Option<bool>,
+    /// Include the logs emitted by each call frame.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub with_log: Option<bool>,
+    /// Return the state differences instead of the prestate. Only used by
+    /// the `prestateTracer`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub diff_mode: Option<bool>,
 }
 
 fn deserialize_tracer<'de, DeserializerT>(
@@ -87,16 +136,10 @@ fn deserialize_tracer<'de, DeserializerT>(
 where
     DeserializerT: Deserializer<'de>,
 {
-    const HARDHAT_ERROR: &str = "Hardhat currently only supports the default tracer, so no tracer parameter should be passed.";
-
-    let tracer = Option::<Tracer>::deserialize(deserializer)
-        .map_err(|_error| serde::de::Error::custom(HARDHAT_ERROR))?;
+    const HARDHAT_ERROR: &str = "Hardhat currently only supports the default tracer, `callTracer` and `prestateTracer`, so no other tracer parameter should be passed.";
 
-    if tracer.is_some() {
-        Err(serde::de::Error::custom(HARDHAT_ERROR))
-    } else {
-        Ok(tracer)
-    }
+    Option::<Tracer>::deserialize(deserializer)
+        .map_err(|_error| serde::de::Error::custom(HARDHAT_ERROR))
 }
 
 impl From<DebugTraceConfig> for edr_evm::DebugTraceConfig {
@@ -105,10 +148,29 @@ impl From<DebugTraceConfig> for edr_evm::DebugTraceConfig {
             disable_storage,
             disable_memory,
             disable_stack,
-            // Tracer argument is not supported by Hardhat
-            tracer: _,
+            tracer,
+            tracer_config,
         } = value;
+
+        let TracerConfig {
+            only_top_call,
+            with_log,
+            diff_mode,
+        } = tracer_config.unwrap_or_default();
+
+        let tracer = match tracer.unwrap_or_default() {
+            Tracer::Default => edr_evm::DebugTracer::Eip3155,
+            Tracer::Call => edr_evm::DebugTracer::Call(edr_evm::CallTracerConfig {
+                only_top_call: only_top_call.unwrap_or_default(),
+                with_log: with_log.unwrap_or_default(),
+            }),
+            Tracer::Prestate => edr_evm::DebugTracer::Prestate(edr_evm::PrestateTracerConfig {
+                diff_mode: diff_mode.unwrap_or_default(),
+            }),
+        };
+
         Self {
+            tracer,
             disable_storage: disable_storage.unwrap_or_default(),
             disable_memory: disable_memory.unwrap_or_default(),
             disable_stack: disable_stack.unwrap_or_default(),
@@ -1,6 +1,7 @@
 mod accounts;
 mod blockchain;
 mod blocks;
+mod bundle;
 mod call;
 mod config;
 mod evm;
@@ -8,11 +9,12 @@ mod filter;
 mod gas;
 mod mine;
 mod sign;
+mod simulate;
 mod state;
 mod transactions;
 mod web3;
 
 pub use self::{
-    accounts::*, blockchain::*, blocks::*, call::*, config::*, evm::*, filter::*, gas::*, mine::*,
-    sign::*, state::*, transactions::*, web3::*,
+    accounts::*, blockchain::*, blocks::*, bundle::*, call::*, config::*, evm::*, filter::*,
+    gas::*, mine::*, sign::*, simulate::*, state::*, transactions::*, web3::*,
 };
@@ -2,6 +2,7 @@ use core::fmt::Debug;
 use std::sync::Arc;
 
 use edr_eth::{
+    receipt::BlockReceipt,
     remote::{eth, BlockSpec, PreEip1898BlockSpec},
     SpecId, B256, U256, U64,
 };
@@ -9,7 +10,10 @@ use edr_evm::{blockchain::BlockchainError, SyncBlock};
 
 use crate::{
     data::{BlockDataForTransaction, ProviderData, TransactionAndBlock},
-    requests::{eth::transaction_to_rpc_result, validation::validate_post_merge_block_tags},
+    requests::{
+        eth::{receipt_to_rpc_output, transaction_to_rpc_result},
+        validation::validate_post_merge_block_tags,
+    },
     ProviderError,
 };
 
@@ -64,6 +68,23 @@ pub fn handle_get_block_by_number_request<LoggerErrorT: Debug>(
         .transpose()
 }
 
+pub fn handle_get_block_receipts_request<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    block_spec: BlockSpec,
+) -> Result<Option<Vec<Arc<BlockReceipt>>>, ProviderError<LoggerErrorT>> {
+    block_by_number(data, &block_spec)?
+        .map(|BlockByNumberResult { block, .. }| {
+            let spec_id = data.spec_id();
+            let receipts = data.block_receipts(&block)?;
+
+            Ok(receipts
+                .into_iter()
+                .map(|receipt| receipt_to_rpc_output(spec_id, receipt))
+                .collect())
+        })
+        .transpose()
+}
+
 pub fn handle_get_block_transaction_count_by_hash_request<LoggerErrorT: Debug>(
     data: &ProviderData<LoggerErrorT>,
     block_hash: B256,
@@ -129,7 +150,7 @@ fn block_by_number<LoggerErrorT: Debug>(
     }
 }
 
-fn block_to_rpc_output<LoggerErrorT: Debug>(
+pub(crate) fn block_to_rpc_output<LoggerErrorT: Debug>(
     spec_id: SpecId,
     block: Arc<dyn SyncBlock<Error = BlockchainError>>,
     pending: bool,
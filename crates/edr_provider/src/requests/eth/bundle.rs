@@ -0,0 +1,323 @@
+use core::fmt::Debug;
+
+use edr_eth::{remote::BlockSpec, transaction::BlobSidecar, Address, Bytes, B256, U256};
+use edr_evm::{trace::Trace, ExecutableTransaction, ExecutionResult, TransactionBundle};
+
+use crate::{
+    data::{BundleTransactionResult, CallBundleResult, PendingBundle, ProviderData},
+    requests::eth::decode_and_validate_raw_transaction,
+    ProviderError, TransactionFailure,
+};
+
+/// Input of `eth_callBundle`
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallBundleRequest {
+    /// The signed, RLP-encoded transactions of the bundle
+    pub txs: Vec<Bytes>,
+    /// The number of the block that the bundle is executed in
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "edr_eth::serde::optional_u64"
+    )]
+    pub block_number: Option<u64>,
+    /// The block whose state the bundle is executed on
+    pub state_block_number: BlockSpec,
+    /// The timestamp of the block that the bundle is executed in
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<u64>,
+}
+
+/// Input of `eth_sendBundle`
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendBundleRequest {
+    /// The signed, RLP-encoded transactions of the bundle
+    pub txs: Vec<Bytes>,
+    /// The number of the block that the bundle targets. If not provided, the
+    /// bundle targets the next block.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "edr_eth::serde::optional_u64"
+    )]
+    pub block_number: Option<u64>,
+    /// The minimum timestamp of the block that the bundle can be included in
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_timestamp: Option<u64>,
+    /// The maximum timestamp of the block that the bundle can be included in
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_timestamp: Option<u64>,
+    /// The hashes of the transactions that are allowed to revert
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reverting_tx_hashes: Vec<B256>,
+}
+
+/// The result of `eth_sendBundle`
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendBundleResult {
+    /// The hash of the bundle
+    pub bundle_hash: B256,
+}
+
+/// The result of `eth_callBundle`
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallBundleOutput {
+    /// The average gas price of the bundle, as paid to the coinbase
+    pub bundle_gas_price: U256,
+    /// The hash of the bundle
+    pub bundle_hash: B256,
+    /// The change of the coinbase's balance caused by the bundle
+    pub coinbase_diff: U256,
+    /// The amount of ETH that was directly transferred to the coinbase
+    pub eth_sent_to_coinbase: U256,
+    /// The gas fees paid to the coinbase, excluding the base fee
+    pub gas_fees: U256,
+    /// The results of the bundle's transactions, in order
+    pub results: Vec<CallBundleTransactionOutput>,
+    /// The number of the block whose state the bundle was executed on
+    #[serde(with = "edr_eth::serde::u64")]
+    pub state_block_number: u64,
+    /// The total gas used by the bundle
+    #[serde(with = "edr_eth::serde::u64")]
+    pub total_gas_used: u64,
+}
+
+/// The result of a single transaction of `eth_callBundle`
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallBundleTransactionOutput {
+    /// The change of the coinbase's balance caused by the transaction
+    pub coinbase_diff: U256,
+    /// The amount of ETH that was directly transferred to the coinbase
+    pub eth_sent_to_coinbase: U256,
+    /// The sender of the transaction
+    pub from_address: Address,
+    /// The gas fees paid to the coinbase, excluding the base fee
+    pub gas_fees: U256,
+    /// The effective gas price of the transaction, as paid to the coinbase
+    pub gas_price: U256,
+    /// The gas used by the transaction
+    #[serde(with = "edr_eth::serde::u64")]
+    pub gas_used: u64,
+    /// The recipient of the transaction, if it's not a contract creation
+    pub to_address: Option<Address>,
+    /// The hash of the transaction
+    pub tx_hash: B256,
+    /// The data returned by the transaction, if it succeeded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<Bytes>,
+    /// The error, if the transaction failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// The revert data, if the transaction reverted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revert: Option<Bytes>,
+}
+
+pub fn handle_call_bundle_request<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    request: CallBundleRequest,
+) -> Result<CallBundleOutput, ProviderError<LoggerErrorT>> {
+    let CallBundleRequest {
+        txs,
+        block_number,
+        state_block_number,
+        timestamp,
+    } = request;
+
+    let transactions = decode_bundle_transactions(data, txs)?
+        .into_iter()
+        .map(|(transaction, _sidecar)| transaction)
+        .collect();
+    let result = data.call_bundle(transactions, &state_block_number, block_number, timestamp)?;
+
+    Ok(call_bundle_result_to_rpc_output(result))
+}
+
+pub fn handle_send_bundle_request<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    request: SendBundleRequest,
+) -> Result<SendBundleResult, ProviderError<LoggerErrorT>> {
+    let SendBundleRequest {
+        txs,
+        block_number,
+        min_timestamp,
+        max_timestamp,
+        reverting_tx_hashes,
+    } = request;
+
+    let (transactions, sidecars): (Vec<_>, Vec<_>) =
+        decode_bundle_transactions(data, txs)?.into_iter().unzip();
+
+    let sidecars = transactions
+        .iter()
+        .zip(sidecars)
+        .filter_map(|(transaction, sidecar)| sidecar.map(|sidecar| (*transaction.hash(), sidecar)))
+        .collect::<Vec<_>>();
+
+    let bundle_hash = data.send_bundle(PendingBundle {
+        bundle: TransactionBundle {
+            transactions,
+            reverting_transaction_hashes: reverting_tx_hashes,
+        },
+        block_number,
+        min_timestamp,
+        max_timestamp,
+    })?;
+
+    // The sidecars need to be known before the bundle is mined
+    for (transaction_hash, sidecar) in sidecars {
+        data.add_pending_blob_sidecar(transaction_hash, sidecar);
+    }
+
+    Ok(SendBundleResult { bundle_hash })
+}
+
+/// Decodes and validates the raw transactions of a bundle, returning them
+/// alongside their blob sidecars.
+fn decode_bundle_transactions<LoggerErrorT: Debug>(
+    data: &ProviderData<LoggerErrorT>,
+    raw_transactions: Vec<Bytes>,
+) -> Result<Vec<(ExecutableTransaction, Option<BlobSidecar>)>, ProviderError<LoggerErrorT>> {
+    if raw_transactions.is_empty() {
+        return Err(ProviderError::InvalidInput(
+            "A bundle must contain at least one transaction".to_string(),
+        ));
+    }
+
+    raw_transactions
+        .into_iter()
+        .map(|raw_transaction| {
+            let (signed_transaction, sidecar) =
+                decode_and_validate_raw_transaction(data, raw_transaction)?;
+
+            let transaction = ExecutableTransaction::new(data.spec_id(), signed_transaction)?;
+            Ok((transaction, sidecar))
+        })
+        .collect()
+}
+
+fn call_bundle_result_to_rpc_output(result: CallBundleResult) -> CallBundleOutput {
+    let CallBundleResult {
+        bundle_hash,
+        state_block_number,
+        transaction_results,
+    } = result;
+
+    let results = transaction_results
+        .into_iter()
+        .map(|result| {
+            let BundleTransactionResult {
+                transaction_hash,
+                caller,
+                to,
+                execution_result,
+                gas_fees,
+                coinbase_diff,
+            } = result;
+
+            let gas_used = execution_result.gas_used();
+            let (value, error, revert) = match execution_result {
+                ExecutionResult::Success { output, .. } => (Some(output.into_data()), None, None),
+                ExecutionResult::Revert { output, .. } => {
+                    (None, Some("execution reverted".to_string()), Some(output))
+                }
+                ExecutionResult::Halt { reason, .. } => (
+                    None,
+                    Some(TransactionFailure::halt(reason, None, Trace::default()).to_string()),
+                    None,
+                ),
+            };
+
+            CallBundleTransactionOutput {
+                coinbase_diff,
+                eth_sent_to_coinbase: coinbase_diff.saturating_sub(gas_fees),
+                from_address: caller,
+                gas_fees,
+                gas_price: average_gas_price(coinbase_diff, gas_used),
+                gas_used,
+                to_address: to,
+                tx_hash: transaction_hash,
+                value,
+                error,
+                revert,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let coinbase_diff = results
+        .iter()
+        .fold(U256::ZERO, |total, result| total + result.coinbase_diff);
+    let gas_fees = results
+        .iter()
+        .fold(U256::ZERO, |total, result| total + result.gas_fees);
+    let total_gas_used = results.iter().map(|result| result.gas_used).sum();
+
+    CallBundleOutput {
+        bundle_gas_price: average_gas_price(coinbase_diff, total_gas_used),
+        bundle_hash,
+        coinbase_diff,
+        eth_sent_to_coinbase: coinbase_diff.saturating_sub(gas_fees),
+        gas_fees,
+        results,
+        state_block_number,
+        total_gas_used,
+    }
+}
+
+fn average_gas_price(coinbase_diff: U256, gas_used: u64) -> U256 {
+    coinbase_diff
+        .checked_div(U256::from(gas_used))
+        .unwrap_or(U256::ZERO)
+}
+
+#[cfg(test)]
+mod tests {
+    use edr_eth::remote::BlockTag;
+
+    use super::*;
+
+    #[test]
+    fn deserialize_send_bundle_request() -> anyhow::Result<()> {
+        let json = r#"{
+            "txs": ["0x01", "0x02"],
+            "blockNumber": "0x10",
+            "minTimestamp": 100,
+            "revertingTxHashes": ["0x0000000000000000000000000000000000000000000000000000000000000001"]
+        }"#;
+
+        let request: SendBundleRequest = serde_json::from_str(json)?;
+
+        assert_eq!(
+            request.txs,
+            vec![Bytes::from(vec![1]), Bytes::from(vec![2])]
+        );
+        assert_eq!(request.block_number, Some(0x10));
+        assert_eq!(request.min_timestamp, Some(100));
+        assert_eq!(request.max_timestamp, None);
+        assert_eq!(request.reverting_tx_hashes, vec![B256::with_last_byte(1)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_call_bundle_request() -> anyhow::Result<()> {
+        let json = r#"{
+            "txs": ["0x01"],
+            "blockNumber": "0x10",
+            "stateBlockNumber": "latest"
+        }"#;
+
+        let request: CallBundleRequest = serde_json::from_str(json)?;
+
+        assert_eq!(request.block_number, Some(0x10));
+        assert_eq!(request.state_block_number, BlockSpec::Tag(BlockTag::Latest));
+        assert_eq!(request.timestamp, None);
+
+        Ok(())
+    }
+}
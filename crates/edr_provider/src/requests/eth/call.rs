@@ -1,14 +1,15 @@
 use core::fmt::Debug;
 
 use edr_eth::{
-    remote::{eth::CallRequest, BlockSpec, StateOverrideOptions},
+    access_list::AccessList,
+    remote::{eth::CallRequest, BlockOverrideOptions, BlockSpec, StateOverrideOptions},
     transaction::{
         Eip1559TransactionRequest, Eip155TransactionRequest, Eip2930TransactionRequest,
-        TransactionRequest,
+        Eip4844TransactionRequest, TransactionRequest,
     },
     Bytes, SpecId, U256,
 };
-use edr_evm::{state::StateOverrides, trace::Trace, ExecutableTransaction};
+use edr_evm::{state::StateOverrides, trace::Trace, ExecutableTransaction, ExecutionResult};
 
 use crate::{
     data::ProviderData, requests::validation::validate_call_request, ProviderError,
@@ -20,6 +21,7 @@ pub fn handle_call_request<LoggerErrorT: Debug>(
     request: CallRequest,
     block_spec: Option<BlockSpec>,
     state_overrides: Option<StateOverrideOptions>,
+    block_overrides: Option<BlockOverrideOptions>,
 ) -> Result<(Bytes, Trace), ProviderError<LoggerErrorT>> {
     let block_spec = resolve_block_spec_for_call_request(block_spec);
     validate_call_request(data.spec_id(), &request, &block_spec)?;
@@ -27,8 +29,15 @@ pub fn handle_call_request<LoggerErrorT: Debug>(
     let state_overrides =
         state_overrides.map_or(Ok(StateOverrides::default()), StateOverrides::try_from)?;
 
+    let block_overrides = block_overrides.unwrap_or_default();
+
     let transaction = resolve_call_request(data, request, &block_spec, &state_overrides)?;
-    let result = data.run_call(transaction.clone(), &block_spec, &state_overrides)?;
+    let result = data.run_call(
+        transaction.clone(),
+        &block_spec,
+        &state_overrides,
+        &block_overrides,
+    )?;
 
     let spec_id = data.spec_id();
     data.logger_mut()
@@ -52,6 +61,46 @@ pub fn handle_call_request<LoggerErrorT: Debug>(
     Ok((output, result.trace))
 }
 
+/// Output of `eth_createAccessList`
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessListResult {
+    /// The access list of the transaction
+    pub access_list: AccessList,
+    /// The gas used by the transaction, when executed with the access list
+    #[serde(with = "edr_eth::serde::u64")]
+    pub gas_used: u64,
+    /// The error, if the transaction failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+pub fn handle_create_access_list_request<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    request: CallRequest,
+    block_spec: Option<BlockSpec>,
+) -> Result<AccessListResult, ProviderError<LoggerErrorT>> {
+    let block_spec = resolve_block_spec_for_call_request(block_spec);
+    validate_call_request(data.spec_id(), &request, &block_spec)?;
+
+    let transaction = resolve_call_request(data, request, &block_spec, &StateOverrides::default())?;
+    let (access_list, result) = data.create_access_list(transaction, &block_spec)?;
+
+    let error = match &result {
+        ExecutionResult::Success { .. } => None,
+        ExecutionResult::Revert { .. } => Some("execution reverted".to_string()),
+        ExecutionResult::Halt { reason, .. } => {
+            Some(TransactionFailure::halt(*reason, None, Trace::default()).to_string())
+        }
+    };
+
+    Ok(AccessListResult {
+        access_list,
+        gas_used: result.gas_used(),
+        error,
+    })
+}
+
 pub(crate) fn resolve_block_spec_for_call_request(block_spec: Option<BlockSpec>) -> BlockSpec {
     block_spec.unwrap_or_else(BlockSpec::latest)
 }
@@ -106,6 +155,7 @@ pub(crate) fn resolve_call_request_inner<LoggerErrorT: Debug>(
         value,
         data: input,
         access_list,
+        blob_hashes,
         ..
     } = request;
 
@@ -116,7 +166,36 @@ pub(crate) fn resolve_call_request_inner<LoggerErrorT: Debug>(
     let nonce = data.nonce(&from, Some(block_spec), state_overrides)?;
     let value = value.unwrap_or(U256::ZERO);
 
-    let transaction = if data.spec_id() < SpecId::LONDON || gas_price.is_some() {
+    let transaction = if let Some(blob_hashes) = blob_hashes {
+        let to = to.ok_or_else(|| {
+            ProviderError::InvalidInput(
+                "Calls with blob hashes must have a receiver (`to`)".to_string(),
+            )
+        })?;
+
+        let (max_fee_per_gas, max_priority_fee_per_gas) = if let Some(gas_price) = gas_price {
+            (gas_price, gas_price)
+        } else {
+            max_fees_fn(data, max_fee_per_gas, max_priority_fee_per_gas)?
+        };
+
+        // Calls can't specify a maximum blob fee, so use the block's blob gas price
+        let max_fee_per_blob_gas = data.blob_gas_price(block_spec)?;
+
+        TransactionRequest::Eip4844(Eip4844TransactionRequest {
+            chain_id,
+            nonce,
+            max_priority_fee_per_gas,
+            max_fee_per_gas,
+            gas_limit,
+            to,
+            value,
+            input,
+            access_list: access_list.unwrap_or_default(),
+            max_fee_per_blob_gas,
+            blob_hashes,
+        })
+    } else if data.spec_id() < SpecId::LONDON || gas_price.is_some() {
         let gas_price = gas_price.map_or_else(|| default_gas_price_fn(data), Ok)?;
         match access_list {
             Some(access_list) if data.spec_id() >= SpecId::BERLIN => {
@@ -165,6 +244,10 @@ pub(crate) fn resolve_call_request_inner<LoggerErrorT: Debug>(
 
 #[cfg(test)]
 mod tests {
+    use std::convert::Infallible;
+
+    use edr_eth::B256;
+
     use super::*;
     use crate::{data::test_utils::ProviderTestFixture, test_utils::pending_base_fee};
 
@@ -232,4 +315,39 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn resolve_call_request_with_blob_hashes() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let blob_hashes = vec![B256::from(U256::from(1))];
+        let request = CallRequest {
+            from: Some(fixture.nth_local_account(0)?),
+            to: Some(fixture.nth_local_account(1)?),
+            blob_hashes: Some(blob_hashes.clone()),
+            ..CallRequest::default()
+        };
+
+        let block_spec = BlockSpec::latest();
+        validate_call_request::<Infallible>(
+            fixture.provider_data.spec_id(),
+            &request,
+            &block_spec,
+        )?;
+
+        let resolved = resolve_call_request(
+            &mut fixture.provider_data,
+            request,
+            &block_spec,
+            &StateOverrides::default(),
+        )?;
+
+        assert_eq!(resolved.blob_hashes(), Some(blob_hashes));
+        assert_eq!(
+            resolved.max_fee_per_blob_gas(),
+            Some(fixture.provider_data.blob_gas_price(&block_spec)?)
+        );
+
+        Ok(())
+    }
 }
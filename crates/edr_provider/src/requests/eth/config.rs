@@ -1,9 +1,19 @@
 use core::fmt::Debug;
 
-use edr_eth::{Address, U256, U64};
+use edr_eth::{Address, SpecId, U256, U64};
 
 use crate::{data::ProviderData, ProviderError};
 
+pub fn handle_blob_base_fee<LoggerErrorT: Debug>(
+    data: &ProviderData<LoggerErrorT>,
+) -> Result<U256, ProviderError<LoggerErrorT>> {
+    data.next_block_blob_base_fee()?
+        .ok_or(ProviderError::UnmetHardfork {
+            actual: data.spec_id(),
+            minimum: SpecId::CANCUN,
+        })
+}
+
 pub fn handle_gas_price<LoggerErrorT: Debug>(
     data: &ProviderData<LoggerErrorT>,
 ) -> Result<U256, ProviderError<LoggerErrorT>> {
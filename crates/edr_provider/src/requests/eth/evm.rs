@@ -40,7 +40,7 @@ pub fn handle_revert_request<LoggerErrorT: Debug>(
     data: &mut ProviderData<LoggerErrorT>,
     snapshot_id: U64,
 ) -> Result<bool, ProviderError<LoggerErrorT>> {
-    Ok(data.revert_to_snapshot(snapshot_id.as_limbs()[0]))
+    data.revert_to_snapshot(snapshot_id.as_limbs()[0])
 }
 
 pub fn handle_set_automine_request<LoggerErrorT: Debug>(
@@ -84,6 +84,7 @@ pub fn handle_subscribe_request<LoggerErrorT: Debug>(
         SubscriptionType::NewPendingTransactions => {
             Ok(data.add_pending_transaction_filter::<true>())
         }
+        SubscriptionType::DroppedTransactions => Ok(data.add_dropped_transaction_filter::<true>()),
     }
 }
 
@@ -0,0 +1,287 @@
+use core::fmt::Debug;
+
+use edr_eth::{
+    remote::{
+        eth::{self, CallRequest},
+        filter::LogOutput,
+        BlockOverrideOptions, BlockSpec, StateOverrideOptions,
+    },
+    Bytes, SpecId,
+};
+use edr_evm::{trace::Trace, ExecutionResult};
+
+use crate::{
+    data::{ProviderData, SimulatedBlockResult, SimulatedCallResult},
+    requests::{
+        eth::{block_to_rpc_output, resolve_block_spec_for_call_request, HashOrTransaction},
+        validation::validate_call_request,
+    },
+    ProviderError, TransactionFailure,
+};
+
+/// Error code for a reverted call, matching `eth_call`
+const REVERTED_ERROR_CODE: i32 = 3;
+/// Error code for a call that halted with an EVM error
+const VM_ERROR_CODE: i32 = -32015;
+/// Error code for a call that couldn't be included in the block
+const INVALID_TRANSACTION_ERROR_CODE: i32 = -32000;
+
+/// Input of `eth_simulateV1`
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulatePayload {
+    /// The blocks to simulate, in order
+    pub block_state_calls: Vec<SimulateBlock>,
+    /// Whether to add synthetic logs for ETH transfers
+    #[serde(default)]
+    pub trace_transfers: bool,
+    /// Whether to validate nonces, balances, and fees of the calls
+    #[serde(default)]
+    pub validation: bool,
+    /// Whether to return full transaction objects instead of hashes
+    #[serde(default)]
+    pub return_full_transactions: bool,
+}
+
+/// A block of calls to simulate with `eth_simulateV1`
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulateBlock {
+    /// Overrides for the block environment
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_overrides: Option<BlockOverrideOptions>,
+    /// Overrides for the state, applied before executing the calls
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_overrides: Option<StateOverrideOptions>,
+    /// The calls to execute, in order
+    #[serde(default)]
+    pub calls: Vec<CallRequest>,
+}
+
+/// A simulated block, returned by `eth_simulateV1`
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulatedBlock {
+    /// The block
+    #[serde(flatten)]
+    pub block: eth::Block<HashOrTransaction>,
+    /// The results of the block's calls
+    pub calls: Vec<SimulatedCall>,
+}
+
+/// The result of a simulated call
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulatedCall {
+    /// The data returned by the call
+    pub return_data: Bytes,
+    /// The logs emitted by the call
+    pub logs: Vec<LogOutput>,
+    /// The gas used by the call
+    #[serde(with = "edr_eth::serde::u64")]
+    pub gas_used: u64,
+    /// 1 if the call succeeded; 0 otherwise
+    #[serde(with = "edr_eth::serde::u64")]
+    pub status: u64,
+    /// The error, if the call failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<SimulatedCallError>,
+}
+
+/// The error of a failed simulated call
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct SimulatedCallError {
+    /// The error code
+    pub code: i32,
+    /// The error message
+    pub message: String,
+    /// The revert data, if the call reverted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<Bytes>,
+}
+
+pub fn handle_simulate_v1_request<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    payload: SimulatePayload,
+    block_spec: Option<BlockSpec>,
+) -> Result<Vec<SimulatedBlock>, ProviderError<LoggerErrorT>> {
+    let block_spec = resolve_block_spec_for_call_request(block_spec);
+
+    for call_request in payload
+        .block_state_calls
+        .iter()
+        .flat_map(|block| &block.calls)
+    {
+        validate_call_request(data.spec_id(), call_request, &block_spec)?;
+    }
+
+    let return_full_transactions = payload.return_full_transactions;
+    let spec_id = data.spec_id();
+
+    data.simulate_v1(payload, &block_spec)?
+        .into_iter()
+        .map(|result| simulated_block_to_rpc_output(spec_id, result, return_full_transactions))
+        .collect()
+}
+
+fn simulated_block_to_rpc_output<LoggerErrorT: Debug>(
+    spec_id: SpecId,
+    result: SimulatedBlockResult,
+    return_full_transactions: bool,
+) -> Result<SimulatedBlock, ProviderError<LoggerErrorT>> {
+    let SimulatedBlockResult {
+        block,
+        call_results,
+    } = result;
+
+    let header = block.header();
+    let block_hash = *block.hash();
+    let block_number = header.number;
+
+    let mut log_index = 0u64;
+    // Rejected calls aren't included in the block, so they don't have a
+    // transaction.
+    let mut transactions = block.transactions().iter().enumerate();
+    let calls = call_results
+        .into_iter()
+        .map(|call_result| {
+            let (execution_result, logs) = match call_result {
+                SimulatedCallResult::Included {
+                    execution_result,
+                    logs,
+                } => (execution_result, logs),
+                SimulatedCallResult::Rejected(message) => {
+                    return SimulatedCall {
+                        return_data: Bytes::new(),
+                        logs: Vec::new(),
+                        gas_used: 0,
+                        status: 0,
+                        error: Some(SimulatedCallError {
+                            code: INVALID_TRANSACTION_ERROR_CODE,
+                            message,
+                            data: None,
+                        }),
+                    };
+                }
+            };
+
+            let (transaction_index, transaction) = transactions
+                .next()
+                .expect("Every included call has a transaction");
+            let transaction_index = transaction_index.try_into().expect("usize fits into u64");
+
+            let logs = logs
+                .into_iter()
+                .map(|log| {
+                    let output = LogOutput {
+                        removed: false,
+                        log_index: Some(log_index),
+                        transaction_index: Some(transaction_index),
+                        transaction_hash: Some(*transaction.hash()),
+                        block_hash: Some(block_hash),
+                        block_number: Some(block_number),
+                        address: log.address,
+                        topics: log.data.topics().to_vec(),
+                        data: log.data.data,
+                    };
+
+                    log_index += 1;
+                    output
+                })
+                .collect();
+
+            let gas_used = execution_result.gas_used();
+            let (return_data, error) = match execution_result {
+                ExecutionResult::Success { output, .. } => (output.into_data(), None),
+                ExecutionResult::Revert { output, .. } => (
+                    output.clone(),
+                    Some(SimulatedCallError {
+                        code: REVERTED_ERROR_CODE,
+                        message: "execution reverted".to_string(),
+                        data: Some(output),
+                    }),
+                ),
+                ExecutionResult::Halt { reason, .. } => (
+                    Bytes::new(),
+                    Some(SimulatedCallError {
+                        code: VM_ERROR_CODE,
+                        message: TransactionFailure::halt(reason, None, Trace::default())
+                            .to_string(),
+                        data: None,
+                    }),
+                ),
+            };
+
+            SimulatedCall {
+                return_data,
+                logs,
+                gas_used,
+                status: u64::from(error.is_none()),
+                error,
+            }
+        })
+        .collect();
+
+    let pending = false;
+    let total_difficulty = None;
+    let block = block_to_rpc_output(
+        spec_id,
+        block,
+        pending,
+        total_difficulty,
+        return_full_transactions,
+    )?;
+
+    Ok(SimulatedBlock { block, calls })
+}
+
+#[cfg(test)]
+mod tests {
+    use edr_eth::Address;
+
+    use super::*;
+
+    #[test]
+    fn deserialize_simulate_payload() -> anyhow::Result<()> {
+        let json = r#"{
+            "blockStateCalls": [
+                {
+                    "blockOverrides": { "number": "0x10", "time": "0x20" },
+                    "stateOverrides": {
+                        "0x0000000000000000000000000000000000000001": { "balance": "0x1" }
+                    },
+                    "calls": [{ "to": "0x0000000000000000000000000000000000000002" }]
+                },
+                {}
+            ],
+            "traceTransfers": true
+        }"#;
+
+        let payload: SimulatePayload = serde_json::from_str(json)?;
+
+        assert!(payload.trace_transfers);
+        assert!(!payload.validation);
+        assert!(!payload.return_full_transactions);
+        assert_eq!(payload.block_state_calls.len(), 2);
+
+        let first_block = &payload.block_state_calls[0];
+        assert_eq!(
+            first_block.block_overrides,
+            Some(BlockOverrideOptions {
+                number: Some(0x10),
+                timestamp: Some(0x20),
+                ..BlockOverrideOptions::default()
+            })
+        );
+        assert!(first_block
+            .state_overrides
+            .as_ref()
+            .map_or(false, |overrides| overrides
+                .contains_key(&Address::with_last_byte(1))));
+        assert_eq!(first_block.calls[0].to, Some(Address::with_last_byte(2)));
+
+        assert_eq!(payload.block_state_calls[1], SimulateBlock::default());
+
+        Ok(())
+    }
+}
@@ -6,13 +6,16 @@ use edr_eth::{
     remote::{self, PreEip1898BlockSpec},
     rlp::Decodable,
     transaction::{
-        Eip1559TransactionRequest, Eip155TransactionRequest, Eip2930TransactionRequest,
-        EthTransactionRequest, SignedTransaction, TransactionKind, TransactionRequest,
-        TransactionRequestAndSender,
+        BlobSidecar, Eip1559TransactionRequest, Eip155TransactionRequest,
+        Eip2930TransactionRequest, Eip4844TransactionWithSidecar, EthTransactionRequest,
+        SignedTransaction, TransactionKind, TransactionRequest, TransactionRequestAndSender,
     },
     Bytes, SpecId, B256, U256,
 };
-use edr_evm::{blockchain::BlockchainError, trace::Trace, ExecutableTransaction, SyncBlock};
+use edr_evm::{
+    blockchain::BlockchainError, trace::Trace, validate_blob_sidecar, ExecutableTransaction,
+    SyncBlock,
+};
 
 use crate::{
     data::{BlockDataForTransaction, ProviderData, TransactionAndBlock},
@@ -25,6 +28,7 @@ use crate::{
 };
 
 const FIRST_HARDFORK_WITH_TRANSACTION_TYPE: SpecId = SpecId::BERLIN;
+const BLOB_TRANSACTION_TYPE: u8 = 3;
 
 pub fn handle_get_transaction_by_block_hash_and_index<LoggerErrorT: Debug>(
     data: &ProviderData<LoggerErrorT>,
@@ -104,22 +108,27 @@ pub fn handle_get_transaction_receipt<LoggerErrorT: Debug>(
 ) -> Result<Option<Arc<BlockReceipt>>, ProviderError<LoggerErrorT>> {
     let receipt = data.transaction_receipt(&transaction_hash)?;
 
+    Ok(receipt.map(|receipt| receipt_to_rpc_output(data.spec_id(), receipt)))
+}
+
+pub(crate) fn receipt_to_rpc_output(
+    spec_id: SpecId,
+    receipt: Arc<BlockReceipt>,
+) -> Arc<BlockReceipt> {
     // The JSON-RPC layer should not return the gas price as effective gas price for
     // receipts in pre-London hardforks.
-    if let Some(receipt) = receipt.as_ref() {
-        if data.spec_id() < SpecId::LONDON && receipt.effective_gas_price.is_some() {
-            return Ok(Some(Arc::new(BlockReceipt {
-                inner: TransactionReceipt {
-                    effective_gas_price: None,
-                    ..receipt.inner.clone()
-                },
-                block_hash: receipt.block_hash,
-                block_number: receipt.block_number,
-            })));
-        }
+    if spec_id < SpecId::LONDON && receipt.effective_gas_price.is_some() {
+        Arc::new(BlockReceipt {
+            inner: TransactionReceipt {
+                effective_gas_price: None,
+                ..receipt.inner.clone()
+            },
+            block_hash: receipt.block_hash,
+            block_number: receipt.block_number,
+        })
+    } else {
+        receipt
     }
-
-    Ok(receipt)
 }
 
 fn transaction_from_block(
@@ -264,6 +273,59 @@ pub fn handle_send_raw_transaction_request<LoggerErrorT: Debug>(
     data: &mut ProviderData<LoggerErrorT>,
     raw_transaction: Bytes,
 ) -> Result<(B256, Vec<Trace>), ProviderError<LoggerErrorT>> {
+    let (signed_transaction, sidecar) = decode_and_validate_raw_transaction(data, raw_transaction)?;
+
+    let pending_transaction = ExecutableTransaction::new(data.spec_id(), signed_transaction)?;
+
+    // The sidecar needs to be known before the transaction is (auto)mined
+    if let Some(sidecar) = sidecar {
+        data.add_pending_blob_sidecar(*pending_transaction.hash(), sidecar);
+    }
+
+    send_raw_transaction_and_log(data, pending_transaction)
+}
+
+/// Decodes and validates an RLP-encoded signed transaction. EIP-4844
+/// transactions need to be in their network representation, of which the blob
+/// sidecar is returned alongside the transaction. As verifying its KZG proofs
+/// is expensive, the sidecar is only validated after the transaction.
+pub(crate) fn decode_and_validate_raw_transaction<LoggerErrorT: Debug>(
+    data: &ProviderData<LoggerErrorT>,
+    raw_transaction: Bytes,
+) -> Result<(SignedTransaction, Option<BlobSidecar>), ProviderError<LoggerErrorT>> {
+    let (signed_transaction, sidecar) = decode_raw_transaction(raw_transaction)?;
+
+    validate_send_raw_transaction_request(data, &signed_transaction)?;
+
+    if let (SignedTransaction::Eip4844(transaction), Some(sidecar)) =
+        (&signed_transaction, &sidecar)
+    {
+        validate_blob_sidecar(transaction, sidecar)?;
+    }
+
+    Ok((signed_transaction, sidecar))
+}
+
+/// Decodes an RLP-encoded signed transaction. EIP-4844 transactions need to
+/// be in their network representation, of which the blob sidecar is returned
+/// alongside the transaction without being validated.
+fn decode_raw_transaction<LoggerErrorT: Debug>(
+    raw_transaction: Bytes,
+) -> Result<(SignedTransaction, Option<BlobSidecar>), ProviderError<LoggerErrorT>> {
+    if let Some((&BLOB_TRANSACTION_TYPE, mut payload)) = raw_transaction.split_first() {
+        let Eip4844TransactionWithSidecar {
+            transaction,
+            sidecar,
+        } = Eip4844TransactionWithSidecar::decode(&mut payload).map_err(|_err| {
+            ProviderError::InvalidArgument(
+                "EIP-4844 transactions need to include their blobs, commitments, and proofs"
+                    .to_string(),
+            )
+        })?;
+
+        return Ok((SignedTransaction::Eip4844(transaction), Some(sidecar)));
+    }
+
     let mut raw_transaction: &[u8] = raw_transaction.as_ref();
     let signed_transaction =
         SignedTransaction::decode(&mut raw_transaction).map_err(|err| match err {
@@ -274,18 +336,10 @@ pub fn handle_send_raw_transaction_request<LoggerErrorT: Debug>(
             err => ProviderError::InvalidArgument(err.to_string()),
         })?;
 
-    if matches!(signed_transaction, SignedTransaction::Eip4844(_)) {
-        return Err(ProviderError::Eip4844TransactionUnsupported);
-    }
-
-    validate_send_raw_transaction_request(data, &signed_transaction)?;
-
-    let pending_transaction = ExecutableTransaction::new(data.spec_id(), signed_transaction)?;
-
-    send_raw_transaction_and_log(data, pending_transaction)
+    Ok((signed_transaction, None))
 }
 
-fn resolve_transaction_request<LoggerErrorT: Debug>(
+pub(crate) fn resolve_transaction_request<LoggerErrorT: Debug>(
     data: &mut ProviderData<LoggerErrorT>,
     transaction_request: EthTransactionRequest,
 ) -> Result<TransactionRequestAndSender, ProviderError<LoggerErrorT>> {
@@ -446,7 +500,7 @@ fn send_raw_transaction_and_log<LoggerErrorT: Debug>(
     Ok(result.into())
 }
 
-fn validate_send_transaction_request<LoggerErrorT: Debug>(
+pub(crate) fn validate_send_transaction_request<LoggerErrorT: Debug>(
     data: &ProviderData<LoggerErrorT>,
     request: &EthTransactionRequest,
 ) -> Result<(), ProviderError<LoggerErrorT>> {
@@ -469,7 +523,15 @@ fn validate_send_transaction_request<LoggerErrorT: Debug>(
         )?;
     }
 
-    validate_transaction_and_call_request(data.spec_id(), request)
+    validate_transaction_and_call_request(data.spec_id(), request)?;
+
+    // Blob transactions need to include the commitments and proofs of their
+    // blobs, so they can only be sent as raw transactions
+    if request.blobs.is_some() || request.blob_hashes.is_some() {
+        return Err(ProviderError::Eip4844TransactionUnsupported);
+    }
+
+    Ok(())
 }
 
 fn validate_send_raw_transaction_request<LoggerErrorT: Debug>(
@@ -501,6 +563,14 @@ fn validate_send_raw_transaction_request<LoggerErrorT: Debug>(
                 "\
 Trying to send an EIP-1559 transaction but they are not supported by the current hard fork.\
 \
+You can use them by running Hardhat Network with 'hardfork' {minimum_hardfork:?} or later."
+            )),
+            ProviderError::UnsupportedEip4844Parameters {
+                minimum_hardfork, ..
+            } => ProviderError::InvalidArgument(format!(
+                "\
+Trying to send an EIP-4844 transaction but they are not supported by the current hard fork.\
+\
 You can use them by running Hardhat Network with 'hardfork' {minimum_hardfork:?} or later."
             )),
             err => err,
@@ -521,13 +591,75 @@ You can use them by running Hardhat Network with 'hardfork' {minimum_hardfork:?}
 mod tests {
     use anyhow::Context;
     use edr_eth::{
-        transaction::{Eip155TransactionRequest, TransactionKind, TransactionRequest},
+        block::BlockOptions,
+        rlp::Encodable,
+        transaction::{
+            Eip155TransactionRequest, Eip4844SignedTransaction, Eip4844TransactionRequest,
+            TransactionKind, TransactionRequest,
+        },
         Address, Bytes, U256,
     };
-    use edr_evm::ExecutableTransaction;
+    use edr_evm::{
+        blob_versioned_hash, test_utils::zero_blob_sidecar, BlobSidecarError,
+        ExecutableTransaction, GAS_PER_BLOB,
+    };
 
     use super::*;
-    use crate::{data::test_utils::ProviderTestFixture, test_utils::one_ether};
+    use crate::{
+        data::test_utils::ProviderTestFixture,
+        test_utils::{create_test_config, one_ether},
+        ProviderConfig,
+    };
+
+    fn signed_blob_transaction(
+        fixture: &ProviderTestFixture,
+        local_account_index: usize,
+        blob_hashes: Vec<B256>,
+    ) -> anyhow::Result<Eip4844SignedTransaction> {
+        let transaction = Eip4844TransactionRequest {
+            chain_id: fixture.config.chain_id,
+            nonce: 0,
+            max_priority_fee_per_gas: U256::from(1_000_000_000u64),
+            max_fee_per_gas: U256::from(42_000_000_000u64),
+            gas_limit: 30_000,
+            to: Address::ZERO,
+            value: U256::ZERO,
+            input: Bytes::new(),
+            access_list: Vec::new(),
+            max_fee_per_blob_gas: U256::from(1_000_000_000u64),
+            blob_hashes,
+        }
+        .sign(&fixture.config.accounts[local_account_index].secret_key)?;
+
+        Ok(transaction)
+    }
+
+    fn raw_blob_transaction(transaction: Eip4844SignedTransaction, sidecar: BlobSidecar) -> Bytes {
+        let mut raw_transaction = vec![BLOB_TRANSACTION_TYPE];
+        Eip4844TransactionWithSidecar {
+            transaction,
+            sidecar,
+        }
+        .encode(&mut raw_transaction);
+
+        Bytes::from(raw_transaction)
+    }
+
+    fn valid_raw_blob_transaction(
+        fixture: &ProviderTestFixture,
+        local_account_index: usize,
+        num_blobs: usize,
+    ) -> anyhow::Result<Bytes> {
+        let sidecar = zero_blob_sidecar(num_blobs);
+        let blob_hashes = sidecar
+            .commitments
+            .iter()
+            .map(blob_versioned_hash)
+            .collect();
+
+        let transaction = signed_blob_transaction(fixture, local_account_index, blob_hashes)?;
+        Ok(raw_blob_transaction(transaction, sidecar))
+    }
 
     #[test]
     fn transaction_by_hash_for_impersonated_account() -> anyhow::Result<()> {
@@ -572,4 +704,168 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn send_raw_blob_transaction() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+        fixture.provider_data.set_auto_mining(true);
+
+        let raw_transaction = valid_raw_blob_transaction(&fixture, 0, 2)?;
+        let (transaction_hash, _traces) =
+            handle_send_raw_transaction_request(&mut fixture.provider_data, raw_transaction)?;
+
+        let block = fixture.provider_data.last_block()?;
+        assert_eq!(block.transactions().len(), 1);
+        assert_eq!(block.transactions()[0].hash(), &transaction_hash);
+
+        let blob_gas = block
+            .header()
+            .blob_gas
+            .as_ref()
+            .context("Cancun blocks have blob gas")?;
+        assert_eq!(blob_gas.gas_used, 2 * GAS_PER_BLOB);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mined_blob_sidecars_are_stored() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+        fixture.provider_data.set_auto_mining(true);
+
+        let sidecar = zero_blob_sidecar(2);
+        let versioned_hash = blob_versioned_hash(&sidecar.commitments[0]);
+        let transaction = signed_blob_transaction(&fixture, 0, vec![versioned_hash; 2])?;
+        let raw_transaction = raw_blob_transaction(transaction, sidecar.clone());
+
+        let (transaction_hash, _traces) =
+            handle_send_raw_transaction_request(&mut fixture.provider_data, raw_transaction)?;
+
+        let block = fixture.provider_data.last_block()?;
+        let blobs = fixture.provider_data.blobs_by_block(&*block);
+        assert_eq!(blobs.len(), 2);
+
+        for (index, blob) in blobs.iter().enumerate() {
+            assert_eq!(blob.index, index as u64);
+            assert_eq!(blob.transaction_hash, transaction_hash);
+            assert_eq!(blob.versioned_hash, versioned_hash);
+            assert_eq!(blob.blob, sidecar.blobs[index]);
+            assert_eq!(blob.commitment, sidecar.commitments[index]);
+            assert_eq!(blob.proof, sidecar.proofs[index]);
+        }
+
+        let blob = fixture
+            .provider_data
+            .blob_by_versioned_hash(&versioned_hash)
+            .context("blob should be stored")?;
+        assert_eq!(blob.transaction_hash, transaction_hash);
+
+        // Blocks without blob transactions don't have any blobs
+        fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+        let block = fixture.provider_data.last_block()?;
+        assert!(fixture.provider_data.blobs_by_block(&*block).is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn send_raw_blob_transaction_with_invalid_sidecar() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let transaction = signed_blob_transaction(&fixture, 0, vec![B256::ZERO])?;
+        let raw_transaction = raw_blob_transaction(transaction, zero_blob_sidecar(1));
+        let result =
+            handle_send_raw_transaction_request(&mut fixture.provider_data, raw_transaction);
+
+        assert!(matches!(
+            result,
+            Err(ProviderError::BlobSidecar(
+                BlobSidecarError::VersionedHashMismatch { index: 0, .. }
+            ))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn send_raw_blob_transaction_pre_cancun() -> anyhow::Result<()> {
+        let config = ProviderConfig {
+            hardfork: SpecId::SHANGHAI,
+            ..create_test_config()
+        };
+
+        let mut fixture = ProviderTestFixture::with_config(config)?;
+
+        // The hardfork is validated before the (invalid) sidecar
+        let transaction = signed_blob_transaction(&fixture, 0, vec![B256::ZERO])?;
+        let raw_transaction = raw_blob_transaction(transaction, zero_blob_sidecar(1));
+        let result =
+            handle_send_raw_transaction_request(&mut fixture.provider_data, raw_transaction);
+
+        assert!(matches!(
+            result,
+            Err(ProviderError::InvalidArgument(message)) if message.contains("EIP-4844")
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn send_raw_blob_transaction_without_sidecar() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let sidecar = zero_blob_sidecar(1);
+        let blob_hashes = sidecar
+            .commitments
+            .iter()
+            .map(blob_versioned_hash)
+            .collect();
+        let transaction = signed_blob_transaction(&fixture, 0, blob_hashes)?;
+
+        let mut raw_transaction = Vec::new();
+        SignedTransaction::Eip4844(transaction).encode(&mut raw_transaction);
+        let raw_transaction = Bytes::from(raw_transaction);
+        let result =
+            handle_send_raw_transaction_request(&mut fixture.provider_data, raw_transaction);
+
+        assert!(matches!(result, Err(ProviderError::InvalidArgument(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn blob_transactions_limited_per_block() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+        fixture.provider_data.set_auto_mining(false);
+
+        let max_blobs_transaction = valid_raw_blob_transaction(&fixture, 0, 6)?;
+        handle_send_raw_transaction_request(&mut fixture.provider_data, max_blobs_transaction)?;
+
+        let single_blob_transaction = valid_raw_blob_transaction(&fixture, 1, 1)?;
+        handle_send_raw_transaction_request(&mut fixture.provider_data, single_blob_transaction)?;
+
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+        assert_eq!(result.block.transactions().len(), 1);
+        assert_eq!(fixture.provider_data.pending_transactions().count(), 1);
+
+        let result = fixture
+            .provider_data
+            .mine_and_commit_block(BlockOptions::default())?;
+        assert_eq!(result.block.transactions().len(), 1);
+        assert_eq!(fixture.provider_data.pending_transactions().count(), 0);
+
+        let blob_gas = result
+            .block
+            .header()
+            .blob_gas
+            .as_ref()
+            .context("Cancun blocks have blob gas")?;
+        assert_eq!(blob_gas.gas_used, GAS_PER_BLOB);
+
+        Ok(())
+    }
 }
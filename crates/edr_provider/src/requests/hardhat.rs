@@ -1,9 +1,13 @@
 mod accounts;
+mod blobs;
 mod config;
 mod log;
 mod miner;
 pub mod rpc_types;
+mod snapshot;
 mod state;
 mod transactions;
 
-pub use self::{accounts::*, config::*, log::*, miner::*, state::*, transactions::*};
+pub use self::{
+    accounts::*, blobs::*, config::*, log::*, miner::*, snapshot::*, state::*, transactions::*,
+};
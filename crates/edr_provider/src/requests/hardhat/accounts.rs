@@ -4,6 +4,15 @@ use edr_eth::Address;
 
 use crate::{data::ProviderData, ProviderError};
 
+pub fn handle_auto_impersonate_account_request<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    enabled: bool,
+) -> Result<bool, ProviderError<LoggerErrorT>> {
+    data.set_auto_impersonate(enabled);
+
+    Ok(true)
+}
+
 pub fn handle_impersonate_account_request<LoggerErrorT: Debug>(
     data: &mut ProviderData<LoggerErrorT>,
     address: Address,
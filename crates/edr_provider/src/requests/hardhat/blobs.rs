@@ -0,0 +1,20 @@
+use core::fmt::Debug;
+
+use edr_eth::remote::BlockSpec;
+
+use crate::{data::ProviderData, requests::hardhat::rpc_types::BlobSidecars, ProviderError};
+
+pub fn handle_get_blob_sidecars<LoggerErrorT: Debug>(
+    data: &ProviderData<LoggerErrorT>,
+    block_spec: BlockSpec,
+) -> Result<Option<BlobSidecars>, ProviderError<LoggerErrorT>> {
+    let Some(block) = data.block_by_block_spec(&block_spec)? else {
+        return Ok(None);
+    };
+
+    let blobs = data.blobs_by_block(&*block);
+
+    Ok(Some(BlobSidecars {
+        data: blobs.into_iter().map(Into::into).collect(),
+    }))
+}
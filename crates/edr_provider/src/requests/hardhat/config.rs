@@ -1,10 +1,13 @@
 use core::fmt::Debug;
 
-use edr_eth::{Address, B256, U256};
+use edr_eth::{withdrawal::Withdrawal, Address, B256, U256};
 
 use crate::{
     data::ProviderData,
-    requests::{eth::client_version, hardhat::rpc_types::Metadata},
+    requests::{
+        eth::client_version,
+        hardhat::rpc_types::{Metadata, MineBlockOptions},
+    },
     ProviderError,
 };
 
@@ -27,6 +30,16 @@ pub fn handle_metadata_request<LoggerErrorT: Debug>(
     })
 }
 
+/// Queues withdrawals to be included in and credited by the next mined block.
+pub fn handle_queue_withdrawals_request<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    withdrawals: Vec<Withdrawal>,
+) -> Result<bool, ProviderError<LoggerErrorT>> {
+    data.queue_withdrawals(withdrawals)?;
+
+    Ok(true)
+}
+
 pub fn handle_set_coinbase_request<LoggerErrorT: Debug>(
     data: &mut ProviderData<LoggerErrorT>,
     coinbase: Address,
@@ -54,6 +67,16 @@ pub fn handle_set_next_block_base_fee_per_gas_request<LoggerErrorT: Debug>(
     Ok(true)
 }
 
+/// Sets the header fields and withdrawals of the next mined block.
+pub fn handle_set_next_block_options_request<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    options: MineBlockOptions,
+) -> Result<bool, ProviderError<LoggerErrorT>> {
+    data.set_next_block_options(options)?;
+
+    Ok(true)
+}
+
 pub fn handle_set_prev_randao_request<LoggerErrorT: Debug>(
     data: &mut ProviderData<LoggerErrorT>,
     prev_randao: B256,
@@ -1,8 +1,19 @@
 use core::fmt::Debug;
 
-use edr_evm::trace::Trace;
+use edr_eth::{Address, Bytes, B256};
+use edr_evm::{trace::Trace, ExecutableTransaction, HashMap, MineOrdering};
 
-use crate::{data::ProviderData, ProviderError};
+use crate::{
+    data::ProviderData,
+    requests::{
+        eth::{
+            decode_and_validate_raw_transaction, resolve_transaction_request,
+            validate_send_transaction_request,
+        },
+        hardhat::rpc_types::{MineBlockOptions, MineBlockTransaction},
+    },
+    ProviderError,
+};
 
 pub fn handle_interval_mine_request<LoggerErrorT: Debug>(
     data: &mut ProviderData<LoggerErrorT>,
@@ -32,3 +43,208 @@ pub fn handle_mine<LoggerErrorT: Debug>(
 
     Ok((true, traces))
 }
+
+/// Mines a single block that contains exactly the provided transactions, in
+/// the provided order. Returns the hash of the mined block.
+pub fn handle_mine_block<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    transactions: Vec<MineBlockTransaction>,
+    options: Option<MineBlockOptions>,
+) -> Result<(B256, Vec<Trace>), ProviderError<LoggerErrorT>> {
+    // Omitted nonces follow the latest state and the preceding transactions,
+    // as the mempool's transactions are not included in the block.
+    let mut next_nonces = HashMap::<Address, u64>::default();
+    let mut sidecars = Vec::new();
+
+    let transactions = transactions
+        .into_iter()
+        .map(|transaction| {
+            let transaction = match transaction {
+                MineBlockTransaction::Raw(raw_transaction) => {
+                    let (signed_transaction, sidecar) =
+                        decode_and_validate_raw_transaction(data, raw_transaction)?;
+
+                    let transaction =
+                        ExecutableTransaction::new(data.spec_id(), signed_transaction)?;
+
+                    if let Some(sidecar) = sidecar {
+                        sidecars.push((*transaction.hash(), sidecar));
+                    }
+
+                    transaction
+                }
+                MineBlockTransaction::Request(mut transaction_request) => {
+                    validate_send_transaction_request(data, &transaction_request)?;
+
+                    if transaction_request.nonce.is_none() {
+                        let nonce = match next_nonces.get(&transaction_request.from) {
+                            Some(nonce) => *nonce,
+                            None => data.get_transaction_count(transaction_request.from, None)?,
+                        };
+
+                        transaction_request.nonce = Some(nonce);
+                    }
+
+                    let transaction_request =
+                        resolve_transaction_request(data, transaction_request)?;
+                    data.sign_transaction_request(transaction_request)?
+                }
+            };
+
+            next_nonces.insert(*transaction.caller(), transaction.nonce() + 1);
+
+            Ok(transaction)
+        })
+        .collect::<Result<Vec<_>, ProviderError<LoggerErrorT>>>()?;
+
+    // The sidecars need to be known before the transactions are mined. Sidecars
+    // that weren't known yet are removed again if the block fails to be mined.
+    let mut added_sidecar_hashes = Vec::new();
+    for (transaction_hash, sidecar) in sidecars {
+        if !data.has_pending_blob_sidecar(&transaction_hash) {
+            data.add_pending_blob_sidecar(transaction_hash, sidecar);
+            added_sidecar_hashes.push(transaction_hash);
+        }
+    }
+
+    let mine_block_result = data
+        .mine_and_commit_block_with_transactions(
+            transactions,
+            options.map(Into::into).unwrap_or_default(),
+        )
+        .map_err(|error| {
+            for transaction_hash in &added_sidecar_hashes {
+                data.remove_pending_blob_sidecar(transaction_hash);
+            }
+
+            error
+        })?;
+
+    let block_hash = *mine_block_result.block.hash();
+    let traces = mine_block_result.transaction_traces.clone();
+
+    let spec_id = data.spec_id();
+    data.logger_mut()
+        .log_mined_block(spec_id, &[mine_block_result])
+        .map_err(ProviderError::Logger)?;
+
+    Ok((block_hash, traces))
+}
+
+pub fn handle_reorg<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    depth: u64,
+    raw_transactions: Option<Vec<Bytes>>,
+) -> Result<(bool, Vec<Trace>), ProviderError<LoggerErrorT>> {
+    let transactions = raw_transactions
+        .unwrap_or_default()
+        .into_iter()
+        .map(|raw_transaction| {
+            let (signed_transaction, sidecar) =
+                decode_and_validate_raw_transaction(data, raw_transaction)?;
+
+            let transaction = ExecutableTransaction::new(data.spec_id(), signed_transaction)?;
+
+            // The sidecar needs to be known before the transaction is mined
+            if let Some(sidecar) = sidecar {
+                data.add_pending_blob_sidecar(*transaction.hash(), sidecar);
+            }
+
+            Ok(transaction)
+        })
+        .collect::<Result<Vec<_>, ProviderError<LoggerErrorT>>>()?;
+
+    let mined_block_results = data.reorg(depth, transactions)?;
+
+    let spec_id = data.spec_id();
+    data.logger_mut()
+        .log_mined_block(spec_id, &mined_block_results)
+        .map_err(ProviderError::Logger)?;
+
+    let traces = mined_block_results
+        .into_iter()
+        .flat_map(|result| result.transaction_traces)
+        .collect();
+
+    Ok((true, traces))
+}
+
+pub fn handle_set_mempool_ordering<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    mine_ordering: MineOrdering,
+) -> Result<bool, ProviderError<LoggerErrorT>> {
+    data.set_mine_ordering(Box::new(mine_ordering));
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use edr_eth::{
+        rlp::Encodable,
+        transaction::{
+            Eip4844TransactionRequest, Eip4844TransactionWithSidecar, EthTransactionRequest,
+        },
+        U256,
+    };
+    use edr_evm::{blob_versioned_hash, test_utils::zero_blob_sidecar};
+
+    use super::*;
+    use crate::data::test_utils::ProviderTestFixture;
+
+    #[test]
+    fn mine_block_removes_sidecars_if_mining_fails() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+
+        let sidecar = zero_blob_sidecar(1);
+        let transaction = Eip4844TransactionRequest {
+            chain_id: fixture.config.chain_id,
+            nonce: 0,
+            max_priority_fee_per_gas: U256::from(1_000_000_000u64),
+            max_fee_per_gas: U256::from(42_000_000_000u64),
+            gas_limit: 30_000,
+            to: Address::ZERO,
+            value: U256::ZERO,
+            input: Bytes::new(),
+            access_list: Vec::new(),
+            max_fee_per_blob_gas: U256::from(1_000_000_000u64),
+            blob_hashes: vec![blob_versioned_hash(&sidecar.commitments[0])],
+        }
+        .sign(&fixture.config.accounts[0].secret_key)?;
+        let transaction_hash = *transaction.hash();
+
+        // EIP-4844 transaction type
+        let mut raw_transaction = vec![3u8];
+        Eip4844TransactionWithSidecar {
+            transaction,
+            sidecar,
+        }
+        .encode(&mut raw_transaction);
+
+        // Reusing the blob transaction's nonce fails the whole block
+        let reused_nonce_request = EthTransactionRequest {
+            from: fixture.nth_local_account(0)?,
+            to: Some(Address::ZERO),
+            nonce: Some(0),
+            ..EthTransactionRequest::default()
+        };
+
+        let last_block_number = fixture.provider_data.last_block_number();
+        let result = handle_mine_block(
+            &mut fixture.provider_data,
+            vec![
+                MineBlockTransaction::Raw(Bytes::from(raw_transaction)),
+                MineBlockTransaction::Request(reused_nonce_request),
+            ],
+            None,
+        );
+
+        assert!(result.is_err());
+        assert_eq!(fixture.provider_data.last_block_number(), last_block_number);
+        assert!(!fixture
+            .provider_data
+            .has_pending_blob_sidecar(&transaction_hash));
+
+        Ok(())
+    }
+}
@@ -1,7 +1,19 @@
+mod account;
+mod blob;
 mod compiler;
 mod config;
 mod metadata;
+mod mine;
+mod snapshot;
+mod state;
 
+pub use account::AccountState;
+pub use blob::{BlobSidecarOutput, BlobSidecars};
 pub use compiler::{CompilerInput, CompilerInputSource, CompilerOutput, CompilerOutputContract};
 pub use config::{ForkConfig, ResetProviderConfig};
 pub use metadata::{ForkMetadata, Metadata};
+pub use mine::{MineBlockOptions, MineBlockTransaction};
+pub use snapshot::{SnapshotInfo, SnapshotSelector};
+pub use state::{
+    DumpStateOptions, SerializedBlockContext, SerializedState, SERIALIZED_STATE_VERSION,
+};
@@ -0,0 +1,27 @@
+use std::collections::BTreeMap;
+
+use edr_eth::{Bytes, U256};
+
+/// The state of an account, as set by `hardhat_setAccount`. Omitted fields
+/// retain their current value.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountState {
+    /// The account's balance
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub balance: Option<U256>,
+    /// The account's nonce
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "edr_eth::serde::optional_u64"
+    )]
+    pub nonce: Option<u64>,
+    /// The account's code
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<Bytes>,
+    /// The account's storage slots. When provided, the account's existing
+    /// storage is replaced.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage: Option<BTreeMap<U256, U256>>,
+}
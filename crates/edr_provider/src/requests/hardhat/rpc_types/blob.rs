@@ -0,0 +1,36 @@
+use edr_eth::Bytes;
+use edr_evm::FixedBytes;
+
+use crate::data::MinedBlob;
+
+/// The blob sidecars of a block, in the shape of the beacon API's
+/// `/eth/v1/beacon/blob_sidecars/{block_id}` response.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct BlobSidecars {
+    /// The sidecars of the block's blobs, in order
+    pub data: Vec<BlobSidecarOutput>,
+}
+
+/// A single blob sidecar, as returned by the beacon API.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct BlobSidecarOutput {
+    /// The index of the blob within the block, as a decimal string
+    pub index: String,
+    /// The blob
+    pub blob: Bytes,
+    /// The KZG commitment of the blob
+    pub kzg_commitment: FixedBytes<48>,
+    /// The KZG proof of the blob
+    pub kzg_proof: FixedBytes<48>,
+}
+
+impl From<MinedBlob> for BlobSidecarOutput {
+    fn from(value: MinedBlob) -> Self {
+        Self {
+            index: value.index.to_string(),
+            blob: value.blob,
+            kzg_commitment: value.commitment,
+            kzg_proof: value.proof,
+        }
+    }
+}
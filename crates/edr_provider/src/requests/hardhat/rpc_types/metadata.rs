@@ -26,7 +26,7 @@ pub struct Metadata {
 }
 
 /// Metadata about the forked network.
-#[derive(Clone, serde::Deserialize, serde::Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ForkMetadata {
     /// The chainId of the network that is being forked
@@ -0,0 +1,86 @@
+use edr_eth::{
+    block::BlockOptions, transaction::EthTransactionRequest, withdrawal::Withdrawal, Address,
+    Bytes, B256, U256,
+};
+
+/// The header fields and withdrawals of a mined block, as provided to
+/// `hardhat_mineBlock` and `hardhat_setNextBlockOptions`. Omitted fields are
+/// determined by the provider.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MineBlockOptions {
+    /// The block's beneficiary
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coinbase: Option<Address>,
+    /// The block's timestamp
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "edr_eth::serde::optional_u64"
+    )]
+    pub timestamp: Option<u64>,
+    /// The block's gas limit
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "edr_eth::serde::optional_u64"
+    )]
+    pub gas_limit: Option<u64>,
+    /// The block's base fee per gas
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_fee_per_gas: Option<U256>,
+    /// The block's difficulty
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub difficulty: Option<U256>,
+    /// The block's mix hash; i.e. the prevrandao in post-merge hardforks
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mix_hash: Option<B256>,
+    /// The block's extra data
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extra_data: Option<Bytes>,
+    /// The hash tree root of the parent beacon block (EIP-4788)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_beacon_block_root: Option<B256>,
+    /// The block's withdrawals (EIP-4895)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub withdrawals: Option<Vec<Withdrawal>>,
+}
+
+impl From<MineBlockOptions> for BlockOptions {
+    fn from(value: MineBlockOptions) -> Self {
+        let MineBlockOptions {
+            coinbase,
+            timestamp,
+            gas_limit,
+            base_fee_per_gas,
+            difficulty,
+            mix_hash,
+            extra_data,
+            parent_beacon_block_root,
+            withdrawals,
+        } = value;
+
+        BlockOptions {
+            beneficiary: coinbase,
+            timestamp,
+            gas_limit,
+            base_fee: base_fee_per_gas,
+            difficulty,
+            mix_hash,
+            extra_data,
+            parent_beacon_block_root,
+            withdrawals,
+            ..BlockOptions::default()
+        }
+    }
+}
+
+/// A transaction to include in a block mined by `hardhat_mineBlock`.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(untagged)]
+pub enum MineBlockTransaction {
+    /// A signed, RLP-encoded transaction
+    Raw(Bytes),
+    /// A transaction request of a local or impersonated account
+    Request(EthTransactionRequest),
+}
@@ -0,0 +1,27 @@
+/// Identifies a snapshot, either by its ID or by its name.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(untagged)]
+pub enum SnapshotSelector {
+    /// The snapshot's ID
+    Id(#[serde(with = "edr_eth::serde::u64")] u64),
+    /// The snapshot's name
+    Name(String),
+}
+
+/// Information about a snapshot, as returned by `hardhat_listSnapshots`.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotInfo {
+    /// The snapshot's ID
+    #[serde(with = "edr_eth::serde::u64")]
+    pub id: u64,
+    /// The snapshot's name, if it was named
+    pub name: Option<String>,
+    /// The number of the latest block at the time of the snapshot
+    #[serde(with = "edr_eth::serde::u64")]
+    pub block_number: u64,
+    /// The time at which the snapshot was created, in seconds since the UNIX
+    /// epoch
+    #[serde(with = "edr_eth::serde::u64")]
+    pub created_at: u64,
+}
@@ -0,0 +1,53 @@
+use edr_eth::Address;
+use edr_evm::state::StateDump;
+
+use super::ForkMetadata;
+
+/// The version of the [`SerializedState`] format that is produced by
+/// `hardhat_dumpState` and accepted by `hardhat_loadState`.
+pub const SERIALIZED_STATE_VERSION: u64 = 1;
+
+/// Options for `hardhat_dumpState`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DumpStateOptions {
+    /// Whether to only dump the changes relative to the fork block. Required
+    /// when forking, as the remote state cannot be dumped in full.
+    #[serde(default)]
+    pub only_changes: bool,
+}
+
+/// A versioned dump of the provider's world state and block context, which
+/// can be loaded back using `hardhat_loadState`.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SerializedState {
+    /// The version of the format
+    #[serde(with = "edr_eth::serde::u64")]
+    pub version: u64,
+    /// The block context at the time of the dump
+    pub block: SerializedBlockContext,
+    /// The fork that the state is relative to, if it only contains the
+    /// changes relative to the fork block.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fork: Option<ForkMetadata>,
+    /// The world state
+    pub state: StateDump,
+}
+
+/// The block context of a [`SerializedState`].
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SerializedBlockContext {
+    /// The number of the latest block
+    #[serde(with = "edr_eth::serde::u64")]
+    pub number: u64,
+    /// The timestamp of the latest block
+    #[serde(with = "edr_eth::serde::u64")]
+    pub timestamp: u64,
+    /// The beneficiary of mined blocks
+    pub coinbase: Address,
+    /// The gas limit of mined blocks
+    #[serde(with = "edr_eth::serde::u64")]
+    pub gas_limit: u64,
+}
@@ -0,0 +1,48 @@
+use core::fmt::Debug;
+
+use edr_eth::U64;
+
+use crate::{
+    data::ProviderData,
+    requests::hardhat::rpc_types::{SnapshotInfo, SnapshotSelector},
+    ProviderError,
+};
+
+pub fn handle_delete_snapshot<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    selector: SnapshotSelector,
+) -> Result<bool, ProviderError<LoggerErrorT>> {
+    Ok(data
+        .snapshot_id(&selector)
+        .map_or(false, |snapshot_id| data.delete_snapshot(snapshot_id)))
+}
+
+pub fn handle_list_snapshots<LoggerErrorT: Debug>(
+    data: &ProviderData<LoggerErrorT>,
+) -> Result<Vec<SnapshotInfo>, ProviderError<LoggerErrorT>> {
+    Ok(data.snapshots())
+}
+
+pub fn handle_revert_to_snapshot<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    selector: SnapshotSelector,
+) -> Result<bool, ProviderError<LoggerErrorT>> {
+    if let Some(snapshot_id) = data.snapshot_id(&selector) {
+        data.revert_to_snapshot_and_keep(snapshot_id)
+    } else {
+        Ok(false)
+    }
+}
+
+pub fn handle_snapshot<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    name: Option<String>,
+) -> Result<U64, ProviderError<LoggerErrorT>> {
+    let snapshot_id = if let Some(name) = name {
+        data.make_named_snapshot(name)?
+    } else {
+        data.make_snapshot()
+    };
+
+    Ok(U64::from(snapshot_id))
+}
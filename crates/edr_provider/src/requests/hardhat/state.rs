@@ -1,8 +1,50 @@
 use core::fmt::Debug;
+use std::collections::BTreeMap;
 
 use edr_eth::{Address, Bytes, U256};
 
-use crate::{data::ProviderData, ProviderError};
+use crate::{
+    data::ProviderData,
+    requests::hardhat::rpc_types::{AccountState, DumpStateOptions, SerializedState},
+    ProviderError,
+};
+
+pub fn handle_delete_account<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    address: Address,
+) -> Result<bool, ProviderError<LoggerErrorT>> {
+    data.delete_account(address)?;
+
+    Ok(true)
+}
+
+pub fn handle_dump_state<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    options: Option<DumpStateOptions>,
+) -> Result<SerializedState, ProviderError<LoggerErrorT>> {
+    let DumpStateOptions { only_changes } = options.unwrap_or_default();
+
+    data.dump_state(only_changes)
+}
+
+pub fn handle_load_state<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    serialized_state: SerializedState,
+) -> Result<bool, ProviderError<LoggerErrorT>> {
+    data.load_state(serialized_state)?;
+
+    Ok(true)
+}
+
+pub fn handle_set_account<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    address: Address,
+    account: AccountState,
+) -> Result<bool, ProviderError<LoggerErrorT>> {
+    data.set_account(address, account)?;
+
+    Ok(true)
+}
 
 pub fn handle_set_balance<LoggerErrorT: Debug>(
     data: &mut ProviderData<LoggerErrorT>,
@@ -34,6 +76,16 @@ pub fn handle_set_nonce<LoggerErrorT: Debug>(
     Ok(true)
 }
 
+pub fn handle_set_storage<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    address: Address,
+    storage: BTreeMap<U256, U256>,
+) -> Result<bool, ProviderError<LoggerErrorT>> {
+    data.set_account_storage(address, storage.into_iter().collect())?;
+
+    Ok(true)
+}
+
 pub fn handle_set_storage_at<LoggerErrorT: Debug>(
     data: &mut ProviderData<LoggerErrorT>,
     address: Address,
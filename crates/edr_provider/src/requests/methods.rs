@@ -1,19 +1,29 @@
+use std::collections::BTreeMap;
+
 use edr_eth::{
     remote::{
         eth::CallRequest,
         filter::{LogFilterOptions, SubscriptionType},
-        BlockSpec, PreEip1898BlockSpec, StateOverrideOptions,
+        BlockOverrideOptions, BlockSpec, PreEip1898BlockSpec, StateOverrideOptions,
     },
     serde::{optional_single_to_sequence, sequence_to_optional_single},
     transaction::EthTransactionRequest,
+    withdrawal::Withdrawal,
     Address, Bytes, B256, U256, U64,
 };
+use edr_evm::{trace::parity::TraceType, MineOrdering};
 use ethers_core::types::transaction::eip712::TypedData;
 
 use super::serde::RpcAddress;
 use crate::requests::{
+    anvil::AnvilForkConfig,
     debug::DebugTraceConfig,
-    hardhat::rpc_types::{CompilerInput, CompilerOutput, ResetProviderConfig},
+    eth::{CallBundleRequest, SendBundleRequest, SimulatePayload},
+    hardhat::rpc_types::{
+        AccountState, CompilerInput, CompilerOutput, DumpStateOptions, MineBlockOptions,
+        MineBlockTransaction, ResetProviderConfig, SerializedState, SnapshotSelector,
+    },
+    trace::TraceFilter,
 };
 
 mod optional_block_spec {
@@ -35,6 +45,9 @@ pub enum MethodInvocation {
     /// eth_accounts
     #[serde(rename = "eth_accounts", with = "edr_eth::serde::empty_params")]
     Accounts(()),
+    /// eth_blobBaseFee
+    #[serde(rename = "eth_blobBaseFee", with = "edr_eth::serde::empty_params")]
+    BlobBaseFee(()),
     /// eth_blockNumber
     #[serde(rename = "eth_blockNumber", with = "edr_eth::serde::empty_params")]
     BlockNumber(()),
@@ -48,13 +61,27 @@ pub enum MethodInvocation {
         )]
         Option<BlockSpec>,
         #[serde(default, skip_serializing_if = "Option::is_none")] Option<StateOverrideOptions>,
+        #[serde(default, skip_serializing_if = "Option::is_none")] Option<BlockOverrideOptions>,
     ),
+    /// eth_callBundle
+    #[serde(rename = "eth_callBundle", with = "edr_eth::serde::sequence")]
+    CallBundle(CallBundleRequest),
     /// eth_chainId
     #[serde(rename = "eth_chainId", with = "edr_eth::serde::empty_params")]
     ChainId(()),
     /// eth_coinbase
     #[serde(rename = "eth_coinbase", with = "edr_eth::serde::empty_params")]
     Coinbase(()),
+    /// eth_createAccessList
+    #[serde(rename = "eth_createAccessList")]
+    CreateAccessList(
+        CallRequest,
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            default = "optional_block_spec::latest"
+        )]
+        Option<BlockSpec>,
+    ),
     /// eth_estimateGas
     #[serde(rename = "eth_estimateGas")]
     EstimateGas(
@@ -64,6 +91,8 @@ pub enum MethodInvocation {
             default = "optional_block_spec::pending"
         )]
         Option<BlockSpec>,
+        #[serde(default, skip_serializing_if = "Option::is_none")] Option<StateOverrideOptions>,
+        #[serde(default, skip_serializing_if = "Option::is_none")] Option<BlockOverrideOptions>,
     ),
     /// eth_feeHistory
     #[serde(rename = "eth_feeHistory")]
@@ -104,6 +133,9 @@ pub enum MethodInvocation {
         /// include transaction data
         bool,
     ),
+    /// eth_getBlockReceipts
+    #[serde(rename = "eth_getBlockReceipts", with = "edr_eth::serde::sequence")]
+    GetBlockReceipts(BlockSpec),
     /// eth_getBlockTransactionCountByHash
     #[serde(
         rename = "eth_getBlockTransactionCountByHash",
@@ -135,6 +167,17 @@ pub enum MethodInvocation {
     /// eth_getLogs
     #[serde(rename = "eth_getLogs", with = "edr_eth::serde::sequence")]
     GetLogs(LogFilterOptions),
+    /// eth_getProof
+    #[serde(rename = "eth_getProof")]
+    GetProof(
+        #[serde(deserialize_with = "crate::requests::serde::deserialize_address")] Address,
+        Vec<U256>,
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            default = "optional_block_spec::latest"
+        )]
+        Option<BlockSpec>,
+    ),
     /// eth_getStorageAt
     #[serde(rename = "eth_getStorageAt")]
     GetStorageAt(
@@ -203,6 +246,9 @@ pub enum MethodInvocation {
         with = "edr_eth::serde::empty_params"
     )]
     PendingTransactions(()),
+    /// eth_sendBundle
+    #[serde(rename = "eth_sendBundle", with = "edr_eth::serde::sequence")]
+    SendBundle(SendBundleRequest),
     /// eth_sendRawTransaction
     #[serde(rename = "eth_sendRawTransaction", with = "edr_eth::serde::sequence")]
     SendRawTransaction(Bytes),
@@ -221,6 +267,16 @@ pub enum MethodInvocation {
         #[serde(deserialize_with = "crate::requests::serde::deserialize_address")] Address,
         #[serde(deserialize_with = "crate::requests::serde::typed_data::deserialize")] TypedData,
     ),
+    /// eth_simulateV1
+    #[serde(rename = "eth_simulateV1")]
+    SimulateV1(
+        SimulatePayload,
+        #[serde(
+            skip_serializing_if = "Option::is_none",
+            default = "optional_block_spec::latest"
+        )]
+        Option<BlockSpec>,
+    ),
     /// eth_subscribe
     #[serde(rename = "eth_subscribe")]
     Subscribe(
@@ -243,7 +299,11 @@ pub enum MethodInvocation {
     #[serde(rename = "web3_sha3", with = "edr_eth::serde::sequence")]
     Web3Sha3(Bytes),
     /// evm_increaseTime
-    #[serde(rename = "evm_increaseTime", with = "edr_eth::serde::sequence")]
+    #[serde(
+        rename = "evm_increaseTime",
+        alias = "anvil_increaseTime",
+        with = "edr_eth::serde::sequence"
+    )]
     EvmIncreaseTime(U64OrUsize),
     /// evm_mine
     #[serde(
@@ -253,13 +313,21 @@ pub enum MethodInvocation {
     )]
     EvmMine(Option<U64OrUsize>),
     /// evm_revert
-    #[serde(rename = "evm_revert", with = "edr_eth::serde::sequence")]
+    #[serde(
+        rename = "evm_revert",
+        alias = "anvil_revert",
+        with = "edr_eth::serde::sequence"
+    )]
     EvmRevert(U64),
     /// evm_setAutomine
     #[serde(rename = "evm_setAutomine", with = "edr_eth::serde::sequence")]
     EvmSetAutomine(bool),
     /// evm_setBlockGasLimit
-    #[serde(rename = "evm_setBlockGasLimit", with = "edr_eth::serde::sequence")]
+    #[serde(
+        rename = "evm_setBlockGasLimit",
+        alias = "anvil_setBlockGasLimit",
+        with = "edr_eth::serde::sequence"
+    )]
     EvmSetBlockGasLimit(U64),
     /// evm_setIntervalMining
     #[serde(rename = "evm_setIntervalMining", with = "edr_eth::serde::sequence")]
@@ -267,13 +335,24 @@ pub enum MethodInvocation {
     /// evm_setNextBlockTimestamp
     #[serde(
         rename = "evm_setNextBlockTimestamp",
+        alias = "anvil_setNextBlockTimestamp",
         with = "edr_eth::serde::sequence"
     )]
     EvmSetNextBlockTimestamp(U64OrUsize),
     /// evm_snapshot
-    #[serde(rename = "evm_snapshot", with = "edr_eth::serde::empty_params")]
+    #[serde(
+        rename = "evm_snapshot",
+        alias = "anvil_snapshot",
+        with = "edr_eth::serde::empty_params"
+    )]
     EvmSnapshot(()),
 
+    /// debug_traceBlockByHash
+    #[serde(rename = "debug_traceBlockByHash")]
+    DebugTraceBlockByHash(B256, #[serde(default)] Option<DebugTraceConfig>),
+    /// debug_traceBlockByNumber
+    #[serde(rename = "debug_traceBlockByNumber")]
+    DebugTraceBlockByNumber(BlockSpec, #[serde(default)] Option<DebugTraceConfig>),
     // debug_traceTransaction
     #[serde(rename = "debug_traceCall")]
     DebugTraceCall(
@@ -285,6 +364,146 @@ pub enum MethodInvocation {
     #[serde(rename = "debug_traceTransaction")]
     DebugTraceTransaction(B256, #[serde(default)] Option<DebugTraceConfig>),
 
+    /// trace_block
+    #[serde(rename = "trace_block", with = "edr_eth::serde::sequence")]
+    TraceBlock(BlockSpec),
+    /// trace_call
+    #[serde(rename = "trace_call")]
+    TraceCall(
+        CallRequest,
+        Vec<TraceType>,
+        #[serde(default = "optional_block_spec::latest")] Option<BlockSpec>,
+    ),
+    /// trace_filter
+    #[serde(rename = "trace_filter", with = "edr_eth::serde::sequence")]
+    TraceFilter(TraceFilter),
+    /// trace_replayTransaction
+    #[serde(rename = "trace_replayTransaction")]
+    TraceReplayTransaction(B256, Vec<TraceType>),
+    /// trace_transaction
+    #[serde(rename = "trace_transaction", with = "edr_eth::serde::sequence")]
+    TraceTransaction(B256),
+    /// txpool_content
+    #[serde(rename = "txpool_content", with = "edr_eth::serde::empty_params")]
+    TxPoolContent(()),
+    /// txpool_inspect
+    #[serde(rename = "txpool_inspect", with = "edr_eth::serde::empty_params")]
+    TxPoolInspect(()),
+    /// txpool_status
+    #[serde(rename = "txpool_status", with = "edr_eth::serde::empty_params")]
+    TxPoolStatus(()),
+
+    /// anvil_autoImpersonateAccount
+    #[serde(
+        rename = "anvil_autoImpersonateAccount",
+        with = "edr_eth::serde::sequence"
+    )]
+    AnvilAutoImpersonateAccount(bool),
+    /// anvil_dropTransaction
+    #[serde(rename = "anvil_dropTransaction", with = "edr_eth::serde::sequence")]
+    AnvilDropTransaction(B256),
+    /// anvil_dumpState
+    #[serde(rename = "anvil_dumpState", with = "edr_eth::serde::empty_params")]
+    AnvilDumpState(()),
+    /// anvil_impersonateAccount
+    #[serde(rename = "anvil_impersonateAccount", with = "edr_eth::serde::sequence")]
+    AnvilImpersonateAccount(RpcAddress),
+    /// anvil_loadState
+    #[serde(rename = "anvil_loadState", with = "edr_eth::serde::sequence")]
+    AnvilLoadState(Bytes),
+    /// anvil_mine
+    #[serde(rename = "anvil_mine")]
+    AnvilMine(
+        /// block count:
+        #[serde(default, with = "edr_eth::serde::optional_u64")]
+        Option<u64>,
+        /// interval:
+        #[serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "edr_eth::serde::optional_u64"
+        )]
+        Option<u64>,
+    ),
+    /// anvil_nodeInfo
+    #[serde(rename = "anvil_nodeInfo", with = "edr_eth::serde::empty_params")]
+    AnvilNodeInfo(()),
+    /// anvil_removePoolTransactions
+    #[serde(
+        rename = "anvil_removePoolTransactions",
+        with = "edr_eth::serde::sequence"
+    )]
+    AnvilRemovePoolTransactions(
+        #[serde(deserialize_with = "crate::requests::serde::deserialize_address")] Address,
+    ),
+    /// anvil_reset
+    #[serde(
+        rename = "anvil_reset",
+        serialize_with = "optional_single_to_sequence",
+        deserialize_with = "sequence_to_optional_single"
+    )]
+    AnvilReset(Option<AnvilForkConfig>),
+    /// anvil_setAutomine
+    #[serde(rename = "anvil_setAutomine", with = "edr_eth::serde::sequence")]
+    AnvilSetAutomine(bool),
+    /// anvil_setBalance
+    #[serde(rename = "anvil_setBalance")]
+    AnvilSetBalance(
+        #[serde(deserialize_with = "crate::requests::serde::deserialize_address")] Address,
+        #[serde(deserialize_with = "crate::requests::serde::deserialize_quantity")] U256,
+    ),
+    /// anvil_setChainId
+    #[serde(rename = "anvil_setChainId", with = "edr_eth::serde::sequence")]
+    AnvilSetChainId(U64OrUsize),
+    /// anvil_setCode
+    #[serde(rename = "anvil_setCode")]
+    AnvilSetCode(
+        #[serde(deserialize_with = "crate::requests::serde::deserialize_address")] Address,
+        #[serde(deserialize_with = "crate::requests::serde::deserialize_data")] Bytes,
+    ),
+    /// anvil_setCoinbase
+    #[serde(rename = "anvil_setCoinbase", with = "edr_eth::serde::sequence")]
+    AnvilSetCoinbase(
+        #[serde(deserialize_with = "crate::requests::serde::deserialize_address")] Address,
+    ),
+    /// anvil_setIntervalMining
+    #[serde(rename = "anvil_setIntervalMining", with = "edr_eth::serde::sequence")]
+    AnvilSetIntervalMining(
+        /// interval in seconds:
+        u64,
+    ),
+    /// anvil_setLoggingEnabled
+    #[serde(rename = "anvil_setLoggingEnabled", with = "edr_eth::serde::sequence")]
+    AnvilSetLoggingEnabled(bool),
+    /// anvil_setMinGasPrice
+    #[serde(rename = "anvil_setMinGasPrice", with = "edr_eth::serde::sequence")]
+    AnvilSetMinGasPrice(U256),
+    /// anvil_setNextBlockBaseFeePerGas
+    #[serde(
+        rename = "anvil_setNextBlockBaseFeePerGas",
+        with = "edr_eth::serde::sequence"
+    )]
+    AnvilSetNextBlockBaseFeePerGas(U256),
+    /// anvil_setNonce
+    #[serde(rename = "anvil_setNonce", alias = "evm_setAccountNonce")]
+    AnvilSetNonce(
+        #[serde(deserialize_with = "crate::requests::serde::deserialize_address")] Address,
+        #[serde(
+            deserialize_with = "crate::requests::serde::deserialize_nonce",
+            serialize_with = "edr_eth::serde::u64::serialize"
+        )]
+        u64,
+    ),
+    /// anvil_setRpcUrl
+    #[serde(rename = "anvil_setRpcUrl", with = "edr_eth::serde::sequence")]
+    AnvilSetRpcUrl(String),
+    /// anvil_stopImpersonatingAccount
+    #[serde(
+        rename = "anvil_stopImpersonatingAccount",
+        with = "edr_eth::serde::sequence"
+    )]
+    AnvilStopImpersonatingAccount(RpcAddress),
+
     /// hardhat_addCompilationResult
     #[serde(rename = "hardhat_addCompilationResult")]
     AddCompilationResult(
@@ -293,12 +512,40 @@ pub enum MethodInvocation {
         CompilerInput,
         CompilerOutput,
     ),
+    /// hardhat_autoImpersonateAccount
+    #[serde(
+        rename = "hardhat_autoImpersonateAccount",
+        with = "edr_eth::serde::sequence"
+    )]
+    AutoImpersonateAccount(bool),
+    /// hardhat_deleteAccount
+    #[serde(rename = "hardhat_deleteAccount", with = "edr_eth::serde::sequence")]
+    DeleteAccount(
+        #[serde(deserialize_with = "crate::requests::serde::deserialize_address")] Address,
+    ),
+    /// hardhat_deleteSnapshot
+    #[serde(rename = "hardhat_deleteSnapshot", with = "edr_eth::serde::sequence")]
+    DeleteSnapshot(SnapshotSelector),
     /// hardhat_dropTransaction
     #[serde(rename = "hardhat_dropTransaction", with = "edr_eth::serde::sequence")]
     DropTransaction(B256),
+    /// hardhat_dumpState
+    #[serde(
+        rename = "hardhat_dumpState",
+        serialize_with = "optional_single_to_sequence",
+        deserialize_with = "sequence_to_optional_single"
+    )]
+    DumpState(Option<DumpStateOptions>),
     /// hardhat_getAutomine
-    #[serde(rename = "hardhat_getAutomine", with = "edr_eth::serde::empty_params")]
+    #[serde(
+        rename = "hardhat_getAutomine",
+        alias = "anvil_getAutomine",
+        with = "edr_eth::serde::empty_params"
+    )]
     GetAutomine(()),
+    /// hardhat_getBlobSidecars
+    #[serde(rename = "hardhat_getBlobSidecars", with = "edr_eth::serde::sequence")]
+    GetBlobSidecars(BlockSpec),
     /// hardhat_getStackTraceFailuresCount
     #[serde(
         rename = "hardhat_getStackTraceFailuresCount",
@@ -314,8 +561,21 @@ pub enum MethodInvocation {
     /// hardhat_intervalMine
     #[serde(rename = "hardhat_intervalMine", with = "edr_eth::serde::empty_params")]
     IntervalMine(()),
+    /// hardhat_listSnapshots
+    #[serde(
+        rename = "hardhat_listSnapshots",
+        with = "edr_eth::serde::empty_params"
+    )]
+    ListSnapshots(()),
+    /// hardhat_loadState
+    #[serde(rename = "hardhat_loadState", with = "edr_eth::serde::sequence")]
+    LoadState(SerializedState),
     /// hardhat_metadata
-    #[serde(rename = "hardhat_metadata", with = "edr_eth::serde::empty_params")]
+    #[serde(
+        rename = "hardhat_metadata",
+        alias = "anvil_metadata",
+        with = "edr_eth::serde::empty_params"
+    )]
     Metadata(()),
     /// hardhat_mine
     #[serde(rename = "hardhat_mine")]
@@ -331,6 +591,25 @@ pub enum MethodInvocation {
         )]
         Option<u64>,
     ),
+    /// hardhat_mineBlock
+    #[serde(rename = "hardhat_mineBlock")]
+    MineBlock(
+        Vec<MineBlockTransaction>,
+        #[serde(default, skip_serializing_if = "Option::is_none")] Option<MineBlockOptions>,
+    ),
+    /// hardhat_queueWithdrawals
+    #[serde(rename = "hardhat_queueWithdrawals", with = "edr_eth::serde::sequence")]
+    QueueWithdrawals(Vec<Withdrawal>),
+    /// hardhat_reorg
+    #[serde(rename = "hardhat_reorg")]
+    Reorg(
+        /// depth:
+        #[serde(with = "edr_eth::serde::u64")]
+        u64,
+        /// raw transactions:
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        Option<Vec<Bytes>>,
+    ),
     /// hardhat_reset
     #[serde(
         rename = "hardhat_reset",
@@ -338,6 +617,15 @@ pub enum MethodInvocation {
         deserialize_with = "sequence_to_optional_single"
     )]
     Reset(Option<ResetProviderConfig>),
+    /// hardhat_revertToSnapshot
+    #[serde(rename = "hardhat_revertToSnapshot", with = "edr_eth::serde::sequence")]
+    RevertToSnapshot(SnapshotSelector),
+    /// hardhat_setAccount
+    #[serde(rename = "hardhat_setAccount")]
+    SetAccount(
+        #[serde(deserialize_with = "crate::requests::serde::deserialize_address")] Address,
+        AccountState,
+    ),
     /// hardhat_setBalance
     #[serde(rename = "hardhat_setBalance")]
     SetBalance(
@@ -359,6 +647,12 @@ pub enum MethodInvocation {
         with = "edr_eth::serde::sequence"
     )]
     SetLoggingEnabled(bool),
+    /// hardhat_setMempoolOrdering
+    #[serde(
+        rename = "hardhat_setMempoolOrdering",
+        with = "edr_eth::serde::sequence"
+    )]
+    SetMempoolOrdering(MineOrdering),
     /// hardhat_setMinGasPrice
     #[serde(rename = "hardhat_setMinGasPrice", with = "edr_eth::serde::sequence")]
     SetMinGasPrice(U256),
@@ -368,6 +662,12 @@ pub enum MethodInvocation {
         with = "edr_eth::serde::sequence"
     )]
     SetNextBlockBaseFeePerGas(U256),
+    /// hardhat_setNextBlockOptions
+    #[serde(
+        rename = "hardhat_setNextBlockOptions",
+        with = "edr_eth::serde::sequence"
+    )]
+    SetNextBlockOptions(MineBlockOptions),
     /// hardhat_setNonce
     #[serde(rename = "hardhat_setNonce")]
     SetNonce(
@@ -381,13 +681,26 @@ pub enum MethodInvocation {
     /// hardhat_setPrevRandao
     #[serde(rename = "hardhat_setPrevRandao", with = "edr_eth::serde::sequence")]
     SetPrevRandao(B256),
+    /// hardhat_setStorage
+    #[serde(rename = "hardhat_setStorage")]
+    SetStorage(
+        #[serde(deserialize_with = "crate::requests::serde::deserialize_address")] Address,
+        BTreeMap<U256, U256>,
+    ),
     /// hardhat_setStorageAt
-    #[serde(rename = "hardhat_setStorageAt")]
+    #[serde(rename = "hardhat_setStorageAt", alias = "anvil_setStorageAt")]
     SetStorageAt(
         #[serde(deserialize_with = "crate::requests::serde::deserialize_address")] Address,
         #[serde(deserialize_with = "crate::requests::serde::deserialize_storage_key")] U256,
         #[serde(with = "crate::requests::serde::storage_value")] U256,
     ),
+    /// hardhat_snapshot
+    #[serde(
+        rename = "hardhat_snapshot",
+        serialize_with = "optional_single_to_sequence",
+        deserialize_with = "sequence_to_optional_single"
+    )]
+    Snapshot(Option<String>),
     /// hardhat_stopImpersonatingAccount
     #[serde(
         rename = "hardhat_stopImpersonatingAccount",
@@ -401,16 +714,20 @@ impl MethodInvocation {
     pub fn method_name(&self) -> &'static str {
         match self {
             MethodInvocation::Accounts(_) => "eth_accounts",
+            MethodInvocation::BlobBaseFee(_) => "eth_blobBaseFee",
             MethodInvocation::BlockNumber(_) => "eth_blockNumber",
-            MethodInvocation::Call(_, _, _) => "eth_call",
+            MethodInvocation::Call(_, _, _, _) => "eth_call",
+            MethodInvocation::CallBundle(_) => "eth_callBundle",
             MethodInvocation::ChainId(_) => "eth_chainId",
             MethodInvocation::Coinbase(_) => "eth_coinbase",
-            MethodInvocation::EstimateGas(_, _) => "eth_estimateGas",
+            MethodInvocation::CreateAccessList(_, _) => "eth_createAccessList",
+            MethodInvocation::EstimateGas(_, _, _, _) => "eth_estimateGas",
             MethodInvocation::FeeHistory(_, _, _) => "eth_feeHistory",
             MethodInvocation::GasPrice(_) => "eth_gasPrice",
             MethodInvocation::GetBalance(_, _) => "eth_getBalance",
             MethodInvocation::GetBlockByNumber(_, _) => "eth_getBlockByNumber",
             MethodInvocation::GetBlockByHash(_, _) => "eth_getBlockByHash",
+            MethodInvocation::GetBlockReceipts(_) => "eth_getBlockReceipts",
             MethodInvocation::GetBlockTransactionCountByHash(_) => {
                 "eth_getBlockTransactionCountByHash"
             }
@@ -421,6 +738,7 @@ impl MethodInvocation {
             MethodInvocation::GetFilterChanges(_) => "eth_getFilterChanges",
             MethodInvocation::GetFilterLogs(_) => "eth_getFilterLogs",
             MethodInvocation::GetLogs(_) => "eth_getLogs",
+            MethodInvocation::GetProof(_, _, _) => "eth_getProof",
             MethodInvocation::GetStorageAt(_, _, _) => "eth_getStorageAt",
             MethodInvocation::GetTransactionByBlockHashAndIndex(_, _) => {
                 "eth_getTransactionByBlockHashAndIndex"
@@ -439,10 +757,12 @@ impl MethodInvocation {
             MethodInvocation::NewFilter(_) => "eth_newFilter",
             MethodInvocation::NewPendingTransactionFilter(_) => "eth_newPendingTransactionFilter",
             MethodInvocation::PendingTransactions(_) => "eth_pendingTransactions",
+            MethodInvocation::SendBundle(_) => "eth_sendBundle",
             MethodInvocation::SendRawTransaction(_) => "eth_sendRawTransaction",
             MethodInvocation::SendTransaction(_) => "eth_sendTransaction",
             MethodInvocation::Sign(_, _) => "eth_sign",
             MethodInvocation::SignTypedDataV4(_, _) => "eth_signTypedData_v4",
+            MethodInvocation::SimulateV1(_, _) => "eth_simulateV1",
             MethodInvocation::Subscribe(_, _) => "eth_subscribe",
             MethodInvocation::Syncing(_) => "eth_syncing",
             MethodInvocation::UninstallFilter(_) => "eth_uninstallFilter",
@@ -457,26 +777,77 @@ impl MethodInvocation {
             MethodInvocation::EvmSetIntervalMining(_) => "evm_setIntervalMining",
             MethodInvocation::EvmSetNextBlockTimestamp(_) => "evm_setNextBlockTimestamp",
             MethodInvocation::EvmSnapshot(_) => "evm_snapshot",
+            MethodInvocation::DebugTraceBlockByHash(_, _) => "debug_traceBlockByHash",
+            MethodInvocation::DebugTraceBlockByNumber(_, _) => "debug_traceBlockByNumber",
             MethodInvocation::DebugTraceCall(_, _, _) => "debug_traceCall",
             MethodInvocation::DebugTraceTransaction(_, _) => "debug_traceTransaction",
+
+            // trace_* methods
+            MethodInvocation::TraceBlock(_) => "trace_block",
+            MethodInvocation::TraceCall(_, _, _) => "trace_call",
+            MethodInvocation::TraceFilter(_) => "trace_filter",
+            MethodInvocation::TraceReplayTransaction(_, _) => "trace_replayTransaction",
+            MethodInvocation::TraceTransaction(_) => "trace_transaction",
+            MethodInvocation::TxPoolContent(_) => "txpool_content",
+            MethodInvocation::TxPoolInspect(_) => "txpool_inspect",
+            MethodInvocation::TxPoolStatus(_) => "txpool_status",
+            MethodInvocation::AnvilAutoImpersonateAccount(_) => "anvil_autoImpersonateAccount",
+            MethodInvocation::AnvilDropTransaction(_) => "anvil_dropTransaction",
+            MethodInvocation::AnvilDumpState(_) => "anvil_dumpState",
+            MethodInvocation::AnvilImpersonateAccount(_) => "anvil_impersonateAccount",
+            MethodInvocation::AnvilLoadState(_) => "anvil_loadState",
+            MethodInvocation::AnvilMine(_, _) => "anvil_mine",
+            MethodInvocation::AnvilNodeInfo(_) => "anvil_nodeInfo",
+            MethodInvocation::AnvilRemovePoolTransactions(_) => "anvil_removePoolTransactions",
+            MethodInvocation::AnvilReset(_) => "anvil_reset",
+            MethodInvocation::AnvilSetAutomine(_) => "anvil_setAutomine",
+            MethodInvocation::AnvilSetBalance(_, _) => "anvil_setBalance",
+            MethodInvocation::AnvilSetChainId(_) => "anvil_setChainId",
+            MethodInvocation::AnvilSetCode(_, _) => "anvil_setCode",
+            MethodInvocation::AnvilSetCoinbase(_) => "anvil_setCoinbase",
+            MethodInvocation::AnvilSetIntervalMining(_) => "anvil_setIntervalMining",
+            MethodInvocation::AnvilSetLoggingEnabled(_) => "anvil_setLoggingEnabled",
+            MethodInvocation::AnvilSetMinGasPrice(_) => "anvil_setMinGasPrice",
+            MethodInvocation::AnvilSetNextBlockBaseFeePerGas(_) => {
+                "anvil_setNextBlockBaseFeePerGas"
+            }
+            MethodInvocation::AnvilSetNonce(_, _) => "anvil_setNonce",
+            MethodInvocation::AnvilSetRpcUrl(_) => "anvil_setRpcUrl",
+            MethodInvocation::AnvilStopImpersonatingAccount(_) => "anvil_stopImpersonatingAccount",
             MethodInvocation::AddCompilationResult(_, _, _) => "hardhat_addCompilationResult",
+            MethodInvocation::AutoImpersonateAccount(_) => "hardhat_autoImpersonateAccount",
+            MethodInvocation::DeleteAccount(_) => "hardhat_deleteAccount",
+            MethodInvocation::DeleteSnapshot(_) => "hardhat_deleteSnapshot",
             MethodInvocation::DropTransaction(_) => "hardhat_dropTransaction",
+            MethodInvocation::DumpState(_) => "hardhat_dumpState",
             MethodInvocation::GetAutomine(_) => "hardhat_getAutomine",
+            MethodInvocation::GetBlobSidecars(_) => "hardhat_getBlobSidecars",
             MethodInvocation::GetStackTraceFailuresCount(_) => "hardhat_getStackTraceFailuresCount",
             MethodInvocation::ImpersonateAccount(_) => "hardhat_impersonateAccount",
             MethodInvocation::IntervalMine(_) => "hardhat_intervalMine",
+            MethodInvocation::ListSnapshots(_) => "hardhat_listSnapshots",
+            MethodInvocation::LoadState(_) => "hardhat_loadState",
             MethodInvocation::Metadata(_) => "hardhat_metadata",
             MethodInvocation::Mine(_, _) => "hardhat_mine",
+            MethodInvocation::MineBlock(_, _) => "hardhat_mineBlock",
+            MethodInvocation::QueueWithdrawals(_) => "hardhat_queueWithdrawals",
+            MethodInvocation::Reorg(_, _) => "hardhat_reorg",
             MethodInvocation::Reset(_) => "hardhat_reset",
+            MethodInvocation::RevertToSnapshot(_) => "hardhat_revertToSnapshot",
+            MethodInvocation::SetAccount(_, _) => "hardhat_setAccount",
             MethodInvocation::SetBalance(_, _) => "hardhat_setBalance",
             MethodInvocation::SetCode(_, _) => "hardhat_setCode",
             MethodInvocation::SetCoinbase(_) => "hardhat_setCoinbase",
             MethodInvocation::SetLoggingEnabled(_) => "hardhat_setLoggingEnabled",
+            MethodInvocation::SetMempoolOrdering(_) => "hardhat_setMempoolOrdering",
             MethodInvocation::SetMinGasPrice(_) => "hardhat_setMinGasPrice",
             MethodInvocation::SetNextBlockBaseFeePerGas(_) => "hardhat_setNextBlockBaseFeePerGas",
+            MethodInvocation::SetNextBlockOptions(_) => "hardhat_setNextBlockOptions",
             MethodInvocation::SetNonce(_, _) => "hardhat_setNonce",
             MethodInvocation::SetPrevRandao(_) => "hardhat_setPrevRandao",
+            MethodInvocation::SetStorage(_, _) => "hardhat_setStorage",
             MethodInvocation::SetStorageAt(_, _, _) => "hardhat_setStorageAt",
+            MethodInvocation::Snapshot(_) => "hardhat_snapshot",
             MethodInvocation::StopImpersonatingAccount(_) => "hardhat_stopImpersonatingAccount",
         }
     }
@@ -0,0 +1,109 @@
+use core::fmt::Debug;
+
+use edr_eth::{
+    remote::{eth::CallRequest, BlockSpec, BlockTag},
+    Address, B256,
+};
+use edr_evm::{
+    state::StateOverrides,
+    trace::parity::{LocalizedFlatTrace, TraceResults, TraceType},
+};
+
+use crate::{
+    data::ProviderData,
+    requests::{
+        eth::{resolve_block_spec_for_call_request, resolve_call_request},
+        validation::validate_call_request,
+    },
+    ProviderError,
+};
+
+pub fn handle_trace_transaction<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    transaction_hash: B256,
+) -> Result<Option<Vec<LocalizedFlatTrace>>, ProviderError<LoggerErrorT>> {
+    data.trace_transaction(&transaction_hash)
+}
+
+pub fn handle_trace_block<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    block_spec: BlockSpec,
+) -> Result<Vec<LocalizedFlatTrace>, ProviderError<LoggerErrorT>> {
+    data.trace_block(&block_spec)
+}
+
+pub fn handle_trace_replay_transaction<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    transaction_hash: B256,
+    trace_types: Vec<TraceType>,
+) -> Result<TraceResults, ProviderError<LoggerErrorT>> {
+    data.trace_replay_transaction(&transaction_hash, &trace_types)
+        .map_err(|error| match error {
+            ProviderError::InvalidTransactionHash(tx_hash) => ProviderError::InvalidInput(format!(
+                "Unable to find a block containing transaction {tx_hash}"
+            )),
+            _ => error,
+        })
+}
+
+pub fn handle_trace_call<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    call_request: CallRequest,
+    trace_types: Vec<TraceType>,
+    block_spec: Option<BlockSpec>,
+) -> Result<TraceResults, ProviderError<LoggerErrorT>> {
+    let block_spec = resolve_block_spec_for_call_request(block_spec);
+    validate_call_request(data.spec_id(), &call_request, &block_spec)?;
+
+    let transaction =
+        resolve_call_request(data, call_request, &block_spec, &StateOverrides::default())?;
+    data.trace_call(transaction, &block_spec, &trace_types)
+}
+
+pub fn handle_trace_filter<LoggerErrorT: Debug>(
+    data: &mut ProviderData<LoggerErrorT>,
+    filter: TraceFilter,
+) -> Result<Vec<LocalizedFlatTrace>, ProviderError<LoggerErrorT>> {
+    let from_block = filter
+        .from_block
+        .unwrap_or(BlockSpec::Tag(BlockTag::Earliest));
+    let to_block = filter.to_block.unwrap_or(BlockSpec::Tag(BlockTag::Latest));
+
+    let traces = data.trace_filter(
+        &from_block,
+        &to_block,
+        &filter.from_address,
+        &filter.to_address,
+    )?;
+
+    let traces = traces.into_iter().skip(filter.after.unwrap_or(0));
+    Ok(if let Some(count) = filter.count {
+        traces.take(count).collect()
+    } else {
+        traces.collect()
+    })
+}
+
+/// Filter options for `trace_filter`
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceFilter {
+    /// The first block to search. Defaults to the earliest block.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from_block: Option<BlockSpec>,
+    /// The last block to search. Defaults to the latest block.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to_block: Option<BlockSpec>,
+    /// Only include traces sent by one of these addresses.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub from_address: Vec<Address>,
+    /// Only include traces received by one of these addresses.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub to_address: Vec<Address>,
+    /// The number of matching traces to skip.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after: Option<usize>,
+    /// The maximum number of traces to return.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub count: Option<usize>,
+}
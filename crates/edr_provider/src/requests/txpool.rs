@@ -0,0 +1,197 @@
+use core::fmt::Debug;
+use std::collections::BTreeMap;
+
+use edr_eth::{remote, Address};
+use edr_evm::ExecutableTransaction;
+
+use crate::{
+    data::{ProviderData, TransactionAndBlock},
+    requests::eth::transaction_to_rpc_result,
+    ProviderError,
+};
+
+/// Transactions grouped by sender and nonce. Nonces are serialized as decimal
+/// strings, as done by geth.
+pub type TransactionsBySender<T> = BTreeMap<Address, BTreeMap<u64, T>>;
+
+/// The result of `txpool_content`
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct TxPoolContent {
+    /// Transactions that can be included in the next block
+    pub pending: TransactionsBySender<remote::eth::Transaction>,
+    /// Transactions that are waiting for a nonce gap to be filled
+    pub queued: TransactionsBySender<remote::eth::Transaction>,
+}
+
+/// The result of `txpool_inspect`
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct TxPoolInspect {
+    /// Summaries of transactions that can be included in the next block
+    pub pending: TransactionsBySender<String>,
+    /// Summaries of transactions that are waiting for a nonce gap to be filled
+    pub queued: TransactionsBySender<String>,
+}
+
+/// The result of `txpool_status`
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct TxPoolStatus {
+    /// The number of transactions that can be included in the next block
+    #[serde(with = "edr_eth::serde::u64")]
+    pub pending: u64,
+    /// The number of transactions that are waiting for a nonce gap to be
+    /// filled
+    #[serde(with = "edr_eth::serde::u64")]
+    pub queued: u64,
+}
+
+pub fn handle_txpool_content<LoggerErrorT: Debug>(
+    data: &ProviderData<LoggerErrorT>,
+) -> Result<TxPoolContent, ProviderError<LoggerErrorT>> {
+    let spec_id = data.spec_id();
+    let to_rpc_transaction = |transaction: &ExecutableTransaction| {
+        transaction_to_rpc_result(
+            TransactionAndBlock {
+                transaction: transaction.clone(),
+                block_data: None,
+                is_pending: true,
+            },
+            spec_id,
+        )
+    };
+
+    Ok(TxPoolContent {
+        pending: group_by_sender(data.executable_transactions(), to_rpc_transaction)?,
+        queued: group_by_sender(data.queued_transactions(), to_rpc_transaction)?,
+    })
+}
+
+pub fn handle_txpool_inspect<LoggerErrorT: Debug>(
+    data: &ProviderData<LoggerErrorT>,
+) -> Result<TxPoolInspect, ProviderError<LoggerErrorT>> {
+    let summarize = |transaction: &ExecutableTransaction| {
+        Ok::<_, ProviderError<LoggerErrorT>>(transaction_summary(transaction))
+    };
+
+    Ok(TxPoolInspect {
+        pending: group_by_sender(data.executable_transactions(), summarize)?,
+        queued: group_by_sender(data.queued_transactions(), summarize)?,
+    })
+}
+
+pub fn handle_txpool_status<LoggerErrorT: Debug>(
+    data: &ProviderData<LoggerErrorT>,
+) -> Result<TxPoolStatus, ProviderError<LoggerErrorT>> {
+    let pending = data.executable_transactions().count();
+    let queued = data.queued_transactions().count();
+
+    Ok(TxPoolStatus {
+        pending: pending.try_into().expect("usize fits into u64"),
+        queued: queued.try_into().expect("usize fits into u64"),
+    })
+}
+
+fn group_by_sender<'a, T, LoggerErrorT: Debug>(
+    transactions: impl Iterator<Item = &'a ExecutableTransaction>,
+    mut map_fn: impl FnMut(&ExecutableTransaction) -> Result<T, ProviderError<LoggerErrorT>>,
+) -> Result<TransactionsBySender<T>, ProviderError<LoggerErrorT>> {
+    let mut grouped = TransactionsBySender::new();
+    for transaction in transactions {
+        grouped
+            .entry(*transaction.caller())
+            .or_default()
+            .insert(transaction.nonce(), map_fn(transaction)?);
+    }
+
+    Ok(grouped)
+}
+
+/// Formats the geth-compatible summary of a transaction, e.g.
+/// `0x6B175474E89094C44Da98b954EedeAC495271d0F: 0 wei + 21000 gas × 1000000000
+/// wei`.
+fn transaction_summary(transaction: &ExecutableTransaction) -> String {
+    let recipient = transaction.to().map_or_else(
+        || "contract creation".to_string(),
+        |to| to.to_checksum(None),
+    );
+
+    format!(
+        "{recipient}: {} wei + {} gas × {} wei",
+        transaction.value(),
+        transaction.gas_limit(),
+        transaction.gas_price()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use edr_eth::transaction::{TransactionKind, TransactionRequest};
+
+    use super::*;
+    use crate::data::test_utils::ProviderTestFixture;
+
+    fn add_transaction(
+        fixture: &mut ProviderTestFixture,
+        nonce: u64,
+        kind: TransactionKind,
+    ) -> anyhow::Result<()> {
+        let mut request = fixture.dummy_transaction_request(0, 30_000, Some(nonce))?;
+        if let TransactionRequest::Eip155(request) = &mut request.request {
+            request.kind = kind;
+        }
+
+        let transaction = fixture.provider_data.sign_transaction_request(request)?;
+        fixture.provider_data.add_pending_transaction(transaction)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn txpool_groups_pending_and_queued() -> anyhow::Result<()> {
+        let mut fixture = ProviderTestFixture::new_local()?;
+        fixture.provider_data.set_auto_mining(false);
+
+        let recipient = Address::random();
+        add_transaction(&mut fixture, 0, TransactionKind::Call(recipient))?;
+        add_transaction(&mut fixture, 1, TransactionKind::Create)?;
+        // Nonce gap
+        add_transaction(&mut fixture, 3, TransactionKind::Call(recipient))?;
+
+        let sender = fixture.nth_local_account(0)?;
+
+        let status = handle_txpool_status(&fixture.provider_data)?;
+        assert_eq!(status.pending, 2);
+        assert_eq!(status.queued, 1);
+
+        let content = handle_txpool_content(&fixture.provider_data)?;
+        let pending = &content.pending[&sender];
+        assert_eq!(pending.keys().copied().collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(pending[&0].to, Some(recipient));
+        assert_eq!(pending[&1].to, None);
+
+        let queued = &content.queued[&sender];
+        assert_eq!(queued.keys().copied().collect::<Vec<_>>(), vec![3]);
+
+        let inspect = handle_txpool_inspect(&fixture.provider_data)?;
+        assert_eq!(
+            inspect.pending[&sender][&0],
+            format!(
+                "{}: 1 wei + 30000 gas × 42000000000 wei",
+                recipient.to_checksum(None)
+            )
+        );
+        assert_eq!(
+            inspect.pending[&sender][&1],
+            "contract creation: 1 wei + 30000 gas × 42000000000 wei"
+        );
+
+        // Nonces are serialized as decimal strings
+        let json = serde_json::to_value(&inspect)?;
+        let queued = json["queued"]
+            .as_object()
+            .and_then(|senders| senders.values().next())
+            .expect("sender has queued transactions");
+        assert!(queued["3"].is_string());
+
+        Ok(())
+    }
+}
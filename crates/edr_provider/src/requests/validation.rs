@@ -145,8 +145,11 @@ pub fn validate_transaction_spec<LoggerErrorT: Debug>(
         }
     }
 
-    if blobs.is_some() || blob_hashes.is_some() {
-        return Err(ProviderError::Eip4844TransactionUnsupported);
+    if spec_id < SpecId::CANCUN && (blobs.is_some() || blob_hashes.is_some()) {
+        return Err(ProviderError::UnsupportedEip4844Parameters {
+            current_hardfork: spec_id,
+            minimum_hardfork: SpecId::CANCUN,
+        });
     }
 
     Ok(())
@@ -162,7 +165,14 @@ pub fn validate_call_request<LoggerErrorT: Debug>(
     validate_transaction_and_call_request(
         spec_id,
         <&CallRequest as Into<SpecValidationData<'_>>>::into(call_request),
-    )
+    )?;
+
+    // Calls can't provide the blobs' commitments and proofs, only their hashes
+    if call_request.blobs.is_some() {
+        return Err(ProviderError::Eip4844TransactionUnsupported);
+    }
+
+    Ok(())
 }
 
 pub fn validate_transaction_and_call_request<'a, LoggerErrorT: Debug>(
@@ -182,6 +192,13 @@ You can use them by running Hardhat Network with 'hardfork' {minimum_hardfork:?}
         } => ProviderError::InvalidArgument(format!("\
 EIP-1559 style fee params (maxFeePerGas or maxPriorityFeePerGas) received but they are not supported by the current hardfork.
 
+You can use them by running Hardhat Network with 'hardfork' {minimum_hardfork:?} or later.
+        ")),
+        ProviderError::UnsupportedEip4844Parameters {
+            minimum_hardfork, ..
+        } => ProviderError::InvalidArgument(format!("\
+EIP-4844 blob params (blobs or blobHashes) received but they are not supported by the current hardfork.
+
 You can use them by running Hardhat Network with 'hardfork' {minimum_hardfork:?} or later.
         ")),
         err => err,
@@ -1,19 +1,28 @@
-use std::time::Instant;
+use std::time::{Instant, SystemTime};
 
 use edr_eth::{Address, U256};
 use edr_evm::{state::IrregularState, MemPool, RandomHashGenerator};
 use rpds::HashTrieMapSync;
 
-use crate::data::StateId;
+use crate::{
+    data::{BlobStore, PendingBundle, StateId},
+    requests::hardhat::rpc_types::MineBlockOptions,
+};
 
+#[derive(Clone)]
 pub(crate) struct Snapshot {
     pub block_number: u64,
     pub block_number_to_state_id: HashTrieMapSync<u64, StateId>,
     pub block_time_offset_seconds: i64,
+    pub blobs: BlobStore,
+    pub bundles: Vec<PendingBundle>,
     pub coinbase: Address,
+    pub created_at: SystemTime,
     pub irregular_state: IrregularState,
     pub mem_pool: MemPool,
+    pub name: Option<String>,
     pub next_block_base_fee_per_gas: Option<U256>,
+    pub next_block_options: MineBlockOptions,
     pub next_block_timestamp: Option<u64>,
     pub parent_beacon_block_root_generator: RandomHashGenerator,
     pub prev_randao_generator: RandomHashGenerator,
@@ -15,6 +15,7 @@ pub enum SubscriptionEventData {
     Logs(Vec<LogOutput>),
     NewHeads(BlockAndTotalDifficulty<BlockchainError>),
     NewPendingTransactions(B256),
+    DroppedTransactions(B256),
 }
 
 /// Supertrait for subscription callbacks.
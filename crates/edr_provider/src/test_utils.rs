@@ -55,6 +55,7 @@ pub fn create_test_config_with_fork(fork: Option<ForkConfig>) -> ProviderConfig
         ],
         allow_blocks_with_same_timestamp: false,
         allow_unlimited_contract_size: false,
+        auto_impersonate: false,
         bail_on_call_failure: false,
         bail_on_transaction_failure: false,
         // SAFETY: literal is non-zero
@@ -75,6 +76,7 @@ pub fn create_test_config_with_fork(fork: Option<ForkConfig>) -> ProviderConfig
         min_gas_price: U256::ZERO,
         mining: MiningConfig::default(),
         network_id: 123,
+        persistence_path: None,
         cache_dir: edr_defaults::CACHE_DIR.into(),
     }
 }
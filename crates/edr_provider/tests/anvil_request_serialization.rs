@@ -0,0 +1,183 @@
+mod common;
+
+use edr_eth::{Address, Bytes, B256, U256, U64};
+use edr_evm::alloy_primitives::U160;
+use edr_provider::{AnvilForkConfig, MethodInvocation, U64OrUsize};
+
+use crate::common::help_test_method_invocation_serde;
+
+/// Asserts that the invocation is deserialized from JSON in which its method
+/// name is replaced by the provided alias.
+fn help_test_method_invocation_alias(call: MethodInvocation, alias: &str) {
+    let serialized = serde_json::json!(call)
+        .to_string()
+        .replace(call.method_name(), alias);
+
+    let call_deserialized: MethodInvocation = serde_json::from_str(&serialized)
+        .unwrap_or_else(|_| panic!("should have successfully deserialized json {serialized}"));
+
+    assert_eq!(call, call_deserialized);
+}
+
+#[test]
+fn serde_anvil_aliases() {
+    help_test_method_invocation_alias(MethodInvocation::EvmRevert(U64::from(1)), "anvil_revert");
+    help_test_method_invocation_alias(
+        MethodInvocation::EvmSetBlockGasLimit(U64::from(1_000_000)),
+        "anvil_setBlockGasLimit",
+    );
+    help_test_method_invocation_alias(MethodInvocation::EvmSnapshot(()), "anvil_snapshot");
+    help_test_method_invocation_alias(MethodInvocation::GetAutomine(()), "anvil_getAutomine");
+    help_test_method_invocation_alias(MethodInvocation::Metadata(()), "anvil_metadata");
+    help_test_method_invocation_alias(
+        MethodInvocation::SetStorageAt(Address::random(), U256::ZERO, U256::MAX),
+        "anvil_setStorageAt",
+    );
+    help_test_method_invocation_alias(
+        MethodInvocation::AnvilSetNonce(Address::random(), 1u64),
+        "evm_setAccountNonce",
+    );
+    help_test_method_invocation_alias(
+        MethodInvocation::EvmIncreaseTime(U64OrUsize::U64(U64::from(60))),
+        "anvil_increaseTime",
+    );
+    help_test_method_invocation_alias(
+        MethodInvocation::EvmSetNextBlockTimestamp(U64OrUsize::U64(U64::from(1_700_000_000))),
+        "anvil_setNextBlockTimestamp",
+    );
+}
+
+#[test]
+fn serde_anvil_auto_impersonate_account() {
+    help_test_method_invocation_serde(MethodInvocation::AnvilAutoImpersonateAccount(true));
+}
+
+#[test]
+fn serde_anvil_drop_transaction() {
+    help_test_method_invocation_serde(MethodInvocation::AnvilDropTransaction(B256::random()));
+}
+
+#[test]
+fn serde_anvil_dump_state() {
+    help_test_method_invocation_serde(MethodInvocation::AnvilDumpState(()));
+}
+
+#[test]
+fn serde_anvil_impersonate_account() {
+    help_test_method_invocation_serde(MethodInvocation::AnvilImpersonateAccount(
+        Address::from(U160::from(1)).into(),
+    ));
+}
+
+#[test]
+fn serde_anvil_load_state() {
+    help_test_method_invocation_serde(MethodInvocation::AnvilLoadState(Bytes::from(
+        &b"{\"version\":1}"[..],
+    )));
+}
+
+#[test]
+fn serde_anvil_mine() {
+    help_test_method_invocation_serde(MethodInvocation::AnvilMine(None, None));
+    help_test_method_invocation_serde(MethodInvocation::AnvilMine(Some(10), None));
+    help_test_method_invocation_serde(MethodInvocation::AnvilMine(Some(10), Some(12)));
+}
+
+#[test]
+fn serde_anvil_node_info() {
+    help_test_method_invocation_serde(MethodInvocation::AnvilNodeInfo(()));
+}
+
+#[test]
+fn serde_anvil_remove_pool_transactions() {
+    help_test_method_invocation_serde(MethodInvocation::AnvilRemovePoolTransactions(
+        Address::random(),
+    ));
+}
+
+#[test]
+fn serde_anvil_reset() {
+    help_test_method_invocation_serde(MethodInvocation::AnvilReset(Some(AnvilForkConfig {
+        json_rpc_url: Some(String::from("http://whatever.com/whatever")),
+        block_number: Some(123456),
+    })));
+    help_test_method_invocation_serde(MethodInvocation::AnvilReset(Some(AnvilForkConfig {
+        json_rpc_url: None,
+        block_number: Some(123456),
+    })));
+}
+
+#[test]
+fn serde_anvil_set_automine() {
+    help_test_method_invocation_serde(MethodInvocation::AnvilSetAutomine(false));
+}
+
+#[test]
+fn serde_anvil_set_balance() {
+    help_test_method_invocation_serde(MethodInvocation::AnvilSetBalance(
+        Address::random(),
+        U256::ZERO,
+    ));
+}
+
+#[test]
+fn serde_anvil_set_chain_id() {
+    help_test_method_invocation_serde(MethodInvocation::AnvilSetChainId(U64OrUsize::U64(
+        U64::from(1337),
+    )));
+}
+
+#[test]
+fn serde_anvil_set_code() {
+    help_test_method_invocation_serde(MethodInvocation::AnvilSetCode(
+        Address::random(),
+        Bytes::from(&b"whatever"[..]),
+    ));
+}
+
+#[test]
+fn serde_anvil_set_coinbase() {
+    help_test_method_invocation_serde(MethodInvocation::AnvilSetCoinbase(Address::random()));
+}
+
+#[test]
+fn serde_anvil_set_interval_mining() {
+    help_test_method_invocation_serde(MethodInvocation::AnvilSetIntervalMining(0));
+    help_test_method_invocation_serde(MethodInvocation::AnvilSetIntervalMining(12));
+}
+
+#[test]
+fn serde_anvil_set_logging_enabled() {
+    help_test_method_invocation_serde(MethodInvocation::AnvilSetLoggingEnabled(true));
+}
+
+#[test]
+fn serde_anvil_set_min_gas_price() {
+    help_test_method_invocation_serde(MethodInvocation::AnvilSetMinGasPrice(U256::from(1)));
+}
+
+#[test]
+fn serde_anvil_set_next_block_base_fee_per_gas() {
+    help_test_method_invocation_serde(MethodInvocation::AnvilSetNextBlockBaseFeePerGas(
+        U256::from(1),
+    ));
+}
+
+#[test]
+fn serde_anvil_set_nonce() {
+    help_test_method_invocation_serde(MethodInvocation::AnvilSetNonce(Address::random(), 1u64));
+}
+
+#[test]
+fn serde_anvil_set_rpc_url() {
+    help_test_method_invocation_serde(MethodInvocation::AnvilSetRpcUrl(String::from(
+        "http://whatever.com/whatever",
+    )));
+}
+
+#[test]
+fn serde_anvil_stop_impersonating_account() {
+    help_test_method_invocation_serde(MethodInvocation::AnvilStopImpersonatingAccount(
+        Address::from(U160::from(1)).into(),
+    ));
+}
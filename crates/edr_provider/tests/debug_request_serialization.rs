@@ -0,0 +1,39 @@
+mod common;
+
+use edr_eth::{
+    remote::{BlockSpec, BlockTag},
+    B256, U256,
+};
+use edr_provider::{DebugTraceConfig, MethodInvocation};
+
+use crate::common::help_test_method_invocation_serde;
+
+#[test]
+fn serde_debug_trace_block_by_hash() {
+    let block_hash = B256::from(U256::from(1));
+
+    help_test_method_invocation_serde(MethodInvocation::DebugTraceBlockByHash(block_hash, None));
+    help_test_method_invocation_serde(MethodInvocation::DebugTraceBlockByHash(
+        block_hash,
+        Some(DebugTraceConfig {
+            disable_storage: Some(true),
+            disable_memory: Some(false),
+            ..DebugTraceConfig::default()
+        }),
+    ));
+}
+
+#[test]
+fn serde_debug_trace_block_by_number() {
+    help_test_method_invocation_serde(MethodInvocation::DebugTraceBlockByNumber(
+        BlockSpec::Number(100),
+        None,
+    ));
+    help_test_method_invocation_serde(MethodInvocation::DebugTraceBlockByNumber(
+        BlockSpec::Tag(BlockTag::Latest),
+        Some(DebugTraceConfig {
+            disable_stack: Some(true),
+            ..DebugTraceConfig::default()
+        }),
+    ));
+}
@@ -4,7 +4,8 @@ use edr_eth::{
     remote::{
         eth::CallRequest,
         filter::{LogFilterOptions, LogOutput, OneOrMore},
-        BlockSpec, BlockTag, PreEip1898BlockSpec,
+        AccountOverrideOptions, BlockOverrideOptions, BlockSpec, BlockTag, PreEip1898BlockSpec,
+        StateOverrideOptions,
     },
     transaction::EthTransactionRequest,
     Address, Bytes, B256, U256, U64,
@@ -21,6 +22,11 @@ fn test_serde_eth_accounts() {
     help_test_method_invocation_serde(MethodInvocation::Accounts(()));
 }
 
+#[test]
+fn test_serde_eth_blob_base_fee() {
+    help_test_method_invocation_serde(MethodInvocation::BlobBaseFee(()));
+}
+
 #[test]
 fn test_serde_eth_block_number() {
     help_test_method_invocation_serde(MethodInvocation::BlockNumber(()));
@@ -46,10 +52,21 @@ fn test_serde_eth_call() {
         tx.clone(),
         Some(BlockSpec::latest()),
         None,
+        None,
+    ));
+    help_test_method_invocation_serde(MethodInvocation::Call(
+        tx.clone(),
+        Some(BlockSpec::latest()),
+        Some(StateOverrideOptions::default()),
+        Some(BlockOverrideOptions {
+            number: Some(100),
+            timestamp: Some(1_700_000_000),
+            ..BlockOverrideOptions::default()
+        }),
     ));
     help_test_method_invocation_serde_with_expected(
-        MethodInvocation::Call(tx.clone(), None, None),
-        MethodInvocation::Call(tx, Some(BlockSpec::latest()), None),
+        MethodInvocation::Call(tx.clone(), None, None, None),
+        MethodInvocation::Call(tx, Some(BlockSpec::latest()), None, None),
     );
 }
 
@@ -63,6 +80,32 @@ fn test_serde_eth_coinbase() {
     help_test_method_invocation_serde(MethodInvocation::Coinbase(()));
 }
 
+#[test]
+fn test_serde_eth_create_access_list() {
+    let tx = CallRequest {
+        from: Some(Address::from(U160::from(1))),
+        to: Some(Address::from(U160::from(2))),
+        gas: Some(3),
+        gas_price: Some(U256::from(4)),
+        max_fee_per_gas: None,
+        max_priority_fee_per_gas: None,
+        value: Some(U256::from(123568919)),
+        data: Some(Bytes::from(&b"whatever"[..])),
+        access_list: None,
+        transaction_type: None,
+        blobs: None,
+        blob_hashes: None,
+    };
+    help_test_method_invocation_serde(MethodInvocation::CreateAccessList(
+        tx.clone(),
+        Some(BlockSpec::latest()),
+    ));
+    help_test_method_invocation_serde_with_expected(
+        MethodInvocation::CreateAccessList(tx.clone(), None),
+        MethodInvocation::CreateAccessList(tx, Some(BlockSpec::latest())),
+    );
+}
+
 #[test]
 fn test_serde_eth_estimate_gas() {
     let tx = CallRequest {
@@ -82,10 +125,31 @@ fn test_serde_eth_estimate_gas() {
     help_test_method_invocation_serde(MethodInvocation::EstimateGas(
         tx.clone(),
         Some(BlockSpec::latest()),
+        None,
+        None,
+    ));
+    help_test_method_invocation_serde(MethodInvocation::EstimateGas(
+        tx.clone(),
+        Some(BlockSpec::latest()),
+        Some(StateOverrideOptions::from([(
+            Address::from(U160::from(1)),
+            AccountOverrideOptions {
+                balance: Some(U256::from(1_000_000)),
+                nonce: None,
+                code: None,
+                storage: None,
+                storage_diff: None,
+            },
+        )])),
+        Some(BlockOverrideOptions {
+            gas_limit: Some(30_000_000),
+            base_fee: Some(U256::from(7)),
+            ..BlockOverrideOptions::default()
+        }),
     ));
     help_test_method_invocation_serde_with_expected(
-        MethodInvocation::EstimateGas(tx.clone(), None),
-        MethodInvocation::EstimateGas(tx, Some(BlockSpec::pending())),
+        MethodInvocation::EstimateGas(tx.clone(), None, None, None),
+        MethodInvocation::EstimateGas(tx, Some(BlockSpec::pending()), None, None),
     );
 }
 
@@ -139,6 +203,12 @@ fn test_serde_eth_get_block_by_hash() {
     ));
 }
 
+#[test]
+fn test_serde_eth_get_block_receipts() {
+    help_test_method_invocation_serde(MethodInvocation::GetBlockReceipts(BlockSpec::Number(100)));
+    help_test_method_invocation_serde(MethodInvocation::GetBlockReceipts(BlockSpec::latest()));
+}
+
 #[test]
 fn test_serde_eth_get_transaction_count() {
     help_test_method_invocation_serde(MethodInvocation::GetTransactionCount(
@@ -223,6 +293,23 @@ fn test_serde_eth_get_logs_by_block_hash() {
     }));
 }
 
+#[test]
+fn test_serde_eth_get_proof() {
+    help_test_method_invocation_serde(MethodInvocation::GetProof(
+        Address::from(U160::from(1)),
+        vec![U256::ZERO, U256::from(1)],
+        Some(BlockSpec::latest()),
+    ));
+    help_test_method_invocation_serde_with_expected(
+        MethodInvocation::GetProof(Address::from(U160::from(1)), Vec::new(), None),
+        MethodInvocation::GetProof(
+            Address::from(U160::from(1)),
+            Vec::new(),
+            Some(BlockSpec::latest()),
+        ),
+    );
+}
+
 #[test]
 fn test_serde_eth_get_storage_at() {
     help_test_method_invocation_serde(MethodInvocation::GetStorageAt(
@@ -386,6 +473,7 @@ impl_serde_eth_subscribe_tests! {
     logs => SubscriptionType::Logs,
     new_pending_transactions => SubscriptionType::NewPendingTransactions,
     new_heads => SubscriptionType::NewHeads,
+    dropped_transactions => SubscriptionType::DroppedTransactions,
 }
 
 #[test]
@@ -472,6 +560,21 @@ fn test_serde_web3_sha3() {
     help_test_method_invocation_serde(MethodInvocation::Web3Sha3(Bytes::from(&b"whatever"[..])));
 }
 
+#[test]
+fn test_serde_txpool_content() {
+    help_test_method_invocation_serde(MethodInvocation::TxPoolContent(()));
+}
+
+#[test]
+fn test_serde_txpool_inspect() {
+    help_test_method_invocation_serde(MethodInvocation::TxPoolInspect(()));
+}
+
+#[test]
+fn test_serde_txpool_status() {
+    help_test_method_invocation_serde(MethodInvocation::TxPoolStatus(()));
+}
+
 #[test]
 fn test_evm_set_automine() {
     help_test_method_invocation_serde(MethodInvocation::EvmSetAutomine(false));
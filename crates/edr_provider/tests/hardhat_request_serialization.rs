@@ -1,9 +1,24 @@
 mod common;
 
-use edr_eth::{Address, Bytes, B256, U256};
-use edr_evm::alloy_primitives::U160;
+use std::collections::BTreeMap;
+
+use edr_eth::{
+    remote::{BlockSpec, BlockTag},
+    transaction::EthTransactionRequest,
+    withdrawal::Withdrawal,
+    Address, Bytes, B256, U256,
+};
+use edr_evm::{
+    alloy_primitives::U160,
+    state::{AccountDump, StateDump},
+    MineOrdering,
+};
 use edr_provider::{
-    hardhat_rpc_types::{CompilerInput, CompilerOutput, ForkConfig, ResetProviderConfig},
+    hardhat_rpc_types::{
+        AccountState, CompilerInput, CompilerOutput, DumpStateOptions, ForkConfig,
+        MineBlockOptions, MineBlockTransaction, ResetProviderConfig, SerializedBlockContext,
+        SerializedState, SnapshotSelector, SERIALIZED_STATE_VERSION,
+    },
     MethodInvocation,
 };
 
@@ -44,16 +59,53 @@ fn serde_hardhat_compiler() {
     }
 }
 
+#[test]
+fn serde_hardhat_auto_impersonate_account() {
+    help_test_method_invocation_serde(MethodInvocation::AutoImpersonateAccount(true));
+    help_test_method_invocation_serde(MethodInvocation::AutoImpersonateAccount(false));
+}
+
+#[test]
+fn serde_hardhat_delete_account() {
+    help_test_method_invocation_serde(MethodInvocation::DeleteAccount(Address::from(U160::from(
+        1,
+    ))));
+}
+
+#[test]
+fn serde_hardhat_delete_snapshot() {
+    help_test_method_invocation_serde(MethodInvocation::DeleteSnapshot(SnapshotSelector::Id(1)));
+    help_test_method_invocation_serde(MethodInvocation::DeleteSnapshot(SnapshotSelector::Name(
+        String::from("fixture"),
+    )));
+}
+
 #[test]
 fn serde_hardhat_drop_transaction() {
     help_test_method_invocation_serde(MethodInvocation::DropTransaction(B256::from(U256::from(1))));
 }
 
+#[test]
+fn serde_hardhat_dump_state() {
+    help_test_method_invocation_serde(MethodInvocation::DumpState(None));
+    help_test_method_invocation_serde(MethodInvocation::DumpState(Some(DumpStateOptions {
+        only_changes: true,
+    })));
+}
+
 #[test]
 fn serde_hardhat_get_automine() {
     help_test_method_invocation_serde(MethodInvocation::GetAutomine(()));
 }
 
+#[test]
+fn serde_hardhat_get_blob_sidecars() {
+    help_test_method_invocation_serde(MethodInvocation::GetBlobSidecars(BlockSpec::Tag(
+        BlockTag::Latest,
+    )));
+    help_test_method_invocation_serde(MethodInvocation::GetBlobSidecars(BlockSpec::Number(1)));
+}
+
 #[test]
 fn serde_hardhat_get_stack_trace_failures_count() {
     help_test_method_invocation_serde(MethodInvocation::GetStackTraceFailuresCount(()));
@@ -71,6 +123,37 @@ fn serde_hardhat_interval_mine() {
     help_test_method_invocation_serde(MethodInvocation::IntervalMine(()));
 }
 
+#[test]
+fn serde_hardhat_list_snapshots() {
+    help_test_method_invocation_serde(MethodInvocation::ListSnapshots(()));
+}
+
+#[test]
+fn serde_hardhat_load_state() {
+    let address = Address::random();
+    let account = AccountDump {
+        balance: U256::from(1),
+        nonce: 2,
+        code: Some(Bytes::from_static(&[0x60, 0x00])),
+        storage: [(U256::from(3), U256::from(4))].into_iter().collect(),
+    };
+
+    help_test_method_invocation_serde(MethodInvocation::LoadState(SerializedState {
+        version: SERIALIZED_STATE_VERSION,
+        block: SerializedBlockContext {
+            number: 10,
+            timestamp: 1_700_000_000,
+            coinbase: Address::random(),
+            gas_limit: 30_000_000,
+        },
+        fork: None,
+        state: StateDump {
+            accounts: [(address, account)].into_iter().collect(),
+            ..StateDump::default()
+        },
+    }));
+}
+
 #[test]
 fn serde_hardhat_metadata() {
     help_test_method_invocation_serde(MethodInvocation::Metadata(()));
@@ -89,6 +172,62 @@ fn serde_hardhat_mine() {
     assert_eq!(MethodInvocation::Mine(None, None), deserialized);
 }
 
+#[test]
+fn serde_hardhat_mine_block() {
+    help_test_method_invocation_serde(MethodInvocation::MineBlock(
+        vec![
+            MineBlockTransaction::Raw(Bytes::from(&b"whatever"[..])),
+            MineBlockTransaction::Request(EthTransactionRequest {
+                from: Address::random(),
+                to: Some(Address::random()),
+                value: Some(U256::from(1)),
+                nonce: Some(3),
+                ..EthTransactionRequest::default()
+            }),
+        ],
+        None,
+    ));
+    help_test_method_invocation_serde(MethodInvocation::MineBlock(
+        Vec::new(),
+        Some(MineBlockOptions {
+            coinbase: Some(Address::random()),
+            timestamp: Some(1_700_000_000),
+            gas_limit: Some(30_000_000),
+            base_fee_per_gas: Some(U256::from(7)),
+            mix_hash: Some(B256::random()),
+            extra_data: Some(Bytes::from(&b"extra"[..])),
+            ..MineBlockOptions::default()
+        }),
+    ));
+}
+
+#[test]
+fn serde_hardhat_queue_withdrawals() {
+    help_test_method_invocation_serde(MethodInvocation::QueueWithdrawals(vec![
+        Withdrawal {
+            index: 0,
+            validator_index: 1,
+            address: Address::random(),
+            amount: U256::from(1_000_000_000),
+        },
+        Withdrawal {
+            index: 1,
+            validator_index: 2,
+            address: Address::random(),
+            amount: U256::from(32),
+        },
+    ]));
+}
+
+#[test]
+fn serde_hardhat_reorg() {
+    help_test_method_invocation_serde(MethodInvocation::Reorg(1, None));
+    help_test_method_invocation_serde(MethodInvocation::Reorg(
+        2,
+        Some(vec![Bytes::from_static(b"whatever")]),
+    ));
+}
+
 #[test]
 fn serde_hardhat_reset() {
     help_test_method_invocation_serde(MethodInvocation::Reset(Some(ResetProviderConfig {
@@ -100,6 +239,31 @@ fn serde_hardhat_reset() {
     })));
 }
 
+#[test]
+fn serde_hardhat_revert_to_snapshot() {
+    help_test_method_invocation_serde(MethodInvocation::RevertToSnapshot(SnapshotSelector::Id(1)));
+    help_test_method_invocation_serde(MethodInvocation::RevertToSnapshot(SnapshotSelector::Name(
+        String::from("fixture"),
+    )));
+}
+
+#[test]
+fn serde_hardhat_set_account() {
+    help_test_method_invocation_serde(MethodInvocation::SetAccount(
+        Address::from(U160::from(1)),
+        AccountState {
+            balance: Some(U256::from(1000)),
+            nonce: Some(2),
+            code: Some(Bytes::from_static(&[0x60, 0x00])),
+            storage: Some(BTreeMap::from([(U256::from(1), U256::from(10))])),
+        },
+    ));
+    help_test_method_invocation_serde(MethodInvocation::SetAccount(
+        Address::from(U160::from(1)),
+        AccountState::default(),
+    ));
+}
+
 #[test]
 fn serde_hardhat_set_balance() {
     help_test_method_invocation_serde(MethodInvocation::SetBalance(
@@ -126,6 +290,62 @@ fn serde_hardhat_set_logging_enabled() {
     help_test_method_invocation_serde(MethodInvocation::SetLoggingEnabled(true));
 }
 
+#[test]
+fn serde_hardhat_set_mempool_ordering() {
+    help_test_method_invocation_serde(MethodInvocation::SetMempoolOrdering(MineOrdering::Fifo));
+    help_test_method_invocation_serde(MethodInvocation::SetMempoolOrdering(
+        MineOrdering::ReversePriority,
+    ));
+    help_test_method_invocation_serde(MethodInvocation::SetMempoolOrdering(MineOrdering::Random(
+        42,
+    )));
+    help_test_method_invocation_serde(MethodInvocation::SetMempoolOrdering(
+        MineOrdering::Explicit(vec![B256::random()]),
+    ));
+}
+
+#[test]
+fn serde_hardhat_set_mempool_ordering_names() {
+    // Orderings are serialized by their variant name
+    assert_eq!(serde_json::json!(MineOrdering::Fifo), "Fifo");
+    assert_eq!(serde_json::json!(MineOrdering::Priority), "Priority");
+    assert_eq!(
+        serde_json::json!(MineOrdering::ReversePriority),
+        "ReversePriority"
+    );
+
+    // The seed is a hex-encoded quantity
+    assert_eq!(
+        serde_json::json!(MineOrdering::Random(42)),
+        serde_json::json!({ "Random": "0x2a" })
+    );
+
+    // All orderings accept their camelCase names
+    let aliases = [
+        (serde_json::json!("fifo"), MineOrdering::Fifo),
+        (serde_json::json!("priority"), MineOrdering::Priority),
+        (
+            serde_json::json!("reversePriority"),
+            MineOrdering::ReversePriority,
+        ),
+        (
+            serde_json::json!({ "random": "0x2a" }),
+            MineOrdering::Random(42),
+        ),
+        (
+            serde_json::json!({ "explicit": [B256::ZERO] }),
+            MineOrdering::Explicit(vec![B256::ZERO]),
+        ),
+    ];
+
+    for (value, expected) in aliases {
+        assert_eq!(
+            serde_json::from_value::<MineOrdering>(value).unwrap(),
+            expected
+        );
+    }
+}
+
 #[test]
 fn serde_hardhat_set_min_gas_price() {
     help_test_method_invocation_serde(MethodInvocation::SetMinGasPrice(U256::from(1)));
@@ -136,6 +356,26 @@ fn serde_hardhat_set_next_block_base_fee_per_gas() {
     help_test_method_invocation_serde(MethodInvocation::SetNextBlockBaseFeePerGas(U256::from(1)));
 }
 
+#[test]
+fn serde_hardhat_set_next_block_options() {
+    help_test_method_invocation_serde(MethodInvocation::SetNextBlockOptions(
+        MineBlockOptions::default(),
+    ));
+    help_test_method_invocation_serde(MethodInvocation::SetNextBlockOptions(MineBlockOptions {
+        difficulty: Some(U256::from(1)),
+        mix_hash: Some(B256::random()),
+        extra_data: Some(Bytes::from(&b"extra"[..])),
+        parent_beacon_block_root: Some(B256::random()),
+        withdrawals: Some(vec![Withdrawal {
+            index: 0,
+            validator_index: 1,
+            address: Address::random(),
+            amount: U256::from(1_000_000_000),
+        }]),
+        ..MineBlockOptions::default()
+    }));
+}
+
 #[test]
 fn serde_hardhat_set_nonce() {
     help_test_method_invocation_serde(MethodInvocation::SetNonce(Address::random(), 1u64));
@@ -146,6 +386,14 @@ fn serde_hardhat_set_prev_randao() {
     help_test_method_invocation_serde(MethodInvocation::SetPrevRandao(B256::random()));
 }
 
+#[test]
+fn serde_hardhat_set_storage() {
+    help_test_method_invocation_serde(MethodInvocation::SetStorage(
+        Address::random(),
+        BTreeMap::from([(U256::ZERO, U256::MAX), (U256::from(1), U256::from(2))]),
+    ));
+}
+
 #[test]
 fn serde_hardhat_set_storage_at() {
     help_test_method_invocation_serde(MethodInvocation::SetStorageAt(
@@ -155,6 +403,12 @@ fn serde_hardhat_set_storage_at() {
     ));
 }
 
+#[test]
+fn serde_hardhat_snapshot() {
+    help_test_method_invocation_serde(MethodInvocation::Snapshot(None));
+    help_test_method_invocation_serde(MethodInvocation::Snapshot(Some(String::from("fixture"))));
+}
+
 #[test]
 fn serde_hardhat_stop_impersonating_account() {
     help_test_method_invocation_serde(MethodInvocation::StopImpersonatingAccount(
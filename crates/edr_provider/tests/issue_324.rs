@@ -39,6 +39,7 @@ async fn issue_324() -> anyhow::Result<()> {
         },
         None,
         None,
+        None,
     )))?;
 
     assert_eq!(
@@ -54,6 +55,7 @@ async fn issue_324() -> anyhow::Result<()> {
         },
         None,
         None,
+        None,
     )))?;
 
     assert_eq!(
@@ -83,6 +85,7 @@ async fn issue_324() -> anyhow::Result<()> {
         },
         None,
         None,
+        None,
     )))?;
 
     assert_eq!(new_x.result, expected_x);
@@ -109,6 +112,7 @@ async fn issue_324() -> anyhow::Result<()> {
         },
         None,
         None,
+        None,
     )))?;
 
     assert_eq!(new_y.result, expected_y);
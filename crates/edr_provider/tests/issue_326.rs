@@ -61,6 +61,8 @@ async fn issue_326() -> anyhow::Result<()> {
             ..CallRequest::default()
         },
         None,
+        None,
+        None,
     )))?;
 
     Ok(())
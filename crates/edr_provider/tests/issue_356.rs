@@ -47,6 +47,7 @@ async fn issue_324() -> anyhow::Result<()> {
         },
         None,
         None,
+        None,
     )))?;
 
     assert_eq!(
@@ -0,0 +1,76 @@
+mod common;
+
+use edr_eth::{
+    remote::{eth::CallRequest, BlockSpec, BlockTag},
+    Address, Bytes, B256, U256,
+};
+use edr_evm::{alloy_primitives::U160, trace::parity::TraceType};
+use edr_provider::{MethodInvocation, TraceFilter};
+
+use crate::common::{
+    help_test_method_invocation_serde, help_test_method_invocation_serde_with_expected,
+};
+
+#[test]
+fn serde_trace_block() {
+    help_test_method_invocation_serde(MethodInvocation::TraceBlock(BlockSpec::Number(100)));
+    help_test_method_invocation_serde(MethodInvocation::TraceBlock(BlockSpec::Tag(
+        BlockTag::Latest,
+    )));
+}
+
+#[test]
+fn serde_trace_call() {
+    let call = CallRequest {
+        from: Some(Address::from(U160::from(1))),
+        to: Some(Address::from(U160::from(2))),
+        data: Some(Bytes::from(&b"whatever"[..])),
+        ..CallRequest::default()
+    };
+
+    help_test_method_invocation_serde(MethodInvocation::TraceCall(
+        call.clone(),
+        vec![TraceType::Trace, TraceType::VmTrace, TraceType::StateDiff],
+        Some(BlockSpec::Number(100)),
+    ));
+    help_test_method_invocation_serde_with_expected(
+        MethodInvocation::TraceCall(call.clone(), vec![TraceType::Trace], None),
+        MethodInvocation::TraceCall(call, vec![TraceType::Trace], Some(BlockSpec::latest())),
+    );
+}
+
+#[test]
+fn serde_trace_filter() {
+    help_test_method_invocation_serde(MethodInvocation::TraceFilter(TraceFilter::default()));
+    help_test_method_invocation_serde(MethodInvocation::TraceFilter(TraceFilter {
+        from_block: Some(BlockSpec::Number(1)),
+        to_block: Some(BlockSpec::Tag(BlockTag::Latest)),
+        from_address: vec![Address::from(U160::from(1))],
+        to_address: vec![Address::from(U160::from(2))],
+        after: Some(1),
+        count: Some(10),
+    }));
+}
+
+#[test]
+fn serde_trace_replay_transaction() {
+    help_test_method_invocation_serde(MethodInvocation::TraceReplayTransaction(
+        B256::from(U256::from(1)),
+        vec![TraceType::VmTrace],
+    ));
+}
+
+#[test]
+fn serde_trace_transaction() {
+    help_test_method_invocation_serde(MethodInvocation::TraceTransaction(B256::from(U256::from(
+        1,
+    ))));
+}
+
+#[test]
+fn serde_trace_type() {
+    assert_eq!(
+        serde_json::json!([TraceType::Trace, TraceType::VmTrace, TraceType::StateDiff]),
+        serde_json::json!(["trace", "vmTrace", "stateDiff"])
+    );
+}
@@ -6,6 +6,16 @@ export const rpcDebugTracingConfig = optionalOrNullable(
   t.type(
     {
       tracer: optionalOrNullable(t.string),
+      tracerConfig: optionalOrNullable(
+        t.type(
+          {
+            onlyTopCall: optionalOrNullable(t.boolean),
+            withLog: optionalOrNullable(t.boolean),
+            diffMode: optionalOrNullable(t.boolean),
+          },
+          "RpcDebugTracerConfig"
+        )
+      ),
       disableStorage: optionalOrNullable(t.boolean),
       disableMemory: optionalOrNullable(t.boolean),
       disableStack: optionalOrNullable(t.boolean),
@@ -36,6 +36,7 @@ import {
   rpcCompilerInput,
   rpcCompilerOutput,
 } from "../../core/jsonrpc/types/input/solc";
+import { RpcDebugTracingConfig } from "../../core/jsonrpc/types/input/debugTraceTransaction";
 import { validateParams } from "../../core/jsonrpc/types/input/validation";
 import {
   InvalidArgumentsError,
@@ -458,8 +459,9 @@ export class EdrProviderWrapper
     if (args.method === "web3_clientVersion") {
       return clientVersion(response.result);
     } else if (
-      args.method === "debug_traceTransaction" ||
-      args.method === "debug_traceCall"
+      (args.method === "debug_traceTransaction" ||
+        args.method === "debug_traceCall") &&
+      usesDefaultTracer(args.method, params)
     ) {
       return edrRpcDebugTraceToHardhat(response.result);
     } else {
@@ -651,3 +653,18 @@ async function makeTracingConfig(
     }
   }
 }
+
+/**
+ * Whether a `debug_traceTransaction` or `debug_traceCall` request uses the
+ * default struct logger. Other tracers' results are returned as-is.
+ */
+function usesDefaultTracer(
+  method: "debug_traceTransaction" | "debug_traceCall",
+  params: unknown[]
+): boolean {
+  const configIndex = method === "debug_traceTransaction" ? 1 : 2;
+  const config = params[configIndex] as RpcDebugTracingConfig;
+  const tracer = config?.tracer;
+
+  return tracer === undefined || tracer === null || tracer === "default";
+}
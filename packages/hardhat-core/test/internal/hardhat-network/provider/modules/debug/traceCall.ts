@@ -133,7 +133,7 @@ describe("Debug module", function () {
                 tracer: "unsupportedTracer",
               },
             ],
-            "Hardhat currently only supports the default tracer, so no tracer parameter should be passed."
+            "Hardhat currently only supports the default tracer, `callTracer` and `prestateTracer`, so no other tracer parameter should be passed."
           );
         });
       });
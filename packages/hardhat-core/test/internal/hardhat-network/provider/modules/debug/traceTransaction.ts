@@ -71,6 +71,40 @@ describe("Debug module", function () {
           });
         });
 
+        it("Should accept the default tracer when it's passed explicitly", async function () {
+          const txHash = await sendDummyTransaction(this.provider, 0, {
+            from: DEFAULT_ACCOUNTS_ADDRESSES[1],
+          });
+
+          const trace: RpcDebugTraceOutput = await this.provider.send(
+            "debug_traceTransaction",
+            [txHash, { tracer: "default" }]
+          );
+          assert.deepEqual(trace, {
+            gas: 21_000,
+            failed: false,
+            returnValue: "",
+            structLogs: [],
+          });
+        });
+
+        it("Should return the callTracer's result without converting it", async function () {
+          const txHash = await sendDummyTransaction(this.provider, 0, {
+            from: DEFAULT_ACCOUNTS_ADDRESSES[1],
+          });
+
+          const trace = await this.provider.send("debug_traceTransaction", [
+            txHash,
+            { tracer: "callTracer", tracerConfig: { onlyTopCall: true } },
+          ]);
+          assert.equal(trace.type, "CALL");
+          assert.equal(
+            trace.from.toLowerCase(),
+            DEFAULT_ACCOUNTS_ADDRESSES[1].toLowerCase()
+          );
+          assert.isUndefined(trace.structLogs);
+        });
+
         it("Should throw an error when the value passed as tracer is not supported", async function () {
           await assertInvalidArgumentsError(
             this.provider,
@@ -81,7 +115,7 @@ describe("Debug module", function () {
                 tracer: "unsupportedTracer",
               },
             ],
-            "Hardhat currently only supports the default tracer, so no tracer parameter should be passed."
+            "Hardhat currently only supports the default tracer, `callTracer` and `prestateTracer`, so no other tracer parameter should be passed."
           );
         });
 
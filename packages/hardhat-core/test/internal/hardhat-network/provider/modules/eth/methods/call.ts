@@ -1603,8 +1603,8 @@ contract C {
           assert.equal(await getChainIdFromContract(this.provider), chainId);
         });
 
-        it("should reject blob transactions", async function () {
-          await assertInvalidInputError(
+        it("should reject blob transactions before Cancun", async function () {
+          await assertInvalidArgumentsError(
             this.provider,
             "eth_call",
             [
@@ -1617,7 +1617,7 @@ contract C {
                 ],
               },
             ],
-            "An EIP-4844 (shard blob) transaction was received, but Hardhat doesn't have support for them yet."
+            "EIP-4844 blob params (blobs or blobHashes) received but they are not supported by the current hardfork"
           );
         });
 
@@ -7,7 +7,10 @@ import {
   rpcQuantityToNumber,
 } from "../../../../../../../src/internal/core/jsonrpc/types/base-types";
 import { workaroundWindowsCiFailures } from "../../../../../../utils/workaround-windows-ci-failures";
-import { assertInvalidInputError } from "../../../../helpers/assertions";
+import {
+  assertInvalidArgumentsError,
+  assertInvalidInputError,
+} from "../../../../helpers/assertions";
 import {
   EXAMPLE_CONTRACT,
   EXAMPLE_REVERT_CONTRACT,
@@ -200,8 +203,8 @@ describe("Eth module", function () {
           assert.isTrue(BigInt(estimation) <= 100_000n);
         });
 
-        it("should reject blob transactions", async function () {
-          await assertInvalidInputError(
+        it("should reject blob transactions before Cancun", async function () {
+          await assertInvalidArgumentsError(
             this.provider,
             "eth_estimateGas",
             [
@@ -214,7 +217,7 @@ describe("Eth module", function () {
                 ],
               },
             ],
-            "An EIP-4844 (shard blob) transaction was received, but Hardhat doesn't have support for them yet."
+            "EIP-4844 blob params (blobs or blobHashes) received but they are not supported by the current hardfork"
           );
         });
 
@@ -109,16 +109,16 @@ describe("Eth module", function () {
           assertReceiptMatchesGethOne(receipt, receiptFromGeth, 1);
         });
 
-        it("should reject blob transactions", async function () {
+        it("should reject blob transactions without blobs", async function () {
           // blob tx signed with the private key of the first default account
           const rawBlobTx =
             "0x03f88380808080809400000000000000000000000000000000000000108080c080e1a0000000000000000000000000000000000000001012345678901234567890123401a0f3f7e5408804e3a0e3c4ac30a4f14b2995656a02d8b0279d7d48044d3cdf05e6a004e7606fef78d5221916053b3ec8a5fefddaa8a62ac6440f24a7c860ca25aa9f";
 
-          await assertInvalidInputError(
+          await assertInvalidArgumentsError(
             this.provider,
             "eth_sendRawTransaction",
             [rawBlobTx],
-            "An EIP-4844 (shard blob) transaction was received, but Hardhat doesn't have support for them yet."
+            "EIP-4844 transactions need to include their blobs, commitments, and proofs"
           );
         });
 
@@ -11,6 +11,7 @@ import {
 import { InvalidInputError } from "../../../../../../../src/internal/core/providers/errors";
 import { workaroundWindowsCiFailures } from "../../../../../../utils/workaround-windows-ci-failures";
 import {
+  assertInvalidArgumentsError,
   assertInvalidInputError,
   assertReceiptMatchesGethOne,
   assertTransactionFailure,
@@ -1144,8 +1145,8 @@ describe("Eth module", function () {
           assert.equal(contractBlockNumber, blockNumberBeforeTx + 1);
         });
 
-        it("should reject blob transactions", async function () {
-          await assertInvalidInputError(
+        it("should reject blob transactions before Cancun", async function () {
+          await assertInvalidArgumentsError(
             this.provider,
             "eth_sendTransaction",
             [
@@ -1158,7 +1159,7 @@ describe("Eth module", function () {
                 ],
               },
             ],
-            "An EIP-4844 (shard blob) transaction was received, but Hardhat doesn't have support for them yet."
+            "EIP-4844 blob params (blobs or blobHashes) received but they are not supported by the current hardfork"
           );
         });
       });